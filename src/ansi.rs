@@ -111,6 +111,14 @@ impl<'a> Iterator for TextIterator<'a> {
     }
 }
 
+/// ANSIエスケープシーケンスを取り除いた文字列を返す
+pub fn strip_ansi(s: &str) -> String {
+    s.ansi_parse()
+        .filter(|text| text.ty == AnsiEscapeSequence::Chars)
+        .map(|text| text.chars)
+        .collect()
+}
+
 #[cfg(test)]
 mod parse_test {
     use super::AnsiEscapeSequence::*;
@@ -198,4 +206,22 @@ mod parse_test {
             assert_eq!(iter.next(), None);
         }
     }
+
+    mod strip_ansi {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        #[test]
+        fn text_only() {
+            assert_eq!(strip_ansi("text"), "text");
+        }
+
+        #[test]
+        fn remove_escape_sequences() {
+            assert_eq!(
+                strip_ansi("\x1b[31mred\x1b[39m and \x1b[1;32mgreen\x1b[0m"),
+                "red and green"
+            );
+        }
+    }
 }
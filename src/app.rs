@@ -6,11 +6,13 @@ use crossbeam::channel::{bounded, Receiver, Sender};
 use crate::{
     cmd::Command,
     config::{
+        keybindings::Keymap,
         theme::{LabelColumnConfig, PodHighlightConfig},
         Config,
     },
     features::{
         api_resources::kube::ApiConfig,
+        component_id::{NODE_COLUMNS_DIALOG_ID, POD_COLUMNS_DIALOG_ID},
         config::{
             ConfigColumn,
             ConfigColumnSpec,
@@ -28,10 +30,27 @@ use crate::{
         },
         node::{NodeColumn, NodeColumnSpec, NodeColumns, NodeLabelColumn},
         pod::{kube::PodHighlightRule, PodColumn, PodColumnSpec, PodColumns, PodLabelColumn},
+        tab::TabColumns,
+        workload::{
+            WorkloadColumn,
+            WorkloadColumnSpec,
+            WorkloadColumns,
+            WorkloadLabelColumn,
+            DEFAULT_WORKLOAD_COLUMNS,
+        },
     },
     logger,
     message::Message,
-    workers::{kube::YamlConfig, ApisConfig, KubeWorker, Render, Tick, UserInput},
+    workers::{
+        kube::YamlConfig,
+        ApisConfig,
+        InputSuspender,
+        KubeWorker,
+        Render,
+        RenderConfig,
+        Tick,
+        UserInput,
+    },
 };
 
 pub struct App;
@@ -39,8 +58,24 @@ pub struct App;
 impl App {
     pub fn run(cmd: Command, config: Config) -> Result<()> {
         let split_direction = cmd.split_direction();
+        let read_only = cmd.read_only || config.read_only;
         let mut kube_worker_config = cmd.kube_worker_config();
 
+        let state_option = cmd.state_option();
+        let mut state = state_option.load();
+
+        // CLI引数で指定された列は前回終了時の列より優先する
+        if cmd.pod_columns.is_some() || cmd.pod_columns_preset.is_some() {
+            state.columns.remove(POD_COLUMNS_DIALOG_ID);
+        }
+
+        if cmd.node_columns.is_some() || cmd.node_columns_preset.is_some() {
+            state.columns.remove(NODE_COLUMNS_DIALOG_ID);
+        }
+
+        kube_worker_config.restored_context = state.context.clone();
+        kube_worker_config.restored_namespaces = state.namespaces.clone();
+
         let (tx_input, rx_main): (Sender<Message>, Receiver<Message>) = bounded(128);
         let (tx_main, rx_kube): (Sender<Message>, Receiver<Message>) = bounded(256);
         let tx_kube = tx_input.clone();
@@ -48,7 +83,13 @@ impl App {
 
         let (tx_shutdown, rx_shutdown) = bounded::<Result<()>>(1);
 
-        let user_input = UserInput::new(tx_input.clone(), tx_shutdown.clone());
+        let input_suspender = InputSuspender::default();
+
+        let user_input = UserInput::new(
+            tx_input.clone(),
+            tx_shutdown.clone(),
+            input_suspender.clone(),
+        );
 
         kube_worker_config.pod_config.pod_highlight_rules =
             build_pod_highlight_rules(&config.theme.pod.highlights);
@@ -61,6 +102,9 @@ impl App {
         let network_label_registry =
             build_network_label_registry(&config.theme.network.label_columns)?;
         let default_network_columns = build_default_network_columns(&network_label_registry);
+        let workload_label_registry =
+            build_workload_label_registry(&config.theme.workload.label_columns)?;
+        let default_workload_columns = build_default_workload_columns(&workload_label_registry);
 
         kube_worker_config.pod_config.default_columns = build_pod_columns(
             cmd.pod_columns,
@@ -80,11 +124,14 @@ impl App {
 
         kube_worker_config.default_config_columns = default_config_columns.clone();
         kube_worker_config.default_network_columns = default_network_columns.clone();
+        kube_worker_config.default_workload_columns = default_workload_columns.clone();
 
         kube_worker_config.event_config = EventConfig::from(config.theme.clone());
         kube_worker_config.api_config = ApiConfig::from(config.theme.clone());
         kube_worker_config.apis_config = ApisConfig::from(config.theme.clone());
         kube_worker_config.yaml_config = YamlConfig::from(config.theme.clone());
+        kube_worker_config.mask_secret_values = config.secret.mask_values;
+        kube_worker_config.read_only = read_only;
 
         kube_worker_config.fallback_namespaces =
             config.fallback_namespaces.and_then(|namespaces| {
@@ -100,6 +147,8 @@ impl App {
                 }
             });
 
+        let keymap = Keymap::new(&config.keybindings)?;
+
         let default_pod_columns = kube_worker_config.pod_config.default_columns.clone();
         let default_node_columns = kube_worker_config.node_config.default_columns.clone();
 
@@ -116,22 +165,43 @@ impl App {
             tx_shutdown.clone(),
         );
 
+        let render_config = RenderConfig {
+            split_direction,
+            pod_columns: TabColumns {
+                default: default_pod_columns,
+                labels: pod_label_registry,
+            },
+            node_columns: TabColumns {
+                default: default_node_columns,
+                labels: node_label_registry,
+            },
+            config_columns: TabColumns {
+                default: default_config_columns,
+                labels: config_label_registry,
+            },
+            network_columns: TabColumns {
+                default: default_network_columns,
+                labels: network_label_registry,
+            },
+            workload_columns: TabColumns {
+                default: default_workload_columns,
+                labels: workload_label_registry,
+            },
+            theme: config.theme.clone(),
+            clipboard_mode: cmd.clipboard,
+            logging: config.logging.clone(),
+            read_only,
+            keymap,
+        };
+
         let render = Render::new(
             tx_main.clone(),
             rx_main.clone(),
             tx_shutdown.clone(),
-            split_direction,
-            default_pod_columns,
-            default_node_columns,
-            default_config_columns,
-            default_network_columns,
-            pod_label_registry,
-            node_label_registry,
-            config_label_registry,
-            network_label_registry,
-            config.theme.clone(),
-            cmd.clipboard,
-            config.logging.max_lines,
+            render_config,
+            input_suspender,
+            state,
+            state_option.path,
         );
 
         logger!(info, "app start");
@@ -490,10 +560,66 @@ fn build_default_network_columns(registry: &[NetworkLabelColumn]) -> NetworkColu
     NetworkColumns::new(specs).ensure_required().dedup_columns()
 }
 
+/// Build the label-column registry for Workload from config, erroring on
+/// builtin name collisions or duplicate label headers.
+fn build_workload_label_registry(
+    label_columns: &Option<Vec<LabelColumnConfig>>,
+) -> Result<Vec<WorkloadLabelColumn>> {
+    let mut out: Vec<WorkloadLabelColumn> = Vec::new();
+    if let Some(defs) = label_columns {
+        for def in defs {
+            let norm = WorkloadColumn::normalize_column(&def.name);
+            if WorkloadColumn::from_str(&norm).is_ok() {
+                anyhow::bail!(
+                    "label_columns name '{}' collides with a builtin column name",
+                    def.name
+                );
+            }
+            if let Some(existing) = out
+                .iter()
+                .find(|lc| WorkloadColumn::normalize_column(&lc.name) == norm)
+            {
+                anyhow::bail!(
+                    "label_columns name '{}' has the same header as previously defined '{}'",
+                    def.name,
+                    existing.name
+                );
+            }
+            out.push(WorkloadLabelColumn {
+                name: def.name.clone(),
+                key: def.label.clone(),
+                header: def.name.to_uppercase(),
+            });
+        }
+    }
+    Ok(out)
+}
+
+/// Build the default Workload columns for startup: all builtin defaults
+/// followed by every label column registered in `registry`. `ensure_required`
+/// and `dedup_columns` guarantee KIND/NAME are present and there are no
+/// duplicates.
+fn build_default_workload_columns(registry: &[WorkloadLabelColumn]) -> WorkloadColumns {
+    let mut specs: Vec<WorkloadColumnSpec> = DEFAULT_WORKLOAD_COLUMNS
+        .iter()
+        .copied()
+        .map(WorkloadColumnSpec::Builtin)
+        .collect();
+    for lc in registry {
+        specs.push(WorkloadColumnSpec::Label {
+            key: lc.key.clone(),
+            header: lc.header.clone(),
+        });
+    }
+    WorkloadColumns::new(specs)
+        .ensure_required()
+        .dedup_columns()
+}
+
 /// Resolve column names (builtin or registry label, or "full") into NodeColumns.
 fn resolve_columns(names: &[String], registry: &[NodeLabelColumn]) -> Result<NodeColumns> {
     if names.len() == 1 && NodeColumn::normalize_column(&names[0]) == "full" {
-        return Ok(NodeColumns::from_builtins(NodeColumn::all()));
+        return Ok(NodeColumns::from_builtins(NodeColumn::full()));
     }
 
     let mut specs = Vec::new();
@@ -644,7 +770,7 @@ mod node_columns_tests {
     }
 
     #[test]
-    fn full_returns_all_builtins() {
+    fn full_returns_all_builtins_except_metrics() {
         let cols = build_node_columns(
             Some(vec!["full".to_string()]),
             None,
@@ -654,7 +780,10 @@ mod node_columns_tests {
         )
         .unwrap()
         .unwrap();
-        assert_eq!(cols.specs().len(), NodeColumn::all().count());
+        assert_eq!(cols.specs().len(), NodeColumn::full().count());
+        assert!(!cols
+            .specs()
+            .contains(&NodeColumnSpec::Builtin(NodeColumn::Cpu)));
     }
 }
 
@@ -80,7 +80,10 @@ mod tests {
     fn フルを渡すと全カラムを返す() {
         let input = "full";
         let actual = parse_pod_columns(input).unwrap();
-        let expected: Vec<PodColumnSpec> = PodColumn::iter().map(PodColumnSpec::Builtin).collect();
+        let expected: Vec<PodColumnSpec> = PodColumn::iter()
+            .filter(|c| c.metrics().is_none())
+            .map(PodColumnSpec::Builtin)
+            .collect();
         assert_eq!(actual.specs(), expected.as_slice());
     }
 
@@ -119,7 +122,7 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
                 result.unwrap_err().to_string(),
-                "Invalid column name: invalid_column. Valid options are: name, ready, status, restarts, age, ip, node, nominatednode, readinessgates"
+                "Invalid column name: invalid_column. Valid options are: name, ready, status, restarts, age, ip, node, nominatednode, readinessgates, cpu, mem, %cpu/r, %mem/l"
             );
     }
 
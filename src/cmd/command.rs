@@ -3,7 +3,12 @@ use clap::Parser;
 use ratatui::layout::Direction;
 use std::path::PathBuf;
 
-use crate::{config::ConfigLoadOption, features::pod::PodColumns, workers::kube::KubeWorkerConfig};
+use crate::{
+    config::ConfigLoadOption,
+    features::pod::PodColumns,
+    state::StateOption,
+    workers::kube::KubeWorkerConfig,
+};
 
 use super::{
     args::{parse_pod_columns, AllNamespaces, ClipboardMode, SplitDirection},
@@ -104,6 +109,14 @@ pub struct Command {
     )]
     pub clipboard: ClipboardMode,
 
+    /// Disable actions that modify the cluster (delete, restart, scale, edit)
+    #[arg(long, display_order = 1000)]
+    pub read_only: bool,
+
+    /// Start without restoring the context, namespaces, tab, log query, filters and columns saved on the last exit
+    #[arg(long, display_order = 1000)]
+    pub no_restore: bool,
+
     #[command(subcommand)]
     pub subcommand: Option<SubCommand>,
 }
@@ -166,6 +179,13 @@ impl Command {
 
         Ok(option)
     }
+
+    pub fn state_option(&self) -> StateOption {
+        StateOption {
+            path: xdg_config_home().join("state.yaml"),
+            restore: !self.no_restore,
+        }
+    }
 }
 
 fn xdg_config_home() -> PathBuf {
@@ -306,7 +326,7 @@ mod tests {
                     PodColumn::IP,
                     PodColumn::Node,
                     PodColumn::NominatedNode,
-                    PodColumn::ReadinessGates
+                    PodColumn::ReadinessGates,
                 ]))
             );
         }
@@ -357,4 +377,34 @@ mod tests {
             );
         }
     }
+    mod read_only {
+        use super::*;
+
+        #[test]
+        fn デフォルトは無効() {
+            let cmd = Command::try_parse_from(["kubetui"]).unwrap();
+            assert!(!cmd.read_only);
+        }
+
+        #[test]
+        fn フラグを指定すると有効になる() {
+            let cmd = Command::try_parse_from(["kubetui", "--read-only"]).unwrap();
+            assert!(cmd.read_only);
+        }
+    }
+    mod no_restore {
+        use super::*;
+
+        #[test]
+        fn デフォルトは復元する() {
+            let cmd = Command::try_parse_from(["kubetui"]).unwrap();
+            assert!(cmd.state_option().restore);
+        }
+
+        #[test]
+        fn フラグを指定すると復元しない() {
+            let cmd = Command::try_parse_from(["kubetui", "--no-restore"]).unwrap();
+            assert!(!cmd.state_option().restore);
+        }
+    }
 }
@@ -1,3 +1,4 @@
+pub mod keybindings;
 pub mod theme;
 
 use std::path::PathBuf;
@@ -9,6 +10,9 @@ use figment::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::features::pod::kube::LogTimestamp;
+
+use keybindings::KeyBindingsConfig;
 use theme::ThemeConfig;
 
 #[derive(Debug, Default)]
@@ -19,9 +23,31 @@ pub enum ConfigLoadOption {
     Path(PathBuf),
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Default, Debug, Clone, Deserialize, Serialize)]
 pub struct LoggingConfig {
     pub max_lines: Option<usize>,
+    /// ログに表示するタイムスタンプの形式の初期値
+    #[serde(default)]
+    pub timestamp: LogTimestamp,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SecretConfig {
+    /// Secretの値を伏せ字で表示する
+    #[serde(default = "default_mask_values")]
+    pub mask_values: bool,
+}
+
+impl Default for SecretConfig {
+    fn default() -> Self {
+        Self {
+            mask_values: default_mask_values(),
+        }
+    }
+}
+
+fn default_mask_values() -> bool {
+    true
 }
 
 #[derive(Default, Debug, Deserialize, Serialize)]
@@ -32,6 +58,14 @@ pub struct Config {
     pub logging: LoggingConfig,
     #[serde(default)]
     pub fallback_namespaces: Option<Vec<String>>,
+    /// 削除・再起動・スケール・編集などクラスタに変更を加える操作を無効にする
+    #[serde(default)]
+    pub read_only: bool,
+    #[serde(default)]
+    pub secret: SecretConfig,
+    /// アクションごとのキーの割り当て
+    #[serde(default)]
+    pub keybindings: KeyBindingsConfig,
 }
 
 impl Config {
@@ -97,6 +131,76 @@ mod tests {
     }
 
     #[test]
+    fn read_only_が未設定の場合は無効() {
+        let config: Config = serde_yaml::from_str("{}").unwrap();
+        assert!(!config.read_only);
+    }
+
+    #[test]
+    fn read_only_が設定されている場合() {
+        let yaml = indoc! {"
+            read_only: true
+        "};
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.read_only);
+    }
+
+    #[test]
+    fn secret_mask_values_が未設定の場合は有効() {
+        let config: Config = serde_yaml::from_str("{}").unwrap();
+        assert!(config.secret.mask_values);
+
+        let config: Config = serde_yaml::from_str("secret: {}").unwrap();
+        assert!(config.secret.mask_values);
+    }
+
+    #[test]
+    fn secret_mask_values_が無効に設定されている場合() {
+        let yaml = indoc! {"
+            secret:
+              mask_values: false
+        "};
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert!(!config.secret.mask_values);
+    }
+
+    #[test]
+    fn keybindings_が設定されている場合() {
+        let yaml = indoc! {r#"
+            keybindings:
+              global:
+                quit: ["q", "ctrl-c"]
+        "#};
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            Vec::<String>::from(config.keybindings.global["quit"].clone()),
+            vec!["q", "Ctrl-c"]
+        );
+    }
+
+    #[test]
+    fn デフォルト値に_keybindings_を上書きできる() {
+        let yaml = indoc! {"
+            keybindings:
+              log:
+                save: ctrl-s
+        "};
+
+        let config: Config = Figment::new()
+            .merge(Serialized::defaults(Config::default()))
+            .merge(Yaml::string(yaml))
+            .extract_lossy()
+            .unwrap();
+
+        assert_eq!(
+            Vec::<String>::from(config.keybindings.log["save"].clone()),
+            vec!["Ctrl-s"]
+        );
+    }
+
+    #[test]
+    // `Jail::expect_with` のクロージャは `figment::Error` を返す必要があり、Boxにできない
+    #[allow(clippy::result_large_err)]
     fn 環境変数で_theme_node_default_preset_を上書きできる() {
         figment::Jail::expect_with(|jail| {
             jail.set_env("KUBETUI_THEME__NODE__DEFAULT_PRESET", "wide");
@@ -108,4 +212,24 @@ mod tests {
             Ok(())
         });
     }
+
+    #[test]
+    fn ログのタイムスタンプの形式を設定できる() {
+        let yaml = indoc! {"
+            logging:
+              timestamp: local
+        "};
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.logging.timestamp, LogTimestamp::Local);
+    }
+
+    #[test]
+    fn ログのタイムスタンプの形式が未設定の場合は表示しない() {
+        let yaml = indoc! {"
+            logging:
+              max_lines: 1000
+        "};
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.logging.timestamp, LogTimestamp::Off);
+    }
 }
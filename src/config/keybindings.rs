@@ -0,0 +1,943 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    str::FromStr,
+};
+
+use anyhow::{bail, Result};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+
+use crate::{message::UserEvent, ui::key_event_to_code};
+
+/// キーバインドの設定
+///
+/// スコープごとにアクション名とキーの対応を指定する。
+/// 指定しなかったアクションはデフォルトのキーを使う。
+///
+/// ```yaml
+/// keybindings:
+///   global:
+///     quit: ["q", "ctrl-c"]
+///   log:
+///     toggle_previous: "ctrl-o"
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct KeyBindingsConfig {
+    #[serde(default)]
+    pub global: BTreeMap<String, KeyChords>,
+
+    #[serde(default)]
+    pub table: BTreeMap<String, KeyChords>,
+
+    #[serde(default)]
+    pub pod: BTreeMap<String, KeyChords>,
+
+    #[serde(default)]
+    pub workload: BTreeMap<String, KeyChords>,
+
+    #[serde(default)]
+    pub event: BTreeMap<String, KeyChords>,
+
+    #[serde(default)]
+    pub network: BTreeMap<String, KeyChords>,
+
+    #[serde(default)]
+    pub log: BTreeMap<String, KeyChords>,
+
+    #[serde(default)]
+    pub raw_data: BTreeMap<String, KeyChords>,
+
+    #[serde(default)]
+    pub api: BTreeMap<String, KeyChords>,
+
+    #[serde(default)]
+    pub yaml: BTreeMap<String, KeyChords>,
+}
+
+impl KeyBindingsConfig {
+    fn scope(&self, scope: KeyBindingScope) -> &BTreeMap<String, KeyChords> {
+        match scope {
+            KeyBindingScope::Global => &self.global,
+            KeyBindingScope::Table => &self.table,
+            KeyBindingScope::Pod => &self.pod,
+            KeyBindingScope::Workload => &self.workload,
+            KeyBindingScope::Event => &self.event,
+            KeyBindingScope::Network => &self.network,
+            KeyBindingScope::Log => &self.log,
+            KeyBindingScope::RawData => &self.raw_data,
+            KeyBindingScope::Api => &self.api,
+            KeyBindingScope::Yaml => &self.yaml,
+        }
+    }
+}
+
+/// アクションが有効になる範囲
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyBindingScope {
+    /// どのタブでも有効
+    Global,
+    /// Pod・Config・Network・Node・Workload・Eventのテーブル
+    Table,
+    /// Podのテーブル
+    Pod,
+    /// Workloadのテーブル
+    Workload,
+    /// Eventのテーブル
+    Event,
+    /// Networkの説明
+    Network,
+    /// ログ
+    Log,
+    /// ConfigMap・Secretのデータ
+    RawData,
+    /// APIタブ
+    Api,
+    /// Yamlタブ
+    Yaml,
+}
+
+impl KeyBindingScope {
+    const ALL: &'static [KeyBindingScope] = &[
+        Self::Global,
+        Self::Table,
+        Self::Pod,
+        Self::Workload,
+        Self::Event,
+        Self::Network,
+        Self::Log,
+        Self::RawData,
+        Self::Api,
+        Self::Yaml,
+    ];
+
+    /// 同じウィジェットで有効になるスコープか
+    ///
+    /// グローバルなキーはどのウィジェットでも有効で、各ウィジェットのキーが優先される。
+    /// Pod・Workload・Eventのテーブルでは`table`のキーも有効になる。
+    fn overlaps(self, other: Self) -> bool {
+        use KeyBindingScope::*;
+
+        match (self, other) {
+            _ if self == other => true,
+            (Global, _) | (_, Global) => true,
+            (Table, Pod | Workload | Event) | (Pod | Workload | Event, Table) => true,
+            _ => false,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Global => "global",
+            Self::Table => "table",
+            Self::Pod => "pod",
+            Self::Workload => "workload",
+            Self::Event => "event",
+            Self::Network => "network",
+            Self::Log => "log",
+            Self::RawData => "raw_data",
+            Self::Api => "api",
+            Self::Yaml => "yaml",
+        }
+    }
+}
+
+/// キーを割り当てられるアクション
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    OpenContext,
+    OpenNamespace,
+    OpenNamespaces,
+    OpenYaml,
+    OpenHelp,
+    ToggleSplit,
+    PortForward,
+    PortForwardList,
+    Delete,
+    Restart,
+    Scale,
+    Edit,
+    CopyNames,
+    Quit,
+    OpenColumns,
+    SortNext,
+    SortPrev,
+    ToggleMark,
+    MarkRange,
+    MarkAll,
+    Exec,
+    Describe,
+    MarkedLogs,
+    ShowLogs,
+    GroupByObject,
+    OpenRelated,
+    InsertBlankLine,
+    TogglePrettyPrint,
+    TogglePrevious,
+    ToggleTimestamp,
+    SaveLog,
+    RevealKey,
+    RevealAll,
+    OpenApiSelect,
+    OpenKindSelect,
+}
+
+/// アクションの定義
+struct ActionDefinition {
+    scope: KeyBindingScope,
+    name: &'static str,
+    default_keys: &'static [&'static str],
+    desc: &'static str,
+}
+
+impl Action {
+    pub const ALL: &'static [Action] = &[
+        Self::OpenContext,
+        Self::OpenNamespace,
+        Self::OpenNamespaces,
+        Self::OpenYaml,
+        Self::OpenHelp,
+        Self::ToggleSplit,
+        Self::PortForward,
+        Self::PortForwardList,
+        Self::Delete,
+        Self::Restart,
+        Self::Scale,
+        Self::Edit,
+        Self::CopyNames,
+        Self::Quit,
+        Self::OpenColumns,
+        Self::SortNext,
+        Self::SortPrev,
+        Self::ToggleMark,
+        Self::MarkRange,
+        Self::MarkAll,
+        Self::Exec,
+        Self::Describe,
+        Self::MarkedLogs,
+        Self::ShowLogs,
+        Self::GroupByObject,
+        Self::OpenRelated,
+        Self::InsertBlankLine,
+        Self::TogglePrettyPrint,
+        Self::TogglePrevious,
+        Self::ToggleTimestamp,
+        Self::SaveLog,
+        Self::RevealKey,
+        Self::RevealAll,
+        Self::OpenApiSelect,
+        Self::OpenKindSelect,
+    ];
+
+    fn definition(self) -> ActionDefinition {
+        use KeyBindingScope::*;
+
+        let (scope, name, default_keys, desc): (_, _, &'static [&'static str], _) = match self {
+            Self::OpenContext => (Global, "open_context", &["c"], "change context"),
+            Self::OpenNamespace => (Global, "open_namespace", &["n"], "select namespace"),
+            Self::OpenNamespaces => (Global, "open_namespaces", &["N"], "select namespaces"),
+            Self::OpenYaml => (Global, "open_yaml", &["y"], "open yaml dialog"),
+            Self::OpenHelp => (Global, "open_help", &["h", "?"], "show this help"),
+            Self::ToggleSplit => (Global, "toggle_split", &["S"], "toggle split direction"),
+            Self::PortForward => {
+                (
+                    Global,
+                    "port_forward",
+                    &["O"],
+                    "port-forward the selected row",
+                )
+            }
+            Self::PortForwardList => {
+                (
+                    Global,
+                    "port_forward_list",
+                    &["F"],
+                    "list and stop port-forwards",
+                )
+            }
+            Self::Delete => (Global, "delete", &["D"], "delete pod"),
+            Self::Restart => (Global, "restart", &["R"], "restart owning workload"),
+            Self::Scale => (Global, "scale", &["s"], "scale owning workload"),
+            Self::Edit => (Global, "edit", &["E"], "edit in $EDITOR and apply"),
+            Self::CopyNames => {
+                (
+                    Global,
+                    "copy_names",
+                    &["Y"],
+                    "copy names of the marked (or selected) rows",
+                )
+            }
+            Self::Quit => (Global, "quit", &["q", "Esc"], "quit / close dialog"),
+            Self::OpenColumns => (Table, "open_columns", &["t"], "customize visible columns"),
+            Self::SortNext => (Table, "sort_next", &[">"], "sort by next column / order"),
+            Self::SortPrev => {
+                (
+                    Table,
+                    "sort_prev",
+                    &["<"],
+                    "sort by previous column / order",
+                )
+            }
+            Self::ToggleMark => (Table, "toggle_mark", &["Space"], "mark / unmark the row"),
+            Self::MarkRange => {
+                (
+                    Table,
+                    "mark_range",
+                    &["V"],
+                    "mark rows from the last marked one",
+                )
+            }
+            Self::MarkAll => (Table, "mark_all", &["A"], "mark / unmark all shown rows"),
+            Self::Exec => (Pod, "exec", &["e"], "exec into a container"),
+            Self::Describe => (Pod, "describe", &["d"], "describe the selected pod"),
+            Self::MarkedLogs => (Pod, "marked_logs", &["L"], "stream logs of the marked pods"),
+            Self::ShowLogs => (Workload, "logs", &["l"], "show logs in the Pod tab"),
+            Self::GroupByObject => {
+                (
+                    Event,
+                    "group_by_object",
+                    &["o"],
+                    "group events by involved object",
+                )
+            }
+            Self::OpenRelated => {
+                (
+                    Network,
+                    "open_related",
+                    &["Enter"],
+                    "open a related resource or owner",
+                )
+            }
+            Self::InsertBlankLine => (Log, "insert_blank_line", &["Enter"], "insert blank line"),
+            Self::TogglePrettyPrint => {
+                (
+                    Log,
+                    "toggle_pretty_print",
+                    &["f", "p"],
+                    "toggle json pretty print",
+                )
+            }
+            Self::TogglePrevious => {
+                (
+                    Log,
+                    "toggle_previous",
+                    &["P"],
+                    "toggle previous container logs",
+                )
+            }
+            Self::ToggleTimestamp => {
+                (
+                    Log,
+                    "toggle_timestamp",
+                    &["t"],
+                    "switch timestamps (off/rfc3339/local/relative)",
+                )
+            }
+            Self::SaveLog => (Log, "save", &["w"], "save log to file"),
+            Self::RevealKey => (RawData, "reveal_key", &["v"], "reveal/mask the focused key"),
+            Self::RevealAll => (RawData, "reveal_all", &["V"], "reveal/mask all keys"),
+            Self::OpenApiSelect => (Api, "open_select", &["f"], "select api resources"),
+            Self::OpenKindSelect => (Yaml, "open_select", &["f"], "select resource kind"),
+        };
+
+        ActionDefinition {
+            scope,
+            name,
+            default_keys,
+            desc,
+        }
+    }
+
+    pub fn scope(self) -> KeyBindingScope {
+        self.definition().scope
+    }
+
+    /// 設定ファイルで使う名前
+    pub fn name(self) -> &'static str {
+        self.definition().name
+    }
+
+    /// ヘルプに表示する説明
+    pub fn desc(self) -> &'static str {
+        self.definition().desc
+    }
+
+    fn default_chords(self) -> Vec<KeyChord> {
+        self.definition()
+            .default_keys
+            .iter()
+            .map(|key| key.parse().expect("invalid default key"))
+            .collect()
+    }
+
+    fn find(scope: KeyBindingScope, name: &str) -> Option<Action> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|action| action.scope() == scope && action.name() == name)
+    }
+}
+
+/// アクションとキーの対応表
+///
+/// ウィンドウ・各ウィジェットへのキーの登録とヘルプの表示はすべてこの対応表から行う
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    bindings: HashMap<Action, Vec<KeyChord>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: Action::ALL
+                .iter()
+                .map(|action| (*action, action.default_chords()))
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// 設定をデフォルトのキーに上書きする
+    ///
+    /// 存在しないアクション名や、同じウィジェットで有効になるキーの重複、
+    /// 移動に使うキーの割り当てはエラーにする
+    pub fn new(config: &KeyBindingsConfig) -> Result<Self> {
+        let mut keymap = Self::default();
+
+        for scope in KeyBindingScope::ALL {
+            for (name, KeyChords(chords)) in config.scope(*scope) {
+                let Some(action) = Action::find(*scope, name) else {
+                    bail!("unknown action '{}' in keybindings.{}", name, scope.name());
+                };
+
+                if let Some(chord) = chords.iter().find(|c| c.is_navigation()) {
+                    bail!(
+                        "key '{}' of keybindings.{}.{} is reserved for navigation",
+                        chord,
+                        scope.name(),
+                        name
+                    );
+                }
+
+                keymap.bindings.insert(action, chords.clone());
+            }
+        }
+
+        keymap.validate()?;
+
+        Ok(keymap)
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (i, lhs) in Action::ALL.iter().enumerate() {
+            for rhs in &Action::ALL[i + 1..] {
+                if !lhs.scope().overlaps(rhs.scope()) {
+                    continue;
+                }
+
+                let Some(chord) = self
+                    .chords(*lhs)
+                    .iter()
+                    .find(|c| self.chords(*rhs).contains(c))
+                else {
+                    continue;
+                };
+
+                if lhs.scope() == rhs.scope() {
+                    bail!(
+                        "key '{}' is bound to both '{}' and '{}' in keybindings.{}",
+                        chord,
+                        lhs.name(),
+                        rhs.name(),
+                        lhs.scope().name()
+                    );
+                }
+
+                // 各ウィジェットのキーが優先されるため、もう一方のアクションが使えなくなる
+                bail!(
+                    "key '{}' of keybindings.{}.{} is shadowed by keybindings.{}.{}",
+                    chord,
+                    lhs.scope().name(),
+                    lhs.name(),
+                    rhs.scope().name(),
+                    rhs.name()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn chords(&self, action: Action) -> &[KeyChord] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// アクションに割り当てられたキーイベント
+    pub fn events(&self, action: Action) -> Vec<UserEvent> {
+        self.chords(action)
+            .iter()
+            .map(|chord| UserEvent::from(*chord))
+            .collect()
+    }
+
+    /// ヘルプに表示するキー
+    pub fn keys(&self, action: Action) -> Vec<String> {
+        self.chords(action)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+}
+
+/// 1つ以上のキー
+///
+/// 設定ファイルでは文字列1つ、または文字列の配列で指定する。
+/// 空の配列を指定するとアクションを無効にする。
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "KeyChordsRepr", into = "Vec<String>")]
+pub struct KeyChords(pub Vec<KeyChord>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KeyChordsRepr {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl TryFrom<KeyChordsRepr> for KeyChords {
+    type Error = anyhow::Error;
+
+    fn try_from(value: KeyChordsRepr) -> Result<Self> {
+        let keys = match value {
+            KeyChordsRepr::One(key) => vec![key],
+            KeyChordsRepr::Many(keys) => keys,
+        };
+
+        keys.iter()
+            .map(|key| key.parse())
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+}
+
+impl From<KeyChords> for Vec<String> {
+    fn from(value: KeyChords) -> Self {
+        value.0.iter().map(ToString::to_string).collect()
+    }
+}
+
+/// 修飾キーとキーの組み合わせ
+///
+/// `n`、`N`、`ctrl-x`、`alt-enter`、`shift-tab`、`esc`、`f1`のように表記する
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // 大文字とShiftの組み合わせはcrosstermの比較に合わせて正規化する
+        match code {
+            KeyCode::Char(c) if c.is_ascii_uppercase() => {
+                Self {
+                    code,
+                    modifiers: modifiers | KeyModifiers::SHIFT,
+                }
+            }
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+                Self {
+                    code: KeyCode::Char(c.to_ascii_uppercase()),
+                    modifiers,
+                }
+            }
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+                Self {
+                    code: KeyCode::BackTab,
+                    modifiers,
+                }
+            }
+            _ => Self { code, modifiers },
+        }
+    }
+
+    /// ウィジェットやタブの移動に使うキーか
+    ///
+    /// これらのキーは各ウィジェットとウィンドウが修飾キーを問わずアクションより先に処理する
+    fn is_navigation(&self) -> bool {
+        use KeyCode::*;
+
+        matches!(
+            key_event_to_code(KeyEvent::new(self.code, self.modifiers)),
+            Char('j' | 'k' | 'g' | 'G' | '/' | '1'..='9')
+                | Up
+                | Down
+                | Left
+                | Right
+                | PageUp
+                | PageDown
+                | Home
+                | End
+                | Tab
+                | BackTab
+        )
+    }
+}
+
+impl From<KeyChord> for UserEvent {
+    fn from(chord: KeyChord) -> Self {
+        UserEvent::Key(KeyEvent::new(chord.code, chord.modifiers))
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+
+        // 単独の`-`や`ctrl--`のようにキー自体が`-`の場合
+        let (modifiers, key) = if s == "-" {
+            ("", s)
+        } else if let Some(modifiers) = s.strip_suffix("--") {
+            (modifiers, "-")
+        } else {
+            match s.rsplit_once('-') {
+                Some((modifiers, key)) => (modifiers, key),
+                None => ("", s),
+            }
+        };
+
+        let modifiers = modifiers.split('-').filter(|m| !m.is_empty()).try_fold(
+            KeyModifiers::NONE,
+            |acc, m| {
+                let m = match m.to_ascii_lowercase().as_str() {
+                    "ctrl" | "control" => KeyModifiers::CONTROL,
+                    "alt" | "meta" => KeyModifiers::ALT,
+                    "shift" => KeyModifiers::SHIFT,
+                    _ => bail!("unknown modifier '{}' in key '{}'", m, s),
+                };
+
+                Ok(acc | m)
+            },
+        )?;
+
+        let code = parse_key_code(key).ok_or_else(|| anyhow::anyhow!("unknown key '{}'", s))?;
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+fn parse_key_code(key: &str) -> Option<KeyCode> {
+    let mut chars = key.chars();
+
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+
+    let code = match key.to_ascii_lowercase().as_str() {
+        "esc" | "escape" => KeyCode::Esc,
+        "enter" | "return" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "space" => KeyCode::Char(' '),
+        "backspace" | "bs" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        key => {
+            let n = key.strip_prefix('f')?.parse().ok()?;
+
+            if !(1..=12).contains(&n) {
+                return None;
+            }
+
+            KeyCode::F(n)
+        }
+    };
+
+    Some(code)
+}
+
+/// ヘルプと同じ表記 (例: `Ctrl-x`、`N`、`Esc`)
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl-")?;
+        }
+
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt-")?;
+        }
+
+        // 大文字とBackTabはShiftを含んでいるため表記しない
+        let shift_implied = matches!(self.code, KeyCode::Char(c) if c.is_ascii_uppercase())
+            || self.code == KeyCode::BackTab;
+
+        if self.modifiers.contains(KeyModifiers::SHIFT) && !shift_implied {
+            write!(f, "Shift-")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::BackTab => write!(f, "BackTab"),
+            KeyCode::Backspace => write!(f, "BS"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Insert => write!(f, "Ins"),
+            KeyCode::Up => write!(f, "Up"),
+            KeyCode::Down => write!(f, "Down"),
+            KeyCode::Left => write!(f, "Left"),
+            KeyCode::Right => write!(f, "Right"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+impl TryFrom<String> for KeyChord {
+    type Error = anyhow::Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::char("n", KeyCode::Char('n'), KeyModifiers::NONE)]
+    #[case::upper("N", KeyCode::Char('N'), KeyModifiers::SHIFT)]
+    #[case::shift("shift-n", KeyCode::Char('N'), KeyModifiers::SHIFT)]
+    #[case::symbol("?", KeyCode::Char('?'), KeyModifiers::NONE)]
+    #[case::minus("-", KeyCode::Char('-'), KeyModifiers::NONE)]
+    #[case::ctrl_minus("ctrl--", KeyCode::Char('-'), KeyModifiers::CONTROL)]
+    #[case::ctrl("Ctrl-x", KeyCode::Char('x'), KeyModifiers::CONTROL)]
+    #[case::ctrl_alt("ctrl-alt-x", KeyCode::Char('x'), KeyModifiers::CONTROL | KeyModifiers::ALT)]
+    #[case::space("ctrl-space", KeyCode::Char(' '), KeyModifiers::CONTROL)]
+    #[case::esc("Esc", KeyCode::Esc, KeyModifiers::NONE)]
+    #[case::enter("alt-enter", KeyCode::Enter, KeyModifiers::ALT)]
+    #[case::backtab("shift-tab", KeyCode::BackTab, KeyModifiers::SHIFT)]
+    #[case::page("pgdn", KeyCode::PageDown, KeyModifiers::NONE)]
+    #[case::function("f5", KeyCode::F(5), KeyModifiers::NONE)]
+    fn parse_key_chord(#[case] s: &str, #[case] code: KeyCode, #[case] modifiers: KeyModifiers) {
+        assert_eq!(s.parse::<KeyChord>().unwrap(), KeyChord { code, modifiers });
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unknown_key("foo")]
+    #[case::unknown_modifier("super-x")]
+    #[case::function_out_of_range("f13")]
+    fn parse_invalid_key_chord(#[case] s: &str) {
+        assert!(s.parse::<KeyChord>().is_err());
+    }
+
+    #[rstest]
+    #[case("n", "n")]
+    #[case("shift-n", "N")]
+    #[case("ctrl-space", "Ctrl-Space")]
+    #[case("shift-enter", "Shift-Enter")]
+    #[case("escape", "Esc")]
+    #[case("pageup", "PgUp")]
+    fn display_key_chord(#[case] s: &str, #[case] expected: &str) {
+        assert_eq!(s.parse::<KeyChord>().unwrap().to_string(), expected);
+    }
+
+    #[test]
+    fn uppercase_chord_matches_terminal_event() {
+        // 端末からはShift付きで届く
+        let ev = UserEvent::Key(KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT));
+
+        assert_eq!(UserEvent::from("N".parse::<KeyChord>().unwrap()), ev);
+    }
+
+    #[test]
+    fn default_keymap() {
+        let keymap = Keymap::new(&KeyBindingsConfig::default()).unwrap();
+
+        assert_eq!(keymap.keys(Action::OpenHelp), vec!["h", "?"]);
+        assert_eq!(keymap.keys(Action::Quit), vec!["q", "Esc"]);
+        assert_eq!(keymap.keys(Action::TogglePrevious), vec!["P"]);
+    }
+
+    #[test]
+    fn override_keymap() {
+        let yaml = indoc! {r#"
+            global:
+              quit: ["q", "ctrl-c"]
+              open_help: "f1"
+            log:
+              toggle_previous: []
+        "#};
+
+        let config: KeyBindingsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let keymap = Keymap::new(&config).unwrap();
+
+        assert_eq!(keymap.keys(Action::Quit), vec!["q", "Ctrl-c"]);
+        assert_eq!(keymap.keys(Action::OpenHelp), vec!["F1"]);
+        assert_eq!(keymap.events(Action::TogglePrevious), vec![]);
+        assert_eq!(keymap.keys(Action::OpenContext), vec!["c"]);
+    }
+
+    #[test]
+    fn same_name_in_different_scopes() {
+        let yaml = indoc! {r#"
+            api:
+              open_select: "a"
+        "#};
+
+        let config: KeyBindingsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let keymap = Keymap::new(&config).unwrap();
+
+        assert_eq!(keymap.keys(Action::OpenApiSelect), vec!["a"]);
+        assert_eq!(keymap.keys(Action::OpenKindSelect), vec!["f"]);
+    }
+
+    #[test]
+    fn unknown_action_is_error() {
+        let yaml = indoc! {r#"
+            log:
+              open_context: "c"
+        "#};
+
+        let config: KeyBindingsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let err = Keymap::new(&config).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "unknown action 'open_context' in keybindings.log"
+        );
+    }
+
+    #[test]
+    fn duplicated_key_in_same_scope_is_error() {
+        let yaml = indoc! {r#"
+            global:
+              open_context: "n"
+        "#};
+
+        let config: KeyBindingsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let err = Keymap::new(&config).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "key 'n' is bound to both 'open_context' and 'open_namespace' in keybindings.global"
+        );
+    }
+
+    #[rstest]
+    #[case::cursor("pod", "describe", "j", "j")]
+    #[case::search("log", "save", "/", "/")]
+    #[case::tab("global", "open_help", "tab", "Tab")]
+    #[case::tab_number("table", "open_columns", "alt-1", "Alt-1")]
+    #[case::alias("log", "toggle_previous", "ctrl-p", "Ctrl-p")]
+    fn navigation_key_is_error(
+        #[case] scope: &str,
+        #[case] action: &str,
+        #[case] key: &str,
+        #[case] display: &str,
+    ) {
+        let yaml = format!("{}:\n  {}: [\"x\", \"{}\"]\n", scope, action, key);
+
+        let config: KeyBindingsConfig = serde_yaml::from_str(&yaml).unwrap();
+
+        let err = Keymap::new(&config).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "key '{}' of keybindings.{}.{} is reserved for navigation",
+                display, scope, action
+            )
+        );
+    }
+
+    #[test]
+    fn global_key_shadowed_by_widget_key_is_error() {
+        let yaml = indoc! {r#"
+            pod:
+              exec: "E"
+        "#};
+
+        let config: KeyBindingsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let err = Keymap::new(&config).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "key 'E' of keybindings.global.edit is shadowed by keybindings.pod.exec"
+        );
+    }
+
+    #[test]
+    fn table_key_shadowed_by_pod_key_is_error() {
+        let yaml = indoc! {r#"
+            pod:
+              describe: "t"
+        "#};
+
+        let config: KeyBindingsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        let err = Keymap::new(&config).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "key 't' of keybindings.table.open_columns is shadowed by keybindings.pod.describe"
+        );
+    }
+
+    #[test]
+    fn same_key_in_separate_widgets() {
+        let yaml = indoc! {r#"
+            network:
+              open_related: "l"
+            log:
+              toggle_previous: "l"
+        "#};
+
+        let config: KeyBindingsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        assert!(Keymap::new(&config).is_ok());
+    }
+
+    #[test]
+    fn invalid_key_is_deserialize_error() {
+        let yaml = indoc! {r#"
+            global:
+              quit: "hyper-q"
+        "#};
+
+        assert!(serde_yaml::from_str::<KeyBindingsConfig>(yaml).is_err());
+    }
+
+    #[test]
+    fn default_keymap_has_no_conflict() {
+        assert!(Keymap::default().validate().is_ok());
+    }
+}
@@ -20,6 +20,7 @@ mod tab;
 mod table;
 mod text;
 mod widget;
+mod workload;
 mod yaml;
 
 use serde::{Deserialize, Serialize};
@@ -52,6 +53,7 @@ pub use style::ThemeStyleConfig;
 pub use table::*;
 pub use text::*;
 pub use widget::WidgetThemeConfig;
+pub use workload::WorkloadThemeConfig;
 pub use yaml::YamlThemeConfig;
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -83,6 +85,9 @@ pub struct ThemeConfig {
     #[serde(default)]
     pub network: NetworkThemeConfig,
 
+    #[serde(default)]
+    pub workload: WorkloadThemeConfig,
+
     #[serde(default)]
     pub api: ApiThemeConfig,
 
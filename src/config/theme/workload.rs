@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use super::LabelColumnConfig;
+
+/// Theme/config-level settings for the Workload tab.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct WorkloadThemeConfig {
+    /// Registry of label columns. All entries are appended to the default
+    /// builtin columns at startup (user can toggle them off via the column
+    /// dialog).
+    pub label_columns: Option<Vec<LabelColumnConfig>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn deserializes_label_columns() {
+        let json = r#"{
+            "label_columns": [
+                { "name": "app", "label": "app.kubernetes.io/name" }
+            ]
+        }"#;
+        let cfg: WorkloadThemeConfig = serde_json::from_str(json).unwrap();
+        let labels = cfg.label_columns.as_ref().unwrap();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].name, "app");
+        assert_eq!(labels[0].label, "app.kubernetes.io/name");
+    }
+
+    #[test]
+    fn default_has_none_label_columns() {
+        let cfg = WorkloadThemeConfig::default();
+        assert!(cfg.label_columns.is_none());
+    }
+}
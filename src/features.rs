@@ -2,14 +2,20 @@ pub mod api_resources;
 pub mod component_id;
 pub mod config;
 pub mod context;
+pub mod edit;
 pub mod event;
 pub mod get;
 pub mod help;
 pub mod namespace;
+pub mod navigation;
 pub mod network;
 pub mod node;
+pub mod operation;
 pub mod pod;
+pub mod port_forward;
 mod styled_api_resource;
+pub mod tab;
+pub mod workload;
 pub mod yaml;
 
 pub use styled_api_resource::StyledApiResource;
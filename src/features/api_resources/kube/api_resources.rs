@@ -449,7 +449,14 @@ fn merge_tables(fetch_data: Vec<FetchData>, insert_ns: bool) -> Table {
     base_table
 }
 
+/// metrics.k8s.ioの一覧 (watchできないため毎回取得する)
+const METRICS_API_PATH_PREFIX: &str = "/apis/metrics.k8s.io/";
+
 async fn try_fetch_table(client: &KubeClient, path: &str) -> Result<Table> {
+    if !path.starts_with(METRICS_API_PATH_PREFIX) {
+        return client.request_watched_table(path).await;
+    }
+
     let table = client.request_table::<Table>(path).await;
 
     if let Ok(t) = table {
@@ -5,7 +5,7 @@ use std::{cell::RefCell, rc::Rc};
 
 use crate::{
     clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
+    config::{keybindings::Keymap, theme::WidgetThemeConfig},
     features::component_id::API_TAB_ID,
     message::Message,
     ui::{
@@ -28,10 +28,11 @@ impl ApiTab {
         tx: &Sender<Message>,
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
         theme: WidgetThemeConfig,
+        keymap: &Keymap,
     ) -> Self {
         let error_theme = theme.error.clone().into();
 
-        let api_widget = api_widget(tx, clipboard, theme.clone());
+        let api_widget = api_widget(tx, clipboard, theme.clone(), keymap);
 
         let layout = TabLayout::new(
             |_| {
@@ -4,7 +4,10 @@ use crossbeam::channel::Sender;
 
 use crate::{
     clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
     features::{
         api_resources::message::ApiRequest,
         component_id::{API_DIALOG_ID, API_WIDGET_ID},
@@ -30,6 +33,7 @@ pub fn api_widget(
     tx: &Sender<Message>,
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
     theme: WidgetThemeConfig,
+    keymap: &Keymap,
 ) -> Widget<'static> {
     let tx = tx.clone();
 
@@ -65,7 +69,7 @@ pub fn api_widget(
 
             base.render_block(text.can_activate() && is_active, is_mouse_over)
         })
-        .action('f', open_subwin);
+        .actions(keymap.events(Action::OpenApiSelect), open_subwin);
 
     if let Some(cb) = clipboard {
         builder.clipboard(cb.clone())
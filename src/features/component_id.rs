@@ -19,6 +19,7 @@ component_id!(
     network_tab,
     yaml_tab,
     node_tab,
+    workload_tab,
     // widgets
     pod_widget,
     node_widget,
@@ -29,6 +30,8 @@ component_id!(
     network_widget,
     network_description_widget,
     node_detail_widget,
+    workload_widget,
+    workload_description_widget,
     event_widget,
     api_widget,
     yaml_widget,
@@ -41,7 +44,19 @@ component_id!(
     config_columns_dialog,
     network_filter_help_dialog,
     network_columns_dialog,
+    network_related_resource_dialog,
+    workload_filter_help_dialog,
+    workload_columns_dialog,
+    event_filter_help_dialog,
     pod_log_query_help_dialog,
+    pod_log_save_dialog,
+    pod_exec_dialog,
+    pod_describe_dialog,
+    port_forward_dialog,
+    port_forward_list_dialog,
+    operation_confirm_dialog,
+    operation_scale_dialog,
+    edit_error_dialog,
     context_dialog,
     single_namespace_dialog,
     multiple_namespaces_dialog,
@@ -12,6 +12,8 @@ use crate::{
     workers::kube::InfiniteWorker,
 };
 
+pub use self::secret::SecretReveal;
+
 use self::{configmap::ConfigMapDataWorker, secret::SecretDataWorker};
 
 #[derive(Clone)]
@@ -19,11 +21,22 @@ pub struct ConfigsDataWorker {
     tx: Sender<Message>,
     client: KubeClient,
     req: ConfigRequest,
+    reveal: SecretReveal,
 }
 
 impl ConfigsDataWorker {
-    pub fn new(tx: Sender<Message>, client: KubeClient, req: ConfigRequest) -> Self {
-        Self { tx, client, req }
+    pub fn new(
+        tx: Sender<Message>,
+        client: KubeClient,
+        req: ConfigRequest,
+        reveal: SecretReveal,
+    ) -> Self {
+        Self {
+            tx,
+            client,
+            req,
+            reveal,
+        }
     }
 }
 
@@ -45,7 +58,7 @@ impl InfiniteWorker for ConfigsDataWorker {
 
 #[async_trait]
 trait Fetch<'a> {
-    fn new(client: &'a KubeClient, namespace: String, name: String) -> Self;
+    fn new(client: &'a KubeClient, namespace: String, name: String, reveal: SecretReveal) -> Self;
 
     async fn fetch(&self) -> Result<ConfigData>;
 }
@@ -61,7 +74,12 @@ impl ConfigsDataWorker {
 
         let RequestData { name, namespace } = self.req.data();
 
-        let worker = Worker::new(&self.client, namespace.to_string(), name.to_string());
+        let worker = Worker::new(
+            &self.client,
+            namespace.to_string(),
+            name.to_string(),
+            self.reveal.clone(),
+        );
 
         loop {
             interval.tick().await;
@@ -7,7 +7,7 @@ use kube::Api;
 
 use crate::{features::config::message::ConfigData, kube::KubeClient, workers::kube::color::Color};
 
-use super::Fetch;
+use super::{Fetch, SecretReveal};
 
 pub(super) struct ConfigMapDataWorker<'a> {
     client: &'a KubeClient,
@@ -17,7 +17,7 @@ pub(super) struct ConfigMapDataWorker<'a> {
 
 #[async_trait()]
 impl<'a> Fetch<'a> for ConfigMapDataWorker<'a> {
-    fn new(client: &'a KubeClient, namespace: String, name: String) -> Self {
+    fn new(client: &'a KubeClient, namespace: String, name: String, _: SecretReveal) -> Self {
         Self {
             client,
             namespace,
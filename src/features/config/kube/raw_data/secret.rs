@@ -1,32 +1,75 @@
 mod any;
+mod docker_config;
 mod format;
 mod helm;
+mod tls;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::Result;
 use async_trait::async_trait;
 use k8s_openapi::{api::core::v1::Secret, ByteString};
 use kube::Api;
 
-use crate::{features::config::message::ConfigData, kube::KubeClient};
+use crate::{
+    features::config::message::{ConfigData, RevealRequest},
+    kube::KubeClient,
+};
 
-use self::{any::Any, helm::Helm};
+use self::{any::Any, docker_config::DockerConfig, helm::Helm, tls::Tls};
 
 use super::Fetch;
 
+/// Secretの値を表示するかどうか
+///
+/// `all`が有効な場合は`keys`に含まれるキーを伏せ字にし、無効な場合は`keys`に含まれるキーのみ表示する
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SecretReveal {
+    all: bool,
+    keys: BTreeSet<String>,
+}
+
+impl SecretReveal {
+    pub fn new(reveal_all: bool) -> Self {
+        Self {
+            all: reveal_all,
+            keys: BTreeSet::new(),
+        }
+    }
+
+    pub fn toggle(&mut self, req: RevealRequest) {
+        match req {
+            RevealRequest::All => {
+                self.all = !self.all;
+                self.keys.clear();
+            }
+            RevealRequest::Key(key) => {
+                if !self.keys.remove(&key) {
+                    self.keys.insert(key);
+                }
+            }
+        }
+    }
+
+    pub fn is_revealed(&self, key: &str) -> bool {
+        self.all != self.keys.contains(key)
+    }
+}
+
 pub(super) struct SecretDataWorker<'a> {
     client: &'a KubeClient,
     namespace: String,
     name: String,
+    reveal: SecretReveal,
 }
 #[async_trait()]
 impl<'a> Fetch<'a> for SecretDataWorker<'a> {
-    fn new(client: &'a KubeClient, namespace: String, name: String) -> Self {
+    fn new(client: &'a KubeClient, namespace: String, name: String, reveal: SecretReveal) -> Self {
         Self {
             client,
             namespace,
             name,
+            reveal,
         }
     }
     async fn fetch(&self) -> Result<ConfigData> {
@@ -40,13 +83,15 @@ impl<'a> Fetch<'a> for SecretDataWorker<'a> {
         };
 
         let data = SecretData::new(type_, data)?;
-        Ok(data.to_string_key_values())
+        Ok(data.to_string_key_values(&self.reveal))
     }
 }
 
 #[derive(Debug)]
 enum SecretData {
     Helm(Helm),
+    Tls(Tls),
+    DockerConfig(DockerConfig),
     Any(Any),
 }
 
@@ -54,14 +99,57 @@ impl SecretData {
     fn new(type_: &str, data: BTreeMap<String, ByteString>) -> Result<Self> {
         match type_ {
             "helm.sh/release.v1" => Ok(Self::Helm(Helm::new(data))),
+            "kubernetes.io/tls" => Ok(Self::Tls(Tls::new(data))),
+            "kubernetes.io/dockerconfigjson" => Ok(Self::DockerConfig(DockerConfig::new(data))),
             _ => Ok(Self::Any(Any::new(data))),
         }
     }
 
-    fn to_string_key_values(&self) -> ConfigData {
+    fn to_string_key_values(&self, reveal: &SecretReveal) -> ConfigData {
         match self {
-            Self::Helm(helm) => helm.to_string_key_values(),
-            Self::Any(any) => any.to_string_key_values(),
+            Self::Helm(helm) => helm.to_string_key_values(reveal),
+            Self::Tls(tls) => tls.to_string_key_values(reveal),
+            Self::DockerConfig(docker_config) => docker_config.to_string_key_values(reveal),
+            Self::Any(any) => any.to_string_key_values(reveal),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::masked(false, vec![], false)]
+    #[case::revealed(true, vec![], true)]
+    #[case::reveal_key(false, vec![RevealRequest::Key("password".into())], true)]
+    #[case::reveal_other_key(false, vec![RevealRequest::Key("username".into())], false)]
+    #[case::mask_key(true, vec![RevealRequest::Key("password".into())], false)]
+    #[case::toggle_key_twice(
+        false,
+        vec![RevealRequest::Key("password".into()), RevealRequest::Key("password".into())],
+        false
+    )]
+    #[case::reveal_all(false, vec![RevealRequest::All], true)]
+    #[case::reveal_all_resets_keys(
+        false,
+        vec![RevealRequest::Key("password".into()), RevealRequest::All],
+        true
+    )]
+    #[case::mask_all(true, vec![RevealRequest::All], false)]
+    fn secret_reveal(
+        #[case] reveal_all: bool,
+        #[case] requests: Vec<RevealRequest>,
+        #[case] expected: bool,
+    ) {
+        let mut reveal = SecretReveal::new(reveal_all);
+
+        for req in requests {
+            reveal.toggle(req);
         }
+
+        assert_eq!(reveal.is_revealed("password"), expected);
     }
 }
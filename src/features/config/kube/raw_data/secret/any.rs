@@ -5,7 +5,10 @@ use k8s_openapi::ByteString;
 
 use crate::workers::kube::color::Color;
 
-use super::format::{format_error, format_utf8};
+use super::{
+    format::{format_error, format_masked, format_utf8},
+    SecretReveal,
+};
 
 /// any type secret
 #[derive(Debug, Default)]
@@ -18,8 +21,8 @@ impl Any {
         Self { data }
     }
 
-    pub fn to_string_key_values(&self) -> Vec<String> {
-        self.iter()
+    pub fn to_string_key_values(&self, reveal: &SecretReveal) -> Vec<String> {
+        self.iter(reveal)
             .flat_map(|key_value| {
                 key_value
                     .lines()
@@ -29,10 +32,11 @@ impl Any {
             .collect()
     }
 
-    fn iter(&self) -> Iter<'_> {
+    fn iter<'a>(&'a self, reveal: &'a SecretReveal) -> Iter<'a> {
         Iter {
             iter: self.data.iter(),
             color: Color::new(),
+            reveal,
         }
     }
 }
@@ -40,6 +44,7 @@ impl Any {
 struct Iter<'a> {
     iter: btree_map::Iter<'a, String, ByteString>,
     color: Color,
+    reveal: &'a SecretReveal,
 }
 
 impl Iterator for Iter<'_> {
@@ -49,6 +54,10 @@ impl Iterator for Iter<'_> {
 
         let color = self.color.next_color();
 
+        if !self.reveal.is_revealed(key) {
+            return Some(format_masked(key, value.len(), color));
+        }
+
         match String::from_utf8(value.to_vec()) {
             Ok(utf8_data) => Some(format_utf8(key, &utf8_data, color)),
             Err(err) => {
@@ -59,3 +68,61 @@ impl Iterator for Iter<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::features::config::message::RevealRequest;
+
+    fn data() -> BTreeMap<String, ByteString> {
+        BTreeMap::from([
+            ("password".to_string(), ByteString(b"p@ssw0rd".to_vec())),
+            ("username".to_string(), ByteString(b"admin".to_vec())),
+        ])
+    }
+
+    #[test]
+    fn values_are_masked() {
+        let actual = Any::new(data()).to_string_key_values(&SecretReveal::new(false));
+
+        assert_eq!(
+            actual,
+            vec![
+                "\x1b[32mpassword:\x1b[39m •••••• \x1b[90m# 8 bytes\x1b[39m",
+                "\x1b[33musername:\x1b[39m •••••• \x1b[90m# 5 bytes\x1b[39m",
+            ]
+        );
+    }
+
+    #[test]
+    fn values_are_revealed() {
+        let actual = Any::new(data()).to_string_key_values(&SecretReveal::new(true));
+
+        assert_eq!(
+            actual,
+            vec![
+                "\x1b[32mpassword:\x1b[39m p@ssw0rd",
+                "\x1b[33musername:\x1b[39m admin",
+            ]
+        );
+    }
+
+    #[test]
+    fn only_revealed_key_is_shown() {
+        let mut reveal = SecretReveal::new(false);
+        reveal.toggle(RevealRequest::Key("username".into()));
+
+        let actual = Any::new(data()).to_string_key_values(&reveal);
+
+        assert_eq!(
+            actual,
+            vec![
+                "\x1b[32mpassword:\x1b[39m •••••• \x1b[90m# 8 bytes\x1b[39m",
+                "\x1b[33musername:\x1b[39m admin",
+            ]
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine};
+use k8s_openapi::ByteString;
+use serde::Deserialize;
+
+use crate::workers::kube::color::{self, Color};
+
+use super::{any::Any, format::format_utf8, SecretReveal};
+
+/// 認証情報が格納されているキー
+const DOCKER_CONFIG_KEY: &str = ".dockerconfigjson";
+
+/// パスワードの代わりに表示する文字列
+const MASKED_PASSWORD: &str = "********";
+
+#[derive(Debug, Deserialize)]
+struct DockerConfigJson {
+    #[serde(default)]
+    auths: BTreeMap<String, DockerAuth>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DockerAuth {
+    username: Option<String>,
+    password: Option<String>,
+    auth: Option<String>,
+}
+
+impl DockerAuth {
+    /// `username`がない場合は`auth`（base64エンコードされた`username:password`）から取り出す
+    fn username(&self) -> Option<String> {
+        if let Some(username) = &self.username {
+            return Some(username.clone());
+        }
+
+        let auth = general_purpose::STANDARD.decode(self.auth.as_ref()?).ok()?;
+
+        let auth = String::from_utf8(auth).ok()?;
+
+        auth.split_once(':')
+            .map(|(username, _)| username.to_string())
+    }
+
+    fn has_password(&self) -> bool {
+        self.password.is_some() || self.auth.is_some()
+    }
+}
+
+/// kubernetes.io/dockerconfigjson type secret
+#[derive(Debug, Default)]
+pub struct DockerConfig {
+    data: BTreeMap<String, ByteString>,
+}
+
+impl DockerConfig {
+    pub fn new(data: BTreeMap<String, ByteString>) -> Self {
+        Self { data }
+    }
+
+    /// レジストリとユーザー名を表示する
+    ///
+    /// パスワードを含む元のデータは表示しない
+    pub fn to_string_key_values(&self, reveal: &SecretReveal) -> Vec<String> {
+        let decoded = self
+            .data
+            .get(DOCKER_CONFIG_KEY)
+            .ok_or_else(|| anyhow!("no {} data", DOCKER_CONFIG_KEY))
+            .and_then(|ByteString(value)| decode_docker_config(value));
+
+        match decoded {
+            Ok(decoded) => {
+                let decoded = format_utf8(
+                    &format!("{} (decoded)", DOCKER_CONFIG_KEY),
+                    &decoded,
+                    Color::new().next_color(),
+                );
+
+                let rest = self
+                    .data
+                    .iter()
+                    .filter(|(key, _)| *key != DOCKER_CONFIG_KEY)
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect();
+
+                decoded
+                    .lines()
+                    .map(ToString::to_string)
+                    .chain(Any::new(rest).to_string_key_values(reveal))
+                    .collect()
+            }
+            Err(err) => {
+                let err = format!(
+                    "\x1b[{red}m# Failed to decode the '{key}' value: {err}\x1b[39m",
+                    red = color::fg::Color::Red as u8,
+                    key = DOCKER_CONFIG_KEY,
+                    err = err
+                );
+
+                std::iter::once(err)
+                    .chain(Any::new(self.data.clone()).to_string_key_values(reveal))
+                    .collect()
+            }
+        }
+    }
+}
+
+fn decode_docker_config(data: &[u8]) -> Result<String> {
+    let config: DockerConfigJson = serde_json::from_slice(data)?;
+
+    if config.auths.is_empty() {
+        return Ok("auths: {}".into());
+    }
+
+    let ret: Vec<String> = config
+        .auths
+        .iter()
+        .flat_map(|(registry, auth)| {
+            let mut lines = vec![format!("- registry: {}", registry)];
+
+            if let Some(username) = auth.username() {
+                lines.push(format!("  username: {}", username));
+            }
+
+            if auth.has_password() {
+                lines.push(format!("  password: {}", MASKED_PASSWORD));
+            }
+
+            lines
+        })
+        .collect();
+
+    Ok(ret.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn decode_registries_and_usernames() {
+        // auth: "alice:p@ss", "bob:secret"
+        let json = r#"{
+            "auths": {
+                "ghcr.io": {"username": "alice", "password": "p@ss", "auth": "YWxpY2U6cEBzcw=="},
+                "registry.example.com": {"auth": "Ym9iOnNlY3JldA=="}
+            }
+        }"#;
+
+        let actual = decode_docker_config(json.as_bytes()).unwrap();
+
+        let expected = indoc! {"
+            - registry: ghcr.io
+              username: alice
+              password: ********
+            - registry: registry.example.com
+              username: bob
+              password: ********"
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn decode_invalid_json() {
+        assert!(decode_docker_config(b"not json").is_err());
+    }
+
+    #[test]
+    fn raw_data_is_not_shown() {
+        let data = BTreeMap::from([(
+            DOCKER_CONFIG_KEY.to_string(),
+            ByteString(br#"{"auths":{"ghcr.io":{"username":"alice","password":"p@ss"}}}"#.to_vec()),
+        )]);
+
+        let actual = DockerConfig::new(data).to_string_key_values(&SecretReveal::new(true));
+
+        assert_eq!(
+            actual,
+            vec![
+                "\x1b[32m.dockerconfigjson (decoded):\x1b[39m |",
+                "  - registry: ghcr.io",
+                "    username: alice",
+                "    password: ********",
+            ]
+        );
+    }
+
+    #[test]
+    fn raw_data_is_shown_when_decoding_fails() {
+        let data = BTreeMap::from([(
+            DOCKER_CONFIG_KEY.to_string(),
+            ByteString(b"broken".to_vec()),
+        )]);
+
+        let actual = DockerConfig::new(data).to_string_key_values(&SecretReveal::new(true));
+
+        assert_eq!(actual.len(), 2);
+        assert!(actual[0].contains("Failed to decode"));
+        assert_eq!(actual[1], "\x1b[32m.dockerconfigjson:\x1b[39m broken");
+    }
+}
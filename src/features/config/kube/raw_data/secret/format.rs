@@ -1,5 +1,8 @@
 use crate::workers::kube::color::fg::Color;
 
+/// 伏せ字にした値の代わりに表示する文字列
+const MASK: &str = "••••••";
+
 pub(super) fn format_utf8(key: &str, value: &str, color: u8) -> String {
     if value.contains('\n') {
         let mut ret = format!("\x1b[{color}m{key}:\x1b[39m |\n", color = color, key = key);
@@ -29,3 +32,30 @@ pub(super) fn format_error(key: &str, value: &str, err: &str, color: u8) -> Stri
         error = err
     )
 }
+
+/// 値を伏せ字にし、長さのみを表示する
+pub(super) fn format_masked(key: &str, len: usize, color: u8) -> String {
+    format!(
+        "\x1b[{color}m{key}:\x1b[39m {mask} \x1b[{hint_color}m# {len} bytes\x1b[39m",
+        color = color,
+        key = key,
+        mask = MASK,
+        hint_color = Color::DarkGray as u8,
+        len = len
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn masked_value_shows_only_length() {
+        assert_eq!(
+            format_masked("password", 12, 32),
+            "\x1b[32mpassword:\x1b[39m •••••• \x1b[90m# 12 bytes\x1b[39m"
+        );
+    }
+}
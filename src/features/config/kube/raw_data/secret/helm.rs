@@ -6,7 +6,13 @@ use k8s_openapi::ByteString;
 
 use crate::workers::kube::color::{self, Color};
 
-use super::format::{format_error, format_utf8};
+use super::{
+    format::{format_error, format_masked, format_utf8},
+    SecretReveal,
+};
+
+/// リリース情報が格納されているキー
+const RELEASE_KEY: &str = "release";
 
 #[derive(Debug, Default)]
 pub struct Helm {
@@ -18,13 +24,18 @@ impl Helm {
         Self { data }
     }
 
-    pub fn to_string_key_values(&self) -> Vec<String> {
-        let Some(ByteString(value)) = self.data.get("release") else {
+    /// リリース情報にはvaluesが含まれるため、伏せ字の場合はデコードした値も表示しない
+    pub fn to_string_key_values(&self, reveal: &SecretReveal) -> Vec<String> {
+        let Some(ByteString(value)) = self.data.get(RELEASE_KEY) else {
             return vec!["no release data".into()];
         };
 
         let mut color = Color::new();
 
+        if !reveal.is_revealed(RELEASE_KEY) {
+            return vec![format_masked(RELEASE_KEY, value.len(), color.next_color())];
+        }
+
         let decoded_release = match decode_release(value) {
             Ok(decoded) => {
                 let color = color.next_color();
@@ -42,10 +53,10 @@ impl Helm {
         let color = color.next_color();
 
         let release = match String::from_utf8(value.to_vec()) {
-            Ok(utf8_data) => format_utf8("release", &utf8_data, color),
+            Ok(utf8_data) => format_utf8(RELEASE_KEY, &utf8_data, color),
             Err(err) => {
                 let base64_encoded = general_purpose::STANDARD.encode(value);
-                format_error("release", &base64_encoded, &err.to_string(), color)
+                format_error(RELEASE_KEY, &base64_encoded, &err.to_string(), color)
             }
         };
 
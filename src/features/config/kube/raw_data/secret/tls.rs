@@ -0,0 +1,280 @@
+use std::{
+    collections::BTreeMap,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use anyhow::{bail, Result};
+use jiff::Timestamp;
+use k8s_openapi::ByteString;
+use x509_parser::{extensions::GeneralName, pem::Pem};
+
+use crate::workers::kube::color::{self, Color};
+
+use super::{any::Any, format::format_utf8, SecretReveal};
+
+/// 証明書が格納されているキー
+const CERTIFICATE_KEY: &str = "tls.crt";
+
+/// 有効期限が近いとみなす残り日数
+const EXPIRY_WARNING_DAYS: i64 = 30;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// kubernetes.io/tls type secret
+#[derive(Debug, Default)]
+pub struct Tls {
+    data: BTreeMap<String, ByteString>,
+}
+
+impl Tls {
+    pub fn new(data: BTreeMap<String, ByteString>) -> Self {
+        Self { data }
+    }
+
+    pub fn to_string_key_values(&self, reveal: &SecretReveal) -> Vec<String> {
+        self.to_string_key_values_at(reveal, Timestamp::now())
+    }
+
+    /// 証明書の情報は秘匿情報ではないため、伏せ字にせず常に表示する
+    fn to_string_key_values_at(&self, reveal: &SecretReveal, now: Timestamp) -> Vec<String> {
+        let decoded = match self.data.get(CERTIFICATE_KEY) {
+            Some(ByteString(value)) => {
+                match decode_certificates(value, now) {
+                    Ok(decoded) => {
+                        format_utf8(
+                            &format!("{} (decoded)", CERTIFICATE_KEY),
+                            &decoded,
+                            Color::new().next_color(),
+                        )
+                    }
+                    Err(err) => {
+                        format!(
+                            "\x1b[{red}m# Failed to decode the '{key}' value: {err}\x1b[39m",
+                            red = color::fg::Color::Red as u8,
+                            key = CERTIFICATE_KEY,
+                            err = err
+                        )
+                    }
+                }
+            }
+            None => format!("no {} data", CERTIFICATE_KEY),
+        };
+
+        decoded
+            .lines()
+            .map(ToString::to_string)
+            .chain(Any::new(self.data.clone()).to_string_key_values(reveal))
+            .collect()
+    }
+}
+
+/// PEM形式の証明書チェーンから各証明書の情報を取り出す
+fn decode_certificates(data: &[u8], now: Timestamp) -> Result<String> {
+    let mut ret = Vec::new();
+
+    for pem in Pem::iter_from_buffer(data) {
+        let pem = pem?;
+
+        if pem.label != "CERTIFICATE" {
+            continue;
+        }
+
+        let cert = pem.parse_x509()?;
+
+        let sans = cert
+            .subject_alternative_name()?
+            .map(|ext| {
+                ext.value
+                    .general_names
+                    .iter()
+                    .map(format_general_name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+
+        let validity = cert.validity();
+
+        let not_before = Timestamp::from_second(validity.not_before.timestamp())?;
+        let not_after = Timestamp::from_second(validity.not_after.timestamp())?;
+
+        ret.push(format!("- subject: {}", cert.subject()));
+        ret.push(format!("  issuer: {}", cert.issuer()));
+        ret.push(format!("  serial: {}", cert.raw_serial_as_string()));
+        ret.push(format!("  sans: {}", sans));
+        ret.push(format!("  notBefore: {}", not_before));
+        ret.push(format!(
+            "  notAfter: {} {}",
+            not_after,
+            format_expiry(not_after, now)
+        ));
+    }
+
+    if ret.is_empty() {
+        bail!("no certificate found");
+    }
+
+    Ok(ret.join("\n"))
+}
+
+/// 有効期限までの日数を色付きで表示する
+fn format_expiry(not_after: Timestamp, now: Timestamp) -> String {
+    let remaining = not_after.as_second() - now.as_second();
+
+    if remaining < 0 {
+        let days = -remaining / SECONDS_PER_DAY;
+
+        return color::fg::Color::Red.wrap(format!("# expired {} days ago", days));
+    }
+
+    let days = remaining / SECONDS_PER_DAY;
+
+    let color = if days < EXPIRY_WARNING_DAYS {
+        color::fg::Color::Yellow
+    } else {
+        color::fg::Color::Green
+    };
+
+    color.wrap(format!("# expires in {} days", days))
+}
+
+fn format_general_name(name: &GeneralName) -> String {
+    match name {
+        GeneralName::DNSName(name) => format!("DNS:{}", name),
+        GeneralName::RFC822Name(name) => format!("email:{}", name),
+        GeneralName::URI(uri) => format!("URI:{}", uri),
+        GeneralName::IPAddress(bytes) => {
+            let ip = match bytes.len() {
+                4 => {
+                    <[u8; 4]>::try_from(*bytes)
+                        .ok()
+                        .map(Ipv4Addr::from)
+                        .map(IpAddr::from)
+                }
+                16 => {
+                    <[u8; 16]>::try_from(*bytes)
+                        .ok()
+                        .map(Ipv6Addr::from)
+                        .map(IpAddr::from)
+                }
+                _ => None,
+            };
+
+            match ip {
+                Some(ip) => format!("IP:{}", ip),
+                None => name.to_string(),
+            }
+        }
+        _ => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    /// CN=example.com, SAN=DNS:example.com,DNS:www.example.com,IP:10.0.0.1
+    /// 2025-01-01T00:00:00Z ~ 2026-01-01T00:00:00Z
+    const CERTIFICATE: &str = indoc! {"
+        -----BEGIN CERTIFICATE-----
+        MIIBxTCCAWugAwIBAgIDGis8MAoGCCqGSM49BAMCMCgxFDASBgNVBAMMC2V4YW1w
+        bGUuY29tMRAwDgYDVQQKDAdFeGFtcGxlMB4XDTI1MDEwMTAwMDAwMFoXDTI2MDEw
+        MTAwMDAwMFowKDEUMBIGA1UEAwwLZXhhbXBsZS5jb20xEDAOBgNVBAoMB0V4YW1w
+        bGUwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAATux9Q/NeeESqAqRhEyzHD97ILb
+        hTAG9xuKUaJ388F31PJbiHk3XkMNjXWJeuKRPxhprfByw2RUEiVbCZ3vaknSo4GD
+        MIGAMB0GA1UdDgQWBBQLN5PeR3rOpw+Cz8GWbtKxv3+pUzAfBgNVHSMEGDAWgBQL
+        N5PeR3rOpw+Cz8GWbtKxv3+pUzAPBgNVHRMBAf8EBTADAQH/MC0GA1UdEQQmMCSC
+        C2V4YW1wbGUuY29tgg93d3cuZXhhbXBsZS5jb22HBAoAAAEwCgYIKoZIzj0EAwID
+        SAAwRQIganAMoDjU7jXXTPphJukWK+VuJ8V4hfu0742kSWS7ev0CIQDKVo3CJkPZ
+        9fmdUOb0/7/d5vELtbss6mErsSCZbHL8bw==
+        -----END CERTIFICATE-----
+    "};
+
+    #[test]
+    fn decode_certificate() {
+        let now: Timestamp = "2025-06-01T00:00:00Z".parse().unwrap();
+
+        let actual = decode_certificates(CERTIFICATE.as_bytes(), now).unwrap();
+
+        let expected = indoc! {"
+            - subject: CN=example.com, O=Example
+              issuer: CN=example.com, O=Example
+              serial: 1a:2b:3c
+              sans: DNS:example.com, DNS:www.example.com, IP:10.0.0.1
+              notBefore: 2025-01-01T00:00:00Z
+              notAfter: 2026-01-01T00:00:00Z \x1b[32m# expires in 214 days\x1b[39m"
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn decode_certificate_chain() {
+        let now: Timestamp = "2025-06-01T00:00:00Z".parse().unwrap();
+
+        let chain = format!("{}{}", CERTIFICATE, CERTIFICATE);
+
+        let actual = decode_certificates(chain.as_bytes(), now).unwrap();
+
+        assert_eq!(actual.matches("- subject:").count(), 2);
+    }
+
+    #[test]
+    fn decode_certificate_without_pem() {
+        let now = Timestamp::UNIX_EPOCH;
+
+        assert!(decode_certificates(b"not a certificate", now).is_err());
+    }
+
+    #[rstest]
+    #[case::valid("2025-06-01T00:00:00Z", "\x1b[32m# expires in 214 days\x1b[39m")]
+    #[case::expiring_soon("2025-12-22T00:00:00Z", "\x1b[33m# expires in 10 days\x1b[39m")]
+    #[case::expired("2026-02-01T00:00:00Z", "\x1b[31m# expired 31 days ago\x1b[39m")]
+    fn expiry(#[case] now: &str, #[case] expected: &str) {
+        let not_after: Timestamp = "2026-01-01T00:00:00Z".parse().unwrap();
+
+        assert_eq!(format_expiry(not_after, now.parse().unwrap()), expected);
+    }
+
+    #[test]
+    fn tls_secret_shows_decoded_certificate_and_raw_data() {
+        let data = BTreeMap::from([
+            (
+                "tls.crt".to_string(),
+                ByteString(CERTIFICATE.as_bytes().to_vec()),
+            ),
+            ("tls.key".to_string(), ByteString(b"key".to_vec())),
+        ]);
+
+        let now: Timestamp = "2025-06-01T00:00:00Z".parse().unwrap();
+
+        let actual = Tls::new(data).to_string_key_values_at(&SecretReveal::new(true), now);
+
+        assert_eq!(actual[0], "\x1b[32mtls.crt (decoded):\x1b[39m |");
+        assert_eq!(actual[1], "  - subject: CN=example.com, O=Example");
+        assert!(actual.contains(&"\x1b[33mtls.key:\x1b[39m key".to_string()));
+    }
+
+    #[test]
+    fn tls_secret_shows_decoded_certificate_when_masked() {
+        let data = BTreeMap::from([
+            (
+                "tls.crt".to_string(),
+                ByteString(CERTIFICATE.as_bytes().to_vec()),
+            ),
+            ("tls.key".to_string(), ByteString(b"key".to_vec())),
+        ]);
+
+        let now: Timestamp = "2025-06-01T00:00:00Z".parse().unwrap();
+
+        let actual = Tls::new(data).to_string_key_values_at(&SecretReveal::new(false), now);
+
+        assert_eq!(actual[1], "  - subject: CN=example.com, O=Example");
+        assert!(actual
+            .contains(&"\x1b[33mtls.key:\x1b[39m •••••• \x1b[90m# 3 bytes\x1b[39m".to_string()));
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
 
 pub type ConfigData = Vec<String>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RequestData {
     pub name: String,
     pub namespace: String,
@@ -25,9 +25,20 @@ pub enum ConfigMessage {
     /// Replace the active column composition (sent from the column dialog).
     /// The poller will use the new columns on the next poll.
     ColumnsRequest(ConfigColumns),
+    /// Toggle whether secret values are shown in clear text.
+    /// The state is reset to the default when another secret is selected.
+    Reveal(RevealRequest),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevealRequest {
+    /// Toggle all keys of the selected secret.
+    All,
+    /// Toggle a single key of the selected secret.
+    Key(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfigRequest {
     ConfigMap(RequestData),
     Secret(RequestData),
@@ -60,6 +71,12 @@ impl From<ConfigRequest> for Message {
     }
 }
 
+impl From<RevealRequest> for Message {
+    fn from(req: RevealRequest) -> Self {
+        ConfigMessage::Reveal(req).into()
+    }
+}
+
 impl From<ConfigResponse> for Message {
     fn from(res: ConfigResponse) -> Self {
         ConfigMessage::Response(res).into()
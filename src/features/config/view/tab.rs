@@ -1,16 +1,11 @@
-use std::{cell::RefCell, rc::Rc};
-
-use crossbeam::channel::Sender;
 use ratatui::layout::{Constraint, Direction};
 
 use crate::{
-    clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
     features::{
         component_id::CONFIG_TAB_ID,
         config::{ConfigColumns, ConfigLabelColumn},
+        tab::{TabColumns, TabParams},
     },
-    message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
         Tab,
@@ -35,17 +30,25 @@ pub struct ConfigTab {
 impl ConfigTab {
     pub fn new(
         title: &'static str,
-        tx: &Sender<Message>,
-        clipboard: &Option<Rc<RefCell<Clipboard>>>,
-        split_direction: Direction,
-        default_columns: ConfigColumns,
-        label_registry: Vec<ConfigLabelColumn>,
-        theme: WidgetThemeConfig,
+        params: TabParams<'_, TabColumns<ConfigColumns, ConfigLabelColumn>>,
     ) -> Self {
+        let TabParams {
+            tx,
+            clipboard,
+            split_direction,
+            columns:
+                TabColumns {
+                    default: default_columns,
+                    labels: label_registry,
+                },
+            theme,
+            keymap,
+        } = params;
+
         let error_theme = theme.error.clone().into();
 
-        let config_widget = config_widget(tx, label_registry.clone(), theme.clone());
-        let raw_data_widget = raw_data_widget(clipboard, theme.clone());
+        let config_widget = config_widget(tx, label_registry.clone(), theme.clone(), keymap);
+        let raw_data_widget = raw_data_widget(tx, clipboard, theme.clone(), keymap);
         let config_columns_dialog =
             config_columns_dialog(tx, default_columns, label_registry, theme.clone());
         let config_filter_help_dialog = config_filter_help_widget(theme);
@@ -1,7 +1,10 @@
 use crossbeam::channel::Sender;
 
 use crate::{
-    config::theme::WidgetThemeConfig,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
     features::{
         component_id::{CONFIG_RAW_DATA_WIDGET_ID, CONFIG_WIDGET_ID},
         config::{
@@ -33,6 +36,7 @@ pub fn config_widget(
     tx: &Sender<Message>,
     label_registry: Vec<ConfigLabelColumn>,
     theme: WidgetThemeConfig,
+    keymap: &Keymap,
 ) -> Widget<'static> {
     let tx = tx.clone();
 
@@ -53,7 +57,19 @@ pub fn config_widget(
         .filter_form(filter_form)
         .theme(table_theme)
         .filter_applicator(config_filter_applicator(label_registry, tx.clone()))
-        .action('t', open_config_columns_dialog())
+        .sort_actions(
+            keymap.events(Action::SortNext),
+            keymap.events(Action::SortPrev),
+        )
+        .mark_actions(
+            keymap.events(Action::ToggleMark),
+            keymap.events(Action::MarkRange),
+            keymap.events(Action::MarkAll),
+        )
+        .actions(
+            keymap.events(Action::OpenColumns),
+            open_config_columns_dialog(),
+        )
         .block_injection(block_injection())
         .on_select(on_select(tx))
         .build()
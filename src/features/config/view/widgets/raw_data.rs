@@ -1,26 +1,37 @@
 use std::{cell::RefCell, rc::Rc};
 
+use crossbeam::channel::Sender;
 use ratatui::widgets::Block;
 
 use crate::{
     clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
-    features::component_id::CONFIG_RAW_DATA_WIDGET_ID,
-    ui::widget::{
-        SearchForm,
-        SearchFormTheme,
-        Text,
-        TextTheme,
-        Widget,
-        WidgetBase,
-        WidgetTheme,
-        WidgetTrait as _,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
+    features::{component_id::CONFIG_RAW_DATA_WIDGET_ID, config::message::RevealRequest},
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{
+            SearchForm,
+            SearchFormTheme,
+            Text,
+            TextTheme,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
     },
 };
 
 pub fn raw_data_widget(
+    tx: &Sender<Message>,
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
     theme: WidgetThemeConfig,
+    keymap: &Keymap,
 ) -> Widget<'static> {
     let widget_theme = WidgetTheme::from(theme.clone());
     let search_theme = SearchFormTheme::from(theme.clone());
@@ -39,7 +50,12 @@ pub fn raw_data_widget(
         .search_form(search_form)
         .theme(text_theme)
         .wrap()
-        .block_injection(block_injection());
+        .block_injection(block_injection())
+        .actions(
+            keymap.events(Action::RevealKey),
+            reveal_focused_key(tx.clone()),
+        )
+        .actions(keymap.events(Action::RevealAll), reveal_all(tx.clone()));
 
     if let Some(cb) = clipboard {
         builder.clipboard(cb.clone())
@@ -61,3 +77,100 @@ fn block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
         base.render_block(text.can_activate() && is_active, is_mouse_over)
     }
 }
+
+/// フォーカスしている行のキーの値の表示・伏せ字を切り替える
+fn reveal_focused_key(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let text = w.find_widget(CONFIG_RAW_DATA_WIDGET_ID).as_text();
+
+        let Some(index) = text.focused_line_index() else {
+            return EventResult::Nop;
+        };
+
+        let lines: Vec<&str> = text.raw_lines().take(index + 1).collect();
+
+        if let Some(key) = focused_key(&lines) {
+            tx.send(RevealRequest::Key(key).into())
+                .expect("Failed to send RevealRequest::Key");
+        }
+
+        EventResult::Nop
+    }
+}
+
+/// すべてのキーの値の表示・伏せ字を切り替える
+fn reveal_all(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |_: &mut Window| {
+        tx.send(RevealRequest::All.into())
+            .expect("Failed to send RevealRequest::All");
+
+        EventResult::Nop
+    }
+}
+
+/// 最後の行が属するキーを返す
+///
+/// 複数行の値はインデントされているため、キーの行が見つかるまで遡る
+fn focused_key(lines: &[&str]) -> Option<String> {
+    lines.iter().rev().find_map(|line| parse_key(line))
+}
+
+/// `\x1b[32mkey:\x1b[39m value` 形式の行からキーを取り出す
+///
+/// デコードした値（`key (decoded)`）は元のキーとして扱う
+fn parse_key(line: &str) -> Option<String> {
+    let (color, rest) = line.strip_prefix("\x1b[")?.split_once('m')?;
+
+    if color.is_empty() || !color.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let (key, _) = rest.split_once(":\x1b[39m")?;
+
+    let key = key.strip_suffix(" (decoded)").unwrap_or(key);
+
+    Some(key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crossbeam::channel;
+    use pretty_assertions::assert_eq;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use rstest::rstest;
+
+    #[test]
+    fn シフト付きのvですべてのキーの表示を切り替える() {
+        let (tx, _rx) = channel::unbounded();
+
+        let mut widget =
+            raw_data_widget(&tx, &None, WidgetThemeConfig::default(), &Keymap::default());
+
+        // crossterm は大文字を SHIFT 付きで通知する
+        let actual = widget.on_key_event(KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT));
+
+        assert!(matches!(actual, EventResult::Callback(_)));
+    }
+
+    #[rstest]
+    #[case::single_line(vec!["\x1b[32mpassword:\x1b[39m •••••• \x1b[90m# 8 bytes\x1b[39m"], Some("password"))]
+    #[case::multi_line(
+        vec!["\x1b[32mconfig:\x1b[39m |", "  a: 1", "  b: 2"],
+        Some("config")
+    )]
+    #[case::next_key(
+        vec!["\x1b[32mconfig:\x1b[39m |", "  a: 1", "\x1b[33mtoken:\x1b[39m abc"],
+        Some("token")
+    )]
+    #[case::decoded(
+        vec!["\x1b[32mtls.crt (decoded):\x1b[39m |", "  - subject: CN=example.com"],
+        Some("tls.crt")
+    )]
+    #[case::error_line(vec!["\x1b[31m# Failed to decode the 'tls.crt' value: error\x1b[39m"], None)]
+    #[case::no_data(vec!["no data"], None)]
+    fn focused_key_cases(#[case] lines: Vec<&str>, #[case] expected: Option<&str>) {
+        assert_eq!(focused_key(&lines), expected.map(String::from));
+    }
+}
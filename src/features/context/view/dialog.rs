@@ -13,6 +13,7 @@ use crate::{
             EVENT_WIDGET_ID,
             MULTIPLE_NAMESPACES_DIALOG_ID,
             NETWORK_DESCRIPTION_WIDGET_ID,
+            NETWORK_RELATED_RESOURCE_DIALOG_ID,
             NETWORK_WIDGET_ID,
             POD_LOG_QUERY_WIDGET_ID,
             POD_LOG_WIDGET_ID,
@@ -108,6 +109,7 @@ fn clear_widgets_and_close_dialog(w: &mut Window) {
     w.widget_clear(CONFIG_RAW_DATA_WIDGET_ID);
     w.widget_clear(NETWORK_WIDGET_ID);
     w.widget_clear(NETWORK_DESCRIPTION_WIDGET_ID);
+    w.widget_clear(NETWORK_RELATED_RESOURCE_DIALOG_ID);
     w.widget_clear(EVENT_WIDGET_ID);
     w.widget_clear(API_WIDGET_ID);
     w.widget_clear(YAML_WIDGET_ID);
@@ -0,0 +1,212 @@
+use anyhow::{anyhow, bail, Context as _, Result};
+use http::header::{ACCEPT, CONTENT_TYPE};
+use kube::core::request::Error as KubeRequestError;
+use serde_yaml::Value;
+
+use crate::{
+    features::{
+        api_resources::kube::ApiResources,
+        get::message::GetRequest,
+        yaml::{kube::resource_url, message::YamlTarget},
+    },
+    kube::{KubeClient, KubeClientRequest as _},
+    logger,
+};
+
+use super::message::EditDocument;
+
+/// リソースを置き換える際のフィールドマネージャー
+const FIELD_MANAGER: &str = "kubetui";
+
+/// 編集するリソース
+#[derive(Debug, Clone)]
+pub enum EditTarget {
+    Yaml(YamlTarget),
+    Get(GetRequest),
+}
+
+impl EditTarget {
+    fn title(&self) -> String {
+        let (namespace, kind, name) = match self {
+            Self::Yaml(target) => {
+                (
+                    &target.namespace,
+                    target.kind.name().to_string(),
+                    &target.name,
+                )
+            }
+            Self::Get(req) => (&req.namespace, req.kind.to_string(), &req.name),
+        };
+
+        if namespace.is_empty() {
+            format!("{}/{}", kind, name)
+        } else {
+            format!("{}/{}/{}", namespace, kind, name)
+        }
+    }
+
+    fn url(&self, api_resources: &ApiResources) -> Result<String> {
+        match self {
+            Self::Yaml(target) => {
+                resource_url(api_resources, &target.kind, &target.name, &target.namespace)
+            }
+            Self::Get(req) => {
+                Ok(format!(
+                    "{}/{}",
+                    req.kind.url_path(&req.namespace),
+                    req.name
+                ))
+            }
+        }
+    }
+}
+
+/// 編集用にリソースのyamlを取得する
+pub async fn fetch_document(
+    client: &KubeClient,
+    api_resources: &ApiResources,
+    target: Option<EditTarget>,
+) -> Result<EditDocument> {
+    let target = target.ok_or_else(|| anyhow!("No resource is selected"))?;
+
+    let path = target.url(api_resources)?;
+
+    logger!(info, "Fetching resource for edit [{}]", path);
+
+    let res = client.request_text(&path).await?;
+
+    Ok(EditDocument {
+        title: target.title(),
+        path,
+        yaml: to_edit_yaml(&res)?,
+    })
+}
+
+/// 編集したyamlでリソースを置き換える
+///
+/// `kubectl edit` と同様に `resourceVersion` で競合を検出する
+pub async fn replace_resource(client: &KubeClient, document: &EditDocument) -> Result<()> {
+    let body = parse_edited_yaml(&document.yaml)?;
+
+    let path = format!("{}?fieldManager={}", document.path, FIELD_MANAGER);
+
+    logger!(info, "Replacing resource [{}]", path);
+
+    let request = http::Request::put(&path)
+        .header(ACCEPT, "application/json")
+        .header(CONTENT_TYPE, "application/json")
+        .body(serde_json::to_vec(&body)?)
+        .map_err(KubeRequestError::BuildRequest)?;
+
+    client
+        .to_client()
+        .request_text(request)
+        .await
+        .with_context(|| format!("Failed to apply {}", document))?;
+
+    Ok(())
+}
+
+/// 取得したjsonを編集用のyamlに変換する
+fn to_edit_yaml(json: &str) -> Result<String> {
+    let mut value: Value = serde_json::from_str(json)?;
+
+    if let Some(Value::Mapping(md)) = value.get_mut("metadata") {
+        md.remove("managedFields");
+    }
+
+    Ok(serde_yaml::to_string(&value)?)
+}
+
+/// 編集したyamlをリクエストボディに変換する
+fn parse_edited_yaml(yaml: &str) -> Result<serde_json::Value> {
+    let value: serde_json::Value = serde_yaml::from_str(yaml).context("Invalid yaml")?;
+
+    if !value.is_object() {
+        bail!("Edited yaml must be a single object");
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::features::get::message::GetYamlKind;
+
+    #[test]
+    fn to_edit_yaml_removes_managed_fields() {
+        let json = r#"{
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": {
+                "name": "app",
+                "namespace": "default",
+                "managedFields": [{"manager": "kubectl"}]
+            },
+            "data": {"key": "value"}
+        }"#;
+
+        let expected = indoc! {"
+            apiVersion: v1
+            kind: ConfigMap
+            metadata:
+              name: app
+              namespace: default
+            data:
+              key: value
+        "};
+
+        assert_eq!(to_edit_yaml(json).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_edited_yaml_to_json() {
+        let yaml = indoc! {"
+            apiVersion: v1
+            kind: ConfigMap
+            metadata:
+              name: app
+            data:
+              replicas: '3'
+        "};
+
+        let expected = serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": {"name": "app"},
+            "data": {"replicas": "3"},
+        });
+
+        assert_eq!(parse_edited_yaml(yaml).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_edited_yaml_rejects_invalid_yaml() {
+        assert!(parse_edited_yaml("metadata: [").is_err());
+    }
+
+    #[test]
+    fn parse_edited_yaml_rejects_non_object() {
+        assert!(parse_edited_yaml("- a\n- b\n").is_err());
+    }
+
+    #[test]
+    fn get_target_title_and_url() {
+        let target = EditTarget::Get(GetRequest {
+            name: "app".into(),
+            namespace: "default".into(),
+            kind: GetYamlKind::ConfigMap,
+        });
+
+        assert_eq!(target.title(), "default/configmaps/app");
+        assert_eq!(
+            target.url(&Default::default()).unwrap(),
+            "/api/v1/namespaces/default/configmaps/app"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+
+use anyhow::Result;
+
+use crate::{message::Message, workers::kube::message::Kube};
+
+#[derive(Debug)]
+pub enum EditMessage {
+    Request(EditRequest),
+    Response(EditResponse),
+}
+
+#[derive(Debug)]
+pub enum EditRequest {
+    /// 表示中のリソースのyamlを編集用に取得する
+    Start(EditSource),
+
+    /// 編集したyamlでリソースを置き換える
+    Apply(EditDocument),
+}
+
+#[derive(Debug)]
+pub enum EditResponse {
+    /// 編集用のyamlを取得した
+    Fetched(Result<EditDocument>),
+
+    /// 編集したyamlを適用した
+    ///
+    /// 失敗した場合に編集し直せるよう、適用しようとしたyamlを返す
+    Applied {
+        document: EditDocument,
+        result: Result<()>,
+    },
+}
+
+/// 編集を開始したビュー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditSource {
+    /// Yamlタブ
+    Yaml,
+
+    /// テーブルから開いたYamlダイアログ
+    Get,
+}
+
+/// エディタで編集するyaml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditDocument {
+    /// 表示用のリソース名
+    pub title: String,
+
+    /// リソースのURLパス
+    pub path: String,
+
+    pub yaml: String,
+}
+
+impl fmt::Display for EditDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.title)
+    }
+}
+
+impl From<EditRequest> for Message {
+    fn from(req: EditRequest) -> Self {
+        Message::Kube(Kube::Edit(EditMessage::Request(req)))
+    }
+}
+
+impl From<EditResponse> for Message {
+    fn from(res: EditResponse) -> Self {
+        Message::Kube(Kube::Edit(EditMessage::Response(res)))
+    }
+}
@@ -0,0 +1,89 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    config::theme::ThemeConfig,
+    features::{
+        component_id::{EDIT_ERROR_DIALOG_ID, YAML_DIALOG_ID, YAML_WIDGET_ID},
+        edit::message::{EditRequest, EditSource},
+    },
+    message::Message,
+    ui::{
+        event::{CallbackFn, EventResult},
+        widget::{
+            SearchForm,
+            SearchFormTheme,
+            Text,
+            TextTheme,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+pub struct EditErrorDialog {
+    pub widget: Widget<'static>,
+}
+
+impl EditErrorDialog {
+    pub fn new(theme: ThemeConfig) -> Self {
+        Self {
+            widget: widget(theme),
+        }
+    }
+}
+
+fn widget(theme: ThemeConfig) -> Widget<'static> {
+    let widget_theme = WidgetTheme::from(theme.component.clone());
+    let search_theme = SearchFormTheme::from(theme.component.clone());
+    let text_theme = TextTheme::from(theme.component);
+
+    let widget_base = WidgetBase::builder()
+        .title("Edit")
+        .theme(widget_theme)
+        .build();
+
+    let search_form = SearchForm::builder().theme(search_theme).build();
+
+    Text::builder()
+        .id(EDIT_ERROR_DIALOG_ID)
+        .widget_base(widget_base)
+        .search_form(search_form)
+        .theme(text_theme)
+        .wrap()
+        .build()
+        .into()
+}
+
+/// 表示中のリソースのyamlをエディタで編集する
+///
+/// Yamlタブ、またはYamlダイアログを開いている場合のみ有効
+pub fn edit_yaml(tx: Sender<Message>) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let source = match w.opening_dialog_id() {
+            Some(YAML_DIALOG_ID) => EditSource::Get,
+            Some(_) => return EventResult::Ignore,
+            None if w.active_tab().active_widget().id() == YAML_WIDGET_ID => EditSource::Yaml,
+            None => return EventResult::Ignore,
+        };
+
+        tx.send(EditRequest::Start(source).into())
+            .expect("Failed to send EditRequest::Start");
+
+        EventResult::Nop
+    }
+}
+
+/// 編集に失敗した理由をダイアログに表示する
+pub fn open_edit_error(w: &mut Window, title: Option<&str>, err: &anyhow::Error) {
+    let dialog = w.find_widget_mut(EDIT_ERROR_DIALOG_ID);
+
+    *dialog.widget_base_mut().append_title_mut() =
+        title.map(|title| format!(" : {}", title).into());
+
+    w.set_widget_error(EDIT_ERROR_DIALOG_ID, err);
+
+    w.open_dialog(EDIT_ERROR_DIALOG_ID);
+}
@@ -1,2 +1,6 @@
+mod filter;
 pub mod kube;
+pub mod message;
 pub mod view;
+
+pub use filter::event_filter_applicator;
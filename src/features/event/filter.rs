@@ -0,0 +1,33 @@
+//! Event tab filter: parser + `TableFilterApplicator` factory.
+//!
+//! The applicator wires `parse_event_filter` (which builds on the shared
+//! `parse_table_filter`) into the Table widget with `EnterToConfirm` strategy.
+//! Events are filtered client-side only, so there are no `on_apply`/`on_cancel`
+//! hooks. Typing `?` or `help` in the filter input opens the
+//! `EVENT_FILTER_HELP_DIALOG_ID` dialog.
+
+mod parser;
+
+use crate::{
+    features::component_id::EVENT_FILTER_HELP_DIALOG_ID,
+    ui::widget::{ApplyStrategy, TableFilterApplicator, TableFilterParser},
+};
+
+pub use parser::parse_event_filter;
+
+pub fn event_filter_applicator() -> TableFilterApplicator {
+    let parser: TableFilterParser = (move |input: &str| parse_event_filter(input)).into();
+
+    TableFilterApplicator::new(parser, ApplyStrategy::EnterToConfirm)
+        .with_help_dialog(EVENT_FILTER_HELP_DIALOG_ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applicator_constructs_without_panic() {
+        let _ = event_filter_applicator();
+    }
+}
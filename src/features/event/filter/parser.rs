@@ -0,0 +1,97 @@
+//! Event filter parser.
+//!
+//! Delegates tokenization/quoting/predicate-building to the shared
+//! `parse_table_filter`. The Event table has no NAME column, so bare values
+//! are matched against OBJECT instead. Events are not filtered server-side:
+//! `label:` is rejected, and `namespace:` returns the same guidance message as
+//! the other tabs.
+
+use crate::{
+    features::event::kube::EVENT_HEADER,
+    ui::widget::{normalize_column_name, parse_table_filter, TableFilterPredicate},
+};
+
+pub fn parse_event_filter(input: &str) -> Result<TableFilterPredicate, String> {
+    let mut predicate = parse_table_filter(input, |column| {
+        let normalized = normalize_column_name(column);
+        if normalized == "namespace" {
+            return Err(
+                "namespace is selected via the namespace selector, not the filter".to_string(),
+            );
+        }
+        if EVENT_HEADER
+            .iter()
+            .any(|header| normalize_column_name(header) == normalized)
+        {
+            Ok(())
+        } else {
+            Err(format!("unknown column '{}'", column))
+        }
+    })?;
+
+    if predicate.label_selector.is_some() {
+        return Err("label selectors are not supported in the Event filter".to_string());
+    }
+
+    // 列名を指定しない値はNAMEの代わりにOBJECTと比較する
+    if let Some(patterns) = predicate.column_includes.remove("name") {
+        predicate
+            .column_includes
+            .entry("object".to_string())
+            .or_default()
+            .extend(patterns);
+    }
+
+    Ok(predicate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn bare_value_becomes_object_include() {
+        let p = parse_event_filter("web").unwrap();
+        assert!(!p.column_includes.contains_key("name"));
+        let patterns = p.column_includes.get("object").expect("object column");
+        assert!(patterns[0].is_match("pod/web-0"));
+    }
+
+    #[test]
+    fn bare_value_and_object_column_are_or_combined() {
+        let p = parse_event_filter("web object:api").unwrap();
+        assert_eq!(p.column_includes.get("object").map(Vec::len), Some(2));
+    }
+
+    #[test]
+    fn builtin_columns_are_accepted() {
+        let p =
+            parse_event_filter("type:Warning reason:BackOff !message:probe last-seen:s$").unwrap();
+        assert!(p.column_includes.contains_key("type"));
+        assert!(p.column_includes.contains_key("reason"));
+        assert!(p.column_includes.contains_key("lastseen"));
+        assert!(p.column_excludes.contains_key("message"));
+    }
+
+    #[test]
+    fn unknown_column_produces_parse_error() {
+        let err = parse_event_filter("status:Running").unwrap_err();
+        assert!(err.contains("unknown column") && err.contains("status"));
+    }
+
+    #[test]
+    fn namespace_returns_guidance_message() {
+        let err = parse_event_filter("namespace:default").unwrap_err();
+        assert_eq!(
+            err,
+            "namespace is selected via the namespace selector, not the filter"
+        );
+    }
+
+    #[test]
+    fn label_selector_is_rejected() {
+        let err = parse_event_filter("label:app=web").unwrap_err();
+        assert_eq!(err, "label selectors are not supported in the Event filter");
+    }
+}
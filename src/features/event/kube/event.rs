@@ -1,4 +1,4 @@
-use std::time;
+use std::{cmp::Reverse, collections::HashMap, sync::Arc, time};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -6,32 +6,36 @@ use crossbeam::channel::Sender;
 use futures::future::try_join_all;
 use k8s_openapi::api::core::v1::Event;
 use kube::Resource as _;
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
+use tokio::sync::RwLock;
 
 use crate::{
+    features::event::message::EventMessage,
     kube::{
         apis::v1_table::{TableRow, ToTime as _},
-        table::{get_resource_per_namespace, insert_ns, KubeTableRow},
+        table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
         KubeClient,
     },
     logger,
     message::Message,
     ui::widget::ansi_color::style_to_ansi,
-    workers::kube::{message::Kube, InfiniteWorker, SharedTargetNamespaces},
+    workers::kube::{InfiniteWorker, SharedTargetNamespaces},
 };
 
+/// イベントを関係するオブジェクトごとにまとめるかどうか
+pub type SharedEventGroup = Arc<RwLock<bool>>;
+
 #[derive(Default, Debug, Clone)]
 pub struct EventConfig {
     pub highlight_rules: Vec<EventHighlightRule>,
 }
 
 impl EventConfig {
-    fn get_style(&self, ty: &str) -> (Style, Style) {
+    fn get_style(&self, ty: &str) -> Option<(Style, Style)> {
         self.highlight_rules
             .iter()
             .find(|rule| rule.ty.is_match(ty))
             .map(|rule| (rule.summary, rule.message))
-            .unwrap_or_else(|| (Style::default(), Style::default().fg(Color::DarkGray)))
     }
 }
 
@@ -46,6 +50,7 @@ pub struct EventHighlightRule {
 pub struct EventPoller {
     tx: Sender<Message>,
     shared_target_namespaces: SharedTargetNamespaces,
+    shared_event_group: SharedEventGroup,
     kube_client: KubeClient,
     config: EventConfig,
 }
@@ -54,12 +59,14 @@ impl EventPoller {
     pub fn new(
         tx: Sender<Message>,
         shared_target_namespaces: SharedTargetNamespaces,
+        shared_event_group: SharedEventGroup,
         kube_client: KubeClient,
         config: EventConfig,
     ) -> Self {
         Self {
             tx,
             shared_target_namespaces,
+            shared_event_group,
             kube_client,
             config,
         }
@@ -72,6 +79,7 @@ impl InfiniteWorker for EventPoller {
         let Self {
             tx,
             shared_target_namespaces,
+            shared_event_group,
             kube_client,
             config,
         } = self;
@@ -81,44 +89,58 @@ impl InfiniteWorker for EventPoller {
         loop {
             interval.tick().await;
             let target_namespaces = shared_target_namespaces.read().await;
+            let grouped = *shared_event_group.read().await;
 
-            let event_list = get_event_table(config, kube_client, &target_namespaces).await;
+            let table = get_event_table(config, kube_client, &target_namespaces, grouped).await;
 
-            if let Err(e) = tx.send(Message::Kube(Kube::Event(event_list))) {
-                logger!(error, "Failed to send Kube::Event: {}", e);
+            if let Err(e) = tx.send(EventMessage::Poll { table, grouped }.into()) {
+                logger!(error, "Failed to send EventMessage::Poll: {}", e);
                 return;
             }
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
 struct EventRow {
+    namespace: String,
+    name: String,
     last_seen: String,
     ty: String,
-    object: String,
     reason: String,
+    object: String,
+    count: u64,
     message: String,
-    namespace: Option<String>,
 }
 
-const TARGET_LEN: usize = 5;
-const TARGET: [&str; TARGET_LEN] = ["Last Seen", "Type", "Object", "Reason", "Message"];
+const TARGET_LEN: usize = 6;
+const TARGET: [&str; TARGET_LEN] = ["Last Seen", "Type", "Reason", "Object", "Count", "Message"];
 
-async fn get_event_per_namespace(
-    client: &KubeClient,
-    namespace: &str,
-    insert_ns: bool,
-) -> Result<Vec<EventRow>> {
+/// Eventタブの列名
+pub const EVENT_HEADER: [&str; TARGET_LEN] =
+    ["LAST SEEN", "TYPE", "REASON", "OBJECT", "COUNT", "MESSAGE"];
+
+async fn get_event_per_namespace(client: &KubeClient, namespace: &str) -> Result<Vec<EventRow>> {
     let tables = get_resource_per_namespace(
         client,
         Event::url_path(&Default::default(), Some(namespace)),
         &TARGET,
         move |row: &TableRow, indexes: &[usize]| {
-            let row: Vec<String> = indexes.iter().map(|i| row.cells[*i].to_string()).collect();
+            let row_cells: Vec<String> =
+                indexes.iter().map(|i| row.cells[*i].to_string()).collect();
+
+            let name = row
+                .object
+                .as_ref()
+                .and_then(|object| object.0.pointer("/metadata/name"))
+                .and_then(|name| name.as_str())
+                .unwrap_or_default()
+                .to_string();
 
             KubeTableRow {
                 namespace: namespace.to_string(),
-                row,
+                name,
+                row: row_cells,
                 ..Default::default()
             }
         },
@@ -129,16 +151,14 @@ async fn get_event_per_namespace(
         .into_iter()
         .map(|table| {
             EventRow {
+                namespace: table.namespace,
+                name: table.name,
                 last_seen: table.row[0].clone(),
                 ty: table.row[1].clone(),
-                object: table.row[2].clone(),
-                reason: table.row[3].clone(),
-                message: table.row[4].clone(),
-                namespace: if insert_ns {
-                    Some(namespace.to_string())
-                } else {
-                    None
-                },
+                reason: table.row[2].clone(),
+                object: table.row[3].clone(),
+                count: table.row[4].parse().unwrap_or(1),
+                message: table.row[5].clone(),
             }
         })
         .collect();
@@ -150,47 +170,237 @@ async fn get_event_table(
     config: &EventConfig,
     client: &KubeClient,
     namespaces: &[String],
-) -> Result<Vec<String>> {
-    let insert_ns = insert_ns(namespaces);
-
+    grouped: bool,
+) -> Result<KubeTable> {
     let jobs = try_join_all(
         namespaces
             .iter()
-            .map(|ns| get_event_per_namespace(client, ns, insert_ns)),
+            .map(|ns| get_event_per_namespace(client, ns)),
     )
     .await?;
 
-    let mut ok_only: Vec<EventRow> = jobs.into_iter().flatten().collect();
+    let rows: Vec<EventRow> = jobs.into_iter().flatten().collect();
+
+    let rows = if grouped { group_by_object(rows) } else { rows };
+
+    Ok(to_table(
+        config,
+        sort_by_last_seen(rows),
+        insert_ns(namespaces),
+    ))
+}
+
+/// 新しいイベントが先頭になるように並べる
+fn sort_by_last_seen(mut rows: Vec<EventRow>) -> Vec<EventRow> {
+    // Time の順序は経過時間の降順なので、逆順にすると新しいものが先頭になる
+    rows.sort_by_key(|row| Reverse(row.last_seen.to_time()));
+    rows
+}
+
+/// 関係するオブジェクトごとに1行にまとめる
+///
+/// LAST SEENとMESSAGEは最新のイベント、COUNTは合計、REASONは新しい順に重複を除いて並べる。
+/// TYPEはWarningが1つでも含まれていればWarningにする。
+fn group_by_object(rows: Vec<EventRow>) -> Vec<EventRow> {
+    let mut groups: Vec<EventRow> = Vec::new();
+    let mut indexes: HashMap<(String, String), usize> = HashMap::new();
 
-    ok_only.sort_by_key(|ev| ev.last_seen.to_time());
+    for row in sort_by_last_seen(rows) {
+        let key = (row.namespace.clone(), row.object.clone());
 
-    Ok(ok_only
-        .iter()
-        .flat_map(|ev| {
-            let (summary_style, message_style) = config.get_style(&ev.ty);
+        let Some(&index) = indexes.get(&key) else {
+            indexes.insert(key, groups.len());
+            groups.push(row);
+            continue;
+        };
 
-            let mut summary = style_to_ansi(summary_style);
+        let group = &mut groups[index];
 
-            summary.push_str(&format!("{:<4}  {:<4}", ev.last_seen, ev.ty));
+        group.count += row.count;
 
-            if let Some(ns) = &ev.namespace {
-                summary.push_str(&format!("  {:<4}", ns));
+        if row.ty == "Warning" {
+            group.ty = row.ty;
+        }
+
+        if !group.reason.split(',').any(|reason| reason == row.reason) {
+            group.reason = format!("{},{}", group.reason, row.reason);
+        }
+    }
+
+    groups
+}
+
+fn to_table(config: &EventConfig, rows: Vec<EventRow>, insert_ns: bool) -> KubeTable {
+    let mut header: Vec<String> = EVENT_HEADER.iter().map(ToString::to_string).collect();
+
+    if insert_ns {
+        header.insert(0, "NAMESPACE".to_string());
+    }
+
+    let rows = rows
+        .into_iter()
+        .map(|ev| {
+            let mut row = vec![
+                ev.last_seen,
+                ev.ty.clone(),
+                ev.reason,
+                ev.object,
+                ev.count.to_string(),
+                ev.message.lines().collect::<Vec<_>>().join(" "),
+            ];
+
+            if insert_ns {
+                row.insert(0, ev.namespace.clone());
             }
 
-            summary.push_str(&format!("  {:<4}  {:<4}", ev.object, ev.reason));
+            if let Some((summary, message)) = config.get_style(&ev.ty) {
+                let (summary, message) = (style_to_ansi(summary), style_to_ansi(message));
 
-            let mut message: Vec<String> = ev
-                .message
-                .lines()
-                .map(|line| format!("{}> {}", style_to_ansi(message_style), line))
-                .collect();
+                let last = row.len() - 1;
 
-            message.push("\x1b[0m".to_string());
+                for (i, cell) in row.iter_mut().enumerate() {
+                    let style = if i == last { &message } else { &summary };
 
-            [summary]
-                .into_iter()
-                .chain(message.into_iter())
-                .collect::<Vec<_>>()
+                    *cell = format!("{}{}\x1b[0m", style, cell);
+                }
+            }
+
+            KubeTableRow {
+                namespace: ev.namespace,
+                name: ev.name,
+                metadata: None,
+                row,
+            }
         })
-        .collect())
+        .collect();
+
+    KubeTable { header, rows }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Color;
+
+    use super::*;
+
+    fn row(object: &str, last_seen: &str, ty: &str, reason: &str, count: u64) -> EventRow {
+        EventRow {
+            namespace: "default".into(),
+            name: format!("{}.{}", object, last_seen),
+            last_seen: last_seen.into(),
+            ty: ty.into(),
+            reason: reason.into(),
+            object: object.into(),
+            count,
+            message: format!("{} {}", reason, last_seen),
+        }
+    }
+
+    #[test]
+    fn 新しいイベントが先頭になる() {
+        let rows = sort_by_last_seen(vec![
+            row("pod/a", "1h2m", "Normal", "Pulled", 1),
+            row("pod/b", "30s", "Normal", "Started", 1),
+            row("pod/c", "5m", "Warning", "BackOff", 1),
+        ]);
+
+        let objects: Vec<&str> = rows.iter().map(|r| r.object.as_str()).collect();
+
+        assert_eq!(objects, vec!["pod/b", "pod/c", "pod/a"]);
+    }
+
+    #[test]
+    fn オブジェクトごとにまとめる() {
+        let rows = group_by_object(vec![
+            row("pod/a", "10m", "Normal", "Pulled", 1),
+            row("pod/b", "3m", "Normal", "Scheduled", 1),
+            row("pod/a", "2m", "Warning", "BackOff", 5),
+            row("pod/a", "1m", "Normal", "Pulled", 2),
+        ]);
+
+        assert_eq!(
+            rows,
+            vec![
+                EventRow {
+                    namespace: "default".into(),
+                    name: "pod/a.1m".into(),
+                    last_seen: "1m".into(),
+                    ty: "Warning".into(),
+                    reason: "Pulled,BackOff".into(),
+                    object: "pod/a".into(),
+                    count: 8,
+                    message: "Pulled 1m".into(),
+                },
+                row("pod/b", "3m", "Normal", "Scheduled", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn 別のnamespaceの同じ名前のオブジェクトはまとめない() {
+        let mut other = row("pod/a", "1m", "Normal", "Pulled", 1);
+        other.namespace = "other".into();
+
+        let rows = group_by_object(vec![row("pod/a", "2m", "Normal", "Pulled", 1), other]);
+
+        assert_eq!(rows.len(), 2);
+    }
+
+    #[test]
+    fn 複数のnamespaceのときnamespace列を先頭に追加する() {
+        let mut ev = row("pod/a", "1m", "Normal", "Pulled", 1);
+        ev.message = "line1\nline2".into();
+
+        let table = to_table(&EventConfig::default(), vec![ev], true);
+
+        assert_eq!(
+            table.header,
+            vec![
+                "NAMESPACE",
+                "LAST SEEN",
+                "TYPE",
+                "REASON",
+                "OBJECT",
+                "COUNT",
+                "MESSAGE"
+            ]
+        );
+        assert_eq!(
+            table.rows[0].row,
+            vec![
+                "default",
+                "1m",
+                "Normal",
+                "Pulled",
+                "pod/a",
+                "1",
+                "line1 line2"
+            ]
+        );
+    }
+
+    #[test]
+    fn typeに一致するルールのスタイルを適用する() {
+        let config = EventConfig {
+            highlight_rules: vec![EventHighlightRule {
+                ty: regex::Regex::new("Warning").unwrap(),
+                summary: Style::default().fg(Color::Red),
+                message: Style::default().fg(Color::Yellow),
+            }],
+        };
+
+        let table = to_table(
+            &config,
+            vec![
+                row("pod/a", "1m", "Warning", "BackOff", 1),
+                row("pod/b", "2m", "Normal", "Pulled", 1),
+            ],
+            false,
+        );
+
+        assert_eq!(table.rows[0].row[0], "\x1b[31m1m\x1b[0m");
+        assert_eq!(table.rows[0].row[5], "\x1b[33mBackOff 1m\x1b[0m");
+        assert_eq!(table.rows[1].row[0], "2m");
+    }
 }
@@ -0,0 +1,20 @@
+use anyhow::Result;
+
+use crate::{kube::table::KubeTable, message::Message, workers::kube::message::Kube};
+
+#[derive(Debug)]
+pub enum EventMessage {
+    Poll {
+        table: Result<KubeTable>,
+        /// 関係するオブジェクトごとにまとめた一覧かどうか
+        grouped: bool,
+    },
+    /// 関係するオブジェクトごとにまとめるかを切り替える
+    ToggleGroup,
+}
+
+impl From<EventMessage> for Message {
+    fn from(m: EventMessage) -> Message {
+        Message::Kube(Kube::Event(m))
+    }
+}
@@ -1,4 +1,4 @@
 mod tab;
-mod widget;
+mod widgets;
 
 pub use tab::*;
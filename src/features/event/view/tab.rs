@@ -1,32 +1,35 @@
-use std::{cell::RefCell, rc::Rc};
-
+use crossbeam::channel::Sender;
 use ratatui::prelude::Constraint;
 
 use crate::{
-    clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
+    config::{keybindings::Keymap, theme::WidgetThemeConfig},
     features::component_id::EVENT_TAB_ID,
+    message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
+        widget::Widget,
         Tab,
     },
 };
 
-use super::widget::event_widget;
+use super::widgets::{event_filter_help_widget, event_widget};
 
 pub struct EventTab {
     pub tab: Tab<'static>,
+    pub event_filter_help_dialog: Widget<'static>,
 }
 
 impl EventTab {
     pub fn new(
         title: &str,
-        clipboard: &Option<Rc<RefCell<Clipboard>>>,
+        tx: &Sender<Message>,
         theme: WidgetThemeConfig,
+        keymap: &Keymap,
     ) -> Self {
         let error_theme = theme.error.clone().into();
 
-        let event_widget = event_widget(clipboard, theme);
+        let event_widget = event_widget(tx, theme.clone(), keymap);
+        let event_filter_help_dialog = event_filter_help_widget(theme);
 
         let layout = TabLayout::new(
             |_| {
@@ -40,6 +43,7 @@ impl EventTab {
 
         EventTab {
             tab: Tab::new(EVENT_TAB_ID, title, [event_widget], layout).error_theme(error_theme),
+            event_filter_help_dialog,
         }
     }
 }
@@ -0,0 +1,5 @@
+mod event;
+mod event_filter_help;
+
+pub(super) use event::*;
+pub(super) use event_filter_help::*;
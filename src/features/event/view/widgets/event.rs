@@ -0,0 +1,89 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
+    features::{
+        component_id::EVENT_WIDGET_ID,
+        event::{event_filter_applicator, message::EventMessage},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{
+            FilterForm,
+            FilterFormTheme,
+            Table,
+            TableTheme,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+pub fn event_widget(
+    tx: &Sender<Message>,
+    theme: WidgetThemeConfig,
+    keymap: &Keymap,
+) -> Widget<'static> {
+    let widget_theme = WidgetTheme::from(theme.clone());
+    let filter_theme = FilterFormTheme::from(theme.clone());
+    let table_theme = TableTheme::from(theme.clone());
+
+    let widget_base = WidgetBase::builder()
+        .title("Event")
+        .theme(widget_theme)
+        .build();
+
+    let filter_form = FilterForm::builder().theme(filter_theme).build();
+
+    Table::builder()
+        .id(EVENT_WIDGET_ID)
+        .widget_base(widget_base)
+        .filter_form(filter_form)
+        .theme(table_theme)
+        .filter_applicator(event_filter_applicator())
+        .sort_actions(
+            keymap.events(Action::SortNext),
+            keymap.events(Action::SortPrev),
+        )
+        .mark_actions(
+            keymap.events(Action::ToggleMark),
+            keymap.events(Action::MarkRange),
+            keymap.events(Action::MarkAll),
+        )
+        .actions(
+            keymap.events(Action::GroupByObject),
+            toggle_group(tx.clone()),
+        )
+        .block_injection(block_injection())
+        .build()
+        .into()
+}
+
+/// 関係するオブジェクトごとにまとめるかを切り替える
+///
+/// 表示はPollerから切り替え後の一覧が届いたときに更新される
+fn toggle_group(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |_: &mut Window| {
+        tx.send(EventMessage::ToggleGroup.into())
+            .expect("Failed to send EventMessage::ToggleGroup");
+
+        EventResult::Nop
+    }
+}
+
+fn block_injection() -> impl Fn(&Table) -> WidgetBase {
+    |table: &Table| {
+        let mut base = table.widget_base().clone();
+
+        *base.append_title_mut() = Some(table.count_indicator().into());
+
+        base
+    }
+}
@@ -2,13 +2,15 @@ use anyhow::Result;
 use crossbeam::channel::Sender;
 use k8s_openapi::{
     api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
         core::v1::{ConfigMap, Pod, Secret, Service},
         networking::v1::{Ingress, NetworkPolicy},
     },
     NamespaceResourceScope,
     Resource as _,
 };
-use kube::Api;
+use kube::{Api, Resource as _};
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
@@ -35,6 +37,11 @@ pub enum GetYamlKind {
     NetworkPolicy,
     Gateway(GatewayVersion),
     HTTPRoute(HTTPRouteVersion),
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    Job,
+    CronJob,
 }
 
 impl std::fmt::Display for GetYamlKind {
@@ -60,6 +67,44 @@ impl std::fmt::Display for GetYamlKind {
                     }
                 }
             }
+            Self::Deployment => write!(f, "{}", Deployment::URL_PATH_SEGMENT),
+            Self::StatefulSet => write!(f, "{}", StatefulSet::URL_PATH_SEGMENT),
+            Self::DaemonSet => write!(f, "{}", DaemonSet::URL_PATH_SEGMENT),
+            Self::Job => write!(f, "{}", Job::URL_PATH_SEGMENT),
+            Self::CronJob => write!(f, "{}", CronJob::URL_PATH_SEGMENT),
+        }
+    }
+}
+
+impl GetYamlKind {
+    /// リソース一覧のURLパス
+    pub fn url_path(&self, namespace: &str) -> String {
+        let ns = Some(namespace);
+
+        match self {
+            Self::Pod => Pod::url_path(&(), ns),
+            Self::ConfigMap => ConfigMap::url_path(&(), ns),
+            Self::Secret => Secret::url_path(&(), ns),
+            Self::Ingress => Ingress::url_path(&(), ns),
+            Self::Service => Service::url_path(&(), ns),
+            Self::NetworkPolicy => NetworkPolicy::url_path(&(), ns),
+            Self::Gateway(version) => {
+                match version {
+                    GatewayVersion::V1 => v1::Gateway::url_path(&(), ns),
+                    GatewayVersion::V1Beta1 => v1beta1::Gateway::url_path(&(), ns),
+                }
+            }
+            Self::HTTPRoute(version) => {
+                match version {
+                    HTTPRouteVersion::V1 => v1::HTTPRoute::url_path(&(), ns),
+                    HTTPRouteVersion::V1Beta1 => v1beta1::HTTPRoute::url_path(&(), ns),
+                }
+            }
+            Self::Deployment => Deployment::url_path(&(), ns),
+            Self::StatefulSet => StatefulSet::url_path(&(), ns),
+            Self::DaemonSet => DaemonSet::url_path(&(), ns),
+            Self::Job => Job::url_path(&(), ns),
+            Self::CronJob => CronJob::url_path(&(), ns),
         }
     }
 }
@@ -68,11 +113,11 @@ impl std::fmt::Display for GetYamlKind {
 pub struct GetYamlWorker {
     tx: Sender<Message>,
     client: KubeClient,
-    req: GetRequest,
+    req: Vec<GetRequest>,
 }
 
 impl GetYamlWorker {
-    pub fn new(tx: Sender<Message>, client: KubeClient, req: GetRequest) -> Self {
+    pub fn new(tx: Sender<Message>, client: KubeClient, req: Vec<GetRequest>) -> Self {
         Self { tx, client, req }
     }
 }
@@ -82,62 +127,18 @@ impl InfiniteWorker for GetYamlWorker {
     async fn run(&self) {
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(3));
 
-        let GetRequest {
-            kind,
-            name,
-            namespace,
-        } = &self.req;
+        let (kind, name) = title(&self.req);
 
         loop {
             interval.tick().await;
 
-            let yaml = match kind {
-                GetYamlKind::Pod => fetch_resource_yaml::<Pod>(&self.client, name, namespace).await,
-                GetYamlKind::ConfigMap => {
-                    fetch_resource_yaml::<ConfigMap>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Secret => {
-                    fetch_resource_yaml::<Secret>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Ingress => {
-                    fetch_resource_yaml::<Ingress>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Service => {
-                    fetch_resource_yaml::<Service>(&self.client, name, namespace).await
-                }
-                GetYamlKind::NetworkPolicy => {
-                    fetch_resource_yaml::<NetworkPolicy>(&self.client, name, namespace).await
-                }
-                GetYamlKind::Gateway(version) => {
-                    match version {
-                        GatewayVersion::V1 => {
-                            fetch_resource_yaml::<v1::Gateway>(&self.client, name, namespace).await
-                        }
-                        GatewayVersion::V1Beta1 => {
-                            fetch_resource_yaml::<v1beta1::Gateway>(&self.client, name, namespace)
-                                .await
-                        }
-                    }
-                }
-                GetYamlKind::HTTPRoute(version) => {
-                    match version {
-                        HTTPRouteVersion::V1 => {
-                            fetch_resource_yaml::<v1::HTTPRoute>(&self.client, name, namespace)
-                                .await
-                        }
-                        HTTPRouteVersion::V1Beta1 => {
-                            fetch_resource_yaml::<v1beta1::HTTPRoute>(&self.client, name, namespace)
-                                .await
-                        }
-                    }
-                }
-            };
+            let yaml = fetch_yamls(&self.client, &self.req).await;
 
             if let Err(e) = self.tx.send(
                 GetResponse {
                     yaml,
-                    kind: kind.to_string(),
-                    name: name.to_string(),
+                    kind: kind.clone(),
+                    name: name.clone(),
                 }
                 .into(),
             ) {
@@ -148,6 +149,86 @@ impl InfiniteWorker for GetYamlWorker {
     }
 }
 
+/// ダイアログのタイトルに表示する種類と名前
+///
+/// 複数のリソースのときは種類をカンマ区切りで並べ、名前の代わりに件数を表示する
+fn title(req: &[GetRequest]) -> (String, String) {
+    if let [req] = req {
+        return (req.kind.to_string(), req.name.to_string());
+    }
+
+    let mut kinds: Vec<String> = Vec::new();
+
+    for kind in req.iter().map(|req| req.kind.to_string()) {
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+    }
+
+    (kinds.join(","), format!("{} resources", req.len()))
+}
+
+/// 複数のリソースは `---` で区切って1つのyamlにする
+async fn fetch_yamls(client: &KubeClient, req: &[GetRequest]) -> Result<Vec<String>> {
+    let mut yaml = Vec::new();
+
+    for (i, req) in req.iter().enumerate() {
+        if 0 < i {
+            yaml.push("---".to_string());
+        }
+
+        yaml.extend(fetch_yaml(client, req).await?);
+    }
+
+    Ok(yaml)
+}
+
+async fn fetch_yaml(client: &KubeClient, req: &GetRequest) -> Result<Vec<String>> {
+    let GetRequest {
+        kind,
+        name,
+        namespace,
+    } = req;
+
+    match kind {
+        GetYamlKind::Pod => fetch_resource_yaml::<Pod>(client, name, namespace).await,
+        GetYamlKind::ConfigMap => fetch_resource_yaml::<ConfigMap>(client, name, namespace).await,
+        GetYamlKind::Secret => fetch_resource_yaml::<Secret>(client, name, namespace).await,
+        GetYamlKind::Ingress => fetch_resource_yaml::<Ingress>(client, name, namespace).await,
+        GetYamlKind::Service => fetch_resource_yaml::<Service>(client, name, namespace).await,
+        GetYamlKind::NetworkPolicy => {
+            fetch_resource_yaml::<NetworkPolicy>(client, name, namespace).await
+        }
+        GetYamlKind::Gateway(version) => {
+            match version {
+                GatewayVersion::V1 => {
+                    fetch_resource_yaml::<v1::Gateway>(client, name, namespace).await
+                }
+                GatewayVersion::V1Beta1 => {
+                    fetch_resource_yaml::<v1beta1::Gateway>(client, name, namespace).await
+                }
+            }
+        }
+        GetYamlKind::HTTPRoute(version) => {
+            match version {
+                HTTPRouteVersion::V1 => {
+                    fetch_resource_yaml::<v1::HTTPRoute>(client, name, namespace).await
+                }
+                HTTPRouteVersion::V1Beta1 => {
+                    fetch_resource_yaml::<v1beta1::HTTPRoute>(client, name, namespace).await
+                }
+            }
+        }
+        GetYamlKind::Deployment => fetch_resource_yaml::<Deployment>(client, name, namespace).await,
+        GetYamlKind::StatefulSet => {
+            fetch_resource_yaml::<StatefulSet>(client, name, namespace).await
+        }
+        GetYamlKind::DaemonSet => fetch_resource_yaml::<DaemonSet>(client, name, namespace).await,
+        GetYamlKind::Job => fetch_resource_yaml::<Job>(client, name, namespace).await,
+        GetYamlKind::CronJob => fetch_resource_yaml::<CronJob>(client, name, namespace).await,
+    }
+}
+
 /// 選択されているリソースのyamlを取得する
 async fn fetch_resource_yaml<K>(client: &KubeClient, name: &str, ns: &str) -> Result<Vec<String>>
 where
@@ -178,3 +259,62 @@ where
 
     Ok(yaml_string)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::pod(GetYamlKind::Pod, "/api/v1/namespaces/default/pods")]
+    #[case::ingress(
+        GetYamlKind::Ingress,
+        "/apis/networking.k8s.io/v1/namespaces/default/ingresses"
+    )]
+    #[case::gateway_v1(
+        GetYamlKind::Gateway(GatewayVersion::V1),
+        "/apis/gateway.networking.k8s.io/v1/namespaces/default/gateways"
+    )]
+    #[case::httproute_v1beta1(
+        GetYamlKind::HTTPRoute(HTTPRouteVersion::V1Beta1),
+        "/apis/gateway.networking.k8s.io/v1beta1/namespaces/default/httproutes"
+    )]
+    #[case::deployment(
+        GetYamlKind::Deployment,
+        "/apis/apps/v1/namespaces/default/deployments"
+    )]
+    #[case::cronjob(GetYamlKind::CronJob, "/apis/batch/v1/namespaces/default/cronjobs")]
+    fn url_path(#[case] kind: GetYamlKind, #[case] expected: &str) {
+        assert_eq!(kind.url_path("default"), expected);
+    }
+
+    fn req(kind: GetYamlKind, name: &str) -> GetRequest {
+        GetRequest {
+            name: name.into(),
+            namespace: "default".into(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn 単一のリソースは種類と名前をタイトルにする() {
+        assert_eq!(
+            title(&[req(GetYamlKind::Pod, "web")]),
+            ("pods".to_string(), "web".to_string())
+        );
+    }
+
+    #[test]
+    fn 複数のリソースは種類と件数をタイトルにする() {
+        assert_eq!(
+            title(&[
+                req(GetYamlKind::Pod, "web"),
+                req(GetYamlKind::Pod, "api"),
+                req(GetYamlKind::Service, "web"),
+            ]),
+            ("pods,services".to_string(), "3 resources".to_string())
+        );
+    }
+}
@@ -6,7 +6,8 @@ pub use super::kube::yaml::GetYamlKind;
 
 #[derive(Debug)]
 pub enum GetMessage {
-    Request(GetRequest),
+    /// 複数のリソースを指定したときは1つのyamlにまとめて表示する
+    Request(Vec<GetRequest>),
     Response(GetResponse),
 }
 
@@ -24,8 +25,8 @@ pub struct GetResponse {
     pub yaml: Result<Vec<String>>,
 }
 
-impl From<GetRequest> for Message {
-    fn from(req: GetRequest) -> Self {
+impl From<Vec<GetRequest>> for Message {
+    fn from(req: Vec<GetRequest>) -> Self {
         Self::Kube(Kube::Get(GetMessage::Request(req)))
     }
 }
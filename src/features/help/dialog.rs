@@ -3,7 +3,10 @@ use unicode_width::UnicodeWidthStr;
 
 use crate::{
     ansi::{AnsiEscapeSequence, TextParser},
-    config::theme::ThemeConfig,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::ThemeConfig,
+    },
     features::component_id::HELP_DIALOG_ID,
     ui::widget::{
         ansi_color::style_to_ansi,
@@ -21,129 +24,108 @@ const LEFT_HELP_TEXT: &[HelpBlock] = &[
     HelpBlock {
         title: "General",
         bindings: &[
-            KeyBindings {
-                keys: &["0~6"],
+            HelpEntry::Fixed(KeyBindings {
+                keys: &["1~8"],
                 desc: "switch tab",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Enter"],
                 desc: "select",
-            },
-            KeyBindings {
-                keys: &["c"],
-                desc: "change context",
-            },
-            KeyBindings {
-                keys: &["n"],
-                desc: "select namespace",
-            },
-            KeyBindings {
-                keys: &["N"],
-                desc: "select namespaces",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Action(Action::OpenContext),
+            HelpEntry::Action(Action::OpenNamespace),
+            HelpEntry::Action(Action::OpenNamespaces),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Tab"],
                 desc: "change focus",
-            },
-            KeyBindings {
-                keys: &["y"],
-                desc: "open yaml dialog",
-            },
-            KeyBindings {
-                keys: &["q", "Esc"],
-                desc: "quit",
-            },
-            KeyBindings {
-                keys: &["q", "Esc"],
-                desc: "close dialog",
-            },
-            KeyBindings {
-                keys: &["h", "?"],
-                desc: "Show this help",
-            },
+            }),
+            HelpEntry::Action(Action::OpenYaml),
+            HelpEntry::Action(Action::ToggleSplit),
+            HelpEntry::Action(Action::Quit),
+            HelpEntry::Action(Action::OpenHelp),
         ],
     },
     HelpBlock {
         title: "Context Dialog",
         bindings: &[
-            KeyBindings {
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Enter"],
                 desc: "switch context (use cached namespaces)",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-Space"],
                 desc: "switch context (preserve current namespaces)",
-            },
+            }),
         ],
     },
     HelpBlock {
         title: "View Control",
         bindings: &[
-            KeyBindings {
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["j", "k", "Down", "Up"],
                 desc: "goto next/previous line",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["PgDn", "PgUp"],
                 desc: "scroll upward/downward",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Left", "Right"],
                 desc: "scroll horizontal",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["g"],
                 desc: "goto first line",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["G"],
                 desc: "goto last line",
-            },
+            }),
         ],
     },
     HelpBlock {
         title: "Remap Keys",
         bindings: &[
-            KeyBindings {
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-p"],
                 desc: "↑",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-n"],
                 desc: "↓",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-f"],
                 desc: "→",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-b"],
                 desc: "←",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-u"],
                 desc: "PgUp",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-d"],
                 desc: "PgDn",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-h", "BS"],
                 desc: "Del",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-a"],
                 desc: "Home",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-e"],
                 desc: "End",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-["],
                 desc: "Esc",
-            },
+            }),
         ],
     },
 ];
@@ -152,142 +134,236 @@ const RIGHT_HELP_TEXT: &[HelpBlock] = &[
     HelpBlock {
         title: "Input Form",
         bindings: &[
-            KeyBindings {
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-a", "Home"],
                 desc: "move the cursor to the first",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-e", "End"],
                 desc: "move the cursor to the end",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-f", "Right"],
                 desc: "move the cursor to the right",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-b", "Left"],
                 desc: "move the cursor to the left",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-w"],
                 desc: "delete the text from the cursor position to the first",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Ctrl-k"],
                 desc: "delete the text from the cursor position to the end",
-            },
+            }),
         ],
     },
     HelpBlock {
         title: "API / Yaml Tab",
-        bindings: &[KeyBindings {
-            keys: &["f"],
-            desc: "open select dialog",
-        }],
+        bindings: &[
+            HelpEntry::Action(Action::OpenApiSelect),
+            HelpEntry::Action(Action::OpenKindSelect),
+        ],
+    },
+    HelpBlock {
+        title: "Yaml Tab / Yaml Dialog",
+        bindings: &[HelpEntry::Action(Action::Edit)],
     },
     HelpBlock {
         title: "Search (Only text view)",
         bindings: &[
-            KeyBindings {
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["/"],
                 desc: "enable search mode",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["q", "Esc"],
                 desc: "disable search mode",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Enter"],
                 desc: "confirm search word",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["n", "N"],
                 desc: "goto next/prev word",
-            },
+            }),
+            HelpEntry::Fixed(KeyBindings {
+                keys: &["Alt-w"],
+                desc: "toggle whole word match",
+            }),
+            HelpEntry::Fixed(KeyBindings {
+                keys: &["Alt-f"],
+                desc: "toggle hiding unmatched lines",
+            }),
+            HelpEntry::Fixed(KeyBindings {
+                keys: &["Alt-c"],
+                desc: "change context lines (0/1/3/5/10)",
+            }),
         ],
     },
     HelpBlock {
         title: "Filter (Only table view)",
         bindings: &[
-            KeyBindings {
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["/"],
                 desc: "open filter form",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["q", "Esc"],
                 desc: "clear filter form",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Enter"],
                 desc: "confirm filter word",
-            },
+            }),
+        ],
+    },
+    HelpBlock {
+        title: "Sort (Only table view)",
+        bindings: &[
+            HelpEntry::Action(Action::SortNext),
+            HelpEntry::Action(Action::SortPrev),
+        ],
+    },
+    HelpBlock {
+        title: "Mark (Only table view)",
+        bindings: &[
+            HelpEntry::Action(Action::ToggleMark),
+            HelpEntry::Action(Action::MarkRange),
+            HelpEntry::Action(Action::MarkAll),
+            HelpEntry::Fixed(KeyBindings {
+                keys: &["Esc"],
+                desc: "clear all marks",
+            }),
+            HelpEntry::Action(Action::CopyNames),
         ],
     },
     HelpBlock {
         title: "Pod",
-        bindings: &[KeyBindings {
-            keys: &["t"],
-            desc: "customize visible columns",
-        }],
+        bindings: &[
+            HelpEntry::Action(Action::OpenColumns),
+            HelpEntry::Action(Action::Exec),
+            HelpEntry::Action(Action::Describe),
+            HelpEntry::Action(Action::MarkedLogs),
+            HelpEntry::Action(Action::Delete),
+            HelpEntry::Action(Action::Restart),
+            HelpEntry::Action(Action::Scale),
+        ],
+    },
+    HelpBlock {
+        title: "Workload",
+        bindings: &[
+            HelpEntry::Action(Action::ShowLogs),
+            HelpEntry::Action(Action::Restart),
+            HelpEntry::Action(Action::Scale),
+        ],
+    },
+    HelpBlock {
+        title: "Event",
+        bindings: &[HelpEntry::Action(Action::GroupByObject)],
+    },
+    HelpBlock {
+        title: "Network Description",
+        bindings: &[HelpEntry::Action(Action::OpenRelated)],
+    },
+    HelpBlock {
+        title: "Port Forward (Pod / Service)",
+        bindings: &[
+            HelpEntry::Action(Action::PortForward),
+            HelpEntry::Action(Action::PortForwardList),
+        ],
     },
     HelpBlock {
         title: "Log",
         bindings: &[
-            KeyBindings {
-                keys: &["Enter"],
-                desc: "insert blank line",
-            },
-            KeyBindings {
-                keys: &["f", "p"],
-                desc: "toggle json pretty print",
-            },
+            HelpEntry::Action(Action::InsertBlankLine),
+            HelpEntry::Action(Action::TogglePrettyPrint),
+            HelpEntry::Action(Action::TogglePrevious),
+            HelpEntry::Action(Action::ToggleTimestamp),
+            HelpEntry::Action(Action::SaveLog),
+        ],
+    },
+    HelpBlock {
+        title: "Secret Raw Data",
+        bindings: &[
+            HelpEntry::Action(Action::RevealKey),
+            HelpEntry::Action(Action::RevealAll),
         ],
     },
     HelpBlock {
         title: "Pod Columns",
         bindings: &[
-            KeyBindings {
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["j", "k", "Up", "Down"],
                 desc: "move cursor up/down",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["g", "G", "Home", "End"],
                 desc: "move cursor to the first/last line",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["Space", "Enter"],
                 desc: "toggle column visibility",
-            },
-            KeyBindings {
+            }),
+            HelpEntry::Fixed(KeyBindings {
                 keys: &["J", "K"],
                 desc: "move column up/down",
-            },
+            }),
         ],
     },
 ];
 
+/// 変更できないキー
 struct KeyBindings {
     keys: &'static [&'static str],
     desc: &'static str,
 }
 
-impl KeyBindings {
-    fn keys(&self) -> String {
-        self.keys.join(" ")
-    }
+enum HelpEntry {
+    Fixed(KeyBindings),
+    /// キーバインドの設定から表示するアクション
+    Action(Action),
+}
 
-    fn desc(&self) -> String {
-        self.desc.to_string()
+impl HelpEntry {
+    /// キーと説明
+    ///
+    /// キーが割り当てられていないアクションは表示しない
+    fn resolve(&self, keymap: &Keymap) -> Option<(String, String)> {
+        match self {
+            Self::Fixed(KeyBindings { keys, desc }) => Some((keys.join(" "), desc.to_string())),
+            Self::Action(action) => {
+                let keys = keymap.keys(*action);
+
+                if keys.is_empty() {
+                    return None;
+                }
+
+                Some((keys.join(" "), action.desc().to_string()))
+            }
+        }
     }
 }
 
-#[derive(Clone)]
 struct HelpBlock {
     title: &'static str,
-    bindings: &'static [KeyBindings],
+    bindings: &'static [HelpEntry],
 }
 
-fn print_help_block(block: &HelpBlock, theme: &HelpItemTheme) -> Vec<String> {
+fn print_help_block(block: &HelpBlock, keymap: &Keymap, theme: &HelpItemTheme) -> Vec<String> {
+    let bindings: Vec<(String, String)> = block
+        .bindings
+        .iter()
+        .filter_map(|b| b.resolve(keymap))
+        .collect();
+
+    let Some(max_key_len) = bindings.iter().map(|(keys, _)| keys.width()).max() else {
+        return Vec::new();
+    };
+
     let mut line = Vec::new();
 
     line.push(format!(
@@ -296,23 +372,15 @@ fn print_help_block(block: &HelpBlock, theme: &HelpItemTheme) -> Vec<String> {
         block.title
     ));
 
-    let max_key_len = block
-        .bindings
+    let lines: Vec<String> = bindings
         .iter()
-        .map(|b| b.keys().width())
-        .max()
-        .expect("no bindings");
-
-    let lines: Vec<String> = block
-        .bindings
-        .iter()
-        .map(|b| {
+        .map(|(keys, desc)| {
             format!(
                 "{}{:>pad$}:\x1b[39m {}{}",
                 style_to_ansi(theme.key_style),
-                b.keys(),
+                keys,
                 style_to_ansi(theme.desc_style),
-                b.desc(),
+                desc,
                 pad = max_key_len
             )
         })
@@ -323,21 +391,23 @@ fn print_help_block(block: &HelpBlock, theme: &HelpItemTheme) -> Vec<String> {
     line
 }
 
-fn print_help_blocks(blocks: &[HelpBlock], theme: &HelpItemTheme) -> Vec<String> {
+fn print_help_blocks(blocks: &[HelpBlock], keymap: &Keymap, theme: &HelpItemTheme) -> Vec<String> {
     blocks
         .iter()
         .flat_map(|block| {
-            let mut lines = print_help_block(block, theme);
-            lines.push("".to_string());
+            let mut lines = print_help_block(block, keymap, theme);
+            if !lines.is_empty() {
+                lines.push("".to_string());
+            }
             lines
         })
         .collect()
 }
 
-fn generate(theme: HelpItemTheme) -> Vec<String> {
-    let mut left = print_help_blocks(LEFT_HELP_TEXT, &theme);
+fn generate(keymap: &Keymap, theme: HelpItemTheme) -> Vec<String> {
+    let mut left = print_help_blocks(LEFT_HELP_TEXT, keymap, &theme);
 
-    let mut right = print_help_blocks(RIGHT_HELP_TEXT, &theme);
+    let mut right = print_help_blocks(RIGHT_HELP_TEXT, keymap, &theme);
 
     let len = left.len().max(right.len());
 
@@ -397,7 +467,7 @@ pub struct HelpDialog {
 }
 
 impl HelpDialog {
-    pub fn new(theme: ThemeConfig) -> Self {
+    pub fn new(theme: ThemeConfig, keymap: &Keymap) -> Self {
         let widget_theme = WidgetTheme::from(theme.component.clone());
         let text_theme = TextTheme::from(theme.component.clone());
         let search_theme = SearchFormTheme::from(theme.component.clone());
@@ -417,9 +487,79 @@ impl HelpDialog {
                 .widget_base(widget_base)
                 .search_form(search_form)
                 .theme(text_theme)
-                .items(generate(item_theme))
+                .items(generate(keymap, item_theme))
                 .build()
                 .into(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn plain(lines: Vec<String>) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| {
+                l.ansi_parse()
+                    .filter(|p| p.ty == AnsiEscapeSequence::Chars)
+                    .map(|p| p.chars)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn すべてのアクションがヘルプに表示される() {
+        let entries: Vec<&HelpEntry> = LEFT_HELP_TEXT
+            .iter()
+            .chain(RIGHT_HELP_TEXT)
+            .flat_map(|block| block.bindings)
+            .collect();
+
+        for action in Action::ALL {
+            assert!(
+                entries
+                    .iter()
+                    .any(|entry| matches!(entry, HelpEntry::Action(a) if a == action)),
+                "{:?} is not shown in help",
+                action
+            );
+        }
+    }
+
+    #[test]
+    fn 設定したキーがヘルプに表示される() {
+        let config = serde_yaml::from_str(
+            r#"
+            log:
+              toggle_previous: "ctrl-o"
+              save: []
+            "#,
+        )
+        .unwrap();
+
+        let keymap = Keymap::new(&config).unwrap();
+
+        let block = RIGHT_HELP_TEXT
+            .iter()
+            .find(|block| block.title == "Log")
+            .unwrap();
+
+        let actual = plain(print_help_block(block, &keymap, &HelpItemTheme::default()));
+
+        assert_eq!(
+            actual,
+            vec![
+                "[ Log ]",
+                " Enter: insert blank line",
+                "   f p: toggle json pretty print",
+                "Ctrl-o: toggle previous container logs",
+                "     t: switch timestamps (off/rfc3339/local/relative)",
+            ]
+        );
+    }
+}
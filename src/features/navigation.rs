@@ -0,0 +1,5 @@
+mod target;
+mod view;
+
+pub use target::*;
+pub use view::*;
@@ -0,0 +1,245 @@
+use std::{collections::BTreeMap, fmt};
+
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
+        core::v1::{Pod, Service},
+        networking::v1::{Ingress, NetworkPolicy},
+    },
+    apimachinery::pkg::apis::meta::v1::OwnerReference,
+    Resource as _,
+};
+use serde_yaml::{Mapping, Value};
+
+use crate::kube::apis::networking::gateway::v1::{Gateway, HTTPRoute};
+
+/// 移動できるリソースの種類
+const KINDS: &[&str] = &[
+    Pod::KIND,
+    Service::KIND,
+    Ingress::KIND,
+    NetworkPolicy::KIND,
+    Gateway::KIND,
+    HTTPRoute::KIND,
+    Deployment::KIND,
+    StatefulSet::KIND,
+    DaemonSet::KIND,
+    Job::KIND,
+    CronJob::KIND,
+];
+
+/// 説明ペインから移動できるリソース
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavigationTarget {
+    pub kind: &'static str,
+    pub name: String,
+    pub namespace: String,
+}
+
+impl NavigationTarget {
+    /// `relatedResources` の値に含まれるリソース
+    ///
+    /// 要素は名前だけの文字列か、`name` と `namespace` を持つマッピング。
+    /// `namespace` がない要素は説明しているリソースと同じnamespaceとして扱う。
+    pub fn related_resources(resources: &Mapping, namespace: &str) -> Vec<Self> {
+        resources
+            .iter()
+            .filter_map(|(key, items)| {
+                let kind = related_resource_kind(key.as_str()?)?;
+
+                Some(items.as_sequence()?.iter().filter_map(move |item| {
+                    let (name, item_namespace) = match item {
+                        Value::String(name) => (name.as_str(), None),
+                        Value::Mapping(_) => {
+                            (
+                                item.get("name")?.as_str()?,
+                                item.get("namespace").and_then(Value::as_str),
+                            )
+                        }
+                        _ => return None,
+                    };
+
+                    Some(Self {
+                        kind,
+                        name: name.to_string(),
+                        namespace: item_namespace.unwrap_or(namespace).to_string(),
+                    })
+                }))
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// Podのオーナーのうち、Workloadタブで開けるもの
+    ///
+    /// ReplicaSetは開けないため、呼び出し側で管理しているDeploymentを辿る
+    pub fn owner(owner: &OwnerReference, namespace: &str) -> Option<Self> {
+        let kind = match owner.kind.as_str() {
+            StatefulSet::KIND => StatefulSet::KIND,
+            DaemonSet::KIND => DaemonSet::KIND,
+            Deployment::KIND => Deployment::KIND,
+            Job::KIND => Job::KIND,
+            CronJob::KIND => CronJob::KIND,
+            _ => return None,
+        };
+
+        Some(Self {
+            kind,
+            name: owner.name.clone(),
+            namespace: namespace.to_string(),
+        })
+    }
+
+    /// 選択ダイアログのアイテムに持たせるメタデータ
+    pub fn metadata(&self) -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("kind".to_string(), self.kind.to_string()),
+            ("name".to_string(), self.name.clone()),
+            ("namespace".to_string(), self.namespace.clone()),
+        ])
+    }
+
+    pub fn from_metadata(metadata: &BTreeMap<String, String>) -> Option<Self> {
+        let kind = metadata.get("kind")?;
+
+        Some(Self {
+            kind: KINDS.iter().copied().find(|k| k == kind)?,
+            name: metadata.get("name")?.clone(),
+            namespace: metadata.get("namespace")?.clone(),
+        })
+    }
+}
+
+impl fmt::Display for NavigationTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} ({})", self.kind, self.name, self.namespace)
+    }
+}
+
+fn related_resource_kind(key: &str) -> Option<&'static str> {
+    match key {
+        "pods" => Some(Pod::KIND),
+        "services" => Some(Service::KIND),
+        "ingresses" => Some(Ingress::KIND),
+        "networkpolicies" => Some(NetworkPolicy::KIND),
+        "gateways" => Some(Gateway::KIND),
+        "httproutes" => Some(HTTPRoute::KIND),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn target(kind: &'static str, name: &str, namespace: &str) -> NavigationTarget {
+        NavigationTarget {
+            kind,
+            name: name.into(),
+            namespace: namespace.into(),
+        }
+    }
+
+    #[test]
+    fn 名前だけの関連リソースは説明しているリソースのnamespaceで返す() {
+        let resources: Mapping = serde_yaml::from_str(indoc! {"
+            ingresses:
+            - ingress-1
+            pods:
+            - pod-1
+            - '12345'
+        "})
+        .unwrap();
+
+        assert_eq!(
+            NavigationTarget::related_resources(&resources, "default"),
+            vec![
+                target("Ingress", "ingress-1", "default"),
+                target("Pod", "pod-1", "default"),
+                target("Pod", "12345", "default"),
+            ]
+        );
+    }
+
+    #[test]
+    fn マッピングの関連リソースはnamespace付きで返す() {
+        let resources: Mapping = serde_yaml::from_str(indoc! {"
+            httproutes:
+            - name: route
+              namespace: app
+            services:
+            - name: api
+              namespace: app
+              httproute: route
+            pods:
+            - name: api-0
+              service: api
+        "})
+        .unwrap();
+
+        assert_eq!(
+            NavigationTarget::related_resources(&resources, "default"),
+            vec![
+                target("HTTPRoute", "route", "app"),
+                target("Service", "api", "app"),
+                target("Pod", "api-0", "default"),
+            ]
+        );
+    }
+
+    #[test]
+    fn 未知の関連リソースは返さない() {
+        let resources: Mapping = serde_yaml::from_str(indoc! {"
+            secrets:
+            - secret-1
+        "})
+        .unwrap();
+
+        assert_eq!(
+            NavigationTarget::related_resources(&resources, "default"),
+            vec![]
+        );
+    }
+
+    fn owner_reference(kind: &str, name: &str) -> OwnerReference {
+        OwnerReference {
+            kind: kind.into(),
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn ワークロードのオーナーを返す() {
+        assert_eq!(
+            NavigationTarget::owner(&owner_reference("StatefulSet", "db"), "default"),
+            Some(target("StatefulSet", "db", "default"))
+        );
+    }
+
+    #[test]
+    fn ワークロード以外のオーナーはnoneを返す() {
+        assert_eq!(
+            NavigationTarget::owner(&owner_reference("ReplicaSet", "web-5d4f8b7c9"), "default"),
+            None
+        );
+        assert_eq!(
+            NavigationTarget::owner(&owner_reference("Node", "node-1"), "default"),
+            None
+        );
+    }
+
+    #[test]
+    fn メタデータから復元する() {
+        let expected = target("Service", "api", "app");
+
+        assert_eq!(
+            NavigationTarget::from_metadata(&expected.metadata()),
+            Some(expected)
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
+        core::v1::Pod,
+    },
+    Resource as _,
+};
+
+use crate::{
+    features::component_id::{
+        NETWORK_TAB_ID,
+        NETWORK_WIDGET_ID,
+        POD_TAB_ID,
+        POD_WIDGET_ID,
+        WORKLOAD_TAB_ID,
+        WORKLOAD_WIDGET_ID,
+    },
+    ui::{event::EventResult, widget::TableItem, Window},
+};
+
+use super::NavigationTarget;
+
+/// 移動先のタブを開いて、対象のリソースの行を選択する
+///
+/// 行を選択したときは、テーブルでその行を選択したときと同じ処理（ログや説明の表示）を行う。
+/// フィルタなどで行が表示されていない場合は、タブの切り替えだけを行う。
+pub fn navigate(w: &mut Window, target: &NavigationTarget) -> EventResult {
+    let (tab_id, widget_id) = match target.kind {
+        Pod::KIND => (POD_TAB_ID, POD_WIDGET_ID),
+        Deployment::KIND | StatefulSet::KIND | DaemonSet::KIND | Job::KIND | CronJob::KIND => {
+            (WORKLOAD_TAB_ID, WORKLOAD_WIDGET_ID)
+        }
+        _ => (NETWORK_TAB_ID, NETWORK_WIDGET_ID),
+    };
+
+    w.activate_tab_by_id(tab_id);
+    w.activate_widget_by_id(widget_id);
+
+    let table = w.find_widget_mut(widget_id).as_mut_table();
+
+    if !table.select_item(|item| is_target_item(item, target)) {
+        return EventResult::Nop;
+    }
+
+    match table.on_select_callback() {
+        Some(cb) => EventResult::Callback(cb),
+        None => EventResult::Nop,
+    }
+}
+
+fn is_target_item(item: &TableItem, target: &NavigationTarget) -> bool {
+    let Some(metadata) = item.metadata.as_ref() else {
+        return false;
+    };
+
+    let get = |key: &str| metadata.get(key).map(String::as_str);
+
+    get("kind") == Some(target.kind)
+        && get("name") == Some(target.name.as_str())
+        && get("namespace") == Some(target.namespace.as_str())
+}
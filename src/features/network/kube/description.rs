@@ -12,7 +12,13 @@ mod related_resources;
 use crate::{
     features::{
         api_resources::kube::SharedApiResources,
-        network::message::{NetworkRequest, NetworkRequestTargetParams, NetworkResponse},
+        navigation::NavigationTarget,
+        network::message::{
+            NetworkDescription,
+            NetworkRequest,
+            NetworkRequestTargetParams,
+            NetworkResponse,
+        },
     },
     kube::KubeClientRequest,
     logger,
@@ -29,13 +35,17 @@ use self::{
     service::ServiceDescriptionWorker,
 };
 
+pub(crate) use self::pod::{extract_pod, pod_lines};
+
 use anyhow::Result;
 use async_trait::async_trait;
 use crossbeam::channel::Sender;
+use serde::Serialize;
+use serde_yaml::Value;
 
 const INTERVAL: u64 = 3;
 
-type FetchedData = Vec<String>;
+type FetchedData = NetworkDescription;
 
 #[async_trait]
 trait Fetch<'a, C: KubeClientRequest> {
@@ -49,6 +59,20 @@ trait Fetch<'a, C: KubeClientRequest> {
     async fn fetch(&self) -> Result<FetchedData>;
 }
 
+/// `relatedResources` をルートに持つ値から移動先のリソースを取り出す
+fn related_resource_targets(
+    related_resources: &impl Serialize,
+    namespace: &str,
+) -> Result<Vec<NavigationTarget>> {
+    let value = serde_yaml::to_value(related_resources)?;
+
+    Ok(value
+        .get("relatedResources")
+        .and_then(Value::as_mapping)
+        .map(|resources| NavigationTarget::related_resources(resources, namespace))
+        .unwrap_or_default())
+}
+
 #[derive(Clone)]
 pub struct NetworkDescriptionWorker<C>
 where
@@ -113,8 +137,8 @@ where
             logger!(error, "Failed to fetch description: {:?}", e);
 
             self.tx
-                .send(NetworkResponse::Yaml(Err(e)).into())
-                .expect("Failed to send NetworkResponse::Yaml");
+                .send(NetworkResponse::Description(Err(e)).into())
+                .expect("Failed to send NetworkResponse::Description");
         }
     }
 }
@@ -140,7 +164,8 @@ where
 
             let fetched_data = worker.fetch().await;
 
-            self.tx.send(NetworkResponse::Yaml(fetched_data).into())?;
+            self.tx
+                .send(NetworkResponse::Description(fetched_data).into())?;
         }
     }
 }
@@ -233,9 +258,9 @@ mod tests {
 
             tokio::spawn(async move { worker.run().await });
 
-            if let Message::Kube(Kube::Network(NetworkMessage::Response(NetworkResponse::Yaml(
-                msg,
-            )))) = rx.recv().unwrap()
+            if let Message::Kube(Kube::Network(NetworkMessage::Response(
+                NetworkResponse::Description(msg),
+            ))) = rx.recv().unwrap()
             {
                 assert!(msg.is_err())
             } else {
@@ -306,7 +331,7 @@ mod tests {
 
         use crate::{
             features::{api_resources::kube::ApiResources, network::message::NetworkMessage},
-            kube::mock::MockTestKubeClient,
+            kube::{apis::metrics::PodMetrics, mock::MockTestKubeClient},
             mock_expect,
             workers::kube::message::Kube,
         };
@@ -339,6 +364,11 @@ mod tests {
                         List<NetworkPolicy>,
                         eq("/apis/networking.k8s.io/v1/namespaces/default/networkpolicies"),
                         Ok(Default::default())
+                    ),
+                    (
+                        PodMetrics,
+                        eq("/apis/metrics.k8s.io/v1beta1/namespaces/default/pods/test"),
+                        bail!("the server could not find the requested resource")
                     )
                 ]
             );
@@ -384,11 +414,11 @@ mod tests {
             .map(ToString::to_string)
             .collect();
 
-            if let Message::Kube(Kube::Network(NetworkMessage::Response(NetworkResponse::Yaml(
-                Ok(actual),
-            )))) = event
+            if let Message::Kube(Kube::Network(NetworkMessage::Response(
+                NetworkResponse::Description(Ok(actual)),
+            ))) = event
             {
-                assert_eq!(actual, expected)
+                assert_eq!(actual.lines, expected)
             } else {
                 unreachable!()
             }
@@ -440,9 +470,9 @@ mod tests {
                     .await
             });
 
-            if let Message::Kube(Kube::Network(NetworkMessage::Response(NetworkResponse::Yaml(
-                msg,
-            )))) = rx.recv().unwrap()
+            if let Message::Kube(Kube::Network(NetworkMessage::Response(
+                NetworkResponse::Description(msg),
+            ))) = rx.recv().unwrap()
             {
                 assert!(msg.is_err())
             } else {
@@ -7,7 +7,7 @@ use kube::{Api, Client};
 use crate::{
     features::{
         api_resources::kube::SharedApiResources,
-        network::message::NetworkRequestTargetParams,
+        network::message::{NetworkDescription, NetworkRequestTargetParams},
     },
     kube::{
         apis::networking::gateway::{self},
@@ -15,7 +15,7 @@ use crate::{
     },
 };
 
-use super::{Fetch, FetchedData};
+use super::{related_resource_targets, Fetch, FetchedData};
 
 pub(super) struct GatewayDescriptionWorker<'a, C>
 where
@@ -80,6 +80,8 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    let targets = related_resource_targets(&related_resources, namespace)?;
+
     let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
         .lines()
         .map(ToString::to_string)
@@ -91,7 +93,10 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
         yaml.append(&mut related_resources_yaml);
     }
 
-    Ok(yaml)
+    Ok(NetworkDescription {
+        lines: yaml,
+        targets,
+    })
 }
 
 async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<FetchedData> {
@@ -112,6 +117,8 @@ async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<Fe
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    let targets = related_resource_targets(&related_resources, namespace)?;
+
     let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
         .lines()
         .map(ToString::to_string)
@@ -123,5 +130,8 @@ async fn fetch_v1beta1(client: Client, name: &str, namespace: &str) -> Result<Fe
         yaml.append(&mut related_resources_yaml);
     }
 
-    Ok(yaml)
+    Ok(NetworkDescription {
+        lines: yaml,
+        targets,
+    })
 }
@@ -7,12 +7,12 @@ use kube::{Api, Client};
 use crate::{
     features::{
         api_resources::kube::SharedApiResources,
-        network::message::NetworkRequestTargetParams,
+        network::message::{NetworkDescription, NetworkRequestTargetParams},
     },
     kube::{apis::networking::gateway, KubeClientRequest},
 };
 
-use super::{Fetch, FetchedData};
+use super::{related_resource_targets, Fetch, FetchedData};
 
 pub(super) struct HTTPRouteDescriptionWorker<'a, C>
 where
@@ -76,6 +76,8 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    let targets = related_resource_targets(&related_resources, namespace)?;
+
     let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
         .lines()
         .map(ToString::to_string)
@@ -87,7 +89,10 @@ async fn fetch_v1(client: Client, name: &str, namespace: &str) -> Result<Fetched
         yaml.append(&mut related_resources_yaml);
     }
 
-    Ok(yaml)
+    Ok(NetworkDescription {
+        lines: yaml,
+        targets,
+    })
 }
 
 async fn fetch_v1beat1(client: Client, name: &str, namespace: &str) -> Result<FetchedData> {
@@ -108,6 +113,8 @@ async fn fetch_v1beat1(client: Client, name: &str, namespace: &str) -> Result<Fe
         .map(ToString::to_string)
         .collect::<Vec<String>>();
 
+    let targets = related_resource_targets(&related_resources, namespace)?;
+
     let mut related_resources_yaml = serde_yaml::to_string(&related_resources)?
         .lines()
         .map(ToString::to_string)
@@ -119,5 +126,8 @@ async fn fetch_v1beat1(client: Client, name: &str, namespace: &str) -> Result<Fe
         yaml.append(&mut related_resources_yaml);
     }
 
-    Ok(yaml)
+    Ok(NetworkDescription {
+        lines: yaml,
+        targets,
+    })
 }
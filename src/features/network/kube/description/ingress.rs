@@ -11,7 +11,8 @@ use serde_yaml::Mapping;
 use crate::{
     features::{
         api_resources::kube::SharedApiResources,
-        network::message::NetworkRequestTargetParams,
+        navigation::NavigationTarget,
+        network::message::{NetworkDescription, NetworkRequestTargetParams},
     },
     kube::KubeClientRequest,
 };
@@ -107,6 +108,8 @@ where
 
         let mut value = ingress;
 
+        let targets = NavigationTarget::related_resources(&related_resources, &self.namespace);
+
         if !related_resources.is_empty() {
             let mut root = Mapping::new();
 
@@ -122,7 +125,10 @@ where
             value.extend(related_resources);
         }
 
-        Ok(value)
+        Ok(NetworkDescription {
+            lines: value,
+            targets,
+        })
     }
 }
 
@@ -375,7 +381,7 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_eq!(result.unwrap(), expected);
+        assert_eq!(result.unwrap().lines, expected);
     }
 
     #[tokio::test]
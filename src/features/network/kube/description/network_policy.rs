@@ -12,7 +12,8 @@ use serde_yaml::Mapping;
 use crate::{
     features::{
         api_resources::kube::SharedApiResources,
-        network::message::NetworkRequestTargetParams,
+        navigation::NavigationTarget,
+        network::message::{NetworkDescription, NetworkRequestTargetParams},
     },
     kube::KubeClientRequest,
 };
@@ -92,6 +93,8 @@ where
 
         let mut value = value;
 
+        let targets = NavigationTarget::related_resources(&related_resources, &self.namespace);
+
         if !related_resources.is_empty() {
             let mut root = Mapping::new();
 
@@ -107,7 +110,10 @@ where
             value.extend(related_resources);
         }
 
-        Ok(value)
+        Ok(NetworkDescription {
+            lines: value,
+            targets,
+        })
     }
 }
 
@@ -360,7 +366,7 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_eq!(result.unwrap(), expected);
+        assert_eq!(result.unwrap().lines, expected);
     }
 
     #[tokio::test]
@@ -1,9 +1,13 @@
+use std::sync::Mutex;
+
 use k8s_openapi::{
     api::{
+        apps::v1::ReplicaSet,
         core::v1::{Pod, Service},
         networking::v1::{Ingress, NetworkPolicy},
     },
     List,
+    Resource as _,
 };
 use kube::{Resource, ResourceExt};
 use serde_yaml::Mapping;
@@ -20,9 +24,13 @@ use super::{
 use crate::{
     features::{
         api_resources::kube::SharedApiResources,
-        network::message::NetworkRequestTargetParams,
+        navigation::NavigationTarget,
+        network::message::{NetworkDescription, NetworkRequestTargetParams},
+    },
+    kube::{
+        metrics::{fetch_pod_sample, pod_ratio_base, MetricsHistory},
+        KubeClientRequest,
     },
-    kube::KubeClientRequest,
 };
 
 pub(super) struct PodDescriptionWorker<'a, C>
@@ -32,6 +40,8 @@ where
     client: &'a C,
     namespace: String,
     name: String,
+    /// 選択中のPodの使用量の推移
+    history: Mutex<MetricsHistory>,
 }
 
 #[async_trait::async_trait]
@@ -45,6 +55,7 @@ impl<'a, C: KubeClientRequest> Fetch<'a, C> for PodDescriptionWorker<'a, C> {
             client,
             namespace,
             name,
+            history: Mutex::default(),
         }
     }
 
@@ -56,8 +67,11 @@ impl<'a, C: KubeClientRequest> Fetch<'a, C> for PodDescriptionWorker<'a, C> {
         );
 
         let pod: Pod = self.client.request(&url).await?;
+        let base = pod_ratio_base(&serde_json::to_value(&pod)?);
         let pod = pod.extract();
 
+        let mut targets = self.owner_targets(&pod).await;
+
         let related_services = RelatedClient::new(self.client, &self.namespace)
             .related_resources::<Service, _>(pod.labels())
             .await?;
@@ -81,12 +95,7 @@ impl<'a, C: KubeClientRequest> Fetch<'a, C> for PodDescriptionWorker<'a, C> {
                 None
             };
 
-        let pod: Vec<String> = serde_yaml::to_string(&pod.to_value()?)?
-            .lines()
-            .map(ToString::to_string)
-            .collect();
-
-        let mut value = pod;
+        let mut value = pod_lines(&pod)?;
 
         let mut related_resources = Mapping::new();
 
@@ -108,6 +117,11 @@ impl<'a, C: KubeClientRequest> Fetch<'a, C> for PodDescriptionWorker<'a, C> {
             }
         }
 
+        targets.extend(NavigationTarget::related_resources(
+            &related_resources,
+            &self.namespace,
+        ));
+
         if !related_resources.is_empty() {
             let mut root = Mapping::new();
 
@@ -120,22 +134,106 @@ impl<'a, C: KubeClientRequest> Fetch<'a, C> for PodDescriptionWorker<'a, C> {
             value.extend(vec);
         }
 
-        Ok(value)
+        // metrics-serverの更新間隔より短い間隔では問い合わせない
+        let sample_due = self
+            .history
+            .lock()
+            .expect("metrics history poisoned")
+            .sample_due();
+
+        // metrics-serverがない場合は推移を表示しない
+        let sample = if sample_due {
+            fetch_pod_sample(self.client, &self.namespace, &self.name)
+                .await
+                .ok()
+        } else {
+            None
+        };
+
+        let metrics = {
+            let mut history = self.history.lock().expect("metrics history poisoned");
+
+            if let Some(sample) = sample {
+                history.push(sample);
+            }
+
+            history.lines(base)
+        };
+
+        if !metrics.is_empty() {
+            value.push(String::default());
+            value.extend(metrics);
+        }
+
+        Ok(NetworkDescription {
+            lines: value,
+            targets,
+        })
     }
 }
 
+impl<C: KubeClientRequest> PodDescriptionWorker<'_, C> {
+    /// Workloadタブで開けるPodのオーナー
+    ///
+    /// ReplicaSetはそれ自体の `ownerReferences` から管理しているDeploymentを辿る
+    async fn owner_targets(&self, pod: &Pod) -> Vec<NavigationTarget> {
+        let mut targets = Vec::new();
+
+        for owner in pod.owner_references() {
+            if owner.kind != ReplicaSet::KIND {
+                targets.extend(NavigationTarget::owner(owner, &self.namespace));
+                continue;
+            }
+
+            let url = format!(
+                "{}/{}",
+                ReplicaSet::url_path(&Default::default(), Some(&self.namespace)),
+                owner.name
+            );
+
+            // ReplicaSetを取得できないときは移動先に含めない
+            let Ok(replicaset) = self.client.request::<ReplicaSet>(&url).await else {
+                continue;
+            };
+
+            targets.extend(
+                replicaset
+                    .owner_references()
+                    .iter()
+                    .filter_map(|owner| NavigationTarget::owner(owner, &self.namespace)),
+            );
+        }
+
+        targets
+    }
+}
+
+/// 表示に必要な情報に絞り込んだPod
+pub(crate) fn extract_pod(pod: &Pod) -> Pod {
+    pod.extract()
+}
+
+/// `pod` をルートにしたYAMLの行
+pub(crate) fn pod_lines(pod: &Pod) -> Result<Vec<String>> {
+    Ok(serde_yaml::to_string(&pod.to_value()?)?
+        .lines()
+        .map(ToString::to_string)
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::{
         features::api_resources::kube::ApiResources,
-        kube::mock::MockTestKubeClient,
+        kube::{apis::metrics::PodMetrics, mock::MockTestKubeClient},
         mock_expect,
     };
     use indoc::indoc;
     use k8s_openapi::{
         api::{
+            apps::v1::ReplicaSet,
             core::v1::{Pod, Service},
             networking::v1::{Ingress, NetworkPolicy},
         },
@@ -426,6 +524,22 @@ mod tests {
         serde_yaml::from_str(yaml).unwrap()
     }
 
+    fn replicaset() -> ReplicaSet {
+        serde_yaml::from_str(indoc! {
+            "
+            metadata:
+              name: test-5d69d5ddc6
+              ownerReferences:
+              - apiVersion: apps/v1
+                controller: true
+                kind: Deployment
+                name: test
+                uid: 1f7a3a5e-2c1b-4d7e-9a8b-3c4d5e6f7a8b
+            "
+        })
+        .unwrap()
+    }
+
     fn networkpolicies() -> List<NetworkPolicy> {
         serde_yaml::from_str(indoc! {
             r#"
@@ -493,6 +607,16 @@ mod tests {
                     List<NetworkPolicy>,
                     eq("/apis/networking.k8s.io/v1/namespaces/default/networkpolicies"),
                     Ok(networkpolicies())
+                ),
+                (
+                    ReplicaSet,
+                    eq("/apis/apps/v1/namespaces/default/replicasets/test-5d69d5ddc6"),
+                    Ok(replicaset())
+                ),
+                (
+                    PodMetrics,
+                    eq("/apis/metrics.k8s.io/v1beta1/namespaces/default/pods/test"),
+                    bail!("the server could not find the requested resource")
                 )
             ]
         );
@@ -515,6 +639,13 @@ mod tests {
                   app: pod-1
                   version: v1
                 name: test
+                ownerReferences:
+                - apiVersion: apps/v1
+                  blockOwnerDeletion: true
+                  controller: true
+                  kind: ReplicaSet
+                  name: test-5d69d5ddc6
+                  uid: f9be5c32-b4a5-4ec9-b8e8-53c240f4e255
               spec:
                 containers:
                 - image: nginx
@@ -561,7 +692,94 @@ mod tests {
         .map(ToString::to_string)
         .collect();
 
-        assert_eq!(result.unwrap(), expected)
+        let result = result.unwrap();
+
+        assert_eq!(result.lines, expected);
+
+        let target = |kind, name: &str| {
+            NavigationTarget {
+                kind,
+                name: name.into(),
+                namespace: "default".into(),
+            }
+        };
+
+        assert_eq!(
+            result.targets,
+            vec![
+                target("Deployment", "test"),
+                target("Service", "service-1"),
+                target("Service", "service-2"),
+                target("Ingress", "ingress-1"),
+                target("Ingress", "ingress-2"),
+                target("NetworkPolicy", "allow-all-egress"),
+                target("NetworkPolicy", "allow-all-ingress"),
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn metrics_serverがあるとき使用量の推移を末尾に追加する() {
+        let mut client = MockTestKubeClient::new();
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Pod,
+                    eq("/api/v1/namespaces/default/pods/test"),
+                    Ok(pod())
+                ),
+                (
+                    List<Service>,
+                    eq("/api/v1/namespaces/default/services"),
+                    Ok(List::default())
+                ),
+                (
+                    List<NetworkPolicy>,
+                    eq("/apis/networking.k8s.io/v1/namespaces/default/networkpolicies"),
+                    Ok(List::default())
+                ),
+                (
+                    ReplicaSet,
+                    eq("/apis/apps/v1/namespaces/default/replicasets/test-5d69d5ddc6"),
+                    bail!("forbidden")
+                ),
+                (
+                    PodMetrics,
+                    eq("/apis/metrics.k8s.io/v1beta1/namespaces/default/pods/test"),
+                    Ok(serde_json::from_value(serde_json::json!({
+                        "metadata": { "name": "test" },
+                        "timestamp": "2025-01-01T00:00:00Z",
+                        "window": "15s",
+                        "containers": [
+                            { "name": "app-0", "usage": { "cpu": "100m", "memory": "32Mi" } }
+                        ]
+                    }))
+                    .unwrap())
+                )
+            ]
+        );
+
+        let target_params = NetworkRequestTargetParams {
+            namespace: "default".to_string(),
+            name: "test".to_string(),
+            version: "v1".to_string(),
+        };
+
+        let worker = PodDescriptionWorker::new(&client, target_params, ApiResources::shared());
+
+        let result = worker.fetch().await.unwrap();
+
+        let expected = vec![
+            "".to_string(),
+            "# Metrics (last 1 samples)".to_string(),
+            "# CPU  █  100m".to_string(),
+            "# MEM  █  32Mi".to_string(),
+        ];
+
+        assert_eq!(result.lines[result.lines.len() - 4..], expected);
+        assert_eq!(result.targets, vec![]);
     }
 
     #[tokio::test(flavor = "multi_thread")]
@@ -624,6 +842,7 @@ mod extract {
                 annotations: self.metadata.annotations.clone(),
                 labels: self.metadata.labels.clone(),
                 name: self.metadata.name.clone(),
+                owner_references: self.metadata.owner_references.clone(),
                 ..Default::default()
             };
 
@@ -957,6 +1176,13 @@ mod extract {
                     app: kubetui-multi-container
                     pod-template-hash: 5d69d5ddc6
                   name: kubetui-multi-container-5d69d5ddc6-f7fkw
+                  ownerReferences:
+                  - apiVersion: apps/v1
+                    blockOwnerDeletion: true
+                    controller: true
+                    kind: ReplicaSet
+                    name: kubetui-multi-container-5d69d5ddc6
+                    uid: f9be5c32-b4a5-4ec9-b8e8-53c240f4e255
                 spec:
                   containers:
                   - image: nginx
@@ -31,7 +31,7 @@ use crate::{
         },
         table::{insert_ns, KubeTable, KubeTableRow},
         KubeClient,
-        KubeClientRequest,
+        KubeClientRequest as _,
     },
     logger,
     message::Message,
@@ -151,7 +151,7 @@ impl TargetResource {
             None => base_path,
         };
 
-        client.request_table(&path).await.with_context(|| {
+        client.request_watched_table(&path).await.with_context(|| {
             format!(
                 "Failed to fetch table: kind={} ({}) namespace={}",
                 self.as_str(),
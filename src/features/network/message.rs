@@ -2,7 +2,7 @@ use anyhow::Result;
 use strum::EnumString;
 
 use crate::{
-    features::network::NetworkColumns,
+    features::{navigation::NavigationTarget, network::NetworkColumns},
     kube::table::KubeTable,
     message::Message,
     workers::kube::message::Kube,
@@ -41,10 +41,18 @@ pub enum NetworkRequest {
     HTTPRoute(NetworkRequestTargetParams),
 }
 
+/// 説明ペインの内容
+#[derive(Debug, Default)]
+pub struct NetworkDescription {
+    pub lines: Vec<String>,
+    /// 関連リソースやオーナーなど、説明ペインから移動できるリソース
+    pub targets: Vec<NavigationTarget>,
+}
+
 #[derive(Debug)]
 pub enum NetworkResponse {
     List(Result<KubeTable>),
-    Yaml(Result<Vec<String>>),
+    Description(Result<NetworkDescription>),
 }
 
 #[derive(Debug)]
@@ -2,3 +2,4 @@ mod tab;
 mod widgets;
 
 pub use tab::*;
+pub use widgets::update_related_resources;
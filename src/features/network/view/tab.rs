@@ -1,11 +1,6 @@
-use std::{cell::RefCell, rc::Rc};
-
-use crossbeam::channel::Sender;
 use ratatui::layout::{Constraint, Direction};
 
 use crate::{
-    clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
     features::{
         component_id::NETWORK_TAB_ID,
         network::{
@@ -14,12 +9,13 @@ use crate::{
                 network_columns_dialog,
                 network_filter_help_widget,
                 network_widget,
+                related_resource_dialog,
             },
             NetworkColumns,
             NetworkLabelColumn,
         },
+        tab::{TabColumns, TabParams},
     },
-    message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
         widget::Widget,
@@ -31,25 +27,35 @@ pub struct NetworkTab {
     pub tab: Tab<'static>,
     pub network_columns_dialog: Widget<'static>,
     pub network_filter_help_dialog: Widget<'static>,
+    pub related_resource_dialog: Widget<'static>,
 }
 
 impl NetworkTab {
     pub fn new(
         title: &'static str,
-        tx: &Sender<Message>,
-        clipboard: &Option<Rc<RefCell<Clipboard>>>,
-        split_direction: Direction,
-        default_columns: NetworkColumns,
-        label_registry: Vec<NetworkLabelColumn>,
-        theme: WidgetThemeConfig,
+        params: TabParams<'_, TabColumns<NetworkColumns, NetworkLabelColumn>>,
     ) -> Self {
+        let TabParams {
+            tx,
+            clipboard,
+            split_direction,
+            columns:
+                TabColumns {
+                    default: default_columns,
+                    labels: label_registry,
+                },
+            theme,
+            keymap,
+        } = params;
+
         let error_theme = theme.error.clone().into();
 
-        let network_widget = network_widget(tx, label_registry.clone(), theme.clone());
-        let description_widget = description_widget(clipboard, theme.clone());
+        let network_widget = network_widget(tx, label_registry.clone(), theme.clone(), keymap);
+        let description_widget = description_widget(clipboard, theme.clone(), keymap);
         let network_columns_dialog =
             network_columns_dialog(tx, default_columns, label_registry, theme.clone());
-        let network_filter_help_dialog = network_filter_help_widget(theme);
+        let network_filter_help_dialog = network_filter_help_widget(theme.clone());
+        let related_resource_dialog = related_resource_dialog(theme);
 
         let layout = TabLayout::new(layout, split_direction);
 
@@ -63,6 +69,7 @@ impl NetworkTab {
             .error_theme(error_theme),
             network_columns_dialog,
             network_filter_help_dialog,
+            related_resource_dialog,
         }
     }
 }
@@ -2,8 +2,11 @@ mod description;
 mod network;
 mod network_columns_dialog;
 mod network_filter_help;
+mod related_resource_dialog;
 
 pub(super) use description::*;
 pub(super) use network::*;
 pub(super) use network_columns_dialog::*;
 pub(super) use network_filter_help::*;
+pub use related_resource_dialog::update_related_resources;
+pub(super) use related_resource_dialog::*;
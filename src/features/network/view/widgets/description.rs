@@ -4,23 +4,31 @@ use ratatui::widgets::Block;
 
 use crate::{
     clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
-    features::component_id::NETWORK_DESCRIPTION_WIDGET_ID,
-    ui::widget::{
-        SearchForm,
-        SearchFormTheme,
-        Text,
-        TextTheme,
-        Widget,
-        WidgetBase,
-        WidgetTheme,
-        WidgetTrait as _,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
+    features::component_id::{NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_RELATED_RESOURCE_DIALOG_ID},
+    ui::{
+        event::EventResult,
+        widget::{
+            SearchForm,
+            SearchFormTheme,
+            Text,
+            TextTheme,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
     },
 };
 
 pub fn description_widget(
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
     theme: WidgetThemeConfig,
+    keymap: &Keymap,
 ) -> Widget<'static> {
     let widget_theme = WidgetTheme::from(theme.clone());
     let search_theme = SearchFormTheme::from(theme.clone());
@@ -38,6 +46,10 @@ pub fn description_widget(
         .widget_base(widget_base)
         .search_form(search_form)
         .theme(text_theme)
+        .actions(
+            keymap.events(Action::OpenRelated),
+            open_related_resource_dialog(),
+        )
         .block_injection(block_injection());
 
     if let Some(cb) = clipboard {
@@ -60,3 +72,15 @@ fn block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
         base.render_block(text.can_activate() && is_active, is_mouse_over)
     }
 }
+
+/// 説明しているリソースの関連リソースやオーナーを選ぶダイアログを開く
+fn open_related_resource_dialog() -> impl Fn(&mut Window) -> EventResult {
+    |w: &mut Window| {
+        w.open_dialog(NETWORK_RELATED_RESOURCE_DIALOG_ID);
+        if let Widget::SingleSelect(w) = w.find_widget_mut(NETWORK_RELATED_RESOURCE_DIALOG_ID) {
+            w.clear_filter();
+            w.select_first();
+        }
+        EventResult::Nop
+    }
+}
@@ -8,9 +8,16 @@ use k8s_openapi::{
 };
 
 use crate::{
-    config::theme::WidgetThemeConfig,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
     features::{
-        component_id::{NETWORK_DESCRIPTION_WIDGET_ID, NETWORK_WIDGET_ID},
+        component_id::{
+            NETWORK_DESCRIPTION_WIDGET_ID,
+            NETWORK_RELATED_RESOURCE_DIALOG_ID,
+            NETWORK_WIDGET_ID,
+        },
         network::{
             message::{NetworkRequest, NetworkRequestTargetParams},
             network_filter_applicator,
@@ -41,6 +48,7 @@ pub fn network_widget(
     tx: &Sender<Message>,
     label_registry: Vec<NetworkLabelColumn>,
     theme: WidgetThemeConfig,
+    keymap: &Keymap,
 ) -> Widget<'static> {
     let tx = tx.clone();
 
@@ -61,7 +69,19 @@ pub fn network_widget(
         .filter_form(filter_form)
         .theme(table_theme)
         .filter_applicator(network_filter_applicator(label_registry, tx.clone()))
-        .action('t', open_network_columns_dialog())
+        .sort_actions(
+            keymap.events(Action::SortNext),
+            keymap.events(Action::SortPrev),
+        )
+        .mark_actions(
+            keymap.events(Action::ToggleMark),
+            keymap.events(Action::MarkRange),
+            keymap.events(Action::MarkAll),
+        )
+        .actions(
+            keymap.events(Action::OpenColumns),
+            open_network_columns_dialog(),
+        )
         .block_injection(block_injection())
         .on_select(on_select(tx))
         .build()
@@ -89,6 +109,7 @@ fn block_injection() -> impl Fn(&Table) -> WidgetBase {
 fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
     move |w: &mut Window, v: &TableItem| {
         w.widget_clear(NETWORK_DESCRIPTION_WIDGET_ID);
+        w.widget_clear(NETWORK_RELATED_RESOURCE_DIALOG_ID);
 
         let Some(metadata) = v.metadata.as_ref() else {
             return EventResult::Ignore;
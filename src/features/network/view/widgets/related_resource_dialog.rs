@@ -0,0 +1,86 @@
+use crate::{
+    config::theme::WidgetThemeConfig,
+    features::{
+        component_id::NETWORK_RELATED_RESOURCE_DIALOG_ID,
+        navigation::{navigate, NavigationTarget},
+    },
+    ui::{
+        event::EventResult,
+        widget::{
+            single_select::{
+                FilterForm,
+                FilterFormTheme,
+                SelectForm,
+                SelectFormTheme,
+                SingleSelectTheme,
+            },
+            Item,
+            LiteralItem,
+            SingleSelect,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+pub fn related_resource_dialog(theme: WidgetThemeConfig) -> Widget<'static> {
+    let widget_theme = WidgetTheme::from(theme.clone());
+    let filter_theme = FilterFormTheme::from(theme.clone());
+    let select_theme = SelectFormTheme::from(theme.clone());
+    let single_select_theme = SingleSelectTheme::default().status_style(theme.list.status);
+
+    let filter_form = FilterForm::builder().theme(filter_theme).build();
+
+    let select_form = SelectForm::builder()
+        .on_select(on_select)
+        .theme(select_theme)
+        .build();
+
+    let widget_base = WidgetBase::builder()
+        .title("Related Resources")
+        .theme(widget_theme)
+        .build();
+
+    SingleSelect::builder()
+        .id(NETWORK_RELATED_RESOURCE_DIALOG_ID)
+        .widget_base(widget_base)
+        .filter_form(filter_form)
+        .select_form(select_form)
+        .theme(single_select_theme)
+        .build()
+        .into()
+}
+
+/// 説明ペインのリソースから移動できるリソースを選択ダイアログに反映する
+///
+/// ダイアログを開いている間は、選択位置やフィルタが変わらないように更新しない
+pub fn update_related_resources(w: &mut Window, targets: Vec<NavigationTarget>) {
+    if w.opening_dialog_id() == Some(NETWORK_RELATED_RESOURCE_DIALOG_ID) {
+        return;
+    }
+
+    let items = targets
+        .into_iter()
+        .map(|target| LiteralItem::new(target.to_string(), Some(target.metadata())))
+        .collect();
+
+    w.find_widget_mut(NETWORK_RELATED_RESOURCE_DIALOG_ID)
+        .update_widget_item(Item::Array(items));
+}
+
+fn on_select(w: &mut Window, v: &LiteralItem) -> EventResult {
+    let Some(target) = v
+        .metadata
+        .as_ref()
+        .and_then(NavigationTarget::from_metadata)
+    else {
+        return EventResult::Ignore;
+    };
+
+    w.close_dialog();
+
+    navigate(w, &target)
+}
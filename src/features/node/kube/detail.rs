@@ -5,7 +5,10 @@ use kube::{api::ListParams, core::ObjectList, Api};
 
 use crate::{
     features::node::message::NodeDetailMessage,
-    kube::KubeClientRequest,
+    kube::{
+        metrics::{fetch_node_sample, node_allocatable, MetricsHistory},
+        KubeClientRequest,
+    },
     logger,
     message::Message,
     workers::kube::InfiniteWorker,
@@ -33,10 +36,17 @@ where
 
     /// Fetch Node + related Pods and combine into a single line array.
     ///
+    /// A metrics-server sample is appended to `history` and drawn as
+    /// sparklines. Missing metrics-server just omits that section.
+    ///
     /// The fetch is a thin delegation to `kube::Api` (matches `log_streamer` /
     /// `pod_watcher` in this codebase). The pure formatters below are what the
     /// unit tests target.
-    pub async fn fetch_for(name: &str, client: &C) -> Result<Vec<String>> {
+    pub async fn fetch_for(
+        name: &str,
+        client: &C,
+        history: &mut MetricsHistory,
+    ) -> Result<Vec<String>> {
         let kube_client = client.client().clone();
 
         // 1) Node: typed get via kube::Api.
@@ -45,8 +55,20 @@ where
             .get(name)
             .await
             .with_context(|| format!("failed to fetch node {}", name))?;
+        let allocatable = node_allocatable(&serde_json::to_value(&node)?);
         let mut lines = strip_and_serialize_node(node)?;
 
+        if history.sample_due() {
+            if let Ok(sample) = fetch_node_sample(client, name).await {
+                history.push(sample);
+            }
+        }
+
+        if !history.is_empty() {
+            lines.push("---".to_string());
+            lines.extend(history.lines(allocatable));
+        }
+
         // 2) Related Pods: typed list across all namespaces with field selector.
         let pod_api: Api<Pod> = Api::all(kube_client);
         let lp = ListParams::default().fields(&format!("spec.nodeName={}", name));
@@ -85,10 +107,13 @@ where
     async fn fetch_loop(&self) -> Result<()> {
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(INTERVAL));
 
+        // The worker is restarted on selection change, so the history is per node.
+        let mut history = MetricsHistory::default();
+
         loop {
             interval.tick().await;
 
-            let result = Self::fetch_for(&self.name, &self.client).await;
+            let result = Self::fetch_for(&self.name, &self.client, &mut history).await;
 
             self.tx.send(NodeDetailMessage::Response(result).into())?;
         }
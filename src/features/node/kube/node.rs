@@ -12,6 +12,7 @@ use crate::{
     features::node::{message::NodeMessage, NodeColumn, NodeColumnSpec, NodeColumns},
     kube::{
         apis::v1_table::Table,
+        metrics::{node_allocatable, MetricsCache},
         table::{KubeTable, KubeTableRow},
         KubeClient,
         KubeClientRequest,
@@ -35,6 +36,7 @@ pub struct NodePoller {
     shared_node_columns: SharedNodeColumns,
     shared_node_filter: SharedNodeFilter,
     kube_client: KubeClient,
+    metrics_cache: MetricsCache,
 }
 
 impl NodePoller {
@@ -49,6 +51,7 @@ impl NodePoller {
             shared_node_columns,
             shared_node_filter,
             kube_client,
+            metrics_cache: MetricsCache::default(),
         }
     }
 }
@@ -64,6 +67,7 @@ impl InfiniteWorker for NodePoller {
                 &self.kube_client,
                 &self.shared_node_columns,
                 &self.shared_node_filter,
+                &self.metrics_cache,
             )
             .await;
             if let Err(e) = tx.send(NodeMessage::Poll(node_info).into()) {
@@ -78,6 +82,7 @@ async fn get_node_table<C: KubeClientRequest>(
     client: &C,
     shared_node_columns: &SharedNodeColumns,
     shared_node_filter: &SharedNodeFilter,
+    metrics_cache: &MetricsCache,
 ) -> Result<KubeTable> {
     let node_columns = shared_node_columns.read().await;
 
@@ -87,27 +92,51 @@ async fn get_node_table<C: KubeClientRequest>(
         .iter()
         .filter_map(|s| {
             match s {
-                NodeColumnSpec::Builtin(c) => Some(c.as_str()),
+                NodeColumnSpec::Builtin(c) if c.metrics().is_none() => Some(c.as_str()),
+                NodeColumnSpec::Builtin(_) | NodeColumnSpec::Label { .. } => None,
+            }
+        })
+        .collect();
+
+    let metrics_columns: Vec<_> = specs
+        .iter()
+        .filter_map(|s| {
+            match s {
+                NodeColumnSpec::Builtin(c) => c.metrics(),
                 NodeColumnSpec::Label { .. } => None,
             }
         })
         .collect();
 
+    // Ratio columns need status.allocatable, which is only in the full object.
+    let include_object = metrics_columns.iter().any(|c| c.needs_object());
+
     let base_path = Node::url_path(&(), None);
     let path = {
         let filter = shared_node_filter.read().await;
-        match filter.as_deref().filter(|s| !s.is_empty()) {
-            Some(sel) => {
-                format!(
-                    "{}?labelSelector={}",
-                    base_path,
-                    utf8_percent_encode(sel, NON_ALPHANUMERIC)
-                )
-            }
-            None => base_path,
+        let mut queries = Vec::new();
+        if let Some(sel) = filter.as_deref().filter(|s| !s.is_empty()) {
+            queries.push(format!(
+                "labelSelector={}",
+                utf8_percent_encode(sel, NON_ALPHANUMERIC)
+            ));
         }
+        if include_object {
+            queries.push("includeObject=Object".to_string());
+        }
+        if queries.is_empty() {
+            base_path
+        } else {
+            format!("{}?{}", base_path, queries.join("&"))
+        }
+    };
+    let table: Table = client.request_watched_table(&path).await?;
+
+    let usage = if metrics_columns.is_empty() {
+        None
+    } else {
+        metrics_cache.node_usage(client).await
     };
-    let table: Table = client.request_table(&path).await?;
 
     let builtin_indexes = table.find_indexes(&builtin_targets)?;
 
@@ -120,12 +149,23 @@ async fn get_node_table<C: KubeClientRequest>(
         .rows
         .iter()
         .map(|row| {
+            let object = row.object.as_ref().map(|o| &o.0);
+            let node_usage = usage.as_ref().and_then(|usage| {
+                let name = object?.pointer("/metadata/name")?.as_str()?;
+                usage.get(name).copied()
+            });
+            let allocatable = object.map(node_allocatable).unwrap_or_default();
+
             let mut builtin_iter = builtin_indexes.iter();
             let cells: Vec<String> = specs
                 .iter()
                 .map(|spec| {
                     match spec {
-                        NodeColumnSpec::Builtin(_) => {
+                        NodeColumnSpec::Builtin(c) => {
+                            if let Some(metrics) = c.metrics() {
+                                return metrics.format(node_usage, allocatable);
+                            }
+
                             let i = builtin_iter.next().expect("builtin index available");
                             row.cells[*i].to_string()
                         }
@@ -169,7 +209,10 @@ async fn get_node_table<C: KubeClientRequest>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::kube::apis::v1_table::{Table, TableColumnDefinition, TableRow, Value};
+    use crate::kube::apis::{
+        metrics::NodeMetricsList,
+        v1_table::{Table, TableColumnDefinition, TableRow, Value},
+    };
     use crate::mock_expect;
     use k8s_openapi::apimachinery::pkg::runtime::RawExtension;
     use mockall::predicate::eq;
@@ -225,7 +268,7 @@ mod tests {
 
         let shared = Arc::new(RwLock::new(NodeColumns::default()));
         let shared_filter = Arc::new(RwLock::new(None));
-        let table = get_node_table(&client, &shared, &shared_filter)
+        let table = get_node_table(&client, &shared, &shared_filter, &MetricsCache::default())
             .await
             .unwrap();
 
@@ -284,7 +327,7 @@ mod tests {
         ]);
         let shared = Arc::new(RwLock::new(specs));
         let shared_filter = Arc::new(RwLock::new(None));
-        let table = get_node_table(&client, &shared, &shared_filter)
+        let table = get_node_table(&client, &shared, &shared_filter, &MetricsCache::default())
             .await
             .unwrap();
 
@@ -308,7 +351,7 @@ mod tests {
 
         let shared = Arc::new(RwLock::new(NodeColumns::default()));
         let shared_filter = Arc::new(RwLock::new(Some("env=prod".to_string())));
-        let table = get_node_table(&client, &shared, &shared_filter)
+        let table = get_node_table(&client, &shared, &shared_filter, &MetricsCache::default())
             .await
             .unwrap();
         assert_eq!(table.rows.len(), 2);
@@ -330,7 +373,7 @@ mod tests {
 
         let shared = Arc::new(RwLock::new(NodeColumns::default()));
         let shared_filter = Arc::new(RwLock::new(Some("env=prod,tier=frontend".to_string())));
-        let table = get_node_table(&client, &shared, &shared_filter)
+        let table = get_node_table(&client, &shared, &shared_filter, &MetricsCache::default())
             .await
             .unwrap();
         assert_eq!(table.rows.len(), 2);
@@ -351,7 +394,7 @@ mod tests {
 
         let shared = Arc::new(RwLock::new(NodeColumns::default()));
         let shared_filter = Arc::new(RwLock::new(Some("env in (prod,dev)".to_string())));
-        let table = get_node_table(&client, &shared, &shared_filter)
+        let table = get_node_table(&client, &shared, &shared_filter, &MetricsCache::default())
             .await
             .unwrap();
         assert_eq!(table.rows.len(), 2);
@@ -370,9 +413,105 @@ mod tests {
 
         let shared = Arc::new(RwLock::new(NodeColumns::default()));
         let shared_filter = Arc::new(RwLock::new(Some(String::new())));
-        let table = get_node_table(&client, &shared, &shared_filter)
+        let table = get_node_table(&client, &shared, &shared_filter, &MetricsCache::default())
             .await
             .unwrap();
         assert_eq!(table.rows.len(), 2);
     }
+
+    fn node_with_allocatable(name: &str) -> TableRow {
+        TableRow {
+            cells: vec![Value(JsonValue::String(name.to_string()))],
+            object: Some(RawExtension(serde_json::json!({
+                "metadata": { "name": name },
+                "status": { "allocatable": { "cpu": "2", "memory": "4Gi" } }
+            }))),
+            ..Default::default()
+        }
+    }
+
+    fn metrics_columns() -> NodeColumns {
+        NodeColumns::from_builtins([
+            NodeColumn::Name,
+            NodeColumn::Cpu,
+            NodeColumn::Memory,
+            NodeColumn::CpuAllocatableRatio,
+            NodeColumn::MemoryAllocatableRatio,
+        ])
+    }
+
+    #[tokio::test]
+    async fn fills_metrics_columns_from_metrics_api() {
+        let mut client = crate::kube::mock::MockTestKubeClient::new();
+        mock_expect!(
+            client,
+            request_table,
+            Table,
+            eq("/api/v1/nodes?includeObject=Object"),
+            Ok(Table {
+                column_definitions: vec![coldef("Name")],
+                rows: vec![node_with_allocatable("node-a")],
+                ..Default::default()
+            })
+        );
+        mock_expect!(
+            client,
+            request,
+            NodeMetricsList,
+            eq("/apis/metrics.k8s.io/v1beta1/nodes"),
+            Ok(serde_json::from_value(serde_json::json!({
+                "items": [{
+                    "metadata": { "name": "node-a" },
+                    "timestamp": "2025-01-01T00:00:00Z",
+                    "window": "15s",
+                    "usage": { "cpu": "500m", "memory": "1Gi" }
+                }]
+            }))
+            .unwrap())
+        );
+
+        let shared = Arc::new(RwLock::new(metrics_columns()));
+        let shared_filter = Arc::new(RwLock::new(None));
+        let table = get_node_table(&client, &shared, &shared_filter, &MetricsCache::default())
+            .await
+            .unwrap();
+
+        assert_eq!(table.header, vec!["NAME", "CPU", "MEM", "%CPU/A", "%MEM/A"]);
+        assert_eq!(
+            table.rows[0].row,
+            vec!["node-a", "500m", "1024Mi", "25%", "25%"]
+        );
+    }
+
+    #[tokio::test]
+    async fn metrics_columns_are_not_available_without_metrics_server() {
+        let mut client = crate::kube::mock::MockTestKubeClient::new();
+        mock_expect!(
+            client,
+            request_table,
+            Table,
+            eq("/api/v1/nodes?labelSelector=env%3Dprod&includeObject=Object"),
+            Ok(Table {
+                column_definitions: vec![coldef("Name")],
+                rows: vec![node_with_allocatable("node-a")],
+                ..Default::default()
+            })
+        );
+        client.expect_request::<NodeMetricsList>().returning(|_| {
+            Err(anyhow::anyhow!(
+                "the server could not find the requested resource"
+            ))
+        });
+
+        let shared = Arc::new(RwLock::new(metrics_columns()));
+        let shared_filter = Arc::new(RwLock::new(Some("env=prod".to_string())));
+        let table = get_node_table(&client, &shared, &shared_filter, &MetricsCache::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            table.rows[0].row,
+            vec!["node-a", "n/a", "n/a", "n/a", "n/a"]
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use strum::{EnumIter, IntoEnumIterator};
 
+use crate::kube::metrics::MetricsColumn;
+
 /// A runtime column in the node table: a built-in column or a label column.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NodeColumnSpec {
@@ -104,11 +106,16 @@ pub enum NodeColumn {
     OSImage,
     KernelVersion,
     ContainerRuntime,
+    Cpu,
+    Memory,
+    CpuAllocatableRatio,
+    MemoryAllocatableRatio,
 }
 
 #[allow(dead_code)]
 impl NodeColumn {
     /// Must match the Table API columnDefinitions[].name.
+    /// Metrics columns are not part of the Table API and are filled from metrics.k8s.io.
     pub const fn as_str(&self) -> &'static str {
         match self {
             NodeColumn::Name => "Name",
@@ -121,6 +128,10 @@ impl NodeColumn {
             NodeColumn::OSImage => "OS-Image",
             NodeColumn::KernelVersion => "Kernel-Version",
             NodeColumn::ContainerRuntime => "Container-Runtime",
+            NodeColumn::Cpu => "CPU",
+            NodeColumn::Memory => "MEM",
+            NodeColumn::CpuAllocatableRatio => "%CPU/A",
+            NodeColumn::MemoryAllocatableRatio => "%MEM/A",
         }
     }
 
@@ -136,6 +147,21 @@ impl NodeColumn {
             NodeColumn::OSImage => "OS-IMAGE",
             NodeColumn::KernelVersion => "KERNEL-VERSION",
             NodeColumn::ContainerRuntime => "CONTAINER-RUNTIME",
+            NodeColumn::Cpu => "CPU",
+            NodeColumn::Memory => "MEM",
+            NodeColumn::CpuAllocatableRatio => "%CPU/A",
+            NodeColumn::MemoryAllocatableRatio => "%MEM/A",
+        }
+    }
+
+    /// Columns filled from metrics.k8s.io. Node ratios are against allocatable.
+    pub const fn metrics(&self) -> Option<MetricsColumn> {
+        match self {
+            NodeColumn::Cpu => Some(MetricsColumn::Cpu),
+            NodeColumn::Memory => Some(MetricsColumn::Memory),
+            NodeColumn::CpuAllocatableRatio => Some(MetricsColumn::CpuRatio),
+            NodeColumn::MemoryAllocatableRatio => Some(MetricsColumn::MemoryRatio),
+            _ => None,
         }
     }
 
@@ -147,6 +173,12 @@ impl NodeColumn {
     pub fn all() -> impl Iterator<Item = NodeColumn> {
         NodeColumn::iter()
     }
+
+    /// Columns expanded from `full`.
+    /// Metrics columns query metrics-server, so they have to be listed explicitly.
+    pub fn full() -> impl Iterator<Item = NodeColumn> {
+        NodeColumn::iter().filter(|c| c.metrics().is_none())
+    }
 }
 
 #[derive(Debug)]
@@ -175,6 +207,10 @@ impl std::str::FromStr for NodeColumn {
             "osimage" => Ok(NodeColumn::OSImage),
             "kernelversion" => Ok(NodeColumn::KernelVersion),
             "containerruntime" => Ok(NodeColumn::ContainerRuntime),
+            "cpu" => Ok(NodeColumn::Cpu),
+            "mem" | "memory" => Ok(NodeColumn::Memory),
+            "%cpu/a" | "cpu/a" => Ok(NodeColumn::CpuAllocatableRatio),
+            "%mem/a" | "mem/a" => Ok(NodeColumn::MemoryAllocatableRatio),
             _ => Err(NodeColumnParseError),
         }
     }
@@ -237,6 +273,22 @@ mod tests {
         assert!(NodeColumn::from_str("bogus").is_err());
     }
 
+    #[test]
+    fn from_str_accepts_metrics_columns() {
+        assert_eq!(NodeColumn::from_str("CPU").unwrap(), NodeColumn::Cpu);
+        assert_eq!(NodeColumn::from_str("mem").unwrap(), NodeColumn::Memory);
+        assert_eq!(
+            NodeColumn::from_str("%cpu/a").unwrap(),
+            NodeColumn::CpuAllocatableRatio
+        );
+        assert_eq!(
+            NodeColumn::from_str("%MEM/A").unwrap(),
+            NodeColumn::MemoryAllocatableRatio
+        );
+        assert_eq!(NodeColumn::Version.metrics(), None);
+        assert!(NodeColumn::from_str("%cpu/r").is_err());
+    }
+
     #[test]
     fn as_str_matches_table_column_definition_names() {
         assert_eq!(NodeColumn::InternalIP.as_str(), "Internal-IP");
@@ -1,16 +1,11 @@
-use std::{cell::RefCell, rc::Rc};
-
-use crossbeam::channel::Sender;
 use ratatui::layout::{Constraint, Direction};
 
 use crate::{
-    clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
     features::{
         component_id::NODE_TAB_ID,
         node::{NodeColumns, NodeLabelColumn},
+        tab::{TabColumns, TabParams},
     },
-    message::Message,
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
         widget::Widget,
@@ -34,14 +29,22 @@ pub struct NodeTab {
 impl NodeTab {
     pub fn new(
         title: &'static str,
-        tx: &Sender<Message>,
-        clipboard: &Option<Rc<RefCell<Clipboard>>>,
-        split_direction: Direction,
-        default_columns: Option<NodeColumns>,
-        label_registry: Vec<NodeLabelColumn>,
-        theme: WidgetThemeConfig,
+        params: TabParams<'_, TabColumns<Option<NodeColumns>, NodeLabelColumn>>,
     ) -> Self {
-        let node_widget = node_widget(tx.clone(), label_registry.clone(), theme.clone());
+        let TabParams {
+            tx,
+            clipboard,
+            split_direction,
+            columns:
+                TabColumns {
+                    default: default_columns,
+                    labels: label_registry,
+                },
+            theme,
+            keymap,
+        } = params;
+
+        let node_widget = node_widget(tx.clone(), label_registry.clone(), theme.clone(), keymap);
         let detail_widget = node_detail_widget(clipboard, theme.clone());
         let node_columns_dialog =
             node_columns_dialog(tx, default_columns, label_registry, theme.clone());
@@ -1,7 +1,10 @@
 use crossbeam::channel::Sender;
 
 use crate::{
-    config::theme::WidgetThemeConfig,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
     features::{
         component_id::{NODE_COLUMNS_DIALOG_ID, NODE_DETAIL_WIDGET_ID, NODE_WIDGET_ID},
         node::{
@@ -32,6 +35,7 @@ pub fn node_widget(
     tx: Sender<Message>,
     label_registry: Vec<NodeLabelColumn>,
     theme: WidgetThemeConfig,
+    keymap: &Keymap,
 ) -> Widget<'static> {
     let widget_theme = WidgetTheme::from(theme.clone());
     let table_theme = TableTheme::from(theme.clone());
@@ -49,8 +53,20 @@ pub fn node_widget(
         .widget_base(widget_base)
         .filter_form(filter_form)
         .filter_applicator(node_filter_applicator(label_registry, tx.clone()))
+        .sort_actions(
+            keymap.events(Action::SortNext),
+            keymap.events(Action::SortPrev),
+        )
+        .mark_actions(
+            keymap.events(Action::ToggleMark),
+            keymap.events(Action::MarkRange),
+            keymap.events(Action::MarkAll),
+        )
         .theme(table_theme)
-        .action('t', open_node_columns_dialog())
+        .actions(
+            keymap.events(Action::OpenColumns),
+            open_node_columns_dialog(),
+        )
         .block_injection(block_injection())
         .on_select(on_select(tx))
         .build()
@@ -0,0 +1,126 @@
+use std::fmt::Debug;
+
+use anyhow::{bail, Result};
+use jiff::Timestamp;
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        core::v1::Pod,
+    },
+    NamespaceResourceScope,
+};
+use kube::{
+    api::{DeleteParams, Patch, PatchParams},
+    Api,
+    Resource,
+};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use crate::{kube::KubeClient, logger};
+
+use super::message::{Operation, WorkloadKind, WorkloadTarget};
+
+/// `kubectl rollout restart` が更新するアノテーション
+const RESTARTED_AT_ANNOTATION: &str = "kubectl.kubernetes.io/restartedAt";
+
+/// 確認済みの操作を実行する
+pub async fn execute(client: &KubeClient, operation: &Operation) -> Result<()> {
+    logger!(info, "Execute operation: {}", operation);
+
+    match operation {
+        Operation::DeletePod { namespace, name } => {
+            let api: Api<Pod> = Api::namespaced(client.to_client(), namespace);
+
+            api.delete(name, &DeleteParams::default()).await?;
+        }
+        Operation::Restart(target) => {
+            match target.kind {
+                WorkloadKind::Deployment => restart::<Deployment>(client, target).await?,
+                WorkloadKind::StatefulSet => restart::<StatefulSet>(client, target).await?,
+                WorkloadKind::DaemonSet => restart::<DaemonSet>(client, target).await?,
+            }
+        }
+        Operation::Scale { target, replicas } => {
+            match target.kind {
+                WorkloadKind::Deployment => scale::<Deployment>(client, target, *replicas).await?,
+                WorkloadKind::StatefulSet => {
+                    scale::<StatefulSet>(client, target, *replicas).await?
+                }
+                WorkloadKind::DaemonSet => bail!("{} cannot be scaled", target),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// ワークロードの現在のレプリカ数を取得する
+pub async fn fetch_replicas(client: &KubeClient, target: &WorkloadTarget) -> Result<i32> {
+    let scale = match target.kind {
+        WorkloadKind::Deployment => {
+            Api::<Deployment>::namespaced(client.to_client(), &target.namespace)
+                .get_scale(&target.name)
+                .await?
+        }
+        WorkloadKind::StatefulSet => {
+            Api::<StatefulSet>::namespaced(client.to_client(), &target.namespace)
+                .get_scale(&target.name)
+                .await?
+        }
+        WorkloadKind::DaemonSet => bail!("{} cannot be scaled", target),
+    };
+
+    Ok(scale
+        .spec
+        .and_then(|spec| spec.replicas)
+        .unwrap_or_default())
+}
+
+async fn restart<K>(client: &KubeClient, target: &WorkloadTarget) -> Result<()>
+where
+    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()>
+        + Clone
+        + DeserializeOwned
+        + Debug,
+{
+    let api: Api<K> = Api::namespaced(client.to_client(), &target.namespace);
+
+    let patch = json!({
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        RESTARTED_AT_ANNOTATION: Timestamp::now().to_string()
+                    }
+                }
+            }
+        }
+    });
+
+    api.patch(&target.name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await?;
+
+    Ok(())
+}
+
+async fn scale<K>(client: &KubeClient, target: &WorkloadTarget, replicas: i32) -> Result<()>
+where
+    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()>
+        + Clone
+        + DeserializeOwned
+        + Debug,
+{
+    let api: Api<K> = Api::namespaced(client.to_client(), &target.namespace);
+
+    let patch = json!({
+        "spec": {
+            "replicas": replicas
+        }
+    });
+
+    api.patch_scale(&target.name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await?;
+
+    Ok(())
+}
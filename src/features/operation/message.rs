@@ -0,0 +1,191 @@
+use std::fmt;
+
+use anyhow::{bail, Result};
+use k8s_openapi::{
+    api::apps::v1::{DaemonSet, Deployment, StatefulSet},
+    Resource as _,
+};
+
+use crate::{message::Message, workers::kube::message::Kube};
+
+#[derive(Debug)]
+pub enum OperationMessage {
+    Request(OperationRequest),
+    Response(OperationResponse),
+}
+
+#[derive(Debug)]
+pub enum OperationRequest {
+    /// ワークロードの現在のレプリカ数を取得する
+    Replicas(WorkloadTarget),
+
+    /// 確認済みの操作を実行する
+    Execute(Operation),
+}
+
+#[derive(Debug)]
+pub enum OperationResponse {
+    Replicas {
+        target: WorkloadTarget,
+        replicas: Result<i32>,
+    },
+
+    Execute {
+        operation: Operation,
+        result: Result<()>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkloadKind {
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+}
+
+impl WorkloadKind {
+    pub fn try_from_kind(kind: &str) -> Result<Self> {
+        match kind {
+            Deployment::KIND => Ok(Self::Deployment),
+            StatefulSet::KIND => Ok(Self::StatefulSet),
+            DaemonSet::KIND => Ok(Self::DaemonSet),
+            _ => bail!("Unsupported workload kind: {}", kind),
+        }
+    }
+
+    /// レプリカ数を変更できるかどうか
+    pub fn is_scalable(&self) -> bool {
+        !matches!(self, Self::DaemonSet)
+    }
+}
+
+impl fmt::Display for WorkloadKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Deployment => write!(f, "deployment"),
+            Self::StatefulSet => write!(f, "statefulset"),
+            Self::DaemonSet => write!(f, "daemonset"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkloadTarget {
+    pub namespace: String,
+    pub kind: WorkloadKind,
+    pub name: String,
+}
+
+impl fmt::Display for WorkloadTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.namespace, self.kind, self.name)
+    }
+}
+
+/// クラスタに変更を加える操作
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    DeletePod {
+        namespace: String,
+        name: String,
+    },
+
+    /// `kubectl rollout restart` と同様に `restartedAt` アノテーションを更新する
+    Restart(WorkloadTarget),
+
+    Scale {
+        target: WorkloadTarget,
+        replicas: i32,
+    },
+}
+
+impl Operation {
+    /// 確認のために入力するリソース名
+    pub fn confirmation_name(&self) -> &str {
+        match self {
+            Self::DeletePod { name, .. } => name,
+            Self::Restart(target) | Self::Scale { target, .. } => &target.name,
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DeletePod { namespace, name } => write!(f, "delete {}/pod/{}", namespace, name),
+            Self::Restart(target) => write!(f, "restart {}", target),
+            Self::Scale { target, replicas } => {
+                write!(f, "scale {} to {} replicas", target, replicas)
+            }
+        }
+    }
+}
+
+impl From<OperationRequest> for Message {
+    fn from(req: OperationRequest) -> Self {
+        Message::Kube(Kube::Operation(OperationMessage::Request(req)))
+    }
+}
+
+impl From<OperationResponse> for Message {
+    fn from(res: OperationResponse) -> Self {
+        Message::Kube(Kube::Operation(OperationMessage::Response(res)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    fn deployment() -> WorkloadTarget {
+        WorkloadTarget {
+            namespace: "default".into(),
+            kind: WorkloadKind::Deployment,
+            name: "web".into(),
+        }
+    }
+
+    #[rstest]
+    #[case::delete_pod(
+        Operation::DeletePod { namespace: "default".into(), name: "web-0".into() },
+        "web-0",
+        "delete default/pod/web-0"
+    )]
+    #[case::restart(
+        Operation::Restart(deployment()),
+        "web",
+        "restart default/deployment/web"
+    )]
+    #[case::scale(
+        Operation::Scale { target: deployment(), replicas: 3 },
+        "web",
+        "scale default/deployment/web to 3 replicas"
+    )]
+    fn operation_display(
+        #[case] operation: Operation,
+        #[case] confirmation_name: &str,
+        #[case] display: &str,
+    ) {
+        assert_eq!(operation.confirmation_name(), confirmation_name);
+        assert_eq!(operation.to_string(), display);
+    }
+
+    #[rstest]
+    #[case::deployment("Deployment", WorkloadKind::Deployment, true)]
+    #[case::statefulset("StatefulSet", WorkloadKind::StatefulSet, true)]
+    #[case::daemonset("DaemonSet", WorkloadKind::DaemonSet, false)]
+    fn workload_kind(#[case] kind: &str, #[case] expected: WorkloadKind, #[case] scalable: bool) {
+        let actual = WorkloadKind::try_from_kind(kind).unwrap();
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual.is_scalable(), scalable);
+    }
+
+    #[test]
+    fn unsupported_workload_kind() {
+        assert!(WorkloadKind::try_from_kind("Job").is_err());
+    }
+}
@@ -0,0 +1,6 @@
+mod confirm_dialog;
+mod scale_dialog;
+mod selection;
+
+pub use confirm_dialog::*;
+pub use scale_dialog::*;
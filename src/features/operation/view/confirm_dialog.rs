@@ -0,0 +1,170 @@
+use std::{cell::RefCell, rc::Rc};
+
+use anyhow::anyhow;
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    config::theme::ThemeConfig,
+    features::{
+        component_id::OPERATION_CONFIRM_DIALOG_ID,
+        operation::message::{Operation, OperationRequest},
+    },
+    logger,
+    message::{Message, UserEvent},
+    ui::{
+        event::{CallbackFn, EventResult},
+        widget::{InputForm, InputFormTheme, Widget, WidgetBase, WidgetTheme, WidgetTrait as _},
+        Window,
+    },
+};
+
+use super::selection::{selected_pod, selected_workload};
+
+/// 確認待ちの操作
+pub type SharedPendingOperation = Rc<RefCell<Option<Operation>>>;
+
+pub struct OperationConfirmDialog {
+    pub widget: Widget<'static>,
+}
+
+impl OperationConfirmDialog {
+    pub fn new(tx: &Sender<Message>, pending: &SharedPendingOperation, theme: ThemeConfig) -> Self {
+        Self {
+            widget: widget(tx.clone(), pending.clone(), theme),
+        }
+    }
+}
+
+fn widget(
+    tx: Sender<Message>,
+    pending: SharedPendingOperation,
+    theme: ThemeConfig,
+) -> Widget<'static> {
+    let widget_theme = WidgetTheme::from(theme.component.clone());
+    let input_theme = InputFormTheme::from(theme.component);
+
+    let widget_base = WidgetBase::builder()
+        .title("Confirm")
+        .theme(widget_theme)
+        .build();
+
+    InputForm::builder()
+        .id(OPERATION_CONFIRM_DIALOG_ID)
+        .widget_base(widget_base)
+        .theme(input_theme)
+        .actions(UserEvent::from(KeyCode::Enter), confirm(tx, pending))
+        .build()
+        .into()
+}
+
+/// 操作の確認ダイアログを開く
+///
+/// 対象のリソース名を入力するまで操作は実行しない
+pub fn open_operation_confirm_dialog(
+    w: &mut Window,
+    pending: &SharedPendingOperation,
+    operation: Operation,
+) {
+    w.clear_widget_error(OPERATION_CONFIRM_DIALOG_ID);
+
+    let dialog = w.find_widget_mut(OPERATION_CONFIRM_DIALOG_ID);
+
+    dialog.clear();
+
+    *dialog.widget_base_mut().append_title_mut() = Some(format!(" : {}", operation).into());
+
+    dialog.as_mut_input().update_prefix(format!(
+        "Type {:?} to confirm: ",
+        operation.confirmation_name()
+    ));
+
+    *pending.borrow_mut() = Some(operation);
+
+    w.open_dialog(OPERATION_CONFIRM_DIALOG_ID);
+}
+
+/// 操作を開始できなかった理由を確認ダイアログに表示する
+pub fn open_operation_error(w: &mut Window, pending: &SharedPendingOperation, err: anyhow::Error) {
+    *pending.borrow_mut() = None;
+
+    let dialog = w.find_widget_mut(OPERATION_CONFIRM_DIALOG_ID);
+
+    dialog.clear();
+
+    *dialog.widget_base_mut().append_title_mut() = None;
+
+    w.set_widget_error(OPERATION_CONFIRM_DIALOG_ID, &err);
+
+    w.open_dialog(OPERATION_CONFIRM_DIALOG_ID);
+}
+
+/// 選択中のPodを削除する
+pub fn delete_pod(pending: SharedPendingOperation) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let Some(pod) = selected_pod(w) else {
+            return EventResult::Ignore;
+        };
+
+        let operation = Operation::DeletePod {
+            namespace: pod.namespace,
+            name: pod.name,
+        };
+
+        open_operation_confirm_dialog(w, &pending, operation);
+
+        EventResult::Nop
+    }
+}
+
+/// 選択中のワークロード (Podテーブルでは選択中のPodを管理しているワークロード) を再起動する
+pub fn restart_workload(pending: SharedPendingOperation) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let Some(workload) = selected_workload(w) else {
+            return EventResult::Ignore;
+        };
+
+        match workload {
+            Ok(target) => open_operation_confirm_dialog(w, &pending, Operation::Restart(target)),
+            Err(err) => open_operation_error(w, &pending, err),
+        }
+
+        EventResult::Nop
+    }
+}
+
+fn confirm(
+    tx: Sender<Message>,
+    pending: SharedPendingOperation,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(operation) = pending.borrow().clone() else {
+            return EventResult::Nop;
+        };
+
+        let content = w
+            .find_widget(OPERATION_CONFIRM_DIALOG_ID)
+            .as_input()
+            .content();
+
+        if content.trim() != operation.confirmation_name() {
+            w.set_widget_error(
+                OPERATION_CONFIRM_DIALOG_ID,
+                &anyhow!("Type {:?} to {}", operation.confirmation_name(), operation),
+            );
+
+            return EventResult::Nop;
+        }
+
+        logger!(info, "Confirmed operation: {}", operation);
+
+        tx.send(OperationRequest::Execute(operation).into())
+            .expect("Failed to send OperationRequest::Execute");
+
+        *pending.borrow_mut() = None;
+
+        w.close_dialog();
+
+        EventResult::Nop
+    }
+}
@@ -0,0 +1,165 @@
+use std::{cell::RefCell, rc::Rc};
+
+use anyhow::{anyhow, Context as _};
+use crossbeam::channel::Sender;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    config::theme::ThemeConfig,
+    features::{
+        component_id::OPERATION_SCALE_DIALOG_ID,
+        operation::message::{Operation, OperationRequest, WorkloadTarget},
+    },
+    message::{Message, UserEvent},
+    ui::{
+        event::{CallbackFn, EventResult},
+        widget::{
+            InputForm,
+            InputFormTheme,
+            Item,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+use super::{
+    confirm_dialog::{open_operation_confirm_dialog, open_operation_error, SharedPendingOperation},
+    selection::selected_workload,
+};
+
+/// レプリカ数を変更するワークロード
+pub type SharedScaleTarget = Rc<RefCell<Option<WorkloadTarget>>>;
+
+pub struct OperationScaleDialog {
+    pub widget: Widget<'static>,
+}
+
+impl OperationScaleDialog {
+    pub fn new(
+        target: &SharedScaleTarget,
+        pending: &SharedPendingOperation,
+        theme: ThemeConfig,
+    ) -> Self {
+        Self {
+            widget: widget(target.clone(), pending.clone(), theme),
+        }
+    }
+}
+
+fn widget(
+    target: SharedScaleTarget,
+    pending: SharedPendingOperation,
+    theme: ThemeConfig,
+) -> Widget<'static> {
+    let widget_theme = WidgetTheme::from(theme.component.clone());
+    let input_theme = InputFormTheme::from(theme.component);
+
+    let widget_base = WidgetBase::builder()
+        .title("Scale")
+        .theme(widget_theme)
+        .build();
+
+    InputForm::builder()
+        .id(OPERATION_SCALE_DIALOG_ID)
+        .widget_base(widget_base)
+        .theme(input_theme)
+        .prefix("Replicas: ")
+        .actions(UserEvent::from(KeyCode::Enter), submit(target, pending))
+        .build()
+        .into()
+}
+
+/// 選択中のワークロード (Podテーブルでは選択中のPodを管理しているワークロード) のレプリカ数を変更する
+pub fn scale_workload(
+    tx: Sender<Message>,
+    target: SharedScaleTarget,
+    pending: SharedPendingOperation,
+) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let Some(workload) = selected_workload(w) else {
+            return EventResult::Ignore;
+        };
+
+        let workload = match workload {
+            Ok(workload) if workload.kind.is_scalable() => workload,
+            Ok(workload) => {
+                open_operation_error(w, &pending, anyhow!("{} cannot be scaled", workload));
+                return EventResult::Nop;
+            }
+            Err(err) => {
+                open_operation_error(w, &pending, err);
+                return EventResult::Nop;
+            }
+        };
+
+        tx.send(OperationRequest::Replicas(workload.clone()).into())
+            .expect("Failed to send OperationRequest::Replicas");
+
+        w.clear_widget_error(OPERATION_SCALE_DIALOG_ID);
+
+        let dialog = w.find_widget_mut(OPERATION_SCALE_DIALOG_ID);
+
+        dialog.clear();
+
+        *dialog.widget_base_mut().append_title_mut() = Some(format!(" : {}", workload).into());
+
+        *target.borrow_mut() = Some(workload);
+
+        w.open_dialog(OPERATION_SCALE_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// 現在のレプリカ数を入力欄に設定する
+///
+/// すでに入力されている場合は上書きしない
+pub fn update_scale_replicas(w: &mut Window, replicas: i32) {
+    let dialog = w.find_widget_mut(OPERATION_SCALE_DIALOG_ID);
+
+    if !dialog.as_input().content().is_empty() {
+        return;
+    }
+
+    dialog.update_widget_item(Item::Single(replicas.to_string().into()));
+}
+
+fn submit(
+    target: SharedScaleTarget,
+    pending: SharedPendingOperation,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(target) = target.borrow().clone() else {
+            return EventResult::Nop;
+        };
+
+        let content = w
+            .find_widget(OPERATION_SCALE_DIALOG_ID)
+            .as_input()
+            .content();
+
+        match content
+            .trim()
+            .parse::<u16>()
+            .with_context(|| format!("Invalid replicas: {:?}", content.trim()))
+        {
+            Ok(replicas) => {
+                let operation = Operation::Scale {
+                    target,
+                    replicas: i32::from(replicas),
+                };
+
+                open_operation_confirm_dialog(w, &pending, operation);
+            }
+            Err(err) => {
+                w.set_widget_error(OPERATION_SCALE_DIALOG_ID, &err);
+            }
+        }
+
+        EventResult::Nop
+    }
+}
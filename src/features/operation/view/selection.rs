@@ -0,0 +1,97 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    features::{
+        component_id::{POD_WIDGET_ID, WORKLOAD_WIDGET_ID},
+        operation::message::{WorkloadKind, WorkloadTarget},
+    },
+    ui::{
+        widget::{SelectedItem, WidgetTrait as _},
+        Window,
+    },
+};
+
+/// Podテーブルで選択中のPod
+pub(super) struct SelectedPod {
+    pub namespace: String,
+    pub name: String,
+    owner: Option<(String, String)>,
+}
+
+impl SelectedPod {
+    /// Podを管理しているワークロード
+    pub fn workload(&self) -> Result<WorkloadTarget> {
+        let (kind, name) = self.owner.as_ref().ok_or_else(|| {
+            anyhow!(
+                "Pod {} is not managed by a Deployment, StatefulSet or DaemonSet",
+                self.name
+            )
+        })?;
+
+        Ok(WorkloadTarget {
+            namespace: self.namespace.clone(),
+            kind: WorkloadKind::try_from_kind(kind)?,
+            name: name.clone(),
+        })
+    }
+}
+
+/// Podテーブルがアクティブな場合に、選択中のPodを返す
+pub(super) fn selected_pod(w: &Window) -> Option<SelectedPod> {
+    let widget = w.active_tab().active_widget();
+
+    if widget.id() != POD_WIDGET_ID {
+        return None;
+    }
+
+    let Some(SelectedItem::TableRow {
+        metadata: Some(metadata),
+        ..
+    }) = widget.widget_item()
+    else {
+        return None;
+    };
+
+    let owner = match (metadata.get("owner_kind"), metadata.get("owner_name")) {
+        (Some(kind), Some(name)) => Some((kind.to_string(), name.to_string())),
+        _ => None,
+    };
+
+    Some(SelectedPod {
+        namespace: metadata.get("namespace")?.to_string(),
+        name: metadata.get("name")?.to_string(),
+        owner,
+    })
+}
+
+/// 操作対象のワークロード
+///
+/// Podテーブルでは選択中のPodを管理しているワークロード、Workloadテーブルでは選択中の行を返す。
+/// どちらのテーブルもアクティブでない場合は `None`
+pub(super) fn selected_workload(w: &Window) -> Option<Result<WorkloadTarget>> {
+    let widget = w.active_tab().active_widget();
+
+    if widget.id() != WORKLOAD_WIDGET_ID {
+        return selected_pod(w).map(|pod| pod.workload());
+    }
+
+    let Some(SelectedItem::TableRow {
+        metadata: Some(metadata),
+        ..
+    }) = widget.widget_item()
+    else {
+        return None;
+    };
+
+    let namespace = metadata.get("namespace")?;
+    let name = metadata.get("name")?;
+    let kind = metadata.get("kind")?;
+
+    Some(WorkloadKind::try_from_kind(kind).map(|kind| {
+        WorkloadTarget {
+            namespace: namespace.to_string(),
+            kind,
+            name: name.to_string(),
+        }
+    }))
+}
@@ -1,6 +1,10 @@
+mod describe;
+mod exec;
 mod filter;
 mod log;
 mod pod;
 
+pub use describe::*;
+pub use exec::*;
 pub use log::*;
 pub use pod::*;
@@ -0,0 +1,507 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use crossbeam::channel::Sender;
+use k8s_openapi::{
+    api::core::v1::{Container, Event, Pod},
+    List,
+    Resource as _,
+};
+use kube::Resource as _;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde_yaml::{Mapping, Value as YamlValue};
+
+use crate::{
+    features::{
+        network::kube::{extract_pod, pod_lines},
+        pod::message::{DescribeMessage, DescribeTarget},
+    },
+    kube::KubeClientRequest,
+    logger,
+    message::Message,
+    workers::kube::InfiniteWorker,
+};
+
+const INTERVAL: u64 = 3;
+
+#[derive(Clone)]
+pub struct PodDescribeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    tx: Sender<Message>,
+    client: C,
+    req: DescribeTarget,
+}
+
+impl<C> PodDescribeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    pub fn new(tx: Sender<Message>, client: C, req: DescribeTarget) -> Self {
+        Self { tx, client, req }
+    }
+}
+
+#[async_trait]
+impl<C> InfiniteWorker for PodDescribeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    async fn run(&self) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(INTERVAL));
+
+        loop {
+            interval.tick().await;
+
+            let fetched_data = self.fetch().await;
+
+            if let Err(e) = &fetched_data {
+                logger!(error, "Failed to describe pod: {:?}", e);
+            }
+
+            if let Err(e) = self.tx.send(DescribeMessage::Response(fetched_data).into()) {
+                logger!(error, "Failed to send DescribeMessage::Response: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+impl<C> PodDescribeWorker<C>
+where
+    C: KubeClientRequest,
+{
+    /// Networkタブと同じPodの情報にコンテナの設定と状態を加えたYAMLと、Podに関するイベントを返す
+    async fn fetch(&self) -> Result<Vec<String>> {
+        let DescribeTarget { namespace, name } = &self.req;
+
+        let url = format!("{}/{}", Pod::url_path(&(), Some(namespace)), name);
+
+        let pod: Pod = self.client.request(&url).await?;
+
+        let events: List<Event> = self.client.request(&self.events_url(&pod)).await?;
+
+        let mut value = pod_lines(&describe_pod(&pod))?;
+
+        let mut root = Mapping::new();
+        root.insert("events".into(), events_value(events.items));
+
+        value.push(String::default());
+        value.extend(
+            serde_yaml::to_string(&root)?
+                .lines()
+                .map(ToString::to_string),
+        );
+
+        Ok(value)
+    }
+
+    /// `involvedObject` がこのPodであるイベント一覧のURL
+    fn events_url(&self, pod: &Pod) -> String {
+        let DescribeTarget { namespace, name } = &self.req;
+
+        let mut selector = format!(
+            "involvedObject.kind={},involvedObject.name={}",
+            Pod::KIND,
+            name
+        );
+
+        // 同じ名前で作り直されたPodのイベントを含めない
+        if let Some(uid) = &pod.metadata.uid {
+            selector.push_str(&format!(",involvedObject.uid={}", uid));
+        }
+
+        format!(
+            "{}?fieldSelector={}",
+            Event::url_path(&(), Some(namespace)),
+            utf8_percent_encode(&selector, NON_ALPHANUMERIC)
+        )
+    }
+}
+
+/// Networkタブで表示する情報に、コンテナの環境変数・マウント・リソースと状態、conditionsを加えたPod
+fn describe_pod(pod: &Pod) -> Pod {
+    let mut described = extract_pod(pod);
+
+    if let (Some(described), Some(spec)) = (described.spec.as_mut(), pod.spec.as_ref()) {
+        merge_containers(&mut described.containers, &spec.containers);
+
+        if let (Some(described), Some(containers)) = (
+            described.init_containers.as_mut(),
+            spec.init_containers.as_ref(),
+        ) {
+            merge_containers(described, containers);
+        }
+    }
+
+    if let (Some(described), Some(status)) = (described.status.as_mut(), pod.status.as_ref()) {
+        described.reason = status.reason.clone();
+        described.qos_class = status.qos_class.clone();
+        described.start_time = status.start_time.clone();
+        described.conditions = status.conditions.clone();
+        described.init_container_statuses = status.init_container_statuses.clone();
+        described.container_statuses = status.container_statuses.clone();
+    }
+
+    described
+}
+
+fn merge_containers(described: &mut [Container], containers: &[Container]) {
+    for (described, container) in described.iter_mut().zip(containers) {
+        described.env = container.env.clone();
+        described.env_from = container.env_from.clone();
+        described.volume_mounts = container.volume_mounts.clone();
+        described.resources = container.resources.clone();
+    }
+}
+
+/// 発生した順に並べたイベント
+///
+/// イベントがない場合は `<none>`
+fn events_value(mut events: Vec<Event>) -> YamlValue {
+    if events.is_empty() {
+        return "<none>".into();
+    }
+
+    events.sort_by_key(last_seen);
+
+    events
+        .iter()
+        .map(|event| {
+            let mut value = Mapping::new();
+            insert_some(
+                &mut value,
+                "lastSeen",
+                last_seen(event).map(|time| time.to_string()),
+            );
+            insert_some(&mut value, "type", event.type_.clone());
+            insert_some(&mut value, "reason", event.reason.clone());
+            insert_some(
+                &mut value,
+                "count",
+                event
+                    .series
+                    .as_ref()
+                    .and_then(|series| series.count)
+                    .or(event.count),
+            );
+            insert_some(&mut value, "message", event.message.clone());
+
+            YamlValue::from(value)
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn last_seen(event: &Event) -> Option<jiff::Timestamp> {
+    event
+        .series
+        .as_ref()
+        .and_then(|series| series.last_observed_time.as_ref())
+        .map(|time| time.0)
+        .or_else(|| event.last_timestamp.as_ref().map(|time| time.0))
+        .or_else(|| event.event_time.as_ref().map(|time| time.0))
+        .or_else(|| {
+            event
+                .metadata
+                .creation_timestamp
+                .as_ref()
+                .map(|time| time.0)
+        })
+}
+
+fn insert_some<V: Into<YamlValue>>(map: &mut Mapping, key: &str, value: Option<V>) {
+    if let Some(value) = value {
+        map.insert(key.into(), value.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::bail;
+    use crossbeam::channel::{bounded, Receiver};
+    use indoc::indoc;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    fn target() -> DescribeTarget {
+        DescribeTarget {
+            namespace: "default".into(),
+            name: "web-0".into(),
+        }
+    }
+
+    fn pod() -> Pod {
+        serde_yaml::from_str(indoc! {"
+            metadata:
+              name: web-0
+              namespace: default
+              uid: pod-uid
+              ownerReferences:
+              - apiVersion: apps/v1
+                kind: StatefulSet
+                name: web
+                uid: sts-uid
+                controller: true
+            spec:
+              nodeName: node-1
+              containers:
+              - name: app
+                image: nginx:1.27
+                ports:
+                - containerPort: 80
+                  name: http
+                env:
+                - name: MODE
+                  value: production
+                - name: PASSWORD
+                  valueFrom:
+                    secretKeyRef:
+                      name: db
+                      key: password
+                - name: POD_NAME
+                  valueFrom:
+                    fieldRef:
+                      fieldPath: metadata.name
+                envFrom:
+                - configMapRef:
+                    name: web-config
+                volumeMounts:
+                - name: data
+                  mountPath: /data
+                - name: config
+                  mountPath: /etc/web
+                  readOnly: true
+                livenessProbe:
+                  httpGet:
+                    path: /healthz
+                    port: 80
+                  periodSeconds: 5
+                readinessProbe:
+                  tcpSocket:
+                    port: http
+                resources:
+                  requests:
+                    cpu: 100m
+                    memory: 128Mi
+                  limits:
+                    memory: 256Mi
+            status:
+              phase: Running
+              podIP: 10.0.0.1
+              qosClass: Burstable
+              startTime: '2024-01-01T00:00:00Z'
+              conditions:
+              - type: Ready
+                status: 'True'
+              containerStatuses:
+              - name: app
+                image: nginx:1.27
+                imageID: ''
+                ready: true
+                restartCount: 2
+                state:
+                  running:
+                    startedAt: '2024-01-01T00:10:00Z'
+                lastState:
+                  terminated:
+                    reason: OOMKilled
+                    exitCode: 137
+                    finishedAt: '2024-01-01T00:09:00Z'
+        "})
+        .unwrap()
+    }
+
+    fn events() -> List<Event> {
+        serde_yaml::from_str(indoc! {"
+            items:
+            - metadata:
+                name: web-0.2
+              involvedObject:
+                kind: Pod
+                name: web-0
+              type: Warning
+              reason: BackOff
+              count: 3
+              lastTimestamp: '2024-01-01T00:09:30Z'
+              message: Back-off restarting failed container
+            - metadata:
+                name: web-0.1
+              involvedObject:
+                kind: Pod
+                name: web-0
+              type: Normal
+              reason: Scheduled
+              eventTime: '2024-01-01T00:00:00.000000Z'
+              message: Successfully assigned default/web-0 to node-1
+        "})
+        .unwrap()
+    }
+
+    const EVENTS_URL: &str = "/api/v1/namespaces/default/events?fieldSelector=involvedObject%2Ekind%3DPod%2CinvolvedObject%2Ename%3Dweb%2D0%2CinvolvedObject%2Euid%3Dpod%2Duid";
+
+    #[tokio::test]
+    async fn podの設定と状態とイベントを返す() {
+        let (tx, _rx): (Sender<Message>, Receiver<Message>) = bounded(1);
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Pod,
+                    eq("/api/v1/namespaces/default/pods/web-0"),
+                    Ok(pod())
+                ),
+                (List<Event>, eq(EVENTS_URL), Ok(events()))
+            ]
+        );
+
+        let worker = PodDescribeWorker::new(tx, client, target());
+
+        let actual = worker.fetch().await.unwrap();
+
+        let expected: Vec<String> = indoc! {"
+            pod:
+              metadata:
+                name: web-0
+                ownerReferences:
+                - apiVersion: apps/v1
+                  controller: true
+                  kind: StatefulSet
+                  name: web
+                  uid: sts-uid
+              spec:
+                containers:
+                - env:
+                  - name: MODE
+                    value: production
+                  - name: PASSWORD
+                    valueFrom:
+                      secretKeyRef:
+                        key: password
+                        name: db
+                  - name: POD_NAME
+                    valueFrom:
+                      fieldRef:
+                        fieldPath: metadata.name
+                  envFrom:
+                  - configMapRef:
+                      name: web-config
+                  image: nginx:1.27
+                  livenessProbe:
+                    httpGet:
+                      path: /healthz
+                      port: 80
+                    periodSeconds: 5
+                  name: app
+                  ports:
+                  - containerPort: 80
+                    name: http
+                  readinessProbe:
+                    tcpSocket:
+                      port: http
+                  resources:
+                    limits:
+                      memory: 256Mi
+                    requests:
+                      cpu: 100m
+                      memory: 128Mi
+                  volumeMounts:
+                  - mountPath: /data
+                    name: data
+                  - mountPath: /etc/web
+                    name: config
+                    readOnly: true
+                nodeName: node-1
+              status:
+                conditions:
+                - status: 'True'
+                  type: Ready
+                containerStatuses:
+                - image: nginx:1.27
+                  imageID: ''
+                  lastState:
+                    terminated:
+                      exitCode: 137
+                      finishedAt: 2024-01-01T00:09:00Z
+                      reason: OOMKilled
+                  name: app
+                  ready: true
+                  restartCount: 2
+                  state:
+                    running:
+                      startedAt: 2024-01-01T00:10:00Z
+                phase: Running
+                podIP: 10.0.0.1
+                qosClass: Burstable
+                startTime: 2024-01-01T00:00:00Z
+
+            events:
+            - lastSeen: 2024-01-01T00:00:00Z
+              type: Normal
+              reason: Scheduled
+              message: Successfully assigned default/web-0 to node-1
+            - lastSeen: 2024-01-01T00:09:30Z
+              type: Warning
+              reason: BackOff
+              count: 3
+              message: Back-off restarting failed container
+        "}
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn イベントがないときnoneを返す() {
+        let (tx, _rx): (Sender<Message>, Receiver<Message>) = bounded(1);
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Pod,
+                    eq("/api/v1/namespaces/default/pods/web-0"),
+                    Ok(pod())
+                ),
+                (List<Event>, eq(EVENTS_URL), Ok(List::<Event>::default()))
+            ]
+        );
+
+        let worker = PodDescribeWorker::new(tx, client, target());
+
+        let actual = worker.fetch().await.unwrap();
+
+        assert_eq!(actual.last().map(String::as_str), Some("events: <none>"));
+    }
+
+    #[tokio::test]
+    async fn 取得に失敗したときerrを返す() {
+        let (tx, _rx): (Sender<Message>, Receiver<Message>) = bounded(1);
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                Pod,
+                eq("/api/v1/namespaces/default/pods/web-0"),
+                bail!("not found")
+            )]
+        );
+
+        let worker = PodDescribeWorker::new(tx, client, target());
+
+        assert!(worker.fetch().await.is_err());
+    }
+}
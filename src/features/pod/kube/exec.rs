@@ -0,0 +1,290 @@
+use std::io::Write as _;
+
+use anyhow::{anyhow, Context as _, Result};
+use crossbeam::channel::Sender;
+use futures::SinkExt as _;
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    api::{AttachParams, TerminalSize},
+    Api,
+};
+use tokio::{
+    io::{AsyncReadExt as _, AsyncWriteExt as _},
+    sync::{mpsc, oneshot},
+    task::AbortHandle,
+};
+
+use crate::{
+    features::pod::message::{ExecInput, ExecResponse, ExecTarget},
+    kube::KubeClient,
+    logger,
+    message::Message,
+};
+
+/// コンテナ内で起動するシェル
+///
+/// bashがあればbashを、なければshを起動する
+const SHELL_COMMAND: [&str; 3] = [
+    "/bin/sh",
+    "-c",
+    "command -v bash >/dev/null 2>&1 && exec bash || exec sh",
+];
+
+/// 端末の明け渡しを待つためのセッション
+///
+/// Renderワーカーは代替スクリーンを抜けたあとに`ready`を呼び出す。
+/// それまでコンテナの出力は端末に書き込まない。
+#[derive(Debug)]
+pub struct ExecSession {
+    ready: oneshot::Sender<()>,
+}
+
+impl ExecSession {
+    pub fn ready(self) {
+        if self.ready.send(()).is_err() {
+            logger!(error, "Exec session has already been closed");
+        }
+    }
+}
+
+pub struct ExecHandle {
+    handler: AbortHandle,
+    input: mpsc::UnboundedSender<ExecInput>,
+}
+
+impl ExecHandle {
+    pub fn abort(&self) {
+        self.handler.abort();
+    }
+
+    pub fn send(&self, input: ExecInput) {
+        if self.input.send(input).is_err() {
+            logger!(error, "Failed to send input to exec session");
+        }
+    }
+}
+
+/// 選択したPodのコンテナ名の一覧を取得する
+pub async fn fetch_containers(
+    client: &KubeClient,
+    namespace: &str,
+    pod: &str,
+) -> Result<Vec<String>> {
+    let api: Api<Pod> = Api::namespaced(client.to_client(), namespace);
+
+    let pod = api.get(pod).await?;
+
+    Ok(container_names(pod))
+}
+
+/// execできるコンテナ名の一覧
+///
+/// 通常のコンテナ、実行中の初期化コンテナ（サイドカーなど）、エフェメラルコンテナの順に並べる
+fn container_names(pod: Pod) -> Vec<String> {
+    let Some(spec) = pod.spec else {
+        return Vec::new();
+    };
+
+    let running_init_containers: Vec<String> = pod
+        .status
+        .and_then(|status| status.init_container_statuses)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|status| {
+            status
+                .state
+                .as_ref()
+                .is_some_and(|state| state.running.is_some())
+        })
+        .map(|status| status.name)
+        .collect();
+
+    let init_containers = spec
+        .init_containers
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| c.name)
+        .filter(|name| running_init_containers.contains(name));
+
+    let ephemeral_containers = spec
+        .ephemeral_containers
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| c.name);
+
+    spec.containers
+        .into_iter()
+        .map(|c| c.name)
+        .chain(init_containers)
+        .chain(ephemeral_containers)
+        .collect()
+}
+
+pub struct ExecWorker {
+    tx: Sender<Message>,
+    client: KubeClient,
+    target: ExecTarget,
+}
+
+impl ExecWorker {
+    pub fn new(tx: Sender<Message>, client: KubeClient, target: ExecTarget) -> Self {
+        Self { tx, client, target }
+    }
+
+    pub fn spawn(self) -> ExecHandle {
+        let (input_tx, input_rx) = mpsc::unbounded_channel();
+
+        let handler = tokio::spawn(async move {
+            let result = self.run(input_rx).await;
+
+            if let Err(err) = &result {
+                logger!(error, "Exec session failed: {:?}", err);
+            }
+
+            if let Err(err) = self.tx.send(ExecResponse::Finished(result).into()) {
+                logger!(error, "Failed to send ExecResponse::Finished: {}", err);
+            }
+        })
+        .abort_handle();
+
+        ExecHandle {
+            handler,
+            input: input_tx,
+        }
+    }
+
+    async fn run(&self, mut input_rx: mpsc::UnboundedReceiver<ExecInput>) -> Result<()> {
+        let ExecTarget {
+            namespace,
+            pod,
+            container,
+        } = &self.target;
+
+        logger!(
+            info,
+            "Exec into container [ns={} pod={} container={}]",
+            namespace,
+            pod,
+            container
+        );
+
+        let api: Api<Pod> = Api::namespaced(self.client.to_client(), namespace);
+
+        let params = AttachParams::interactive_tty().container(container);
+
+        let mut attached = api
+            .exec(pod, SHELL_COMMAND, &params)
+            .await
+            .with_context(|| format!("Failed to exec into {}/{}", pod, container))?;
+
+        let mut stdin = attached
+            .stdin()
+            .ok_or_else(|| anyhow!("stdin is not available"))?;
+
+        let mut stdout = attached
+            .stdout()
+            .ok_or_else(|| anyhow!("stdout is not available"))?;
+
+        let mut terminal_size = attached.terminal_size();
+
+        let (ready_tx, ready_rx) = oneshot::channel();
+
+        self.tx
+            .send(ExecResponse::Attached(ExecSession { ready: ready_tx }).into())?;
+
+        ready_rx
+            .await
+            .context("Terminal was not released for exec session")?;
+
+        if let (Some(sender), Ok((width, height))) =
+            (terminal_size.as_mut(), ratatui::crossterm::terminal::size())
+        {
+            sender.send(TerminalSize { width, height }).await?;
+        }
+
+        let output = async {
+            let mut buf = [0; 4096];
+
+            loop {
+                let n = stdout.read(&mut buf).await?;
+
+                if n == 0 {
+                    break;
+                }
+
+                let mut out = std::io::stdout().lock();
+                out.write_all(&buf[..n])?;
+                out.flush()?;
+            }
+
+            anyhow::Ok(())
+        };
+
+        let input = async {
+            while let Some(input) = input_rx.recv().await {
+                match input {
+                    ExecInput::Bytes(bytes) => {
+                        stdin.write_all(&bytes).await?;
+                    }
+                    ExecInput::Resize { width, height } => {
+                        if let Some(sender) = terminal_size.as_mut() {
+                            sender.send(TerminalSize { width, height }).await?;
+                        }
+                    }
+                }
+            }
+
+            anyhow::Ok(())
+        };
+
+        tokio::select! {
+            result = output => result?,
+            result = input => result?,
+        }
+
+        attached.join().await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn 実行中の初期化コンテナとエフェメラルコンテナを含める() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "spec": {
+                "containers": [{"name": "app"}],
+                "initContainers": [{"name": "init"}, {"name": "sidecar"}],
+                "ephemeralContainers": [{"name": "debugger"}]
+            },
+            "status": {
+                "initContainerStatuses": [
+                    {
+                        "name": "init",
+                        "image": "busybox",
+                        "imageID": "",
+                        "ready": false,
+                        "restartCount": 0,
+                        "state": {"terminated": {"exitCode": 0}}
+                    },
+                    {
+                        "name": "sidecar",
+                        "image": "envoy",
+                        "imageID": "",
+                        "ready": true,
+                        "restartCount": 0,
+                        "state": {"running": {}}
+                    }
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(container_names(pod), ["app", "sidecar", "debugger"]);
+    }
+}
@@ -8,6 +8,7 @@ use jaq_core::{
     Native,
 };
 use jaq_json::Val;
+use jiff::Timestamp;
 use jmespath::JmespathError;
 use regex::Regex;
 
@@ -61,6 +62,43 @@ pub enum JsonFilter {
     JMESPath(JMESPathProgram),
 }
 
+/// ログの取得開始位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSince {
+    /// 現在時刻からの相対秒数
+    Seconds(u64),
+    /// 絶対時刻
+    Time(Timestamp),
+}
+
+/// 複数のストリームから受け取ったログを並べる順序
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogOrder {
+    /// 受信した順
+    #[default]
+    Arrival,
+    /// APIのタイムスタンプ順（一定時間バッファしてから出力する）
+    Time,
+}
+
+/// JSONログを列として表示するフィールド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogColumn {
+    /// `.` 区切りでネストしたフィールドを表すフィールド名
+    pub field: String,
+    /// 列幅（省略時は既定の幅）
+    pub width: Option<usize>,
+}
+
+impl std::fmt::Display for LogColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.width {
+            Some(width) => write!(f, "{}:{}", self.field, width),
+            None => write!(f, "{}", self.field),
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum FilterError {
     #[error(transparent)]
@@ -76,6 +114,9 @@ pub enum FilterError {
     /// JMESPathフィルターのコンパイルエラー
     #[error("jmespath compilation failed:\n{0}")]
     JMESPathCompile(JmespathError),
+    /// since-timeのタイムスタンプ解析エラー
+    #[error("invalid since-time '{0}': {1}")]
+    SinceTime(String, jiff::Error),
 }
 
 #[derive(Debug, Default, Clone)]
@@ -90,8 +131,18 @@ pub struct Filter {
     pub exclude_log: Option<Vec<Regex>>,
     /// JSONログに適用するフィルター（jq、JMESPathなど）
     pub json_filter: Option<JsonFilter>,
+    /// JSONログを列として表示するフィールド
+    pub log_columns: Option<Vec<LogColumn>>,
     /// ログバッファの最大行数
     pub limit: Option<usize>,
+    /// 前回終了したコンテナのログを取得する（`kubectl logs --previous` 相当）
+    pub previous: bool,
+    /// 複数のストリームのログを並べる順序
+    pub order: LogOrder,
+    /// ログの取得開始位置（`since:` または `since-time:`）
+    pub since: Option<LogSince>,
+    /// コンテナごとに末尾から取得する行数
+    pub tail: Option<usize>,
 }
 
 impl Filter {
@@ -243,9 +294,47 @@ impl Filter {
                     filter.json_filter = Some(json_filter);
                 }
 
+                FilterAttribute::LogColumns(columns) => {
+                    filter.log_columns = Some(
+                        columns
+                            .into_iter()
+                            .map(|(field, width)| {
+                                LogColumn {
+                                    field: field.to_string(),
+                                    width,
+                                }
+                            })
+                            .collect(),
+                    );
+                }
+
                 FilterAttribute::Limit(n) => {
                     filter.limit = Some(n);
                 }
+
+                FilterAttribute::Previous(previous) => {
+                    filter.previous = previous;
+                }
+
+                FilterAttribute::Order(order) => {
+                    filter.order = order;
+                }
+
+                FilterAttribute::Since(seconds) => {
+                    filter.since = Some(LogSince::Seconds(seconds));
+                }
+
+                FilterAttribute::SinceTime(time) => {
+                    let ts = time
+                        .parse::<Timestamp>()
+                        .map_err(|err| FilterError::SinceTime(time.to_string(), err))?;
+
+                    filter.since = Some(LogSince::Time(ts));
+                }
+
+                FilterAttribute::Tail(n) => {
+                    filter.tail = Some(n);
+                }
             }
         }
 
@@ -267,6 +356,18 @@ impl Filter {
             bail!(FilterError::Syntax("Label selectors and resource/name queries cannot be used together. Please choose one filtering option.".into()));
         }
 
+        // `fields:` はJSONログの列を指定するキーワードではないため、`columns:` を案内する
+        for attr in &attrs {
+            if let FilterAttribute::FieldSelector(selector) = attr {
+                if selector.split(',').any(|term| !term.contains('=')) {
+                    bail!(FilterError::Syntax(format!(
+                        "Field selector '{}' must be written as <field>=<value> or <field>!=<value>. To show JSON log fields as columns, use 'columns:{}' instead.",
+                        selector, selector
+                    )));
+                }
+            }
+        }
+
         Ok(attrs)
     }
 }
@@ -407,10 +508,39 @@ impl std::fmt::Display for Filter {
             }
         }
 
+        if let Some(columns) = &self.log_columns {
+            buf.push(format!(
+                "columns={}",
+                columns
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ));
+        }
+
         if let Some(limit) = self.limit {
             buf.push(format!("limit={}", limit));
         }
 
+        if self.previous {
+            buf.push("previous=true".to_string());
+        }
+
+        if self.order == LogOrder::Time {
+            buf.push("order=time".to_string());
+        }
+
+        match self.since {
+            Some(LogSince::Seconds(seconds)) => buf.push(format!("since={}s", seconds)),
+            Some(LogSince::Time(ts)) => buf.push(format!("since_time={}", ts)),
+            None => {}
+        }
+
+        if let Some(tail) = self.tail {
+            buf.push(format!("tail={}", tail));
+        }
+
         write!(f, "{}", buf.join(" "))
     }
 }
@@ -489,7 +619,15 @@ pub enum FilterAttribute<'a> {
     ExcludeLog(Cow<'a, str>),
     Jq(Cow<'a, str>),
     JMESPath(Cow<'a, str>),
+    /// フィールド名と列幅
+    LogColumns(Vec<(&'a str, Option<usize>)>),
     Limit(usize),
+    Previous(bool),
+    Order(LogOrder),
+    /// 秒数
+    Since(u64),
+    SinceTime(Cow<'a, str>),
+    Tail(usize),
 }
 
 struct FilterAttributes;
@@ -699,4 +837,103 @@ mod tests {
         let filter = Filter::parse("lim:10000").unwrap();
         assert_eq!(filter.limit, Some(10000));
     }
+
+    #[test]
+    fn test_parse_with_previous() {
+        let filter = Filter::parse("pod:api previous:true").unwrap();
+        assert!(filter.previous);
+        assert!(format!("{}", filter).contains("previous=true"));
+    }
+
+    #[test]
+    fn test_parse_with_log_columns() {
+        let filter = Filter::parse("pod:api columns:ts:24,level,msg").unwrap();
+        assert_eq!(
+            filter.log_columns,
+            Some(vec![
+                LogColumn {
+                    field: "ts".to_string(),
+                    width: Some(24)
+                },
+                LogColumn {
+                    field: "level".to_string(),
+                    width: None
+                },
+                LogColumn {
+                    field: "msg".to_string(),
+                    width: None
+                },
+            ])
+        );
+        assert_eq!(filter.field_selector, None);
+        assert!(format!("{}", filter).contains("columns=ts:24,level,msg"));
+    }
+
+    #[test]
+    fn test_parse_fields_without_operator_points_to_columns() {
+        let err = Filter::parse("fields:ts,level,msg,trace_id").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("use 'columns:ts,level,msg,trace_id' instead"));
+
+        assert!(Filter::parse("field:status.phase=Running,spec.nodeName").is_err());
+
+        let filter = Filter::parse("fields:status.phase!=Running,spec.nodeName==node-1").unwrap();
+        assert_eq!(
+            filter.field_selector,
+            Some("status.phase!=Running,spec.nodeName==node-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_with_order() {
+        let filter = Filter::parse("deploy/api order:time").unwrap();
+        assert_eq!(filter.order, LogOrder::Time);
+        assert!(format!("{}", filter).contains("order=time"));
+
+        let filter = Filter::parse("deploy/api").unwrap();
+        assert_eq!(filter.order, LogOrder::Arrival);
+        assert!(!format!("{}", filter).contains("order="));
+    }
+
+    #[test]
+    fn test_parse_with_since() {
+        let filter = Filter::parse("pod:api since:15m").unwrap();
+        assert_eq!(filter.since, Some(LogSince::Seconds(15 * 60)));
+        assert!(format!("{}", filter).contains("since=900s"));
+    }
+
+    #[test]
+    fn test_parse_with_since_time() {
+        let filter = Filter::parse("since-time:2024-01-01T00:00:00Z").unwrap();
+        assert_eq!(
+            filter.since,
+            Some(LogSince::Time("2024-01-01T00:00:00Z".parse().unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_parse_with_invalid_since_time() {
+        let err = Filter::parse("since-time:yesterday").unwrap_err();
+        assert!(err.to_string().contains("invalid since-time 'yesterday'"));
+    }
+
+    #[test]
+    fn test_since_and_since_time_last_wins() {
+        let filter = Filter::parse("since-time:2024-01-01T00:00:00Z since:1h").unwrap();
+        assert_eq!(filter.since, Some(LogSince::Seconds(60 * 60)));
+    }
+
+    #[test]
+    fn test_parse_with_tail() {
+        let filter = Filter::parse("pod:api tail:200").unwrap();
+        assert_eq!(filter.tail, Some(200));
+        assert!(format!("{}", filter).contains("tail=200"));
+    }
+
+    #[test]
+    fn test_parse_without_previous() {
+        let filter = Filter::parse("pod:api").unwrap();
+        assert!(!filter.previous);
+    }
 }
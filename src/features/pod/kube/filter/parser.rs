@@ -4,15 +4,15 @@ use nom::{
     branch::alt,
     bytes::complete::{is_not, tag},
     character::complete::{alphanumeric1, anychar, char, digit1, multispace0, multispace1},
-    combinator::{all_consuming, map, recognize, value, verify},
+    combinator::{all_consuming, eof, map, opt, peek, recognize, value, verify},
     error::{ContextError, ParseError},
-    multi::{fold_many0, many1_count, separated_list1},
-    sequence::{delimited, preceded, separated_pair},
+    multi::{fold_many0, fold_many1, many1_count, separated_list1},
+    sequence::{delimited, pair, preceded, separated_pair, terminated},
     IResult,
     Parser,
 };
 
-use super::{FilterAttribute, SpecifiedResource};
+use super::{FilterAttribute, LogOrder, SpecifiedResource};
 
 /// 空白文字を含まない文字列をパースする
 fn non_space<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
@@ -209,6 +209,39 @@ fn field_selector<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     Ok((remaining, FilterAttribute::FieldSelector(value)))
 }
 
+/// JSONログのフィールド名（`.` 区切りでネストしたフィールドを表す）
+fn log_field_name<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, &'a str, E> {
+    recognize(many1_count(alt((
+        alphanumeric1,
+        tag("_"),
+        tag("-"),
+        tag("."),
+        tag("@"),
+    ))))
+    .parse(s)
+}
+
+/// `columns:ts:24,level,msg` のようにJSONログを列として表示するフィールドと列幅を指定する
+fn log_columns<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    let (remaining, (_, value)) = separated_pair(
+        tag("columns"),
+        char(':'),
+        terminated(
+            separated_list1(
+                char(','),
+                pair(log_field_name, opt(preceded(char(':'), positive_integer))),
+            ),
+            peek(alt((multispace1, eof))),
+        ),
+    )
+    .parse(s)?;
+    Ok((remaining, FilterAttribute::LogColumns(value)))
+}
+
 fn jq<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     s: &'a str,
 ) -> IResult<&'a str, FilterAttribute<'a>, E> {
@@ -254,6 +287,84 @@ fn limit<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     Ok((remaining, FilterAttribute::Limit(value)))
 }
 
+fn boolean<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, bool, E> {
+    alt((value(true, tag("true")), value(false, tag("false")))).parse(s)
+}
+
+fn previous<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    let (remaining, (_, value)) =
+        separated_pair(alt((tag("previous"), tag("prev"))), char(':'), boolean).parse(s)?;
+    Ok((remaining, FilterAttribute::Previous(value)))
+}
+
+fn order<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    let (remaining, (_, value)) = separated_pair(
+        tag("order"),
+        char(':'),
+        alt((
+            value(LogOrder::Time, tag("time")),
+            value(LogOrder::Arrival, tag("arrival")),
+        )),
+    )
+    .parse(s)?;
+    Ok((remaining, FilterAttribute::Order(value)))
+}
+
+/// `15m`、`2h`、`1h30m` のような期間をパースして秒数を返す
+fn duration<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, u64, E> {
+    let unit = alt((
+        value(1, char('s')),
+        value(60, char('m')),
+        value(60 * 60, char('h')),
+        value(24 * 60 * 60, char('d')),
+    ));
+
+    fold_many1(
+        pair(positive_integer, unit),
+        || 0,
+        |acc: u64, (n, unit): (usize, u64)| acc.saturating_add((n as u64).saturating_mul(unit)),
+    )
+    .parse(s)
+}
+
+fn since<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    let (remaining, (_, value)) = separated_pair(tag("since"), char(':'), duration).parse(s)?;
+    Ok((remaining, FilterAttribute::Since(value)))
+}
+
+fn since_time<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    let (remaining, (_, value)) =
+        separated_pair(tag("since-time"), char(':'), alt((quoted, unquoted))).parse(s)?;
+    Ok((remaining, FilterAttribute::SinceTime(value)))
+}
+
+fn tail<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    let (remaining, (_, value)) =
+        separated_pair(tag("tail"), char(':'), positive_integer).parse(s)?;
+    Ok((remaining, FilterAttribute::Tail(value)))
+}
+
+/// ログの取得範囲を指定する属性
+fn log_range<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
+    s: &'a str,
+) -> IResult<&'a str, FilterAttribute<'a>, E> {
+    alt((previous, since_time, since, tail)).parse(s)
+}
+
 fn specified_daemonset<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     s: &'a str,
 ) -> IResult<&'a str, FilterAttribute<'a>, E> {
@@ -366,9 +477,12 @@ fn attribute<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
         specified_replicaset,
         specified_service,
         specified_statefulset,
+        log_columns,
         field_selector,
         label_selector,
         limit,
+        order,
+        log_range,
         pod,
         exclude_pod,
         container,
@@ -500,6 +614,27 @@ mod tests {
         assert_eq!(remaining, "");
     }
 
+    #[rstest]
+    #[case("columns:msg", vec![("msg", None)])]
+    #[case("columns:ts,level,msg,trace_id", vec![("ts", None), ("level", None), ("msg", None), ("trace_id", None)])]
+    #[case("columns:ts:24,level:5,msg", vec![("ts", Some(24)), ("level", Some(5)), ("msg", None)])]
+    #[case("columns:@timestamp,http.status", vec![("@timestamp", None), ("http.status", None)])]
+    fn log_columns(#[case] query: &str, #[case] expected: Vec<(&str, Option<usize>)>) {
+        let (remaining, actual) = super::log_columns::<Error<_>>(query).unwrap();
+
+        assert_eq!(actual, FilterAttribute::LogColumns(expected));
+        assert_eq!(remaining, "");
+    }
+
+    #[rstest]
+    #[case("columns:foo=bar")]
+    #[case("columns:ts:abc")]
+    #[case("columns:\"msg\"")]
+    #[case("columns:")]
+    fn log_columns_error(#[case] query: &str) {
+        assert!(super::log_columns::<Error<_>>(query).is_err());
+    }
+
     /// Field selector
     #[rstest]
     #[case("fields:foo=bar,baz=qux", "foo=bar,baz=qux")]
@@ -746,6 +881,60 @@ mod tests {
         assert_eq!(remaining, "");
     }
 
+    #[rstest]
+    #[case("previous:true", true)]
+    #[case("previous:false", false)]
+    #[case("prev:true", true)]
+    fn previous(#[case] query: &str, #[case] expected: bool) {
+        let (remaining, actual) = super::previous::<Error<_>>(query).unwrap();
+
+        assert_eq!(actual, FilterAttribute::Previous(expected));
+        assert_eq!(remaining, "");
+    }
+
+    #[rstest]
+    #[case("order:time", LogOrder::Time)]
+    #[case("order:arrival", LogOrder::Arrival)]
+    fn order(#[case] query: &str, #[case] expected: LogOrder) {
+        let (remaining, actual) = super::order::<Error<_>>(query).unwrap();
+
+        assert_eq!(actual, FilterAttribute::Order(expected));
+        assert_eq!(remaining, "");
+    }
+
+    #[rstest]
+    #[case("since:30s", 30)]
+    #[case("since:15m", 15 * 60)]
+    #[case("since:2h", 2 * 60 * 60)]
+    #[case("since:1d", 24 * 60 * 60)]
+    #[case("since:1h30m", 60 * 60 + 30 * 60)]
+    fn since(#[case] query: &str, #[case] expected: u64) {
+        let (remaining, actual) = super::since::<Error<_>>(query).unwrap();
+
+        assert_eq!(actual, FilterAttribute::Since(expected));
+        assert_eq!(remaining, "");
+    }
+
+    #[rstest]
+    #[case("since-time:2024-01-01T00:00:00Z", "2024-01-01T00:00:00Z")]
+    #[case("since-time:'2024-01-01T09:00:00+09:00'", "2024-01-01T09:00:00+09:00")]
+    fn since_time(#[case] query: &str, #[case] expected: &str) {
+        let (remaining, actual) = super::since_time::<Error<_>>(query).unwrap();
+
+        assert_eq!(actual, FilterAttribute::SinceTime(expected.into()));
+        assert_eq!(remaining, "");
+    }
+
+    #[rstest]
+    #[case("tail:200", 200)]
+    #[case("tail:1", 1)]
+    fn tail(#[case] query: &str, #[case] expected: usize) {
+        let (remaining, actual) = super::tail::<Error<_>>(query).unwrap();
+
+        assert_eq!(actual, FilterAttribute::Tail(expected));
+        assert_eq!(remaining, "");
+    }
+
     #[rustfmt::skip]
     #[rstest]
     #[case("pod:hoge", FilterAttribute::Pod("hoge".into()))]
@@ -756,6 +945,8 @@ mod tests {
     #[case("!log:hoge", FilterAttribute::ExcludeLog("hoge".into()))]
     #[case("labels:foo=bar", FilterAttribute::LabelSelector("foo=bar".into()))]
     #[case("fields:foo=bar", FilterAttribute::FieldSelector("foo=bar".into()))]
+    #[case("fields:ts,level", FilterAttribute::FieldSelector("ts,level".into()))]
+    #[case("columns:ts:24,msg", FilterAttribute::LogColumns(vec![("ts", Some(24)), ("msg", None)]))]
     #[case("daemonset/app", FilterAttribute::Resource(SpecifiedResource::DaemonSet("app")))]
     #[case("deployment/app", FilterAttribute::Resource(SpecifiedResource::Deployment("app")))]
     #[case("job/app", FilterAttribute::Resource(SpecifiedResource::Job("app")))]
@@ -769,6 +960,12 @@ mod tests {
     #[case("jm:data.id", FilterAttribute::JMESPath("data.id".into()))]
     #[case("limit:5000", FilterAttribute::Limit(5000))]
     #[case("lim:1000", FilterAttribute::Limit(1000))]
+    #[case("previous:true", FilterAttribute::Previous(true))]
+    #[case("prev:true", FilterAttribute::Previous(true))]
+    #[case("order:time", FilterAttribute::Order(LogOrder::Time))]
+    #[case("since:15m", FilterAttribute::Since(15 * 60))]
+    #[case("since-time:2024-01-01T00:00:00Z", FilterAttribute::SinceTime("2024-01-01T00:00:00Z".into()))]
+    #[case("tail:200", FilterAttribute::Tail(200))]
     fn attribute(#[case] query: &str, #[case] expected: FilterAttribute) {
         let (remaining, actual) = super::attribute::<Error<_>>(query).unwrap();
 
@@ -788,6 +985,7 @@ mod tests {
             "!log:hoge",
             "labels:foo=bar",
             "fields:foo=bar",
+            "columns:level:5,msg",
             "daemonset/app",
             "deployment/app",
             "job/app",
@@ -798,6 +996,11 @@ mod tests {
             "jq:.message",
             "jmespath:data.id",
             "limit:5000",
+            "previous:true",
+            "order:time",
+            "since:15m",
+            "since-time:2024-01-01T00:00:00Z",
+            "tail:200",
             "     ",
         ]
         .join("  ");
@@ -813,6 +1016,7 @@ mod tests {
             FilterAttribute::ExcludeLog("hoge".into()),
             FilterAttribute::LabelSelector("foo=bar".into()),
             FilterAttribute::FieldSelector("foo=bar".into()),
+            FilterAttribute::LogColumns(vec![("level", Some(5)), ("msg", None)]),
             FilterAttribute::Resource(SpecifiedResource::DaemonSet("app")),
             FilterAttribute::Resource(SpecifiedResource::Deployment("app")),
             FilterAttribute::Resource(SpecifiedResource::Job("app")),
@@ -823,6 +1027,11 @@ mod tests {
             FilterAttribute::Jq(".message".into()),
             FilterAttribute::JMESPath("data.id".into()),
             FilterAttribute::Limit(5000),
+            FilterAttribute::Previous(true),
+            FilterAttribute::Order(LogOrder::Time),
+            FilterAttribute::Since(15 * 60),
+            FilterAttribute::SinceTime("2024-01-01T00:00:00Z".into()),
+            FilterAttribute::Tail(200),
         ];
 
         assert_eq!(actual, expected);
@@ -856,6 +1065,11 @@ mod tests {
     #[case("     ")]
     #[case("")]
     #[case("hoge:hoge")]
+    #[case("previous:yes")]
+    #[case("order:random")]
+    #[case("since:15")]
+    #[case("since:15x")]
+    #[case("tail:-1")]
     fn parse_error(#[case] query: &str) {
         let actual = super::parse_attributes::<Error<_>>(query);
 
@@ -1,6 +1,9 @@
 mod log_collector;
+mod log_columns;
 mod log_content;
+mod log_reorder;
 mod log_streamer;
+mod log_timestamp;
 mod pod_watcher;
 
 use std::collections::BTreeMap;
@@ -25,8 +28,8 @@ use crate::{
     workers::kube::{InfiniteWorker, Worker},
 };
 
-pub use self::log_streamer::LogPrefixType;
-pub use super::filter::{Filter, LabelSelector, RetrievableResource};
+pub use self::{log_streamer::LogPrefixType, log_timestamp::LogTimestamp};
+pub use super::filter::{Filter, LabelSelector, LogSince, RetrievableResource};
 
 use self::{
     log_collector::{LogBuffer, LogCollector},
@@ -40,6 +43,8 @@ pub struct LogConfig {
     pub query: String,
     pub prefix_type: LogPrefixType,
     pub json_pretty_print: bool,
+    /// ログウィジェットから切り替えられ、クエリの `previous:` 指定を反転する
+    pub previous: bool,
 }
 
 impl LogConfig {
@@ -54,6 +59,7 @@ impl LogConfig {
             query,
             prefix_type,
             json_pretty_print,
+            previous: false,
         }
     }
 }
@@ -80,6 +86,8 @@ impl LogWorker {
 
         let log_buffer = LogBuffer::default();
 
+        let previous = filter.previous != self.config.previous;
+
         for namespace in namespaces {
             // retrieve label selector
             let label_selector = if let Some(value) = &filter.label_selector {
@@ -131,6 +139,9 @@ impl LogWorker {
                 prefix_type: self.config.prefix_type,
                 include_log: filter.include_log.clone(),
                 exclude_log: filter.exclude_log.clone(),
+                previous,
+                since: filter.since,
+                tail: filter.tail,
             });
 
             pod_watchers.push(pod_watcher);
@@ -145,6 +156,8 @@ impl LogWorker {
             self.config.json_pretty_print,
             filter.json_filter,
         )
+        .order(filter.order)
+        .log_columns(filter.log_columns)
         .spawn();
 
         handles.push(collector_handle);
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use async_trait::async_trait;
 use crossbeam::channel::Sender;
@@ -7,13 +7,20 @@ use jaq_json::Val;
 use tokio::{sync::Mutex, time};
 
 use crate::{
-    features::pod::{kube::filter::JsonFilter, message::LogMessage},
+    features::pod::{
+        kube::filter::{JsonFilter, LogColumn, LogOrder},
+        message::{LogLine, LogMessage},
+    },
     logger,
     message::Message,
     workers::kube::Worker,
 };
 
-use super::log_content::LogContent;
+use super::{
+    log_columns::LogColumns,
+    log_content::LogContent,
+    log_reorder::{LogReorderBuffer, REORDER_MAX_LINES, REORDER_WINDOW},
+};
 
 pub type LogBuffer = Arc<Mutex<Vec<LogContent>>>;
 
@@ -24,6 +31,10 @@ pub struct LogCollector {
     json_pretty_print: bool,
     /// JSONログに適用するフィルター（jq、JMESPathなど）
     json_filter: Option<JsonFilter>,
+    /// 複数のストリームのログを並べる順序
+    order: LogOrder,
+    /// JSONログを列として表示するフィールド
+    log_columns: Option<Arc<LogColumns>>,
 }
 
 impl LogCollector {
@@ -38,11 +49,23 @@ impl LogCollector {
             buffer,
             json_pretty_print,
             json_filter,
+            order: LogOrder::default(),
+            log_columns: None,
         }
     }
 
+    pub fn log_columns(mut self, columns: Option<Vec<LogColumn>>) -> Self {
+        self.log_columns = columns.map(|columns| Arc::new(LogColumns::new(columns)));
+        self
+    }
+
+    pub fn order(mut self, order: LogOrder) -> Self {
+        self.order = order;
+        self
+    }
+
     fn render_content(&self, content: LogContent) -> Vec<String> {
-        if !self.json_pretty_print && self.json_filter.is_none() {
+        if !self.json_pretty_print && self.json_filter.is_none() && self.log_columns.is_none() {
             return vec![print_content(&content.prefix, &content.content)];
         }
 
@@ -110,6 +133,12 @@ impl LogCollector {
     }
 
     fn print_json(&self, prefix: &str, json: &serde_json::Value) -> Vec<String> {
+        if let Some(columns) = &self.log_columns {
+            if let Some(line) = columns.render(json) {
+                return vec![print_content(&columns.pad_prefix(prefix), line)];
+            }
+        }
+
         if self.json_pretty_print {
             pretty_print_json(prefix, json)
         } else {
@@ -136,20 +165,46 @@ impl Worker for LogCollector {
     async fn run(&self) -> Self::Output {
         let mut interval = tokio::time::interval(time::Duration::from_millis(200));
 
+        let mut reorder = (self.order == LogOrder::Time)
+            .then(|| LogReorderBuffer::new(REORDER_WINDOW, REORDER_MAX_LINES));
+
         loop {
             interval.tick().await;
 
             let mut buf = self.buffer.lock().await;
 
-            let contents = std::mem::take(&mut *buf);
+            let mut contents = std::mem::take(&mut *buf);
+
+            if let Some(reorder) = &mut reorder {
+                let now = Instant::now();
+
+                contents
+                    .into_iter()
+                    .for_each(|content| reorder.push(content, now));
+
+                contents = reorder.drain(now);
+            }
 
             if contents.is_empty() {
                 continue;
             }
 
-            let logs: Vec<String> = contents
+            // 同じ回に表示する行のプレフィックスの幅を揃える
+            if let Some(columns) = &self.log_columns {
+                contents
+                    .iter()
+                    .for_each(|content| columns.observe_prefix(&content.prefix));
+            }
+
+            let logs: Vec<LogLine> = contents
                 .into_iter()
-                .flat_map(|content| self.render_content(content))
+                .flat_map(|content| {
+                    let timestamp = content.timestamp;
+
+                    self.render_content(content)
+                        .into_iter()
+                        .map(move |content| LogLine { content, timestamp })
+                })
                 .collect();
 
             if logs.is_empty() {
@@ -182,6 +237,7 @@ mod tests {
         let content = LogContent {
             prefix: "[pod]".to_string(),
             content: r#"{"level":"error","message":"test error"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -201,6 +257,7 @@ mod tests {
         let content = LogContent {
             prefix: "[test]".to_string(),
             content: r#"{"level":"info","message":"hello","timestamp":"2024-01-01"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -220,6 +277,7 @@ mod tests {
         let content = LogContent {
             prefix: "[pod]".to_string(),
             content: "plain text log".to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -238,6 +296,7 @@ mod tests {
         let content = LogContent {
             prefix: "[pod]".to_string(),
             content: r#"{"level":"error","message":"test"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -257,6 +316,7 @@ mod tests {
         let content = LogContent {
             prefix: "[test]".to_string(),
             content: r#"{"level":"warn","message":"warning message","extra":"data"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -283,6 +343,7 @@ mod tests {
         let content = LogContent {
             prefix: "[pod]".to_string(),
             content: r#"{"level":"info","message":"test message"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -298,6 +359,71 @@ mod tests {
         assert_eq!(result.join("\n"), expected);
     }
 
+    #[test]
+    fn test_render_content_with_log_columns() {
+        // columnsで指定したフィールドを列として表示
+        let (tx, _rx) = channel::unbounded();
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+
+        let filter = Filter::parse("columns:level:6,msg").unwrap();
+        let collector = LogCollector::new(tx, buffer, true, None).log_columns(filter.log_columns);
+
+        let result = [("[pod-a]", "info"), ("[b]", "notice")]
+            .into_iter()
+            .flat_map(|(prefix, level)| {
+                collector.render_content(LogContent {
+                    prefix: prefix.to_string(),
+                    content: format!(r#"{{"level":"{}","msg":"hello","extra":1}}"#, level),
+                    timestamp: None,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            result,
+            vec!["[pod-a]  info    hello", "[b]      notice  hello"]
+        );
+    }
+
+    #[test]
+    fn test_render_content_non_json_with_log_columns() {
+        // 非JSON入力の場合は生ログを返す
+        let (tx, _rx) = channel::unbounded();
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+
+        let filter = Filter::parse("columns:level,msg").unwrap();
+        let collector = LogCollector::new(tx, buffer, false, None).log_columns(filter.log_columns);
+
+        let content = LogContent {
+            prefix: "[pod]".to_string(),
+            content: "plain text log".to_string(),
+            timestamp: None,
+        };
+
+        let result = collector.render_content(content);
+        assert_eq!(result, vec!["[pod]  plain text log"]);
+    }
+
+    #[test]
+    fn test_render_content_with_jq_and_log_columns() {
+        // jqフィルターの結果を列として表示
+        let (tx, _rx) = channel::unbounded();
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+
+        let filter = Filter::parse("jq:.payload columns:id:1,msg").unwrap();
+        let collector = LogCollector::new(tx, buffer, false, filter.json_filter)
+            .log_columns(filter.log_columns);
+
+        let content = LogContent {
+            prefix: "[pod]".to_string(),
+            content: r#"{"payload":{"id":7,"msg":"done"}}"#.to_string(),
+            timestamp: None,
+        };
+
+        let result = collector.render_content(content);
+        assert_eq!(result, vec!["[pod]  7  done"]);
+    }
+
     #[test]
     fn test_render_content_jq_returns_multiple_values() {
         // jqが複数の値を返す場合
@@ -311,6 +437,7 @@ mod tests {
         let content = LogContent {
             prefix: "[test]".to_string(),
             content: r#"{"items":["a","b","c"]}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -333,6 +460,7 @@ mod tests {
         let content = LogContent {
             prefix: "[test]".to_string(),
             content: r#"{"level":"error","message":"test"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -356,6 +484,7 @@ mod tests {
         let content = LogContent {
             prefix: "[pod]".to_string(),
             content: r#"{"level":"error","message":"test error"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -375,6 +504,7 @@ mod tests {
         let content = LogContent {
             prefix: "[test]".to_string(),
             content: r#"{"level":"info","message":"hello","timestamp":"2024-01-01"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -394,6 +524,7 @@ mod tests {
         let content = LogContent {
             prefix: "[pod]".to_string(),
             content: "plain text log".to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -413,6 +544,7 @@ mod tests {
         let content = LogContent {
             prefix: "[test]".to_string(),
             content: r#"{"level":"warn","message":"warning message","extra":"data"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -441,6 +573,7 @@ mod tests {
         let content = LogContent {
             prefix: "[test]".to_string(),
             content: r#"{"level":"error","message":"test"}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -462,6 +595,7 @@ mod tests {
         let content = LogContent {
             prefix: "[test]".to_string(),
             content: r#"{"items":[{"name":"a","id":1},{"name":"b","id":2}]}"#.to_string(),
+            timestamp: None,
         };
 
         let result = collector.render_content(content);
@@ -0,0 +1,321 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde_json::Value;
+use unicode_width::UnicodeWidthStr as _;
+
+use crate::{features::pod::kube::filter::LogColumn, workers::kube::color::fg::Color};
+
+/// 列幅を指定しなかった列を広げる最大の幅（これより長い値はそのまま表示する）
+const MAX_COLUMN_WIDTH: usize = 40;
+
+/// 行の色を決めるログレベルのフィールド
+const LEVEL_FIELDS: [&str; 3] = ["level", "lvl", "severity"];
+
+/// JSONログの指定したフィールドを列として並べる
+///
+/// 列幅はそれまでに表示した値の最大幅に合わせて広がる。
+/// 列幅を指定した列はその幅で固定し、列幅より長い値はその行だけ後ろの列をずらして表示する
+pub struct LogColumns {
+    columns: Vec<LogColumn>,
+    /// 列ごとのそれまでに表示した値の最大幅
+    widths: Vec<AtomicUsize>,
+    /// それまでに表示したプレフィックスの最大幅
+    prefix_width: AtomicUsize,
+}
+
+impl LogColumns {
+    pub fn new(columns: Vec<LogColumn>) -> Self {
+        let widths = columns.iter().map(|_| AtomicUsize::new(0)).collect();
+
+        Self {
+            columns,
+            widths,
+            prefix_width: AtomicUsize::new(0),
+        }
+    }
+
+    /// プレフィックスの幅を列の位置合わせに含める
+    pub fn observe_prefix(&self, prefix: &str) {
+        self.prefix_width
+            .fetch_max(display_width(prefix), Ordering::Relaxed);
+    }
+
+    /// プレフィックスをそれまでに表示したプレフィックスの最大幅に揃える
+    pub fn pad_prefix(&self, prefix: &str) -> String {
+        self.observe_prefix(prefix);
+
+        let width = self.prefix_width.load(Ordering::Relaxed);
+
+        format!(
+            "{}{}",
+            prefix,
+            " ".repeat(width.saturating_sub(display_width(prefix)))
+        )
+    }
+
+    /// JSONオブジェクトの指定したフィールドを列に並べた文字列を返す
+    ///
+    /// オブジェクトでない場合は `None` を返す
+    pub fn render(&self, json: &Value) -> Option<String> {
+        if !json.is_object() {
+            return None;
+        }
+
+        let last = self.columns.len().saturating_sub(1);
+
+        let line = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                let value = lookup(json, &column.field).map_or_else(|| "-".to_string(), to_cell);
+
+                if i == last {
+                    return value;
+                }
+
+                let width = column
+                    .width
+                    .unwrap_or_else(|| self.observe(i, value.width()));
+
+                format!(
+                    "{}{}",
+                    value,
+                    " ".repeat(width.saturating_sub(value.width()))
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        let line = match level_color(json) {
+            Some(color) => color.wrap(line),
+            None => line,
+        };
+
+        Some(line)
+    }
+
+    /// 値の幅を列幅に含め、列幅を返す
+    fn observe(&self, index: usize, width: usize) -> usize {
+        let max = &self.widths[index];
+
+        if width <= MAX_COLUMN_WIDTH {
+            max.fetch_max(width, Ordering::Relaxed);
+        }
+
+        max.load(Ordering::Relaxed)
+    }
+}
+
+/// エスケープシーケンスを除いた表示幅
+fn display_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut rest = s;
+
+    while let Some(start) = rest.find('\x1b') {
+        width += rest[..start].width();
+
+        let escape = &rest[start..];
+
+        rest = match escape.strip_prefix("\x1b[").and_then(|s| {
+            s.find(|c: char| c.is_ascii_alphabetic())
+                .map(|end| &s[end + 1..])
+        }) {
+            Some(rest) => rest,
+            None => &escape[1..],
+        };
+    }
+
+    width + rest.width()
+}
+
+/// フィールドの値を取り出す
+///
+/// `.` を含むキーがない場合は `.` 区切りでネストしたフィールドをたどる
+fn lookup<'a>(json: &'a Value, field: &str) -> Option<&'a Value> {
+    if let Some(value) = json.get(field) {
+        return Some(value);
+    }
+
+    field.split('.').try_fold(json, |value, key| value.get(key))
+}
+
+fn to_cell(value: &Value) -> String {
+    let s = match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        _ => value.to_string(),
+    };
+
+    s.replace('\n', "\\n")
+}
+
+/// ログレベルに対応する色
+///
+/// pinoなどの数値のログレベルにも対応する
+fn level_color(json: &Value) -> Option<Color> {
+    let level = LEVEL_FIELDS.iter().find_map(|field| json.get(field))?;
+
+    if let Some(n) = level.as_u64() {
+        return match n {
+            50.. => Some(Color::Red),
+            40..50 => Some(Color::Yellow),
+            30..40 => None,
+            _ => Some(Color::DarkGray),
+        };
+    }
+
+    match level.as_str()?.to_lowercase().as_str() {
+        "fatal" | "panic" | "critical" | "crit" | "alert" | "emerg" | "emergency" | "error"
+        | "err" => Some(Color::Red),
+        "warn" | "warning" => Some(Color::Yellow),
+        "debug" | "trace" => Some(Color::DarkGray),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn columns(columns: &[(&str, Option<usize>)]) -> LogColumns {
+        LogColumns::new(
+            columns
+                .iter()
+                .map(|(field, width)| {
+                    LogColumn {
+                        field: field.to_string(),
+                        width: *width,
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn 指定したフィールドを列幅を揃えて並べる() {
+        let columns = columns(&[("ts", Some(5)), ("level", None), ("msg", None)]);
+
+        let actual = [
+            json!({"ts": "10:00", "level": "info", "msg": "started", "extra": 1}),
+            json!({"ts": "10:01", "level": "notice", "msg": "listening"}),
+            json!({"ts": "10:02", "level": "info", "msg": "ready"}),
+        ]
+        .iter()
+        .map(|json| columns.render(json).unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                "10:00  info  started",
+                "10:01  notice  listening",
+                "10:02  info    ready",
+            ]
+        );
+    }
+
+    #[test]
+    fn 列幅を指定した列は幅を固定する() {
+        let columns = columns(&[("id", Some(5)), ("msg", None)]);
+
+        let actual = [
+            json!({"id": "abcdefg", "msg": "a"}),
+            json!({"id": "short", "msg": "b"}),
+        ]
+        .iter()
+        .map(|json| columns.render(json).unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(actual, vec!["abcdefg  a", "short  b"]);
+    }
+
+    #[test]
+    fn 最大幅を超える値は列幅に含めない() {
+        let columns = columns(&[("id", None), ("msg", None)]);
+
+        let long = "x".repeat(MAX_COLUMN_WIDTH + 1);
+
+        let actual = [
+            json!({"id": "abc", "msg": "a"}),
+            json!({"id": long, "msg": "b"}),
+            json!({"id": "short", "msg": "c"}),
+        ]
+        .iter()
+        .map(|json| columns.render(json).unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                "abc  a".to_string(),
+                format!("{}  b", long),
+                "short  c".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn プレフィックスを最大幅に揃える() {
+        let columns = columns(&[("msg", None)]);
+
+        let long = "\x1b[37m[pod-a app]\x1b[39m";
+
+        columns.observe_prefix(long);
+
+        assert_eq!(columns.pad_prefix("[b app]"), "[b app]    ");
+        assert_eq!(columns.pad_prefix(long), long);
+    }
+
+    #[test]
+    fn ないフィールドはハイフンで表示する() {
+        let columns = columns(&[("level", Some(4)), ("trace_id", Some(1)), ("msg", None)]);
+
+        let actual = columns.render(&json!({"level": "info", "msg": "hello", "trace_id": null}));
+
+        assert_eq!(actual, Some("info  -  hello".to_string()));
+    }
+
+    #[test]
+    fn ネストしたフィールドはドット区切りで指定できる() {
+        let columns = columns(&[("http.status", Some(3)), ("a.b", Some(6)), ("msg", None)]);
+
+        let actual = columns.render(&json!({
+            "http": {"status": 200},
+            "a.b": "dotted",
+            "msg": "line1\nline2",
+        }));
+
+        assert_eq!(actual, Some(r"200  dotted  line1\nline2".to_string()));
+    }
+
+    #[test]
+    fn ログレベルで行を色付けする() {
+        let columns = columns(&[("msg", None)]);
+
+        assert_eq!(
+            columns.render(&json!({"level": "ERROR", "msg": "failed"})),
+            Some(Color::Red.wrap("failed"))
+        );
+        assert_eq!(
+            columns.render(&json!({"severity": "warning", "msg": "slow"})),
+            Some(Color::Yellow.wrap("slow"))
+        );
+        assert_eq!(
+            columns.render(&json!({"level": 20, "msg": "debug"})),
+            Some(Color::DarkGray.wrap("debug"))
+        );
+        assert_eq!(
+            columns.render(&json!({"level": "info", "msg": "ok"})),
+            Some("ok".to_string())
+        );
+    }
+
+    #[test]
+    fn オブジェクトでない場合は表示しない() {
+        assert_eq!(columns(&[("msg", None)]).render(&json!("text")), None);
+    }
+}
@@ -1,4 +1,8 @@
+use jiff::Timestamp;
+
 pub struct LogContent {
     pub prefix: String,
     pub content: String,
+    /// APIから取得したログのタイムスタンプ（開始・終了メッセージなどにはない）
+    pub timestamp: Option<Timestamp>,
 }
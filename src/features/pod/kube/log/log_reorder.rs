@@ -0,0 +1,197 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use jiff::Timestamp;
+
+use super::log_content::LogContent;
+
+/// タイムスタンプ順に並べ替えるためにログを保持する時間
+pub const REORDER_WINDOW: Duration = Duration::from_secs(2);
+
+/// タイムスタンプ順に並べ替えるために保持する最大行数
+pub const REORDER_MAX_LINES: usize = 10000;
+
+struct PendingLog {
+    timestamp: Timestamp,
+    arrived: Instant,
+    content: LogContent,
+}
+
+/// 複数のストリームから受け取ったログをタイムスタンプ順に並べ替えるバッファ
+///
+/// 受信したログを `window` の間保持し、保持期間を過ぎたログとそれより古いログをタイムスタンプ順に出力する。
+/// 保持している行数が `max_lines` を超えた場合は保持期間にかかわらず古い順に出力する。
+/// 保持期間を過ぎてから届いた古いログは並べ替えずに次の出力に含める。
+pub struct LogReorderBuffer {
+    window: Duration,
+    max_lines: usize,
+    /// タイムスタンプ順（同じタイムスタンプは受信順）
+    pending: VecDeque<PendingLog>,
+    /// これまでに受け取ったログの最新のタイムスタンプ
+    latest: Option<Timestamp>,
+}
+
+impl LogReorderBuffer {
+    pub fn new(window: Duration, max_lines: usize) -> Self {
+        Self {
+            window,
+            max_lines,
+            pending: VecDeque::new(),
+            latest: None,
+        }
+    }
+
+    /// ログを保持する
+    ///
+    /// タイムスタンプのないログ（開始・終了メッセージなど）は、それまでに受け取ったログの最新のタイムスタンプの位置に並べる
+    pub fn push(&mut self, content: LogContent, now: Instant) {
+        let timestamp = content.timestamp.or(self.latest).unwrap_or(Timestamp::MIN);
+
+        self.latest = self.latest.max(Some(timestamp));
+
+        let index = self.pending.partition_point(|p| p.timestamp <= timestamp);
+
+        self.pending.insert(
+            index,
+            PendingLog {
+                timestamp,
+                arrived: now,
+                content,
+            },
+        );
+    }
+
+    /// 出力できるログをタイムスタンプ順に取り出す
+    pub fn drain(&mut self, now: Instant) -> Vec<LogContent> {
+        let cutoff = self
+            .pending
+            .iter()
+            .filter(|p| now.duration_since(p.arrived) >= self.window)
+            .map(|p| p.timestamp)
+            .max();
+
+        let ready = cutoff.map_or(0, |cutoff| {
+            self.pending.partition_point(|p| p.timestamp <= cutoff)
+        });
+
+        let overflow = self.pending.len().saturating_sub(self.max_lines);
+
+        self.pending
+            .drain(..ready.max(overflow))
+            .map(|p| p.content)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn log(content: &str, timestamp: Option<&str>) -> LogContent {
+        LogContent {
+            prefix: String::new(),
+            content: content.to_string(),
+            timestamp: timestamp.map(|ts| ts.parse().unwrap()),
+        }
+    }
+
+    fn contents(logs: Vec<LogContent>) -> Vec<String> {
+        logs.into_iter().map(|log| log.content).collect()
+    }
+
+    #[test]
+    fn 保持期間を過ぎるまで出力しない() {
+        let start = Instant::now();
+
+        let mut buffer = LogReorderBuffer::new(Duration::from_secs(2), 100);
+
+        buffer.push(log("a", Some("2024-01-01T00:00:01Z")), start);
+
+        assert!(buffer.drain(start + Duration::from_secs(1)).is_empty());
+
+        assert_eq!(
+            contents(buffer.drain(start + Duration::from_secs(2))),
+            vec!["a"]
+        );
+    }
+
+    #[test]
+    fn 複数のストリームのログをタイムスタンプ順に出力する() {
+        let start = Instant::now();
+
+        let mut buffer = LogReorderBuffer::new(Duration::from_secs(2), 100);
+
+        buffer.push(log("pod-a 1", Some("2024-01-01T00:00:01Z")), start);
+        buffer.push(log("pod-a 3", Some("2024-01-01T00:00:03Z")), start);
+        buffer.push(
+            log("pod-b 2", Some("2024-01-01T00:00:02Z")),
+            start + Duration::from_secs(1),
+        );
+        buffer.push(
+            log("pod-b 4", Some("2024-01-01T00:00:04Z")),
+            start + Duration::from_secs(1),
+        );
+
+        assert_eq!(
+            contents(buffer.drain(start + Duration::from_secs(2))),
+            vec!["pod-a 1", "pod-b 2", "pod-a 3"]
+        );
+
+        assert_eq!(
+            contents(buffer.drain(start + Duration::from_secs(3))),
+            vec!["pod-b 4"]
+        );
+    }
+
+    #[test]
+    fn 同じタイムスタンプは受信順に出力する() {
+        let start = Instant::now();
+
+        let mut buffer = LogReorderBuffer::new(Duration::ZERO, 100);
+
+        buffer.push(log("first", Some("2024-01-01T00:00:01Z")), start);
+        buffer.push(log("second", Some("2024-01-01T00:00:01Z")), start);
+
+        assert_eq!(contents(buffer.drain(start)), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn タイムスタンプのないログは直前の最新のログの位置に並べる() {
+        let start = Instant::now();
+
+        let mut buffer = LogReorderBuffer::new(Duration::ZERO, 100);
+
+        buffer.push(log("+ pod-a", None), start);
+        buffer.push(log("pod-a 2", Some("2024-01-01T00:00:02Z")), start);
+        buffer.push(log("- pod-a", None), start);
+        buffer.push(log("pod-b 1", Some("2024-01-01T00:00:01Z")), start);
+        buffer.push(log("pod-b 3", Some("2024-01-01T00:00:03Z")), start);
+
+        assert_eq!(
+            contents(buffer.drain(start)),
+            vec!["+ pod-a", "pod-b 1", "pod-a 2", "- pod-a", "pod-b 3"]
+        );
+    }
+
+    #[test]
+    fn 最大行数を超えた分は保持期間にかかわらず古い順に出力する() {
+        let start = Instant::now();
+
+        let mut buffer = LogReorderBuffer::new(Duration::from_secs(2), 2);
+
+        buffer.push(log("3", Some("2024-01-01T00:00:03Z")), start);
+        buffer.push(log("1", Some("2024-01-01T00:00:01Z")), start);
+        buffer.push(log("2", Some("2024-01-01T00:00:02Z")), start);
+
+        assert_eq!(contents(buffer.drain(start)), vec!["1"]);
+
+        assert_eq!(
+            contents(buffer.drain(start + Duration::from_secs(2))),
+            vec!["2", "3"]
+        );
+    }
+}
@@ -22,7 +22,7 @@ use crate::{
     workers::kube::{color::fg::Color, InfiniteWorker},
 };
 
-use super::{log_collector::LogBuffer, log_content::LogContent};
+use super::{log_collector::LogBuffer, log_content::LogContent, LogSince};
 
 #[derive(Debug, Clone, Copy, Default)]
 pub enum LogPrefixType {
@@ -70,6 +70,12 @@ pub struct LogStreamerOptions {
     pub prefix_type: LogPrefixType,
     pub include_log: Option<Vec<Regex>>,
     pub exclude_log: Option<Vec<Regex>>,
+    /// 前回終了したコンテナのログを取得する
+    pub previous: bool,
+    /// ログの取得開始位置
+    pub since: Option<LogSince>,
+    /// 末尾から取得する行数
+    pub tail: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -113,6 +119,11 @@ impl InfiniteWorker for LogStreamer {
                 //   - 長時間実行
                 break;
             }
+
+            // 前回のコンテナのログはfollowできないため、1度取得したら終了する
+            if self.options.previous {
+                break;
+            }
         }
 
         self.send_finished_message().await;
@@ -163,6 +174,7 @@ impl LogStreamer {
                     buf.push(LogContent {
                         prefix: prefix.to_string(),
                         content: content.to_string(),
+                        timestamp: Some(ts),
                     });
 
                     *last_timestamp = Some(ts);
@@ -177,6 +189,7 @@ impl LogStreamer {
             buf.push(LogContent {
                 prefix: prefix.to_string(),
                 content: line.to_string(),
+                timestamp: None,
             });
         }
 
@@ -206,6 +219,7 @@ impl LogStreamer {
         buf.push(LogContent {
             prefix: sign,
             content: self.log_prefix_content(),
+            timestamp: None,
         });
     }
 
@@ -217,6 +231,7 @@ impl LogStreamer {
         buf.push(LogContent {
             prefix: sign,
             content: self.log_prefix_content(),
+            timestamp: None,
         });
     }
 
@@ -302,13 +317,31 @@ impl LogStreamer {
     }
 
     fn log_params(&self, last_timestamp: &Option<Timestamp>) -> LogParams {
-        LogParams {
-            follow: true,
+        let mut lp = LogParams {
+            follow: !self.options.previous,
+            previous: self.options.previous,
             container: Some(self.container_name().to_string()),
             timestamps: true,
             since_time: *last_timestamp,
             ..Default::default()
+        };
+
+        // 再接続時は最後に受信したログの時刻から再開するため、取得範囲の指定は初回のみ適用する
+        if last_timestamp.is_none() {
+            match self.options.since {
+                Some(LogSince::Seconds(seconds)) => {
+                    lp.since_seconds = Some(seconds.try_into().unwrap_or(i64::MAX));
+                }
+                Some(LogSince::Time(ts)) => {
+                    lp.since_time = Some(ts);
+                }
+                None => {}
+            }
+
+            lp.tail_lines = self.options.tail.map(|n| n.try_into().unwrap_or(i64::MAX));
         }
+
+        lp
     }
 
     fn namespace(&self) -> &str {
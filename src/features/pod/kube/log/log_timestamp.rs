@@ -0,0 +1,143 @@
+use jiff::{tz::TimeZone, Timestamp};
+use serde::{Deserialize, Serialize};
+
+use crate::workers::kube::color::fg::Color;
+
+/// ログの各行の先頭に表示するタイムスタンプの形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogTimestamp {
+    /// 表示しない
+    #[default]
+    Off,
+    /// UTCのRFC3339形式（例: `2024-01-02T03:04:05.678Z`）
+    Rfc3339,
+    /// ローカルタイムゾーンの日時（例: `2024-01-02 12:04:05.678`）
+    Local,
+    /// 表示している時点からの経過時間（例: `12s ago`）
+    Relative,
+}
+
+impl LogTimestamp {
+    /// ログウィジェットから切り替えたときの次の形式
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Rfc3339,
+            Self::Rfc3339 => Self::Local,
+            Self::Local => Self::Relative,
+            Self::Relative => Self::Off,
+        }
+    }
+
+    /// ログの行の先頭に付けるタイムスタンプ
+    pub fn prefix(self, timestamp: Timestamp) -> Option<String> {
+        self.format(timestamp, Timestamp::now(), &TimeZone::system())
+            .map(|ts| format!("{} ", Color::DarkGray.wrap(ts)))
+    }
+
+    /// 時間の経過で表示が変わるかどうか
+    pub fn is_relative(self) -> bool {
+        self == Self::Relative
+    }
+
+    fn format(self, timestamp: Timestamp, now: Timestamp, tz: &TimeZone) -> Option<String> {
+        match self {
+            Self::Off => None,
+            Self::Rfc3339 => Some(timestamp.strftime("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()),
+            Self::Local => {
+                Some(
+                    timestamp
+                        .to_zoned(tz.clone())
+                        .strftime("%Y-%m-%d %H:%M:%S%.3f")
+                        .to_string(),
+                )
+            }
+            Self::Relative => Some(relative(timestamp, now)),
+        }
+    }
+}
+
+/// `now` からみた経過時間を最も大きい単位で表す
+fn relative(timestamp: Timestamp, now: Timestamp) -> String {
+    let seconds = now.as_second().saturating_sub(timestamp.as_second()).max(0);
+
+    match seconds {
+        s if s < 60 => format!("{}s ago", s),
+        s if s < 60 * 60 => format!("{}m ago", s / 60),
+        s if s < 60 * 60 * 24 => format!("{}h ago", s / (60 * 60)),
+        s => format!("{}d ago", s / (60 * 60 * 24)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::tz::Offset;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn ts(s: &str) -> Timestamp {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn rfc3339はミリ秒までのutcで表示する() {
+        let actual = LogTimestamp::Rfc3339.format(
+            ts("2024-01-02T03:04:05.678901234Z"),
+            Timestamp::now(),
+            &TimeZone::UTC,
+        );
+
+        assert_eq!(actual, Some("2024-01-02T03:04:05.678Z".to_string()));
+    }
+
+    #[test]
+    fn localはタイムゾーンの日時で表示する() {
+        let tz = TimeZone::fixed(Offset::constant(9));
+
+        let actual =
+            LogTimestamp::Local.format(ts("2024-01-02T03:04:05.678Z"), Timestamp::now(), &tz);
+
+        assert_eq!(actual, Some("2024-01-02 12:04:05.678".to_string()));
+    }
+
+    #[test]
+    fn relativeは経過時間を表示する() {
+        let now = ts("2024-01-02T03:04:05Z");
+
+        let format = |s: &str| LogTimestamp::Relative.format(ts(s), now, &TimeZone::UTC);
+
+        assert_eq!(format("2024-01-02T03:03:53Z"), Some("12s ago".to_string()));
+        assert_eq!(format("2024-01-02T02:59:05Z"), Some("5m ago".to_string()));
+        assert_eq!(format("2024-01-01T23:04:05Z"), Some("4h ago".to_string()));
+        assert_eq!(format("2023-12-30T03:04:05Z"), Some("3d ago".to_string()));
+        assert_eq!(format("2024-01-02T03:04:06Z"), Some("0s ago".to_string()));
+    }
+
+    #[test]
+    fn offは表示しない() {
+        assert_eq!(LogTimestamp::Off.prefix(ts("2024-01-02T03:04:05Z")), None);
+    }
+
+    #[test]
+    fn 切り替えると次の形式になり一巡する() {
+        let mut timestamp = LogTimestamp::Off;
+
+        let mut actual = Vec::new();
+
+        for _ in 0..4 {
+            timestamp = timestamp.next();
+            actual.push(timestamp);
+        }
+
+        assert_eq!(
+            actual,
+            vec![
+                LogTimestamp::Rfc3339,
+                LogTimestamp::Local,
+                LogTimestamp::Relative,
+                LogTimestamp::Off,
+            ]
+        );
+    }
+}
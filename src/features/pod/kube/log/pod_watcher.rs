@@ -219,7 +219,9 @@ impl PodWatcher {
                 container_name: container_name.clone(),
             };
 
-            let Some(container_id) = Self::is_container_log_available(&status) else {
+            let Some(container_id) =
+                Self::is_container_log_available(&status, self.log_streamer_options.previous)
+            else {
                 logger!(
                     info,
                     "Container ID is empty. state={} task_id={}",
@@ -324,9 +326,18 @@ impl PodWatcher {
         })
     }
 
-    fn is_container_log_available(status: &ContainerStatus) -> Option<String> {
+    fn is_container_log_available(status: &ContainerStatus, previous: bool) -> Option<String> {
         let last_state = &status.last_state;
 
+        // 前回のコンテナのログは lastState.terminated がある場合のみ取得できる。
+        // 再起動のたびにコンテナIDが変わるため、新しい前回のコンテナとして扱われる。
+        if previous {
+            return last_state
+                .as_ref()
+                .and_then(|last_state| last_state.terminated.as_ref())
+                .and_then(|terminated| terminated.container_id.clone());
+        }
+
         let Some(state) = &status.state else {
             return None;
         };
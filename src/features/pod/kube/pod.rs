@@ -4,7 +4,13 @@ use anyhow::Result;
 use async_trait::async_trait;
 use crossbeam::channel::Sender;
 use futures::future::try_join_all;
-use k8s_openapi::{api::core::v1::Pod, Resource as _};
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        core::v1::Pod,
+    },
+    Resource as _,
+};
 use kube::Resource;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use ratatui::style::{Color, Style};
@@ -14,6 +20,7 @@ use crate::{
     features::pod::{message::PodMessage, PodColumn, PodColumnSpec, PodColumns},
     kube::{
         apis::v1_table::TableRow,
+        metrics::{pod_ratio_base, MetricsCache, ResourceAmount, ResourceUsage},
         table::{get_resource_per_namespace, insert_ns, KubeTable, KubeTableRow},
         KubeClient,
     },
@@ -61,6 +68,7 @@ pub struct PodPoller {
     shared_pod_filter: SharedPodFilter,
     kube_client: KubeClient,
     config: PodConfig,
+    metrics_cache: MetricsCache,
 }
 
 impl PodPoller {
@@ -79,6 +87,7 @@ impl PodPoller {
             shared_pod_filter,
             kube_client,
             config,
+            metrics_cache: MetricsCache::default(),
         }
     }
 }
@@ -106,18 +115,24 @@ impl InfiniteWorker for PodPoller {
 /// Build the per-row cell vector from a spec list and a k8s API `TableRow`.
 ///
 /// `builtin_indexes` are the positional indexes into `row.cells` that correspond,
-/// in order, to each `PodColumnSpec::Builtin` entry in `specs`.
+/// in order, to each `PodColumnSpec::Builtin` entry in `specs` that is not a
+/// metrics column. Metrics columns are filled from `usage` instead.
 pub(crate) fn build_row_cells(
     specs: &[PodColumnSpec],
     row: &TableRow,
     builtin_indexes: &[usize],
+    usage: Option<ResourceUsage>,
 ) -> Vec<String> {
     let mut builtin_iter = builtin_indexes.iter();
     specs
         .iter()
         .map(|s| {
             match s {
-                PodColumnSpec::Builtin(_) => {
+                PodColumnSpec::Builtin(c) => {
+                    if let Some(metrics) = c.metrics() {
+                        return metrics.format(usage, pod_usage_base(row));
+                    }
+
                     let i = builtin_iter.next().expect("builtin index available");
                     row.cells[*i].to_string()
                 }
@@ -136,6 +151,48 @@ pub(crate) fn build_row_cells(
         .collect()
 }
 
+fn pod_usage_base(row: &TableRow) -> ResourceAmount {
+    row.object
+        .as_ref()
+        .map(|object| pod_ratio_base(&object.0))
+        .unwrap_or_default()
+}
+
+/// メトリクスと突き合わせるためのPod名
+fn object_name(row: &TableRow) -> Option<&str> {
+    row.object.as_ref()?.0.pointer("/metadata/name")?.as_str()
+}
+
+/// Podを管理しているワークロードの種類と名前を返す
+///
+/// ReplicaSetが所有している場合は `pod-template-hash` ラベルからDeployment名を求める。
+/// Deployment、StatefulSet、DaemonSet以外が所有している場合は `None` を返す。
+pub(crate) fn controller_workload(row: &TableRow) -> Option<(&'static str, String)> {
+    let metadata = row.object.as_ref()?.0.get("metadata")?;
+
+    let owner = metadata
+        .get("ownerReferences")?
+        .as_array()?
+        .iter()
+        .find(|owner| owner.get("controller").and_then(|c| c.as_bool()) == Some(true))?;
+
+    let kind = owner.get("kind")?.as_str()?;
+    let name = owner.get("name")?.as_str()?;
+
+    match kind {
+        "ReplicaSet" => {
+            let hash = metadata.get("labels")?.get("pod-template-hash")?.as_str()?;
+
+            let deployment = name.strip_suffix(hash)?.strip_suffix('-')?;
+
+            Some((Deployment::KIND, deployment.to_string()))
+        }
+        "StatefulSet" => Some((StatefulSet::KIND, name.to_string())),
+        "DaemonSet" => Some((DaemonSet::KIND, name.to_string())),
+        _ => None,
+    }
+}
+
 impl PodPoller {
     async fn get_pod_info(&self) -> Result<KubeTable> {
         let namespaces = self.shared_target_namespaces.read().await;
@@ -189,12 +246,26 @@ impl PodPoller {
             .iter()
             .filter_map(|s| {
                 match s {
-                    PodColumnSpec::Builtin(c) => Some(c.as_str()),
+                    PodColumnSpec::Builtin(c) if c.metrics().is_none() => Some(c.as_str()),
+                    PodColumnSpec::Builtin(_) | PodColumnSpec::Label { .. } => None,
+                }
+            })
+            .collect();
+
+        let metrics_columns: Vec<_> = pod_columns
+            .specs()
+            .iter()
+            .filter_map(|s| {
+                match s {
+                    PodColumnSpec::Builtin(c) => c.metrics(),
                     PodColumnSpec::Label { .. } => None,
                 }
             })
             .collect();
 
+        // requests・limitsを取り出すためにPodのオブジェクト全体を取得する
+        let include_object = metrics_columns.iter().any(|c| c.needs_object());
+
         let pod_columns_specs: Vec<PodColumnSpec> = pod_columns.specs().to_vec();
 
         let label_selector = label_selector.map(|s| s.to_string());
@@ -202,63 +273,97 @@ impl PodPoller {
         try_join_all(namespaces.iter().map(|ns| {
             let pod_columns_specs = pod_columns_specs.clone();
             let base_path = Pod::url_path(&Default::default(), Some(ns));
-            let path = match label_selector.as_deref().filter(|s| !s.is_empty()) {
-                Some(sel) => {
-                    format!(
-                        "{}?labelSelector={}",
-                        base_path,
-                        utf8_percent_encode(sel, NON_ALPHANUMERIC)
-                    )
-                }
-                None => base_path,
-            };
-            get_resource_per_namespace(
-                &self.kube_client,
-                path,
-                &columns,
-                move |row: &TableRow, indexes: &[usize]| {
-                    let mut row_cells = build_row_cells(&pod_columns_specs, row, indexes);
-
-                    let name = row_cells[name_index].clone();
-
-                    let color = if let Some(index) = status_index {
-                        let status = row_cells[index].as_str();
-
-                        self.config
-                            .pod_highlight_rules
-                            .iter()
-                            .find(|rule| rule.status_regex.is_match(status))
-                            .map(|rule| style_to_ansi(rule.style))
-                    } else {
-                        None
-                    };
-
-                    if insert_ns {
-                        row_cells.insert(0, ns.to_string())
-                    }
-
-                    if let Some(color) = color {
-                        row_cells
-                            .iter_mut()
-                            .for_each(|r| *r = format!("{}{}\x1b[0m", color, r))
-                    }
-
-                    KubeTableRow {
-                        namespace: ns.to_string(),
-                        name,
-                        row: row_cells,
-                        metadata: Some(BTreeMap::from([(
-                            "kind".to_string(),
-                            Pod::KIND.to_string(),
-                        )])),
-                    }
-                },
-            )
+            let path = pod_table_path(base_path, label_selector.as_deref(), include_object);
+            let columns = &columns;
+            let metrics_columns = &metrics_columns;
+
+            async move {
+                let usage = if metrics_columns.is_empty() {
+                    None
+                } else {
+                    self.metrics_cache.pod_usage(&self.kube_client, ns).await
+                };
+
+                get_resource_per_namespace(
+                    &self.kube_client,
+                    path,
+                    columns,
+                    move |row: &TableRow, indexes: &[usize]| {
+                        let pod_usage = usage
+                            .as_ref()
+                            .and_then(|usage| usage.get(object_name(row)?).copied());
+
+                        let mut row_cells =
+                            build_row_cells(&pod_columns_specs, row, indexes, pod_usage);
+
+                        let name = row_cells[name_index].clone();
+
+                        let mut metadata =
+                            BTreeMap::from([("kind".to_string(), Pod::KIND.to_string())]);
+
+                        if let Some((kind, name)) = controller_workload(row) {
+                            metadata.insert("owner_kind".to_string(), kind.to_string());
+                            metadata.insert("owner_name".to_string(), name);
+                        }
+
+                        let color = if let Some(index) = status_index {
+                            let status = row_cells[index].as_str();
+
+                            self.config
+                                .pod_highlight_rules
+                                .iter()
+                                .find(|rule| rule.status_regex.is_match(status))
+                                .map(|rule| style_to_ansi(rule.style))
+                        } else {
+                            None
+                        };
+
+                        if insert_ns {
+                            row_cells.insert(0, ns.to_string())
+                        }
+
+                        if let Some(color) = color {
+                            row_cells
+                                .iter_mut()
+                                .for_each(|r| *r = format!("{}{}\x1b[0m", color, r))
+                        }
+
+                        KubeTableRow {
+                            namespace: ns.to_string(),
+                            name,
+                            row: row_cells,
+                            metadata: Some(metadata),
+                        }
+                    },
+                )
+                .await
+            }
         }))
         .await
     }
 }
 
+fn pod_table_path(base_path: String, label_selector: Option<&str>, include_object: bool) -> String {
+    let mut queries = Vec::new();
+
+    if let Some(sel) = label_selector.filter(|s| !s.is_empty()) {
+        queries.push(format!(
+            "labelSelector={}",
+            utf8_percent_encode(sel, NON_ALPHANUMERIC)
+        ));
+    }
+
+    if include_object {
+        queries.push("includeObject=Object".to_string());
+    }
+
+    if queries.is_empty() {
+        base_path
+    } else {
+        format!("{}?{}", base_path, queries.join("&"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -306,7 +411,7 @@ mod tests {
         ];
         let row = make_row_with_labels(&["my-pod"], &[("app", "nginx")]);
         // builtin_indexes: only one Builtin spec, maps to cell[0]
-        let cells = build_row_cells(&specs, &row, &[0]);
+        let cells = build_row_cells(&specs, &row, &[0], None);
         assert_eq!(cells, vec!["my-pod", "nginx"]);
     }
 
@@ -322,7 +427,7 @@ mod tests {
             },
         ];
         let row = make_row_with_labels(&["my-pod"], &[("app", "nginx")]);
-        let cells = build_row_cells(&specs, &row, &[0]);
+        let cells = build_row_cells(&specs, &row, &[0], None);
         assert_eq!(cells, vec!["my-pod", ""]);
     }
 
@@ -339,7 +444,7 @@ mod tests {
         ];
         // row with no object (no metadata at all)
         let row = make_row(&["my-pod"]);
-        let cells = build_row_cells(&specs, &row, &[0]);
+        let cells = build_row_cells(&specs, &row, &[0], None);
         assert_eq!(cells, vec!["my-pod", ""]);
     }
 
@@ -360,7 +465,7 @@ mod tests {
             object: Some(RawExtension(object)),
             ..Default::default()
         };
-        let cells = build_row_cells(&specs, &row, &[0]);
+        let cells = build_row_cells(&specs, &row, &[0], None);
         assert_eq!(cells, vec!["my-pod", ""]);
     }
 
@@ -386,10 +491,72 @@ mod tests {
             &["web-pod", "Running"],
             &[("env", "prod"), ("team", "platform")],
         );
-        let cells = build_row_cells(&specs, &row, &[0, 1]);
+        let cells = build_row_cells(&specs, &row, &[0, 1], None);
         assert_eq!(cells, vec!["web-pod", "prod", "Running", "platform"]);
     }
 
+    fn make_row_with_owner(kind: &str, name: &str, labels: &[(&str, &str)]) -> TableRow {
+        let labels_json: serde_json::Map<String, JsonValue> = labels
+            .iter()
+            .map(|(k, v)| (k.to_string(), JsonValue::String(v.to_string())))
+            .collect();
+        let object = serde_json::json!({
+            "metadata": {
+                "labels": labels_json,
+                "ownerReferences": [
+                    { "kind": kind, "name": name, "controller": true }
+                ]
+            }
+        });
+        TableRow {
+            object: Some(RawExtension(object)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn controller_workload_resolves_deployment_from_replicaset() {
+        let row = make_row_with_owner(
+            "ReplicaSet",
+            "web-7d4b9c8f6d",
+            &[("pod-template-hash", "7d4b9c8f6d")],
+        );
+
+        assert_eq!(
+            controller_workload(&row),
+            Some(("Deployment", "web".to_string()))
+        );
+    }
+
+    #[test]
+    fn controller_workload_ignores_standalone_replicaset() {
+        let row = make_row_with_owner("ReplicaSet", "web", &[]);
+
+        assert_eq!(controller_workload(&row), None);
+    }
+
+    #[test]
+    fn controller_workload_returns_statefulset_and_daemonset() {
+        let row = make_row_with_owner("StatefulSet", "db", &[]);
+        assert_eq!(
+            controller_workload(&row),
+            Some(("StatefulSet", "db".to_string()))
+        );
+
+        let row = make_row_with_owner("DaemonSet", "agent", &[]);
+        assert_eq!(
+            controller_workload(&row),
+            Some(("DaemonSet", "agent".to_string()))
+        );
+    }
+
+    #[test]
+    fn controller_workload_ignores_job() {
+        let row = make_row_with_owner("Job", "migrate", &[]);
+
+        assert_eq!(controller_workload(&row), None);
+    }
+
     #[test]
     fn label_before_builtin_preserves_cell_and_index_alignment() {
         // ユーザが dialog で label 列を Name より前に reorder したケース。
@@ -409,7 +576,7 @@ mod tests {
         // builtin_indexes: Name→cell[0], Status→cell[1].
         let row = make_row_with_labels(&["web-pod", "Running"], &[("app", "frontend")]);
 
-        let cells = build_row_cells(&specs, &row, &[0, 1]);
+        let cells = build_row_cells(&specs, &row, &[0, 1], None);
         assert_eq!(cells, vec!["frontend", "web-pod", "Running"]);
 
         // 呼び出し側がやる name_index 計算と同じことを直接確認:
@@ -421,4 +588,58 @@ mod tests {
         assert_eq!(name_index, 1);
         assert_eq!(cells[name_index], "web-pod");
     }
+
+    #[test]
+    fn metrics_cells_are_formatted_against_requests_and_limits() {
+        let specs = vec![
+            PodColumnSpec::Builtin(PodColumn::Name),
+            PodColumnSpec::Builtin(PodColumn::Cpu),
+            PodColumnSpec::Builtin(PodColumn::Memory),
+            PodColumnSpec::Builtin(PodColumn::CpuRequestRatio),
+            PodColumnSpec::Builtin(PodColumn::MemoryLimitRatio),
+        ];
+        let row = TableRow {
+            cells: vec![Value(JsonValue::String("web".to_string()))],
+            object: Some(RawExtension(serde_json::json!({
+                "metadata": { "name": "web" },
+                "spec": {
+                    "containers": [{
+                        "name": "app",
+                        "resources": {
+                            "requests": { "cpu": "200m" },
+                            "limits": { "memory": "128Mi" }
+                        }
+                    }]
+                }
+            }))),
+            ..Default::default()
+        };
+        let usage = ResourceUsage {
+            cpu: 0.1,
+            memory: 32.0 * 1024.0 * 1024.0,
+        };
+
+        assert_eq!(object_name(&row), Some("web"));
+
+        let cells = build_row_cells(&specs, &row, &[0], Some(usage));
+        assert_eq!(cells, vec!["web", "100m", "32Mi", "50%", "25%"]);
+
+        let cells = build_row_cells(&specs, &row, &[0], None);
+        assert_eq!(cells, vec!["web", "n/a", "n/a", "n/a", "n/a"]);
+    }
+
+    #[test]
+    fn pod_table_path_joins_queries() {
+        let base = "/api/v1/namespaces/default/pods".to_string();
+
+        assert_eq!(pod_table_path(base.clone(), None, false), base);
+        assert_eq!(
+            pod_table_path(base.clone(), Some(""), true),
+            "/api/v1/namespaces/default/pods?includeObject=Object"
+        );
+        assert_eq!(
+            pod_table_path(base, Some("app=web"), true),
+            "/api/v1/namespaces/default/pods?labelSelector=app%3Dweb&includeObject=Object"
+        );
+    }
 }
@@ -1,14 +1,19 @@
 use anyhow::Result;
+use jiff::Timestamp;
 
 use crate::{kube::table::KubeTable, message::Message, workers::kube::message::Kube};
 
-use super::{kube::LogConfig, PodColumns};
+use super::{
+    kube::{ExecSession, LogConfig},
+    PodColumns,
+};
 
 #[derive(Debug)]
 pub enum LogMessage {
     Request(LogConfig),
-    Response(Result<Vec<String>>),
+    Response(Result<Vec<LogLine>>),
     ToggleJsonPrettyPrint,
+    TogglePrevious,
     SetMaxLines(Option<usize>),
     StreamError(String),
     /// Non-fatal informational notice tied to a namespace. Used to surface
@@ -22,6 +27,14 @@ pub enum LogMessage {
     },
 }
 
+/// 表示するログの1行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    pub content: String,
+    /// APIから取得したタイムスタンプ（表示するときに書式を決める）
+    pub timestamp: Option<Timestamp>,
+}
+
 impl From<LogMessage> for Message {
     fn from(m: LogMessage) -> Message {
         Message::Kube(Kube::Log(m))
@@ -40,3 +53,78 @@ impl From<PodMessage> for Message {
         Message::Kube(Kube::Pod(m))
     }
 }
+
+#[derive(Debug)]
+pub enum ExecMessage {
+    Request(ExecRequest),
+    Response(ExecResponse),
+}
+
+#[derive(Debug)]
+pub enum ExecRequest {
+    /// 選択したPodのコンテナ一覧を取得する
+    Containers { namespace: String, pod: String },
+    /// コンテナ内でシェルを起動する
+    Start(ExecTarget),
+    /// 起動中のシェルへの入力
+    Input(ExecInput),
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecTarget {
+    pub namespace: String,
+    pub pod: String,
+    pub container: String,
+}
+
+#[derive(Debug)]
+pub enum ExecInput {
+    Bytes(Vec<u8>),
+    Resize { width: u16, height: u16 },
+}
+
+#[derive(Debug)]
+pub enum ExecResponse {
+    Containers {
+        namespace: String,
+        pod: String,
+        containers: Result<Vec<String>>,
+    },
+    /// シェルが起動し、端末の明け渡しを待っている
+    Attached(ExecSession),
+    /// シェルが終了した
+    Finished(Result<()>),
+}
+
+impl From<ExecRequest> for Message {
+    fn from(m: ExecRequest) -> Message {
+        Message::Kube(Kube::Exec(ExecMessage::Request(m)))
+    }
+}
+
+impl From<ExecResponse> for Message {
+    fn from(m: ExecResponse) -> Message {
+        Message::Kube(Kube::Exec(ExecMessage::Response(m)))
+    }
+}
+
+#[derive(Debug)]
+pub enum DescribeMessage {
+    Request(DescribeTarget),
+    Response(Result<Vec<String>>),
+    /// ダイアログを閉じたときに取得を止める
+    Close,
+}
+
+/// 詳細を表示するPod
+#[derive(Debug, Clone)]
+pub struct DescribeTarget {
+    pub namespace: String,
+    pub name: String,
+}
+
+impl From<DescribeMessage> for Message {
+    fn from(m: DescribeMessage) -> Message {
+        Message::Kube(Kube::Describe(m))
+    }
+}
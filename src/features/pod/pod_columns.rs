@@ -1,5 +1,7 @@
 use strum::{EnumIter, IntoEnumIterator};
 
+use crate::kube::metrics::MetricsColumn;
+
 /// A runtime column in the pod table: a built-in column or a label column.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PodColumnSpec {
@@ -55,8 +57,9 @@ impl PodColumns {
         }
     }
 
+    /// メトリクスの列はmetrics-serverに問い合わせるため含めない
     pub fn full() -> Self {
-        Self::from_builtins(PodColumn::iter())
+        Self::from_builtins(PodColumn::iter().filter(|c| c.metrics().is_none()))
     }
 
     pub fn specs(&self) -> &[PodColumnSpec] {
@@ -106,6 +109,10 @@ pub enum PodColumn {
     Node,
     NominatedNode,
     ReadinessGates,
+    Cpu,
+    Memory,
+    CpuRequestRatio,
+    MemoryLimitRatio,
 }
 
 impl PodColumn {
@@ -120,6 +127,10 @@ impl PodColumn {
             PodColumn::Node => "Node",
             PodColumn::NominatedNode => "Nominated Node",
             PodColumn::ReadinessGates => "Readiness Gates",
+            PodColumn::Cpu => "CPU",
+            PodColumn::Memory => "MEM",
+            PodColumn::CpuRequestRatio => "%CPU/R",
+            PodColumn::MemoryLimitRatio => "%MEM/L",
         }
     }
 
@@ -134,6 +145,10 @@ impl PodColumn {
             PodColumn::Node => "node",
             PodColumn::NominatedNode => "nominatednode",
             PodColumn::ReadinessGates => "readinessgates",
+            PodColumn::Cpu => "cpu",
+            PodColumn::Memory => "mem",
+            PodColumn::CpuRequestRatio => "%cpu/r",
+            PodColumn::MemoryLimitRatio => "%mem/l",
         }
     }
 
@@ -148,6 +163,24 @@ impl PodColumn {
             PodColumn::Node => "NODE",
             PodColumn::NominatedNode => "NOMINATED NODE",
             PodColumn::ReadinessGates => "READINESS GATES",
+            PodColumn::Cpu => "CPU",
+            PodColumn::Memory => "MEM",
+            PodColumn::CpuRequestRatio => "%CPU/R",
+            PodColumn::MemoryLimitRatio => "%MEM/L",
+        }
+    }
+
+    /// metrics.k8s.ioから値を取得する列
+    ///
+    /// Table APIの列ではないため、サーバーに列名を問い合わせない。
+    /// `%CPU/R`はrequests、`%MEM/L`はlimitsに対する使用量の割合を表す。
+    pub const fn metrics(&self) -> Option<MetricsColumn> {
+        match self {
+            PodColumn::Cpu => Some(MetricsColumn::Cpu),
+            PodColumn::Memory => Some(MetricsColumn::Memory),
+            PodColumn::CpuRequestRatio => Some(MetricsColumn::CpuRatio),
+            PodColumn::MemoryLimitRatio => Some(MetricsColumn::MemoryRatio),
+            _ => None,
         }
     }
 
@@ -181,6 +214,10 @@ impl std::str::FromStr for PodColumn {
             "node" => Ok(PodColumn::Node),
             "nominatednode" => Ok(PodColumn::NominatedNode),
             "readinessgates" => Ok(PodColumn::ReadinessGates),
+            "cpu" => Ok(PodColumn::Cpu),
+            "mem" | "memory" => Ok(PodColumn::Memory),
+            "%cpu/r" | "cpu/r" => Ok(PodColumn::CpuRequestRatio),
+            "%mem/l" | "mem/l" => Ok(PodColumn::MemoryLimitRatio),
             _ => Err(PodColumnParseError),
         }
     }
@@ -334,6 +371,31 @@ mod tests {
             assert_eq!(columns, expected);
         }
 
+        #[test]
+        fn メトリクスの列を文字列から変換する() {
+            use std::str::FromStr as _;
+
+            assert_eq!(PodColumn::from_str("cpu").unwrap(), PodColumn::Cpu);
+            assert_eq!(PodColumn::from_str("Memory").unwrap(), PodColumn::Memory);
+            assert_eq!(
+                PodColumn::from_str("%CPU/R").unwrap(),
+                PodColumn::CpuRequestRatio
+            );
+            assert_eq!(
+                PodColumn::from_str("mem/l").unwrap(),
+                PodColumn::MemoryLimitRatio
+            );
+        }
+
+        #[test]
+        fn メトリクスの列はtable_apiの列ではない() {
+            assert_eq!(PodColumn::Name.metrics(), None);
+            assert_eq!(PodColumn::Cpu.metrics(), Some(MetricsColumn::Cpu));
+            assert!(PodColumn::MemoryLimitRatio
+                .metrics()
+                .is_some_and(|c| c.needs_object()));
+        }
+
         mod normalize_column {
             use super::*;
             use pretty_assertions::assert_eq;
@@ -2,3 +2,10 @@ mod tab;
 mod widgets;
 
 pub use tab::*;
+pub use widgets::{
+    log_item,
+    refresh_log_timestamps,
+    request_logs,
+    LogQueryHistory,
+    SharedLogQueryHistory,
+};
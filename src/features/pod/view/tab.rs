@@ -1,17 +1,15 @@
 use std::{cell::RefCell, rc::Rc};
 
-use crossbeam::channel::Sender;
 use ratatui::layout::{Constraint, Direction};
 
 use crate::{
-    clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
+    config::LoggingConfig,
     features::{
         component_id::{POD_TAB_ID, POD_WIDGET_ID},
         pod::{PodColumns, PodLabelColumn},
+        tab::{TabColumns, TabParams},
     },
-    kube::context::Namespace,
-    message::Message,
+    kube::context::{Context, Namespace},
     ui::{
         tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
         widget::Widget,
@@ -20,17 +18,24 @@ use crate::{
 };
 
 use super::widgets::{
+    describe_dialog,
+    exec_dialog,
     log_query_help_widget,
     log_query_widget,
+    log_save_dialog,
     log_widget,
     pod_columns_dialog,
     pod_filter_help_widget,
     pod_widget,
+    SharedLogQueryHistory,
 };
 
 pub struct PodTab {
     pub tab: Tab<'static>,
+    pub exec_dialog: Widget<'static>,
+    pub describe_dialog: Widget<'static>,
     pub log_query_help_dialog: Widget<'static>,
+    pub log_save_dialog: Widget<'static>,
     pub pod_columns_dialog: Widget<'static>,
     pub pod_filter_help_dialog: Widget<'static>,
 }
@@ -38,23 +43,45 @@ pub struct PodTab {
 impl PodTab {
     pub fn new(
         title: &'static str,
-        tx: &Sender<Message>,
-        clipboard: &Option<Rc<RefCell<Clipboard>>>,
-        split_direction: Direction,
+        params: TabParams<'_, TabColumns<Option<PodColumns>, PodLabelColumn>>,
+        context: Rc<RefCell<Context>>,
         namespaces: Rc<RefCell<Namespace>>,
-        default_columns: Option<PodColumns>,
-        label_registry: Vec<PodLabelColumn>,
-        theme: WidgetThemeConfig,
-        log_max_lines: Option<usize>,
+        log_query_history: SharedLogQueryHistory,
+        logging: LoggingConfig,
     ) -> Self {
+        let TabParams {
+            tx,
+            clipboard,
+            split_direction,
+            columns:
+                TabColumns {
+                    default: default_columns,
+                    labels: label_registry,
+                },
+            theme,
+            keymap,
+        } = params;
+
         let error_theme = theme.error.clone().into();
 
-        let pod_widget = pod_widget(tx, label_registry.clone(), theme.clone());
-        let log_query_widget = log_query_widget(tx, namespaces, theme.clone());
+        let pod_widget = pod_widget(tx, label_registry.clone(), theme.clone(), keymap);
+        let log_query_widget =
+            log_query_widget(tx, namespaces.clone(), log_query_history, theme.clone());
         let pod_columns_dialog =
             pod_columns_dialog(tx, default_columns, label_registry, theme.clone());
         let pod_filter_help_dialog = pod_filter_help_widget(theme.clone());
-        let log_widget = log_widget(tx, clipboard, theme.clone(), log_max_lines);
+        let log_widget = log_widget(
+            tx,
+            clipboard,
+            context,
+            namespaces,
+            theme.clone(),
+            &logging,
+            keymap,
+        );
+        let log_save_dialog = log_save_dialog(theme.clone());
+        let exec_dialog = exec_dialog(tx, theme.clone());
+        let describe_dialog = describe_dialog(tx, clipboard, theme.clone(), keymap);
         let log_query_help_widget = log_query_help_widget(theme);
 
         let layout = TabLayout::new(layout, split_direction);
@@ -71,7 +98,10 @@ impl PodTab {
 
         Self {
             tab,
+            exec_dialog,
+            describe_dialog,
             log_query_help_dialog: log_query_help_widget,
+            log_save_dialog,
             pod_columns_dialog,
             pod_filter_help_dialog,
         }
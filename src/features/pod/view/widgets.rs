@@ -1,13 +1,21 @@
+mod describe_dialog;
+mod exec_dialog;
 mod log;
 mod log_query;
 mod log_query_help;
+mod log_save_dialog;
 mod pod;
 mod pod_columns_dialog;
 mod pod_filter_help;
 
+pub(super) use describe_dialog::*;
+pub(super) use exec_dialog::*;
 pub(super) use log::*;
+pub use log::{log_item, refresh_log_timestamps};
 pub(super) use log_query::*;
+pub use log_query::{request_logs, LogQueryHistory, SharedLogQueryHistory};
 pub(super) use log_query_help::*;
+pub(super) use log_save_dialog::*;
 pub(super) use pod::*;
 pub(super) use pod_columns_dialog::*;
 pub(super) use pod_filter_help::*;
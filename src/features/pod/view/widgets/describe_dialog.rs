@@ -0,0 +1,124 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    clipboard::Clipboard,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
+    features::{
+        component_id::{POD_DESCRIBE_DIALOG_ID, POD_WIDGET_ID},
+        pod::message::{DescribeMessage, DescribeTarget},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{
+            SearchForm,
+            SearchFormTheme,
+            SelectedItem,
+            Text,
+            TextTheme,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+pub fn describe_dialog(
+    tx: &Sender<Message>,
+    clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    theme: WidgetThemeConfig,
+    keymap: &Keymap,
+) -> Widget<'static> {
+    let widget_theme = WidgetTheme::from(theme.clone());
+    let search_theme = SearchFormTheme::from(theme.clone());
+    let text_theme = TextTheme::from(theme);
+
+    let widget_base = WidgetBase::builder()
+        .title("Describe")
+        .theme(widget_theme)
+        .build();
+
+    let search_form = SearchForm::builder().theme(search_theme).build();
+
+    let mut builder = Text::builder()
+        .id(POD_DESCRIBE_DIALOG_ID)
+        .widget_base(widget_base)
+        .search_form(search_form)
+        .theme(text_theme)
+        .block_injection(|text: &Text, is_active: bool, is_mouse_over: bool| {
+            let (index, size) = text.state();
+
+            let mut base = text.widget_base().clone();
+
+            *base.title_mut() = format!("Describe [{}/{}]", index, size).into();
+
+            base.render_block(text.can_activate() && is_active, is_mouse_over)
+        })
+        .actions(
+            keymap.events(Action::Quit),
+            close_describe_dialog(tx.clone()),
+        )
+        .wrap();
+
+    if let Some(clipboard) = clipboard {
+        builder = builder.clipboard(clipboard.clone());
+    }
+
+    builder.build().into()
+}
+
+/// 選択中のPodの詳細を取得し、ダイアログを開く
+pub fn open_describe_dialog(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = w.find_widget(POD_WIDGET_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(namespace), Some(name)) = (metadata.get("namespace"), metadata.get("name"))
+        else {
+            return EventResult::Ignore;
+        };
+
+        tx.send(
+            DescribeMessage::Request(DescribeTarget {
+                namespace: namespace.to_string(),
+                name: name.to_string(),
+            })
+            .into(),
+        )
+        .expect("Failed to send DescribeMessage::Request");
+
+        w.widget_clear(POD_DESCRIBE_DIALOG_ID);
+
+        *(w.find_widget_mut(POD_DESCRIBE_DIALOG_ID)
+            .widget_base_mut()
+            .append_title_mut()) = Some(format!(" : {}", name).into());
+
+        w.open_dialog(POD_DESCRIBE_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// ダイアログを閉じ、Podの詳細の取得を止める
+fn close_describe_dialog(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        tx.send(DescribeMessage::Close.into())
+            .expect("Failed to send DescribeMessage::Close");
+
+        w.close_dialog();
+
+        EventResult::Nop
+    }
+}
@@ -0,0 +1,117 @@
+use crossbeam::channel::Sender;
+
+use crate::{
+    config::theme::WidgetThemeConfig,
+    features::{
+        component_id::{POD_EXEC_DIALOG_ID, POD_WIDGET_ID},
+        pod::message::{ExecRequest, ExecTarget},
+    },
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{
+            single_select::{
+                FilterForm,
+                FilterFormTheme,
+                SelectForm,
+                SelectFormTheme,
+                SingleSelectTheme,
+            },
+            LiteralItem,
+            SelectedItem,
+            SingleSelect,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+pub fn exec_dialog(tx: &Sender<Message>, theme: WidgetThemeConfig) -> Widget<'static> {
+    let widget_theme = WidgetTheme::from(theme.clone());
+    let filter_theme = FilterFormTheme::from(theme.clone());
+    let select_theme = SelectFormTheme::from(theme.clone());
+    let single_select_theme = SingleSelectTheme::default().status_style(theme.list.status);
+
+    let filter_form = FilterForm::builder().theme(filter_theme).build();
+
+    let select_form = SelectForm::builder()
+        .on_select(on_select(tx.clone()))
+        .theme(select_theme)
+        .build();
+
+    let widget_base = WidgetBase::builder()
+        .title("Exec")
+        .theme(widget_theme)
+        .build();
+
+    SingleSelect::builder()
+        .id(POD_EXEC_DIALOG_ID)
+        .widget_base(widget_base)
+        .filter_form(filter_form)
+        .select_form(select_form)
+        .theme(single_select_theme)
+        .build()
+        .into()
+}
+
+/// 選択中のPodのコンテナ一覧を取得し、コンテナ選択ダイアログを開く
+pub fn open_exec_dialog(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = w.find_widget(POD_WIDGET_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(namespace), Some(pod)) = (metadata.get("namespace"), metadata.get("name")) else {
+            return EventResult::Ignore;
+        };
+
+        tx.send(
+            ExecRequest::Containers {
+                namespace: namespace.to_string(),
+                pod: pod.to_string(),
+            }
+            .into(),
+        )
+        .expect("Failed to send ExecRequest::Containers");
+
+        w.clear_widget_error(POD_EXEC_DIALOG_ID);
+        w.widget_clear(POD_EXEC_DIALOG_ID);
+        w.find_widget_mut(POD_EXEC_DIALOG_ID)
+            .update_items_title(&format!("{}/{}", namespace, pod));
+
+        w.open_dialog(POD_EXEC_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// ダイアログのアイテムからexecの対象を取り出す
+fn exec_target(item: &LiteralItem) -> Option<ExecTarget> {
+    let metadata = item.metadata.as_ref()?;
+
+    Some(ExecTarget {
+        namespace: metadata.get("namespace")?.to_string(),
+        pod: metadata.get("pod")?.to_string(),
+        container: metadata.get("container")?.to_string(),
+    })
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |_: &mut Window, v: &LiteralItem| {
+        let Some(target) = exec_target(v) else {
+            return EventResult::Ignore;
+        };
+
+        tx.send(ExecRequest::Start(target).into())
+            .expect("Failed to send ExecRequest::Start");
+
+        EventResult::Nop
+    }
+}
@@ -1,17 +1,33 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    rc::Rc,
+    time::Duration,
+};
 
 use crossbeam::channel::Sender;
-use ratatui::{crossterm::event::KeyCode, widgets::Block};
+use jiff::Timestamp;
+use ratatui::widgets::Block;
 
 use crate::{
     clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
-    features::{component_id::POD_LOG_WIDGET_ID, pod::message::LogMessage},
-    message::{Message, UserEvent},
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+        LoggingConfig,
+    },
+    features::{
+        component_id::POD_LOG_WIDGET_ID,
+        pod::{kube::LogTimestamp, message::LogMessage},
+    },
+    kube::context::{Context, Namespace},
+    message::Message,
     ui::{
         event::EventResult,
         widget::{
             Item,
+            LinePrefix,
+            LiteralItem,
             SearchForm,
             SearchFormTheme,
             Text,
@@ -25,12 +41,25 @@ use crate::{
     },
 };
 
+use super::open_log_save_dialog;
+
+/// ログの行のタイムスタンプを保持するメタデータのキー
+const TIMESTAMP_KEY: &str = "timestamp";
+
+/// 経過時間で表示するタイムスタンプを更新する間隔
+const RELATIVE_TIMESTAMP_INTERVAL: Duration = Duration::from_secs(1);
+
 pub fn log_widget(
     tx: &Sender<Message>,
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    context: Rc<RefCell<Context>>,
+    namespaces: Rc<RefCell<Namespace>>,
     theme: WidgetThemeConfig,
-    max_lines: Option<usize>,
+    logging: &LoggingConfig,
+    keymap: &Keymap,
 ) -> Widget<'static> {
+    let timestamp = Rc::new(Cell::new(logging.timestamp));
+
     let widget_theme = WidgetTheme::from(theme.clone());
 
     let widget_base = WidgetBase::builder()
@@ -52,17 +81,26 @@ pub fn log_widget(
         .wrap()
         .follow()
         .block_injection(block_injection())
-        .action(UserEvent::from(KeyCode::Enter), add_blankline())
-        .action(
-            UserEvent::from(KeyCode::Char('f')),
+        .line_prefix(timestamp_prefix(timestamp.clone()))
+        .actions(keymap.events(Action::InsertBlankLine), add_blankline())
+        .actions(
+            keymap.events(Action::TogglePrettyPrint),
             toggle_json_pretty_print(tx.clone()),
         )
-        .action(
-            UserEvent::from(KeyCode::Char('p')),
-            toggle_json_pretty_print(tx.clone()),
+        .actions(
+            keymap.events(Action::TogglePrevious),
+            toggle_previous(tx.clone()),
+        )
+        .actions(
+            keymap.events(Action::ToggleTimestamp),
+            toggle_timestamp(timestamp),
+        )
+        .actions(
+            keymap.events(Action::SaveLog),
+            open_log_save_dialog(context, namespaces),
         );
 
-    if let Some(max) = max_lines {
+    if let Some(max) = logging.max_lines {
         builder = builder.max_lines(Some(max));
     }
 
@@ -110,3 +148,162 @@ fn toggle_json_pretty_print(tx: Sender<Message>) -> impl Fn(&mut Window) -> Even
         EventResult::Nop
     }
 }
+
+fn toggle_previous(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let w = w.find_widget_mut(POD_LOG_WIDGET_ID);
+
+        w.clear();
+
+        tx.send(LogMessage::TogglePrevious.into())
+            .expect("Failed to send LogMessage::TogglePrevious");
+
+        EventResult::Nop
+    }
+}
+
+/// タイムスタンプの形式を切り替え、受信済みのログに付け直す
+///
+/// ログの取得はやり直さない
+fn toggle_timestamp(timestamp: Rc<Cell<LogTimestamp>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        timestamp.set(timestamp.get().next());
+
+        w.find_widget_mut(POD_LOG_WIDGET_ID)
+            .as_mut_text()
+            .refresh_line_prefix();
+
+        EventResult::Nop
+    }
+}
+
+/// メタデータのタイムスタンプを選択中の形式で行の先頭に表示する
+fn timestamp_prefix(
+    timestamp: Rc<Cell<LogTimestamp>>,
+) -> impl Fn(&LiteralItem) -> Option<LinePrefix> {
+    move |item: &LiteralItem| {
+        let format = timestamp.get();
+
+        let ts: Timestamp = item.metadata.as_ref()?.get(TIMESTAMP_KEY)?.parse().ok()?;
+
+        Some(LinePrefix {
+            text: format.prefix(ts)?,
+            volatile: format.is_relative(),
+        })
+    }
+}
+
+/// ログの1行を表示するアイテム
+///
+/// タイムスタンプは表示するときに選択中の形式で付ける
+pub fn log_item(content: String, timestamp: Option<Timestamp>) -> LiteralItem {
+    LiteralItem {
+        metadata: timestamp.map(|ts| BTreeMap::from([(TIMESTAMP_KEY.to_string(), ts.to_string())])),
+        item: content,
+    }
+}
+
+/// 経過時間で表示しているタイムスタンプを更新する
+pub fn refresh_log_timestamps(window: &mut Window) {
+    window
+        .find_widget_mut(POD_LOG_WIDGET_ID)
+        .as_mut_text()
+        .refresh_volatile_line_prefix(RELATIVE_TIMESTAMP_INTERVAL);
+}
+
+#[cfg(test)]
+mod tests {
+    use crossbeam::channel;
+    use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use super::*;
+    use crate::{ui::dialog::Dialog, workers::kube::color::fg::Color};
+
+    #[test]
+    fn シフト付きのpで前回のコンテナのログに切り替える() {
+        let (tx, _rx) = channel::unbounded();
+
+        let mut widget = log_widget(
+            &tx,
+            &None,
+            Rc::default(),
+            Rc::default(),
+            WidgetThemeConfig::default(),
+            &LoggingConfig::default(),
+            &Keymap::default(),
+        );
+
+        // crossterm は大文字を SHIFT 付きで通知する
+        let actual = widget.on_key_event(KeyEvent::new(KeyCode::Char('P'), KeyModifiers::SHIFT));
+
+        assert!(matches!(actual, EventResult::Callback(_)));
+    }
+
+    #[test]
+    fn タイムスタンプを切り替えても取得し直さずに付け直す() {
+        let (tx, rx) = channel::unbounded();
+
+        let logging = LoggingConfig {
+            timestamp: LogTimestamp::Rfc3339,
+            ..Default::default()
+        };
+
+        let widget = log_widget(
+            &tx,
+            &None,
+            Rc::default(),
+            Rc::default(),
+            WidgetThemeConfig::default(),
+            &logging,
+            &Keymap::default(),
+        );
+
+        let mut window = Window::builder()
+            .dialogs(vec![Dialog::builder().widget(widget).build()])
+            .build();
+
+        let ts: Timestamp = "2024-01-02T03:04:05Z".parse().unwrap();
+
+        window
+            .find_widget_mut(POD_LOG_WIDGET_ID)
+            .append_widget_item(Item::Array(vec![
+                log_item("[pod] hello".into(), Some(ts)),
+                log_item("+ pod".into(), None),
+            ]));
+
+        let lines = |window: &Window| {
+            window
+                .find_widget(POD_LOG_WIDGET_ID)
+                .as_text()
+                .raw_lines()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            lines(&window),
+            vec![
+                format!(
+                    "{} [pod] hello",
+                    Color::DarkGray.wrap("2024-01-02T03:04:05.000Z")
+                ),
+                "+ pod".to_string(),
+            ]
+        );
+
+        // rfc3339 -> local -> relative -> off
+        for _ in 0..3 {
+            let EventResult::Callback(cb) = window
+                .find_widget_mut(POD_LOG_WIDGET_ID)
+                .on_key_event(KeyEvent::from(KeyCode::Char('t')))
+            else {
+                panic!("toggle_timestamp is not bound");
+            };
+
+            cb(&mut window);
+        }
+
+        assert_eq!(lines(&window), vec!["[pod] hello", "+ pod"]);
+        assert!(rx.try_recv().is_err());
+    }
+}
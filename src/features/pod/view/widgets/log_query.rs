@@ -19,6 +19,7 @@ use crate::{
         widget::{
             InputFormBuilder,
             InputFormTheme,
+            Item,
             SelectedItem,
             Widget,
             WidgetBase,
@@ -28,9 +29,86 @@ use crate::{
     },
 };
 
+/// 履歴に残すログクエリの数
+const HISTORY_LIMIT: usize = 50;
+
+pub type SharedLogQueryHistory = Rc<RefCell<LogQueryHistory>>;
+
+/// 実行したログクエリの履歴
+///
+/// 入力欄で上下キーを押すと履歴を遡って入力欄に表示する
+#[derive(Debug, Default)]
+pub struct LogQueryHistory {
+    /// 古い順
+    entries: Vec<String>,
+    /// 表示中の履歴の位置。履歴を表示していない場合は `None`
+    cursor: Option<usize>,
+    /// 履歴を遡る前に入力していたクエリ
+    draft: String,
+}
+
+impl LogQueryHistory {
+    pub fn new(entries: Vec<String>) -> Self {
+        let skip = entries.len().saturating_sub(HISTORY_LIMIT);
+
+        Self {
+            entries: entries.into_iter().skip(skip).collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// 実行したクエリを追加する。同じクエリは新しい方だけを残す
+    pub fn push(&mut self, query: impl Into<String>) {
+        let query = query.into();
+
+        self.entries.retain(|entry| *entry != query);
+        self.entries.push(query);
+
+        if HISTORY_LIMIT < self.entries.len() {
+            self.entries.remove(0);
+        }
+
+        self.cursor = None;
+    }
+
+    /// 1つ古いクエリ
+    fn prev(&mut self, current: &str) -> Option<&str> {
+        let index = match self.cursor {
+            Some(0) => return None,
+            Some(index) => index - 1,
+            None => {
+                self.draft = current.to_string();
+                self.entries.len().checked_sub(1)?
+            }
+        };
+
+        self.cursor = Some(index);
+
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// 1つ新しいクエリ。最新のクエリより先に進むと遡る前の入力に戻る
+    fn next(&mut self) -> Option<&str> {
+        let index = self.cursor? + 1;
+
+        if index < self.entries.len() {
+            self.cursor = Some(index);
+            self.entries.get(index).map(String::as_str)
+        } else {
+            self.cursor = None;
+            Some(&self.draft)
+        }
+    }
+}
+
 pub fn log_query_widget(
     tx: &Sender<Message>,
     namespaces: Rc<RefCell<Namespace>>,
+    history: SharedLogQueryHistory,
     theme: WidgetThemeConfig,
 ) -> Widget<'static> {
     let tx = tx.clone();
@@ -46,14 +124,62 @@ pub fn log_query_widget(
         .id(POD_LOG_QUERY_WIDGET_ID)
         .widget_base(widget_base)
         .theme(input_theme)
-        .actions(UserEvent::from(KeyCode::Enter), exec_query(tx, namespaces))
+        .actions(
+            UserEvent::from(KeyCode::Enter),
+            exec_query(tx, namespaces, history.clone()),
+        )
+        .actions(UserEvent::from(KeyCode::Up), history_prev(history.clone()))
+        .actions(UserEvent::from(KeyCode::Down), history_next(history))
         .build()
         .into()
 }
 
+/// ログの取得をリクエストする
+pub fn request_logs(tx: &Sender<Message>, query: String, namespaces: &Namespace) {
+    let prefix_type = if 1 < namespaces.len() {
+        LogPrefixType::All
+    } else {
+        LogPrefixType::PodAndContainer
+    };
+
+    let config = LogConfig::new(query, namespaces.to_owned(), prefix_type, false);
+
+    tx.send(LogMessage::Request(config).into())
+        .expect("Failed to send LogMessage::Request");
+}
+
+fn history_prev(history: SharedLogQueryHistory) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let widget = w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID);
+
+        let current = match widget.widget_item() {
+            Some(SelectedItem::Literal { item, .. }) => item,
+            _ => String::new(),
+        };
+
+        if let Some(query) = history.borrow_mut().prev(&current) {
+            widget.update_widget_item(Item::Single(query.into()));
+        }
+
+        EventResult::Nop
+    }
+}
+
+fn history_next(history: SharedLogQueryHistory) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        if let Some(query) = history.borrow_mut().next() {
+            w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+                .update_widget_item(Item::Single(query.into()));
+        }
+
+        EventResult::Nop
+    }
+}
+
 fn exec_query(
     tx: Sender<Message>,
     namespaces: Rc<RefCell<Namespace>>,
+    history: SharedLogQueryHistory,
 ) -> impl Fn(&mut Window) -> EventResult {
     move |w: &mut Window| {
         let widget = w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID);
@@ -70,19 +196,67 @@ fn exec_query(
 
         w.widget_clear(POD_LOG_WIDGET_ID);
 
-        let namespaces = namespaces.borrow();
+        history.borrow_mut().push(item.clone());
 
-        let prefix_type = if 1 < namespaces.len() {
-            LogPrefixType::All
-        } else {
-            LogPrefixType::PodAndContainer
-        };
+        request_logs(&tx, item, &namespaces.borrow());
 
-        let config = LogConfig::new(item, namespaces.to_owned(), prefix_type, false);
+        EventResult::Ignore
+    }
+}
 
-        tx.send(LogMessage::Request(config).into())
-            .expect("Failed to send LogMessage::Request");
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
 
-        EventResult::Ignore
+    use super::*;
+
+    fn history(entries: &[&str]) -> LogQueryHistory {
+        LogQueryHistory::new(entries.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn 上キーで新しい順に遡り先頭で止まる() {
+        let mut history = history(&["pod:a", "pod:b"]);
+
+        assert_eq!(history.prev(""), Some("pod:b"));
+        assert_eq!(history.prev("pod:b"), Some("pod:a"));
+        assert_eq!(history.prev("pod:a"), None);
+    }
+
+    #[test]
+    fn 下キーで最新より先に進むと入力中のクエリに戻る() {
+        let mut history = history(&["pod:a", "pod:b"]);
+
+        history.prev("pod:c");
+        history.prev("pod:b");
+
+        assert_eq!(history.next(), Some("pod:b"));
+        assert_eq!(history.next(), Some("pod:c"));
+        assert_eq!(history.next(), None);
+    }
+
+    #[test]
+    fn 同じクエリは新しい方だけを残す() {
+        let mut history = history(&["pod:a", "pod:b"]);
+
+        history.push("pod:a");
+
+        assert_eq!(history.entries(), ["pod:b", "pod:a"]);
+    }
+
+    #[test]
+    fn 上限を超えた古いクエリは捨てる() {
+        let entries: Vec<String> = (0..=HISTORY_LIMIT).map(|i| format!("pod:{i}")).collect();
+
+        let mut history = LogQueryHistory::new(entries);
+
+        assert_eq!(history.entries().len(), HISTORY_LIMIT);
+        assert_eq!(history.entries()[0], "pod:1");
+
+        history.push("pod:new");
+
+        assert_eq!(history.entries().len(), HISTORY_LIMIT);
+        assert_eq!(history.entries()[0], "pod:2");
+        assert_eq!(history.entries().last().unwrap(), "pod:new");
     }
 }
@@ -48,9 +48,15 @@ fn content() -> Vec<String> {
            !log:<regex>          (alias: !logs, !lo, !l)
            label:<selector>      (alias: labels)
            field:<selector>      (alias: fields)
+           columns:<name>[:<width>],...
            jq:<expr>
            jmespath:<expr>       (alias: jmes, jm)
            limit:<number>        (alias: lim)
+           previous:<bool>       (alias: prev)
+           order:<time|arrival>
+           since:<duration>      (e.g. 30s, 15m, 2h, 1d, 1h30m)
+           since-time:<RFC3339>
+           tail:<number>
            <resource>/<name>
 
         Resources:
@@ -0,0 +1,340 @@
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    fs::OpenOptions,
+    io::{BufWriter, Write as _},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use anyhow::{Context as _, Result};
+use jiff::Timestamp;
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    ansi::strip_ansi,
+    config::theme::WidgetThemeConfig,
+    features::component_id::{POD_LOG_QUERY_WIDGET_ID, POD_LOG_SAVE_DIALOG_ID, POD_LOG_WIDGET_ID},
+    kube::context::{Context, Namespace},
+    logger,
+    message::UserEvent,
+    ui::{
+        event::EventResult,
+        widget::{
+            InputForm,
+            InputFormTheme,
+            Item,
+            SelectedItem,
+            Widget,
+            WidgetBase,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+/// ファイル名の各要素の最大文字数
+const MAX_FILENAME_COMPONENT_LEN: usize = 64;
+
+/// ログの保存形式
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum LogSaveFormat {
+    /// ANSIエスケープシーケンスを取り除いたテキスト
+    #[default]
+    Plain,
+    /// 受信したままのテキスト
+    Raw,
+}
+
+impl LogSaveFormat {
+    fn toggle(self) -> Self {
+        match self {
+            Self::Plain => Self::Raw,
+            Self::Raw => Self::Plain,
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            Self::Plain => " [plain] ",
+            Self::Raw => " [raw] ",
+        }
+    }
+
+    fn format_line(self, line: &str) -> Cow<'_, str> {
+        match self {
+            Self::Plain => Cow::Owned(strip_ansi(line)),
+            Self::Raw => Cow::Borrowed(line),
+        }
+    }
+}
+
+pub fn log_save_dialog(theme: WidgetThemeConfig) -> Widget<'static> {
+    let input_theme = InputFormTheme::from(theme.clone());
+
+    let widget_base = WidgetBase::builder()
+        .title("Save Log")
+        .theme(theme.into())
+        .build();
+
+    let format = Rc::new(Cell::new(LogSaveFormat::default()));
+
+    InputForm::builder()
+        .id(POD_LOG_SAVE_DIALOG_ID)
+        .widget_base(widget_base)
+        .theme(input_theme)
+        .prefix("File: ")
+        .suffix(format.get().suffix())
+        .actions(UserEvent::from(KeyCode::Enter), save_log(format.clone()))
+        .actions(UserEvent::from(KeyCode::Tab), toggle_format(format))
+        .build()
+        .into()
+}
+
+/// ログ保存ダイアログを開く
+///
+/// ファイル名には `<context>_<namespace>_<query>_<timestamp>.log` を初期値として設定する
+pub fn open_log_save_dialog(
+    context: Rc<RefCell<Context>>,
+    namespaces: Rc<RefCell<Namespace>>,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let query = match w.find_widget(POD_LOG_QUERY_WIDGET_ID).widget_item() {
+            Some(SelectedItem::Literal { item, .. }) => item,
+            _ => String::default(),
+        };
+
+        let filename = default_filename(
+            &context.borrow(),
+            &namespaces.borrow(),
+            &query,
+            Timestamp::now(),
+        );
+
+        w.clear_widget_error(POD_LOG_SAVE_DIALOG_ID);
+
+        w.find_widget_mut(POD_LOG_SAVE_DIALOG_ID)
+            .update_widget_item(Item::Single(filename.into()));
+
+        w.open_dialog(POD_LOG_SAVE_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+fn toggle_format(format: Rc<Cell<LogSaveFormat>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        format.set(format.get().toggle());
+
+        w.find_widget_mut(POD_LOG_SAVE_DIALOG_ID)
+            .as_mut_input()
+            .update_suffix(format.get().suffix());
+
+        EventResult::Nop
+    }
+}
+
+fn save_log(format: Rc<Cell<LogSaveFormat>>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let path = w.find_widget(POD_LOG_SAVE_DIALOG_ID).as_input().content();
+
+        if path.trim().is_empty() {
+            return EventResult::Nop;
+        }
+
+        let path = expand_home_dir(path.trim());
+
+        let log = w.find_widget(POD_LOG_WIDGET_ID).as_text();
+
+        match write_log(&path, log.raw_lines(), format.get()) {
+            Ok(lines) => {
+                logger!(info, "Saved {} lines of log to {}", lines, path.display());
+
+                w.close_dialog();
+
+                // ログバッファには追加せず、ログウィジェットのタイトルに表示する
+                *(w.find_widget_mut(POD_LOG_WIDGET_ID)
+                    .widget_base_mut()
+                    .append_title_mut()) =
+                    Some(format!(" : saved {} lines to {}", lines, path.display()).into());
+            }
+            Err(err) => {
+                logger!(error, "{:?}", err);
+
+                w.set_widget_error(POD_LOG_SAVE_DIALOG_ID, &err);
+            }
+        }
+
+        EventResult::Nop
+    }
+}
+
+/// ログを新規ファイルに書き込み、書き込んだ行数を返す
+///
+/// 既存のファイルを上書きしないよう、ファイルが存在する場合はエラーを返す
+fn write_log<'a>(
+    path: &Path,
+    lines: impl Iterator<Item = &'a str>,
+    format: LogSaveFormat,
+) -> Result<usize> {
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_context(|| format!("Failed to create {}", path.display()))?;
+
+    let mut writer = BufWriter::new(file);
+
+    let mut count = 0;
+
+    for line in lines {
+        writeln!(writer, "{}", format.format_line(line))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        count += 1;
+    }
+
+    writer
+        .flush()
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    Ok(count)
+}
+
+fn expand_home_dir(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn default_filename(
+    context: &Context,
+    namespaces: &Namespace,
+    query: &str,
+    timestamp: Timestamp,
+) -> String {
+    let timestamp = timestamp.strftime("%Y%m%dT%H%M%SZ").to_string();
+
+    [
+        sanitize(&context.0),
+        sanitize(&namespaces.join("+")),
+        sanitize(query),
+        timestamp,
+    ]
+    .into_iter()
+    .filter(|s| !s.is_empty())
+    .collect::<Vec<_>>()
+    .join("_")
+        + ".log"
+}
+
+/// ファイル名に使用できる文字列に変換する
+///
+/// 英数字と `-` `.` `+` 以外の文字は `_` に置き換え、連続する `_` はひとつにまとめる
+fn sanitize(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        let c = if c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '+') {
+            c
+        } else {
+            '_'
+        };
+
+        if c == '_' && (ret.is_empty() || ret.ends_with('_')) {
+            continue;
+        }
+
+        ret.push(c);
+    }
+
+    ret.chars()
+        .take(MAX_FILENAME_COMPONENT_LEN)
+        .collect::<String>()
+        .trim_end_matches('_')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::plain("kind-kind", "kind-kind")]
+    #[case::colon(
+        "arn:aws:eks:ap-northeast-1:123:cluster/foo",
+        "arn_aws_eks_ap-northeast-1_123_cluster_foo"
+    )]
+    #[case::query("pod:app-.* container:nginx", "pod_app-._container_nginx")]
+    #[case::leading_and_trailing("  pod:foo  ", "pod_foo")]
+    #[case::empty("", "")]
+    fn sanitize_filename_component(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(sanitize(input), expected);
+    }
+
+    #[test]
+    fn sanitize_truncates_long_component() {
+        let input = "a".repeat(100);
+
+        assert_eq!(sanitize(&input).len(), MAX_FILENAME_COMPONENT_LEN);
+    }
+
+    #[test]
+    fn default_filename_contains_all_components() {
+        let context = Context("kind-kind".into());
+        let namespaces = Namespace(vec!["default".into(), "kube-system".into()]);
+        let timestamp: Timestamp = "2024-01-02T03:04:05Z".parse().unwrap();
+
+        let actual = default_filename(&context, &namespaces, "pod:nginx", timestamp);
+
+        assert_eq!(
+            actual,
+            "kind-kind_default+kube-system_pod_nginx_20240102T030405Z.log"
+        );
+    }
+
+    #[test]
+    fn default_filename_skips_empty_query() {
+        let context = Context("kind-kind".into());
+        let namespaces = Namespace(vec!["default".into()]);
+        let timestamp: Timestamp = "2024-01-02T03:04:05Z".parse().unwrap();
+
+        let actual = default_filename(&context, &namespaces, "", timestamp);
+
+        assert_eq!(actual, "kind-kind_default_20240102T030405Z.log");
+    }
+
+    #[rstest]
+    #[case::plain(LogSaveFormat::Plain, "\x1b[31merror\x1b[39m: failed", "error: failed")]
+    #[case::raw(
+        LogSaveFormat::Raw,
+        "\x1b[31merror\x1b[39m: failed",
+        "\x1b[31merror\x1b[39m: failed"
+    )]
+    fn format_line(#[case] format: LogSaveFormat, #[case] input: &str, #[case] expected: &str) {
+        assert_eq!(format.format_line(input), expected);
+    }
+
+    #[test]
+    fn write_log_does_not_overwrite_existing_file() {
+        let path =
+            std::env::temp_dir().join(format!("kubetui-write-log-test-{}.log", std::process::id()));
+
+        let _ = std::fs::remove_file(&path);
+
+        let lines = ["\x1b[32mfoo\x1b[39m", "bar"];
+
+        let actual = write_log(&path, lines.into_iter(), LogSaveFormat::Plain).unwrap();
+
+        assert_eq!(actual, 2);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "foo\nbar\n");
+
+        assert!(write_log(&path, lines.into_iter(), LogSaveFormat::Raw).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
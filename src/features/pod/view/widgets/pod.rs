@@ -1,7 +1,10 @@
 use crossbeam::channel::Sender;
 
 use crate::{
-    config::theme::WidgetThemeConfig,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
     features::{
         component_id::{
             POD_COLUMNS_DIALOG_ID,
@@ -37,10 +40,13 @@ use crate::{
     },
 };
 
+use super::{open_describe_dialog, open_exec_dialog};
+
 pub fn pod_widget(
     tx: &Sender<Message>,
     label_registry: Vec<PodLabelColumn>,
     theme: WidgetThemeConfig,
+    keymap: &Keymap,
 ) -> Widget<'static> {
     let tx = tx.clone();
 
@@ -62,7 +68,25 @@ pub fn pod_widget(
         .filter_form(filter_form)
         .theme(table_theme)
         .filter_applicator(pod_filter_applicator(label_registry, tx.clone()))
-        .action('t', open_pod_columns_dialog())
+        .sort_actions(
+            keymap.events(Action::SortNext),
+            keymap.events(Action::SortPrev),
+        )
+        .mark_actions(
+            keymap.events(Action::ToggleMark),
+            keymap.events(Action::MarkRange),
+            keymap.events(Action::MarkAll),
+        )
+        .actions(
+            keymap.events(Action::OpenColumns),
+            open_pod_columns_dialog(),
+        )
+        .actions(keymap.events(Action::Exec), open_exec_dialog(tx.clone()))
+        .actions(
+            keymap.events(Action::Describe),
+            open_describe_dialog(tx.clone()),
+        )
+        .actions(keymap.events(Action::MarkedLogs), marked_logs(tx.clone()))
         .block_injection(block_injection())
         .on_select(on_select(tx))
         .build()
@@ -122,3 +146,101 @@ fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventRe
         EventResult::WindowAction(WindowAction::Continue)
     }
 }
+
+/// マークしたPodのログを `pod:` クエリでまとめて表示する
+fn marked_logs(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let items = w.find_widget(POD_WIDGET_ID).as_table().marked_items();
+
+        let Some((query, namespaces)) = marked_pods_query(&items) else {
+            return EventResult::Ignore;
+        };
+
+        w.widget_clear(POD_LOG_WIDGET_ID);
+
+        w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+            .update_widget_item(Item::Single(query.clone().into()));
+
+        let prefix_type = if 1 < namespaces.len() {
+            LogPrefixType::All
+        } else {
+            LogPrefixType::PodAndContainer
+        };
+
+        let config = LogConfig::new(query, namespaces, prefix_type, false);
+
+        tx.send(LogMessage::Request(config).into())
+            .expect("Failed to send LogMessage::Request");
+
+        EventResult::Nop
+    }
+}
+
+/// マークしたPodの名前に完全一致する `pod:` クエリと、Podのnamespace
+fn marked_pods_query(items: &[TableItem]) -> Option<(String, Namespace)> {
+    let mut names: Vec<String> = Vec::new();
+    let mut namespaces: Vec<String> = Vec::new();
+
+    for metadata in items.iter().filter_map(|item| item.metadata.as_ref()) {
+        let (Some(namespace), Some(name)) = (metadata.get("namespace"), metadata.get("name"))
+        else {
+            continue;
+        };
+
+        let name = regex::escape(name);
+
+        if !names.contains(&name) {
+            names.push(name);
+        }
+
+        if !namespaces.contains(namespace) {
+            namespaces.push(namespace.to_string());
+        }
+    }
+
+    if names.is_empty() {
+        return None;
+    }
+
+    Some((
+        format!("pod:^(?:{})$", names.join("|")),
+        Namespace(namespaces),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn pod(namespace: &str, name: &str) -> TableItem {
+        TableItem::new(
+            vec![name.to_string()],
+            Some(BTreeMap::from([
+                ("namespace".to_string(), namespace.to_string()),
+                ("name".to_string(), name.to_string()),
+            ])),
+        )
+    }
+
+    #[test]
+    fn マークしたpodに完全一致するクエリを作る() {
+        let (query, namespaces) = marked_pods_query(&[
+            pod("default", "web-0"),
+            pod("default", "web.1"),
+            pod("other", "api"),
+        ])
+        .unwrap();
+
+        assert_eq!(query, r"pod:^(?:web\-0|web\.1|api)$");
+        assert_eq!(namespaces.0, vec!["default", "other"]);
+    }
+
+    #[test]
+    fn マークがなければnoneを返す() {
+        assert!(marked_pods_query(&[]).is_none());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod kube;
+pub mod message;
+mod port_mapping;
+pub mod view;
+
+pub use port_mapping::*;
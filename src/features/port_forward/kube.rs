@@ -0,0 +1,399 @@
+use std::{net::Ipv4Addr, sync::Arc};
+
+use anyhow::{anyhow, bail, Context as _, Result};
+use k8s_openapi::{
+    api::core::v1::{Pod, Service, ServicePort},
+    apimachinery::pkg::util::intstr::IntOrString,
+};
+use kube::{api::ListParams, Api};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::RwLock,
+    task::{AbortHandle, JoinSet},
+};
+
+use crate::{kube::KubeClient, logger};
+
+use super::{
+    message::{PortForwardEntry, PortForwardKind, PortForwardTarget},
+    PortMapping,
+};
+
+pub type SharedPortForwards = Arc<RwLock<PortForwards>>;
+
+struct PortForward {
+    entry: PortForwardEntry,
+    handler: AbortHandle,
+}
+
+/// 実行中のポートフォワードを管理する
+///
+/// コンテキストを切り替えるまでタブの切り替えやNamespaceの変更に関わらず転送を継続する
+#[derive(Default)]
+pub struct PortForwards {
+    next_id: usize,
+    forwards: Vec<PortForward>,
+}
+
+impl PortForwards {
+    pub fn shared() -> SharedPortForwards {
+        Arc::new(RwLock::new(Self::default()))
+    }
+
+    pub fn entries(&self) -> Vec<PortForwardEntry> {
+        self.forwards.iter().map(|f| f.entry.clone()).collect()
+    }
+
+    pub fn stop(&mut self, id: usize) {
+        self.forwards.retain(|f| {
+            if f.entry.id == id {
+                logger!(info, "Stop port-forward {}", f.entry);
+                f.handler.abort();
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    pub fn stop_all(&mut self) {
+        for f in self.forwards.drain(..) {
+            logger!(info, "Stop port-forward {}", f.entry);
+            f.handler.abort();
+        }
+    }
+
+    /// 準備したポートフォワードを開始する
+    pub fn start(&mut self, pending: PendingPortForward) -> Result<()> {
+        let PendingPortForward {
+            api,
+            target,
+            pod,
+            ports,
+        } = pending;
+
+        for (listener, remote_port, pod_port) in ports {
+            let entry = PortForwardEntry {
+                id: self.next_id,
+                target: target.clone(),
+                pod: pod.clone(),
+                local_port: listener.local_addr()?.port(),
+                remote_port,
+                pod_port,
+            };
+
+            logger!(info, "Start port-forward {}", entry);
+
+            let handler =
+                tokio::spawn(serve(listener, api.clone(), pod.clone(), pod_port)).abort_handle();
+
+            self.next_id += 1;
+
+            self.forwards.push(PortForward { entry, handler });
+        }
+
+        Ok(())
+    }
+}
+
+/// 待ち受けを開始し、転送先が決まったポートフォワード
+///
+/// [`PortForwards`] のロックを取らずに準備し、[`PortForwards::start`] で転送を開始する
+pub struct PendingPortForward {
+    api: Api<Pod>,
+    target: PortForwardTarget,
+    pod: String,
+    /// (待ち受けるソケット, 指定したリモートポート, Podのポート)
+    ports: Vec<(TcpListener, u16, u16)>,
+}
+
+impl PendingPortForward {
+    /// ポートフォワードを準備する
+    ///
+    /// Serviceの場合はセレクタに一致する実行中のPodを1つ選び、そのPodに転送する。
+    /// すべてのポートで待ち受けを開始できた場合のみ成功する。
+    pub async fn prepare(
+        client: &KubeClient,
+        target: PortForwardTarget,
+        mappings: &[PortMapping],
+    ) -> Result<Self> {
+        let api: Api<Pod> = Api::namespaced(client.to_client(), &target.namespace);
+
+        let (pod, pod_ports) = match target.kind {
+            PortForwardKind::Pod => {
+                let ports = mappings.iter().map(|m| m.remote).collect();
+
+                (target.name.clone(), ports)
+            }
+            PortForwardKind::Service => {
+                resolve_service(client, &target.namespace, &target.name, mappings).await?
+            }
+        };
+
+        let mut ports = Vec::with_capacity(mappings.len());
+
+        for (mapping, pod_port) in mappings.iter().zip(pod_ports) {
+            let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, mapping.local))
+                .await
+                .with_context(|| format!("Failed to listen on 127.0.0.1:{}", mapping.local))?;
+
+            ports.push((listener, mapping.remote, pod_port));
+        }
+
+        Ok(Self {
+            api,
+            target,
+            pod,
+            ports,
+        })
+    }
+}
+
+/// ローカルの接続を受け付け、接続ごとにPodへのポートフォワードを確立する
+///
+/// このタスクを中断すると、確立済みの接続もすべて切断される
+async fn serve(listener: TcpListener, api: Api<Pod>, pod: String, port: u16) {
+    let mut connections = JoinSet::new();
+
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                match accepted {
+                    Ok((stream, _)) => {
+                        let api = api.clone();
+                        let pod = pod.clone();
+
+                        connections.spawn(async move {
+                            if let Err(err) = forward_connection(stream, &api, &pod, port).await {
+                                logger!(error, "Port-forward to {}:{} failed: {:?}", pod, port, err);
+                            }
+                        });
+                    }
+                    Err(err) => {
+                        logger!(error, "Failed to accept connection: {}", err);
+                    }
+                }
+            }
+            Some(_) = connections.join_next() => {}
+        }
+    }
+}
+
+async fn forward_connection(
+    mut stream: TcpStream,
+    api: &Api<Pod>,
+    pod: &str,
+    port: u16,
+) -> Result<()> {
+    let mut forwarder = api.portforward(pod, &[port]).await?;
+
+    let mut upstream = forwarder
+        .take_stream(port)
+        .ok_or_else(|| anyhow!("Port {} is not available", port))?;
+
+    tokio::io::copy_bidirectional(&mut stream, &mut upstream).await?;
+
+    drop(upstream);
+
+    forwarder.join().await?;
+
+    Ok(())
+}
+
+/// 転送先の候補となるポートを取得する
+///
+/// PodはコンテナのcontainerPortを、ServiceはServiceのportを返す
+pub async fn fetch_ports(client: &KubeClient, target: &PortForwardTarget) -> Result<Vec<u16>> {
+    match target.kind {
+        PortForwardKind::Pod => {
+            let api: Api<Pod> = Api::namespaced(client.to_client(), &target.namespace);
+
+            let pod = api.get(&target.name).await?;
+
+            Ok(pod_container_ports(&pod)
+                .filter_map(|(_, port)| u16::try_from(port).ok())
+                .collect())
+        }
+        PortForwardKind::Service => {
+            let api: Api<Service> = Api::namespaced(client.to_client(), &target.namespace);
+
+            let service = api.get(&target.name).await?;
+
+            Ok(service_ports(&service)
+                .iter()
+                .filter_map(|p| u16::try_from(p.port).ok())
+                .collect())
+        }
+    }
+}
+
+/// Serviceの転送先となるPodと、各ポートに対応するPodのポートを求める
+async fn resolve_service(
+    client: &KubeClient,
+    namespace: &str,
+    name: &str,
+    mappings: &[PortMapping],
+) -> Result<(String, Vec<u16>)> {
+    let service_api: Api<Service> = Api::namespaced(client.to_client(), namespace);
+
+    let service = service_api.get(name).await?;
+
+    let selector = service
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.selector.as_ref())
+        .filter(|selector| !selector.is_empty())
+        .ok_or_else(|| anyhow!("Service {} has no selector", name))?;
+
+    let label_selector = selector
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let pod_api: Api<Pod> = Api::namespaced(client.to_client(), namespace);
+
+    let pods = pod_api
+        .list(&ListParams::default().labels(&label_selector))
+        .await?;
+
+    let pod = pods
+        .items
+        .into_iter()
+        .find(is_running)
+        .ok_or_else(|| anyhow!("No running pod found for service {}", name))?;
+
+    let pod_ports = mappings
+        .iter()
+        .map(|mapping| {
+            let service_port = service_ports(&service)
+                .iter()
+                .find(|p| p.port == i32::from(mapping.remote))
+                .ok_or_else(|| anyhow!("Service {} does not have port {}", name, mapping.remote))?;
+
+            resolve_target_port(service_port, &pod)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let pod_name = pod
+        .metadata
+        .name
+        .ok_or_else(|| anyhow!("Pod name is not found"))?;
+
+    Ok((pod_name, pod_ports))
+}
+
+fn service_ports(service: &Service) -> &[ServicePort] {
+    service
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.ports.as_deref())
+        .unwrap_or_default()
+}
+
+fn pod_container_ports(pod: &Pod) -> impl Iterator<Item = (Option<&str>, i32)> {
+    pod.spec
+        .iter()
+        .flat_map(|spec| spec.containers.iter())
+        .flat_map(|container| container.ports.iter().flatten())
+        .map(|port| (port.name.as_deref(), port.container_port))
+}
+
+fn is_running(pod: &Pod) -> bool {
+    pod.metadata.deletion_timestamp.is_none()
+        && pod
+            .status
+            .as_ref()
+            .and_then(|status| status.phase.as_deref())
+            .is_some_and(|phase| phase == "Running")
+}
+
+/// ServiceのポートからPodのポートを求める
+///
+/// targetPortが名前の場合はPodのコンテナのポート名から解決する
+fn resolve_target_port(service_port: &ServicePort, pod: &Pod) -> Result<u16> {
+    let port = match &service_port.target_port {
+        Some(IntOrString::Int(port)) => *port,
+        Some(IntOrString::String(name)) => {
+            pod_container_ports(pod)
+                .find(|(port_name, _)| *port_name == Some(name.as_str()))
+                .map(|(_, port)| port)
+                .with_context(|| format!("Pod does not have a port named {:?}", name))?
+        }
+        None => service_port.port,
+    };
+
+    match u16::try_from(port) {
+        Ok(port) if port != 0 => Ok(port),
+        _ => bail!("Invalid target port: {}", port),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    fn pod() -> Pod {
+        serde_yaml::from_str(indoc! {
+            r#"
+            metadata:
+              name: app
+            spec:
+              containers:
+                - name: app
+                  ports:
+                    - name: http
+                      containerPort: 8080
+                    - name: metrics
+                      containerPort: 9090
+            status:
+              phase: Running
+            "#
+        })
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case::int(Some(IntOrString::Int(8080)), 8080)]
+    #[case::named(Some(IntOrString::String("metrics".into())), 9090)]
+    #[case::omitted(None, 80)]
+    fn resolve_service_target_port(
+        #[case] target_port: Option<IntOrString>,
+        #[case] expected: u16,
+    ) {
+        let service_port = ServicePort {
+            port: 80,
+            target_port,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            resolve_target_port(&service_port, &pod()).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn resolve_unknown_named_target_port() {
+        let service_port = ServicePort {
+            port: 80,
+            target_port: Some(IntOrString::String("grpc".into())),
+            ..Default::default()
+        };
+
+        assert!(resolve_target_port(&service_port, &pod()).is_err());
+    }
+
+    #[test]
+    fn running_pod_is_selected() {
+        let mut pending = pod();
+        pending.status.as_mut().unwrap().phase = Some("Pending".into());
+
+        assert!(is_running(&pod()));
+        assert!(!is_running(&pending));
+    }
+}
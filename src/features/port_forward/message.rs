@@ -0,0 +1,117 @@
+use std::fmt;
+
+use anyhow::Result;
+
+use crate::{message::Message, workers::kube::message::Kube};
+
+use super::PortMapping;
+
+#[derive(Debug)]
+pub enum PortForwardMessage {
+    Request(PortForwardRequest),
+    Response(PortForwardResponse),
+}
+
+#[derive(Debug)]
+pub enum PortForwardRequest {
+    /// 転送先の候補となるポートを取得する
+    Ports(PortForwardTarget),
+
+    /// ポートフォワードを開始する
+    Start {
+        target: PortForwardTarget,
+        mappings: Vec<PortMapping>,
+    },
+
+    /// 指定したIDのポートフォワードを停止する
+    Stop(usize),
+}
+
+#[derive(Debug)]
+pub enum PortForwardResponse {
+    Ports {
+        target: PortForwardTarget,
+        ports: Result<Vec<u16>>,
+    },
+
+    Start(Result<()>),
+
+    /// 実行中のポートフォワードの一覧
+    List(Vec<PortForwardEntry>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortForwardKind {
+    Pod,
+    Service,
+}
+
+impl fmt::Display for PortForwardKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pod => write!(f, "pod"),
+            Self::Service => write!(f, "service"),
+        }
+    }
+}
+
+/// ポートフォワードの対象リソース
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortForwardTarget {
+    pub namespace: String,
+    pub kind: PortForwardKind,
+    pub name: String,
+}
+
+impl fmt::Display for PortForwardTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.namespace, self.kind, self.name)
+    }
+}
+
+/// 実行中のポートフォワード
+#[derive(Debug, Clone)]
+pub struct PortForwardEntry {
+    pub id: usize,
+    pub target: PortForwardTarget,
+
+    /// 実際に転送しているPod
+    pub pod: String,
+
+    /// ローカルで待ち受けているポート
+    pub local_port: u16,
+
+    /// 指定されたリモートポート（Serviceの場合はServiceのポート）
+    pub remote_port: u16,
+
+    /// 転送先のPodのポート
+    pub pod_port: u16,
+}
+
+impl fmt::Display for PortForwardEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "127.0.0.1:{} -> {}:{}",
+            self.local_port, self.target, self.remote_port
+        )?;
+
+        if self.target.kind == PortForwardKind::Service {
+            write!(f, " (pod/{}:{})", self.pod, self.pod_port)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<PortForwardRequest> for Message {
+    fn from(req: PortForwardRequest) -> Self {
+        Message::Kube(Kube::PortForward(PortForwardMessage::Request(req)))
+    }
+}
+
+impl From<PortForwardResponse> for Message {
+    fn from(res: PortForwardResponse) -> Self {
+        Message::Kube(Kube::PortForward(PortForwardMessage::Response(res)))
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+
+use anyhow::{bail, Context as _, Result};
+
+/// ローカルポートとリモートポートの対応
+///
+/// `kubectl port-forward` と同じ `[LOCAL:]REMOTE` 形式で指定する。
+/// `LOCAL` を省略した場合は `REMOTE` と同じポートを、`:REMOTE` の場合は空いているポートを使用する。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortMapping {
+    /// ローカルで待ち受けるポート（0の場合は空いているポートを割り当てる）
+    pub local: u16,
+
+    /// 転送先のポート
+    pub remote: u16,
+}
+
+impl PortMapping {
+    pub fn parse(s: &str) -> Result<Self> {
+        let (local, remote) = match s.split_once(':') {
+            Some(("", remote)) => (0, parse_port(remote)?),
+            Some((local, remote)) => (parse_port(local)?, parse_port(remote)?),
+            None => {
+                let port = parse_port(s)?;
+                (port, port)
+            }
+        };
+
+        if remote == 0 {
+            bail!("Remote port must not be 0: {}", s);
+        }
+
+        Ok(Self { local, remote })
+    }
+}
+
+impl fmt::Display for PortMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.local == self.remote {
+            write!(f, "{}", self.remote)
+        } else if self.local == 0 {
+            write!(f, ":{}", self.remote)
+        } else {
+            write!(f, "{}:{}", self.local, self.remote)
+        }
+    }
+}
+
+fn parse_port(s: &str) -> Result<u16> {
+    s.parse()
+        .with_context(|| format!("Invalid port number: {:?}", s))
+}
+
+/// 空白区切りで並べたポートの対応を解析する
+pub fn parse_port_mappings(s: &str) -> Result<Vec<PortMapping>> {
+    let mappings = s
+        .split_whitespace()
+        .map(PortMapping::parse)
+        .collect::<Result<Vec<_>>>()?;
+
+    if mappings.is_empty() {
+        bail!("No ports specified. Use [LOCAL:]REMOTE (e.g. 8080 or 18080:80)");
+    }
+
+    Ok(mappings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::same_port("8080", PortMapping { local: 8080, remote: 8080 })]
+    #[case::local_and_remote("18080:80", PortMapping { local: 18080, remote: 80 })]
+    #[case::random_local(":80", PortMapping { local: 0, remote: 80 })]
+    fn parse_port_mapping(#[case] input: &str, #[case] expected: PortMapping) {
+        assert_eq!(PortMapping::parse(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::not_a_number("http")]
+    #[case::out_of_range("70000")]
+    #[case::zero_remote("8080:0")]
+    #[case::missing_remote("8080:")]
+    fn parse_port_mapping_error(#[case] input: &str) {
+        assert!(PortMapping::parse(input).is_err());
+    }
+
+    #[rstest]
+    #[case::same_port("8080")]
+    #[case::local_and_remote("18080:80")]
+    #[case::random_local(":80")]
+    fn display_round_trip(#[case] input: &str) {
+        assert_eq!(PortMapping::parse(input).unwrap().to_string(), input);
+    }
+
+    #[test]
+    fn parse_multiple_port_mappings() {
+        let actual = parse_port_mappings(" 8080  18443:443 ").unwrap();
+
+        assert_eq!(
+            actual,
+            vec![
+                PortMapping {
+                    local: 8080,
+                    remote: 8080
+                },
+                PortMapping {
+                    local: 18443,
+                    remote: 443
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_empty_port_mappings() {
+        assert!(parse_port_mappings("  ").is_err());
+    }
+}
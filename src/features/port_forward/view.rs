@@ -0,0 +1,5 @@
+mod port_forward_dialog;
+mod port_forward_list_dialog;
+
+pub use port_forward_dialog::*;
+pub use port_forward_list_dialog::*;
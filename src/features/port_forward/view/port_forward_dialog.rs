@@ -0,0 +1,180 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use k8s_openapi::{
+    api::core::v1::{Pod, Service},
+    Resource as _,
+};
+use ratatui::crossterm::event::KeyCode;
+
+use crate::{
+    config::theme::ThemeConfig,
+    features::{
+        component_id::{NETWORK_WIDGET_ID, POD_WIDGET_ID, PORT_FORWARD_DIALOG_ID},
+        port_forward::{
+            message::{PortForwardKind, PortForwardRequest, PortForwardTarget},
+            parse_port_mappings,
+        },
+    },
+    message::{Message, UserEvent},
+    ui::{
+        event::{CallbackFn, EventResult},
+        widget::{
+            InputForm,
+            InputFormTheme,
+            Item,
+            SelectedItem,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
+    },
+};
+
+/// ポートフォワードダイアログで選択中の転送対象
+pub type SharedPortForwardTarget = Rc<RefCell<Option<PortForwardTarget>>>;
+
+pub struct PortForwardDialog {
+    pub widget: Widget<'static>,
+}
+
+impl PortForwardDialog {
+    pub fn new(tx: &Sender<Message>, target: &SharedPortForwardTarget, theme: ThemeConfig) -> Self {
+        Self {
+            widget: widget(tx.clone(), target.clone(), theme),
+        }
+    }
+}
+
+fn widget(
+    tx: Sender<Message>,
+    target: SharedPortForwardTarget,
+    theme: ThemeConfig,
+) -> Widget<'static> {
+    let widget_theme = WidgetTheme::from(theme.component.clone());
+    let input_theme = InputFormTheme::from(theme.component);
+
+    let widget_base = WidgetBase::builder()
+        .title("Port Forward")
+        .theme(widget_theme)
+        .build();
+
+    InputForm::builder()
+        .id(PORT_FORWARD_DIALOG_ID)
+        .widget_base(widget_base)
+        .theme(input_theme)
+        .prefix("Ports: ")
+        .suffix(" [LOCAL:]REMOTE ")
+        .actions(UserEvent::from(KeyCode::Enter), start(tx, target))
+        .build()
+        .into()
+}
+
+/// 選択中のPodまたはServiceを対象にポートフォワードダイアログを開く
+pub fn open_port_forward_dialog(
+    tx: Sender<Message>,
+    target: SharedPortForwardTarget,
+) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let widget = w.active_tab().active_widget();
+
+        match widget.id() {
+            POD_WIDGET_ID | NETWORK_WIDGET_ID => {}
+            _ => {
+                return EventResult::Ignore;
+            }
+        }
+
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = widget.widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let kind = match metadata.get("kind").map(|v| v.as_str()) {
+            Some(Pod::KIND) => PortForwardKind::Pod,
+            Some(Service::KIND) => PortForwardKind::Service,
+            _ => {
+                return EventResult::Ignore;
+            }
+        };
+
+        let (Some(namespace), Some(name)) = (metadata.get("namespace"), metadata.get("name"))
+        else {
+            return EventResult::Ignore;
+        };
+
+        let new_target = PortForwardTarget {
+            namespace: namespace.to_string(),
+            kind,
+            name: name.to_string(),
+        };
+
+        tx.send(PortForwardRequest::Ports(new_target.clone()).into())
+            .expect("Failed to send PortForwardRequest::Ports");
+
+        w.clear_widget_error(PORT_FORWARD_DIALOG_ID);
+
+        let dialog = w.find_widget_mut(PORT_FORWARD_DIALOG_ID);
+
+        dialog.clear();
+
+        *dialog.widget_base_mut().append_title_mut() = Some(format!(" : {}", new_target).into());
+
+        *target.borrow_mut() = Some(new_target);
+
+        w.open_dialog(PORT_FORWARD_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// 転送先の候補となるポートを入力欄に設定する
+///
+/// すでに入力されている場合は上書きしない
+pub fn update_port_forward_ports(w: &mut Window, ports: &[u16]) {
+    let dialog = w.find_widget_mut(PORT_FORWARD_DIALOG_ID);
+
+    if !dialog.as_input().content().is_empty() {
+        return;
+    }
+
+    let ports = ports
+        .iter()
+        .map(|port| port.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    dialog.update_widget_item(Item::Single(ports.into()));
+}
+
+fn start(
+    tx: Sender<Message>,
+    target: SharedPortForwardTarget,
+) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(target) = target.borrow().clone() else {
+            return EventResult::Nop;
+        };
+
+        let content = w.find_widget(PORT_FORWARD_DIALOG_ID).as_input().content();
+
+        match parse_port_mappings(&content) {
+            Ok(mappings) => {
+                w.clear_widget_error(PORT_FORWARD_DIALOG_ID);
+
+                tx.send(PortForwardRequest::Start { target, mappings }.into())
+                    .expect("Failed to send PortForwardRequest::Start");
+            }
+            Err(err) => {
+                w.set_widget_error(PORT_FORWARD_DIALOG_ID, &err);
+            }
+        }
+
+        EventResult::Nop
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    config::theme::ThemeConfig,
+    features::{
+        component_id::PORT_FORWARD_LIST_DIALOG_ID,
+        port_forward::message::{PortForwardEntry, PortForwardRequest},
+    },
+    message::Message,
+    ui::{
+        event::{CallbackFn, EventResult},
+        widget::{
+            single_select::{
+                FilterForm,
+                FilterFormTheme,
+                SelectForm,
+                SelectFormTheme,
+                SingleSelectTheme,
+            },
+            LiteralItem,
+            SingleSelect,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+        },
+        Window,
+    },
+};
+
+pub struct PortForwardListDialog {
+    pub widget: Widget<'static>,
+}
+
+impl PortForwardListDialog {
+    pub fn new(tx: &Sender<Message>, theme: ThemeConfig) -> Self {
+        Self {
+            widget: widget(tx.clone(), theme),
+        }
+    }
+}
+
+fn widget(tx: Sender<Message>, theme: ThemeConfig) -> Widget<'static> {
+    let widget_theme = WidgetTheme::from(theme.component.clone());
+    let filter_theme = FilterFormTheme::from(theme.component.clone());
+    let select_theme = SelectFormTheme::from(theme.component.clone());
+    let single_select_theme =
+        SingleSelectTheme::default().status_style(theme.component.list.status);
+
+    let filter_form = FilterForm::builder().theme(filter_theme).build();
+    let select_form = SelectForm::builder()
+        .theme(select_theme)
+        .on_select(on_select(tx))
+        .build();
+
+    let widget_base = WidgetBase::builder()
+        .title("Port Forwards (Enter: stop)")
+        .theme(widget_theme)
+        .build();
+
+    SingleSelect::builder()
+        .id(PORT_FORWARD_LIST_DIALOG_ID)
+        .widget_base(widget_base)
+        .filter_form(filter_form)
+        .select_form(select_form)
+        .theme(single_select_theme)
+        .build()
+        .into()
+}
+
+pub fn open_port_forward_list_dialog() -> impl CallbackFn {
+    |w: &mut Window| {
+        w.open_dialog(PORT_FORWARD_LIST_DIALOG_ID);
+
+        EventResult::Nop
+    }
+}
+
+/// 実行中のポートフォワードをダイアログのアイテムに変換する
+pub fn port_forward_list_items(entries: Vec<PortForwardEntry>) -> Vec<LiteralItem> {
+    entries
+        .into_iter()
+        .map(|entry| {
+            let metadata = BTreeMap::from([("id".to_string(), entry.id.to_string())]);
+
+            LiteralItem::new(entry.to_string(), Some(metadata))
+        })
+        .collect()
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &LiteralItem) -> EventResult {
+    move |_: &mut Window, v: &LiteralItem| {
+        let Some(id) = v
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("id"))
+            .and_then(|id| id.parse().ok())
+        else {
+            return EventResult::Ignore;
+        };
+
+        tx.send(PortForwardRequest::Stop(id).into())
+            .expect("Failed to send PortForwardRequest::Stop");
+
+        EventResult::Nop
+    }
+}
@@ -0,0 +1,27 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crossbeam::channel::Sender;
+use ratatui::layout::Direction;
+
+use crate::{
+    clipboard::Clipboard,
+    config::{keybindings::Keymap, theme::WidgetThemeConfig},
+    message::Message,
+};
+
+/// 各タブを作るときに共通で渡す値
+pub struct TabParams<'a, C> {
+    pub tx: &'a Sender<Message>,
+    pub clipboard: &'a Option<Rc<RefCell<Clipboard>>>,
+    pub split_direction: Direction,
+    pub columns: C,
+    pub theme: WidgetThemeConfig,
+    pub keymap: &'a Keymap,
+}
+
+/// 列の初期値と、設定ファイルで追加したラベル列
+#[derive(Debug, Clone)]
+pub struct TabColumns<C, L> {
+    pub default: C,
+    pub labels: Vec<L>,
+}
@@ -0,0 +1,14 @@
+mod columns;
+mod filter;
+pub mod kube;
+pub mod message;
+pub mod view;
+
+pub use columns::{
+    WorkloadColumn,
+    WorkloadColumnSpec,
+    WorkloadColumns,
+    WorkloadLabelColumn,
+    DEFAULT_WORKLOAD_COLUMNS,
+};
+pub use filter::workload_filter_applicator;
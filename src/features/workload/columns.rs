@@ -0,0 +1,300 @@
+use strum::EnumIter;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WorkloadColumnSpec {
+    Builtin(WorkloadColumn),
+    Label { key: String, header: String },
+}
+
+impl WorkloadColumnSpec {
+    pub fn header(&self) -> String {
+        match self {
+            WorkloadColumnSpec::Builtin(c) => c.display().to_string(),
+            WorkloadColumnSpec::Label { header, .. } => header.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkloadLabelColumn {
+    pub name: String,
+    pub key: String,
+    pub header: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkloadColumns {
+    columns: Vec<WorkloadColumnSpec>,
+}
+
+impl Default for WorkloadColumns {
+    fn default() -> Self {
+        WorkloadColumns::from_builtins(DEFAULT_WORKLOAD_COLUMNS.iter().copied())
+    }
+}
+
+impl WorkloadColumns {
+    pub fn new(columns: impl IntoIterator<Item = WorkloadColumnSpec>) -> Self {
+        WorkloadColumns {
+            columns: columns.into_iter().collect(),
+        }
+    }
+
+    pub fn from_builtins(columns: impl IntoIterator<Item = WorkloadColumn>) -> Self {
+        WorkloadColumns {
+            columns: columns
+                .into_iter()
+                .map(WorkloadColumnSpec::Builtin)
+                .collect(),
+        }
+    }
+
+    pub fn specs(&self) -> &[WorkloadColumnSpec] {
+        &self.columns
+    }
+
+    /// KIND と NAME が存在しない場合のみ挿入する (KIND を index 0、NAME を
+    /// その直後)。既存の列順は保持し、reorder はしない。
+    pub fn ensure_required(mut self) -> Self {
+        let has_kind = self
+            .columns
+            .iter()
+            .any(|s| matches!(s, WorkloadColumnSpec::Builtin(WorkloadColumn::Kind)));
+        if !has_kind {
+            self.columns
+                .insert(0, WorkloadColumnSpec::Builtin(WorkloadColumn::Kind));
+        }
+
+        let kind_pos = self
+            .columns
+            .iter()
+            .position(|s| matches!(s, WorkloadColumnSpec::Builtin(WorkloadColumn::Kind)))
+            .expect("Kind just ensured");
+        let has_name = self
+            .columns
+            .iter()
+            .any(|s| matches!(s, WorkloadColumnSpec::Builtin(WorkloadColumn::Name)));
+        if !has_name {
+            self.columns.insert(
+                kind_pos + 1,
+                WorkloadColumnSpec::Builtin(WorkloadColumn::Name),
+            );
+        }
+
+        self
+    }
+
+    pub fn dedup_columns(self) -> Self {
+        let mut unique: Vec<WorkloadColumnSpec> = Vec::new();
+        for spec in self.columns {
+            if !unique.contains(&spec) {
+                unique.push(spec);
+            }
+        }
+        WorkloadColumns { columns: unique }
+    }
+}
+
+pub const DEFAULT_WORKLOAD_COLUMNS: &[WorkloadColumn] = &[
+    WorkloadColumn::Kind,
+    WorkloadColumn::Name,
+    WorkloadColumn::Ready,
+    WorkloadColumn::UpToDate,
+    WorkloadColumn::Available,
+    WorkloadColumn::Images,
+    WorkloadColumn::Age,
+];
+
+#[derive(EnumIter, PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub enum WorkloadColumn {
+    Kind,
+    Name,
+    Ready,
+    UpToDate,
+    Available,
+    Images,
+    Age,
+}
+
+impl WorkloadColumn {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            WorkloadColumn::Kind => "Kind",
+            WorkloadColumn::Name => "Name",
+            WorkloadColumn::Ready => "Ready",
+            WorkloadColumn::UpToDate => "Up-to-date",
+            WorkloadColumn::Available => "Available",
+            WorkloadColumn::Images => "Images",
+            WorkloadColumn::Age => "Age",
+        }
+    }
+
+    pub const fn display(&self) -> &'static str {
+        match self {
+            WorkloadColumn::Kind => "KIND",
+            WorkloadColumn::Name => "NAME",
+            WorkloadColumn::Ready => "READY",
+            WorkloadColumn::UpToDate => "UP-TO-DATE",
+            WorkloadColumn::Available => "AVAILABLE",
+            WorkloadColumn::Images => "IMAGES",
+            WorkloadColumn::Age => "AGE",
+        }
+    }
+
+    /// Table APIのセルから取得する列かどうか
+    ///
+    /// それ以外の列は `includeObject=Object` で取得したオブジェクトから算出する
+    pub const fn is_table_cell(&self) -> bool {
+        matches!(self, WorkloadColumn::Name | WorkloadColumn::Age)
+    }
+
+    pub fn normalize_column(column: &str) -> String {
+        column.to_lowercase().replace([' ', '_', '-'], "")
+    }
+}
+
+#[derive(Debug)]
+pub struct WorkloadColumnParseError;
+
+impl std::fmt::Display for WorkloadColumnParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid WorkloadColumn string representation")
+    }
+}
+
+impl std::error::Error for WorkloadColumnParseError {}
+
+impl std::str::FromStr for WorkloadColumn {
+    type Err = WorkloadColumnParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::normalize_column(s).as_str() {
+            "kind" => Ok(WorkloadColumn::Kind),
+            "name" => Ok(WorkloadColumn::Name),
+            "ready" => Ok(WorkloadColumn::Ready),
+            "uptodate" => Ok(WorkloadColumn::UpToDate),
+            "available" => Ok(WorkloadColumn::Available),
+            "images" | "image" => Ok(WorkloadColumn::Images),
+            "age" => Ok(WorkloadColumn::Age),
+            _ => Err(WorkloadColumnParseError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn builtins(cols: &[WorkloadColumn]) -> Vec<WorkloadColumnSpec> {
+        cols.iter()
+            .copied()
+            .map(WorkloadColumnSpec::Builtin)
+            .collect()
+    }
+
+    #[test]
+    fn default_has_all_builtin_columns_in_order() {
+        let cols = WorkloadColumns::default();
+        assert_eq!(
+            cols.specs(),
+            builtins(&[
+                WorkloadColumn::Kind,
+                WorkloadColumn::Name,
+                WorkloadColumn::Ready,
+                WorkloadColumn::UpToDate,
+                WorkloadColumn::Available,
+                WorkloadColumn::Images,
+                WorkloadColumn::Age,
+            ])
+            .as_slice()
+        );
+    }
+
+    #[test]
+    fn ensure_required_inserts_both_when_absent() {
+        let cols = WorkloadColumns::from_builtins([WorkloadColumn::Age]).ensure_required();
+        assert_eq!(
+            cols.specs(),
+            builtins(&[
+                WorkloadColumn::Kind,
+                WorkloadColumn::Name,
+                WorkloadColumn::Age
+            ])
+            .as_slice()
+        );
+    }
+
+    #[test]
+    fn ensure_required_inserts_name_after_existing_kind() {
+        let cols = WorkloadColumns::from_builtins([WorkloadColumn::Ready, WorkloadColumn::Kind])
+            .ensure_required();
+        assert_eq!(
+            cols.specs(),
+            builtins(&[
+                WorkloadColumn::Ready,
+                WorkloadColumn::Kind,
+                WorkloadColumn::Name
+            ])
+            .as_slice()
+        );
+    }
+
+    #[test]
+    fn ensure_required_prepends_to_label_only_input() {
+        let label = WorkloadColumnSpec::Label {
+            key: "app.kubernetes.io/name".to_string(),
+            header: "APP".to_string(),
+        };
+        let cols = WorkloadColumns::new([label.clone()]).ensure_required();
+        assert_eq!(
+            cols.specs(),
+            &[
+                WorkloadColumnSpec::Builtin(WorkloadColumn::Kind),
+                WorkloadColumnSpec::Builtin(WorkloadColumn::Name),
+                label,
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_columns_removes_duplicates_preserving_first() {
+        let cols = WorkloadColumns::new([
+            WorkloadColumnSpec::Builtin(WorkloadColumn::Kind),
+            WorkloadColumnSpec::Builtin(WorkloadColumn::Name),
+            WorkloadColumnSpec::Builtin(WorkloadColumn::Kind),
+        ])
+        .dedup_columns();
+        assert_eq!(
+            cols.specs(),
+            builtins(&[WorkloadColumn::Kind, WorkloadColumn::Name]).as_slice()
+        );
+    }
+
+    #[test]
+    fn from_str_accepts_normalized_forms() {
+        use std::str::FromStr;
+        assert!(matches!(
+            WorkloadColumn::from_str("UP-TO-DATE"),
+            Ok(WorkloadColumn::UpToDate)
+        ));
+        assert!(matches!(
+            WorkloadColumn::from_str("image"),
+            Ok(WorkloadColumn::Images)
+        ));
+        assert!(matches!(
+            WorkloadColumn::from_str("Ready"),
+            Ok(WorkloadColumn::Ready)
+        ));
+        assert!(WorkloadColumn::from_str("bogus").is_err());
+    }
+
+    #[test]
+    fn only_name_and_age_are_table_cells() {
+        use strum::IntoEnumIterator;
+        let from_cells: Vec<WorkloadColumn> = WorkloadColumn::iter()
+            .filter(|c| c.is_table_cell())
+            .collect();
+        assert_eq!(from_cells, vec![WorkloadColumn::Name, WorkloadColumn::Age]);
+    }
+}
@@ -0,0 +1,65 @@
+mod parser;
+
+use crossbeam::channel::Sender;
+
+use crate::{
+    features::{
+        component_id::WORKLOAD_FILTER_HELP_DIALOG_ID,
+        workload::{message::WorkloadMessage, WorkloadLabelColumn},
+    },
+    message::Message,
+    ui::{
+        widget::{
+            ApplyStrategy,
+            OnFilterApply,
+            OnFilterCancel,
+            TableFilterApplicator,
+            TableFilterParser,
+        },
+        Window,
+    },
+};
+
+pub use parser::parse_workload_filter;
+
+pub fn workload_filter_applicator(
+    label_registry: Vec<WorkloadLabelColumn>,
+    tx: Sender<Message>,
+) -> TableFilterApplicator {
+    let parser: TableFilterParser =
+        (move |input: &str| parse_workload_filter(input, &label_registry)).into();
+
+    let tx_apply = tx.clone();
+    let tx_cancel = tx;
+
+    let on_apply: OnFilterApply = (move |predicate: &crate::ui::widget::TableFilterPredicate,
+                                         _window: &mut Window| {
+        tx_apply
+            .send(WorkloadMessage::Filter(predicate.label_selector.clone()).into())
+            .expect("Failed to send WorkloadMessage::Filter");
+    })
+    .into();
+
+    let on_cancel: OnFilterCancel = (move |_window: &mut Window| {
+        tx_cancel
+            .send(WorkloadMessage::Filter(None).into())
+            .expect("Failed to send WorkloadMessage::Filter(None) on cancel");
+    })
+    .into();
+
+    TableFilterApplicator::new(parser, ApplyStrategy::EnterToConfirm)
+        .with_help_dialog(WORKLOAD_FILTER_HELP_DIALOG_ID)
+        .with_on_apply(on_apply)
+        .with_on_cancel(on_cancel)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applicator_constructs_without_panic() {
+        let (tx, _rx) = crossbeam::channel::bounded(1);
+        let _ = workload_filter_applicator(Vec::new(), tx);
+    }
+}
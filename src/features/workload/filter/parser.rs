@@ -0,0 +1,103 @@
+//! Workload filter parser.
+//!
+//! Delegates tokenization/quoting/predicate-building to the shared
+//! `parse_table_filter`. As in the Network tab, `namespace:` returns a
+//! guidance message, other unknown columns return `unknown column '<x>'`, and
+//! builtin `WorkloadColumn`s plus registered label columns are accepted.
+
+use std::collections::HashSet;
+
+use strum::IntoEnumIterator;
+
+use crate::{
+    features::workload::{WorkloadColumn, WorkloadLabelColumn},
+    ui::widget::{normalize_column_name, parse_table_filter, TableFilterPredicate},
+};
+
+fn valid_columns(label_registry: &[WorkloadLabelColumn]) -> HashSet<String> {
+    let mut set: HashSet<String> = WorkloadColumn::iter()
+        .map(|c| normalize_column_name(c.display()))
+        .collect();
+    for lc in label_registry {
+        set.insert(normalize_column_name(&lc.header));
+    }
+    set
+}
+
+pub fn parse_workload_filter(
+    input: &str,
+    label_registry: &[WorkloadLabelColumn],
+) -> Result<TableFilterPredicate, String> {
+    let valid = valid_columns(label_registry);
+    parse_table_filter(input, |column| {
+        let normalized = normalize_column_name(column);
+        if normalized == "namespace" {
+            return Err(
+                "namespace is selected via the namespace selector, not the filter".to_string(),
+            );
+        }
+        if valid.contains(&normalized) {
+            Ok(())
+        } else {
+            Err(format!("unknown column '{}'", column))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn no_label_cols() -> Vec<WorkloadLabelColumn> {
+        Vec::new()
+    }
+
+    #[test]
+    fn bare_value_becomes_name_include() {
+        let p = parse_workload_filter("web", &no_label_cols()).unwrap();
+        let patterns = p.column_includes.get("name").expect("name column");
+        assert!(patterns[0].is_match("web-frontend"));
+    }
+
+    #[test]
+    fn builtin_columns_are_accepted() {
+        let p = parse_workload_filter("kind:Deployment !ready:0/ up-to-date:1", &no_label_cols())
+            .unwrap();
+        assert!(p.column_includes.contains_key("kind"));
+        assert!(p.column_includes.contains_key("uptodate"));
+        assert!(p.column_excludes.contains_key("ready"));
+    }
+
+    #[test]
+    fn label_selector_is_captured() {
+        let p = parse_workload_filter("label:app=nginx", &no_label_cols()).unwrap();
+        assert_eq!(p.label_selector.as_deref(), Some("app=nginx"));
+    }
+
+    #[test]
+    fn unknown_column_produces_parse_error() {
+        let err = parse_workload_filter("status:Running", &no_label_cols()).unwrap_err();
+        assert!(err.contains("unknown column") && err.contains("status"));
+    }
+
+    #[test]
+    fn namespace_returns_guidance_message() {
+        let err = parse_workload_filter("namespace:default", &no_label_cols()).unwrap_err();
+        assert_eq!(
+            err,
+            "namespace is selected via the namespace selector, not the filter"
+        );
+    }
+
+    #[test]
+    fn registered_label_column_header_is_accepted() {
+        let regs = vec![WorkloadLabelColumn {
+            name: "team".to_string(),
+            key: "example.com/team".to_string(),
+            header: "TEAM".to_string(),
+        }];
+        let p = parse_workload_filter("team:payments", &regs).unwrap();
+        assert!(p.column_includes.contains_key("team"));
+    }
+}
@@ -0,0 +1,6 @@
+mod description;
+mod status;
+mod workload;
+
+pub use description::*;
+pub use workload::*;
@@ -0,0 +1,672 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use crossbeam::channel::Sender;
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet},
+        batch::v1::{CronJob, Job},
+        core::v1::Pod,
+    },
+    apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta},
+    List,
+};
+use kube::Resource;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::de::DeserializeOwned;
+use serde_json::Value as JsonValue;
+use serde_yaml::{Mapping, Value as YamlValue};
+
+use crate::{
+    features::workload::message::{WorkloadRequest, WorkloadRequestTargetParams, WorkloadResponse},
+    kube::KubeClientRequest,
+    logger,
+    message::Message,
+    workers::kube::InfiniteWorker,
+};
+
+use super::status::WorkloadStatus;
+
+const INTERVAL: u64 = 3;
+
+/// ReplicaSetのリビジョンを示すアノテーション
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+/// Jobが作成したPodに付くラベル
+const JOB_NAME_LABEL: &str = "job-name";
+
+#[derive(Clone)]
+pub struct WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    tx: Sender<Message>,
+    client: C,
+    req: WorkloadRequest,
+}
+
+impl<C> WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    pub fn new(tx: Sender<Message>, client: C, req: WorkloadRequest) -> Self {
+        Self { tx, client, req }
+    }
+}
+
+#[async_trait]
+impl<C> InfiniteWorker for WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    async fn run(&self) {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(INTERVAL));
+
+        loop {
+            interval.tick().await;
+
+            let fetched_data = self.fetch().await;
+
+            if let Err(e) = &fetched_data {
+                logger!(error, "Failed to fetch workload description: {:?}", e);
+            }
+
+            if let Err(e) = self.tx.send(WorkloadResponse::Yaml(fetched_data).into()) {
+                logger!(error, "Failed to send WorkloadResponse::Yaml: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+impl<C> WorkloadDescriptionWorker<C>
+where
+    C: KubeClientRequest,
+{
+    /// ワークロードの状態・rolloutのconditions・管理しているReplicaSet (Job) とPodをYAMLで返す
+    async fn fetch(&self) -> Result<Vec<String>> {
+        let mut root = match &self.req {
+            WorkloadRequest::Deployment(_) => {
+                let deployment: Deployment = self.fetch_object().await?;
+                let mut root = self.summary(&deployment)?;
+
+                let selector = deployment
+                    .spec
+                    .as_ref()
+                    .and_then(|spec| selector_query(&spec.selector));
+
+                if let Some(selector) = &selector {
+                    let replica_sets: List<ReplicaSet> = self.fetch_list(Some(selector)).await?;
+
+                    let replica_sets: Vec<YamlValue> = replica_sets
+                        .items
+                        .iter()
+                        .filter(|rs| is_owned_by(&rs.metadata, &deployment.metadata))
+                        .map(replica_set_value)
+                        .collect();
+
+                    if !replica_sets.is_empty() {
+                        root.insert("replicaSets".into(), replica_sets.into());
+                    }
+                }
+
+                self.insert_pods(&mut root, selector).await?;
+
+                root
+            }
+            WorkloadRequest::StatefulSet(_) => {
+                let statefulset: StatefulSet = self.fetch_object().await?;
+                let mut root = self.summary(&statefulset)?;
+
+                let selector = statefulset
+                    .spec
+                    .as_ref()
+                    .and_then(|spec| selector_query(&spec.selector));
+
+                self.insert_pods(&mut root, selector).await?;
+
+                root
+            }
+            WorkloadRequest::DaemonSet(_) => {
+                let daemonset: DaemonSet = self.fetch_object().await?;
+                let mut root = self.summary(&daemonset)?;
+
+                let selector = daemonset
+                    .spec
+                    .as_ref()
+                    .and_then(|spec| selector_query(&spec.selector));
+
+                self.insert_pods(&mut root, selector).await?;
+
+                root
+            }
+            WorkloadRequest::Job(_) => {
+                let job: Job = self.fetch_object().await?;
+                let mut root = self.summary(&job)?;
+
+                let selector = job
+                    .spec
+                    .as_ref()
+                    .and_then(|spec| spec.selector.as_ref())
+                    .and_then(selector_query);
+
+                self.insert_pods(&mut root, selector).await?;
+
+                root
+            }
+            WorkloadRequest::CronJob(_) => {
+                let cronjob: CronJob = self.fetch_object().await?;
+                let mut root = self.summary(&cronjob)?;
+
+                let jobs: List<Job> = self.fetch_list(None).await?;
+
+                let jobs: Vec<&Job> = jobs
+                    .items
+                    .iter()
+                    .filter(|job| is_owned_by(&job.metadata, &cronjob.metadata))
+                    .collect();
+
+                let selector = (!jobs.is_empty()).then(|| {
+                    format!(
+                        "{} in ({})",
+                        JOB_NAME_LABEL,
+                        jobs.iter()
+                            .filter_map(|job| job.metadata.name.as_deref())
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    )
+                });
+
+                if !jobs.is_empty() {
+                    let jobs: Vec<YamlValue> = jobs.into_iter().map(job_value).collect();
+                    root.insert("jobs".into(), jobs.into());
+                }
+
+                self.insert_pods(&mut root, selector).await?;
+
+                root
+            }
+        };
+
+        if root.get("conditions").is_some_and(YamlValue::is_null) {
+            root.remove("conditions");
+        }
+
+        Ok(serde_yaml::to_string(&root)?
+            .lines()
+            .map(ToString::to_string)
+            .collect())
+    }
+
+    async fn fetch_object<K>(&self) -> Result<K>
+    where
+        K: Resource<DynamicType = ()> + DeserializeOwned + 'static,
+    {
+        let WorkloadRequestTargetParams { name, namespace } = self.req.data();
+
+        let url = format!("{}/{}", K::url_path(&(), Some(namespace)), name);
+
+        self.client.request(&url).await
+    }
+
+    async fn fetch_list<K>(&self, selector: Option<&str>) -> Result<List<K>>
+    where
+        K: Resource<DynamicType = ()> + k8s_openapi::ListableResource + DeserializeOwned + 'static,
+    {
+        let namespace = &self.req.data().namespace;
+
+        let url = match selector {
+            Some(selector) => {
+                format!(
+                    "{}?labelSelector={}",
+                    K::url_path(&(), Some(namespace)),
+                    utf8_percent_encode(selector, NON_ALPHANUMERIC)
+                )
+            }
+            None => K::url_path(&(), Some(namespace)),
+        };
+
+        self.client.request(&url).await
+    }
+
+    /// 一覧と同じ状態の要約とconditions
+    fn summary<K>(&self, object: &K) -> Result<Mapping>
+    where
+        K: Resource<DynamicType = ()> + serde::Serialize,
+    {
+        let WorkloadRequestTargetParams { name, namespace } = self.req.data();
+
+        let kind = K::kind(&());
+        let value = serde_json::to_value(object)?;
+        let status = WorkloadStatus::new(&kind, &value);
+
+        let mut summary = Mapping::new();
+        summary.insert("kind".into(), kind.to_string().into());
+        summary.insert("name".into(), name.clone().into());
+        summary.insert("namespace".into(), namespace.clone().into());
+        summary.insert("ready".into(), status.ready.into());
+        summary.insert("upToDate".into(), status.up_to_date.into());
+        summary.insert("available".into(), status.available.into());
+        summary.insert("images".into(), status.images.into());
+
+        let mut root = Mapping::new();
+        root.insert("workload".into(), summary.into());
+        root.insert(
+            "conditions".into(),
+            serde_yaml::to_value(
+                value
+                    .pointer("/status/conditions")
+                    .unwrap_or(&JsonValue::Null),
+            )?,
+        );
+
+        Ok(root)
+    }
+
+    async fn insert_pods(&self, root: &mut Mapping, selector: Option<String>) -> Result<()> {
+        let Some(selector) = selector else {
+            return Ok(());
+        };
+
+        let pods: List<Pod> = self.fetch_list(Some(&selector)).await?;
+
+        if !pods.items.is_empty() {
+            let pods: Vec<YamlValue> = pods.items.iter().map(pod_value).collect();
+            root.insert("pods".into(), pods.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// LabelSelectorをlabelSelectorクエリの文字列に変換する
+///
+/// 条件が1つもない場合は `None`
+fn selector_query(selector: &LabelSelector) -> Option<String> {
+    let mut terms: Vec<String> = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect();
+
+    for expr in selector.match_expressions.iter().flatten() {
+        let values = expr.values.as_deref().unwrap_or_default().join(",");
+
+        match expr.operator.as_str() {
+            "In" => terms.push(format!("{} in ({})", expr.key, values)),
+            "NotIn" => terms.push(format!("{} notin ({})", expr.key, values)),
+            "Exists" => terms.push(expr.key.clone()),
+            "DoesNotExist" => terms.push(format!("!{}", expr.key)),
+            _ => {}
+        }
+    }
+
+    (!terms.is_empty()).then(|| terms.join(","))
+}
+
+fn is_owned_by(metadata: &ObjectMeta, owner: &ObjectMeta) -> bool {
+    let Some(uid) = owner.uid.as_deref() else {
+        return false;
+    };
+
+    metadata
+        .owner_references
+        .iter()
+        .flatten()
+        .any(|owner| owner.uid == uid)
+}
+
+fn replica_set_value(rs: &ReplicaSet) -> YamlValue {
+    let desired = rs.spec.as_ref().and_then(|spec| spec.replicas).unwrap_or(0);
+    let ready = rs
+        .status
+        .as_ref()
+        .and_then(|status| status.ready_replicas)
+        .unwrap_or(0);
+
+    let mut value = Mapping::new();
+    value.insert(
+        "name".into(),
+        rs.metadata.name.clone().unwrap_or_default().into(),
+    );
+    if let Some(revision) = rs
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get(REVISION_ANNOTATION))
+    {
+        value.insert("revision".into(), revision.clone().into());
+    }
+    value.insert("ready".into(), format!("{}/{}", ready, desired).into());
+
+    value.into()
+}
+
+fn job_value(job: &Job) -> YamlValue {
+    let completions = job
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.completions)
+        .unwrap_or(1);
+    let status = job.status.as_ref();
+    let succeeded = status.and_then(|s| s.succeeded).unwrap_or(0);
+
+    let mut value = Mapping::new();
+    value.insert(
+        "name".into(),
+        job.metadata.name.clone().unwrap_or_default().into(),
+    );
+    value.insert(
+        "completions".into(),
+        format!("{}/{}", succeeded, completions).into(),
+    );
+    if let Some(start_time) = status.and_then(|s| s.start_time.as_ref()) {
+        value.insert("startTime".into(), start_time.0.to_string().into());
+    }
+
+    value.into()
+}
+
+fn pod_value(pod: &Pod) -> YamlValue {
+    let containers = pod
+        .spec
+        .as_ref()
+        .map(|spec| spec.containers.len())
+        .unwrap_or(0);
+    let status = pod.status.as_ref();
+    let container_statuses = status
+        .and_then(|s| s.container_statuses.as_deref())
+        .unwrap_or_default();
+
+    let ready = container_statuses.iter().filter(|c| c.ready).count();
+    let restarts: i32 = container_statuses.iter().map(|c| c.restart_count).sum();
+
+    let mut value = Mapping::new();
+    value.insert(
+        "name".into(),
+        pod.metadata.name.clone().unwrap_or_default().into(),
+    );
+    value.insert("ready".into(), format!("{}/{}", ready, containers).into());
+    value.insert(
+        "status".into(),
+        status
+            .and_then(|s| s.phase.clone())
+            .unwrap_or_default()
+            .into(),
+    );
+    value.insert("restarts".into(), restarts.into());
+
+    value.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::bail;
+    use crossbeam::channel::{bounded, Receiver};
+    use indoc::indoc;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    fn params() -> WorkloadRequestTargetParams {
+        WorkloadRequestTargetParams {
+            name: "web".into(),
+            namespace: "default".into(),
+        }
+    }
+
+    fn deployment() -> Deployment {
+        serde_yaml::from_str(indoc! {"
+            metadata:
+              name: web
+              namespace: default
+              uid: deploy-uid
+            spec:
+              replicas: 2
+              selector:
+                matchLabels:
+                  app: web
+              template:
+                spec:
+                  containers:
+                  - name: app
+                    image: nginx
+            status:
+              readyReplicas: 2
+              updatedReplicas: 2
+              availableReplicas: 2
+              conditions:
+              - type: Available
+                status: 'True'
+                reason: MinimumReplicasAvailable
+        "})
+        .unwrap()
+    }
+
+    fn replica_sets() -> List<ReplicaSet> {
+        serde_yaml::from_str(indoc! {"
+            items:
+            - metadata:
+                name: web-6d4cf56db6
+                annotations:
+                  deployment.kubernetes.io/revision: '2'
+                ownerReferences:
+                - apiVersion: apps/v1
+                  kind: Deployment
+                  name: web
+                  uid: deploy-uid
+              spec:
+                replicas: 2
+                selector:
+                  matchLabels:
+                    app: web
+              status:
+                replicas: 2
+                readyReplicas: 2
+            - metadata:
+                name: other-5f8d7c9b4
+                ownerReferences:
+                - apiVersion: apps/v1
+                  kind: Deployment
+                  name: other
+                  uid: other-uid
+              spec:
+                selector:
+                  matchLabels:
+                    app: web
+              status:
+                replicas: 0
+        "})
+        .unwrap()
+    }
+
+    fn pods() -> List<Pod> {
+        serde_yaml::from_str(indoc! {"
+            items:
+            - metadata:
+                name: web-6d4cf56db6-abcde
+              spec:
+                containers:
+                - name: app
+                  image: nginx
+              status:
+                phase: Running
+                containerStatuses:
+                - name: app
+                  image: nginx
+                  imageID: ''
+                  ready: true
+                  restartCount: 1
+        "})
+        .unwrap()
+    }
+
+    #[test]
+    fn label_selectorをクエリ文字列に変換する() {
+        let selector: LabelSelector = serde_yaml::from_str(indoc! {"
+            matchLabels:
+              app: web
+            matchExpressions:
+            - key: tier
+              operator: In
+              values: [frontend, backend]
+            - key: canary
+              operator: DoesNotExist
+        "})
+        .unwrap();
+
+        assert_eq!(
+            selector_query(&selector).as_deref(),
+            Some("app=web,tier in (frontend,backend),!canary")
+        );
+        assert_eq!(selector_query(&LabelSelector::default()), None);
+    }
+
+    #[tokio::test]
+    async fn deploymentの状態と管理しているreplicasetとpodを返す() {
+        let (tx, _rx): (Sender<Message>, Receiver<Message>) = bounded(1);
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    Deployment,
+                    eq("/apis/apps/v1/namespaces/default/deployments/web"),
+                    Ok(deployment())
+                ),
+                (
+                    List<ReplicaSet>,
+                    eq("/apis/apps/v1/namespaces/default/replicasets?labelSelector=app%3Dweb"),
+                    Ok(replica_sets())
+                ),
+                (
+                    List<Pod>,
+                    eq("/api/v1/namespaces/default/pods?labelSelector=app%3Dweb"),
+                    Ok(pods())
+                )
+            ]
+        );
+
+        let worker =
+            WorkloadDescriptionWorker::new(tx, client, WorkloadRequest::Deployment(params()));
+
+        let actual = worker.fetch().await.unwrap();
+
+        let expected: Vec<String> = indoc! {"
+            workload:
+              kind: Deployment
+              name: web
+              namespace: default
+              ready: 2/2
+              upToDate: '2'
+              available: '2'
+              images: nginx
+            conditions:
+            - reason: MinimumReplicasAvailable
+              status: 'True'
+              type: Available
+            replicaSets:
+            - name: web-6d4cf56db6
+              revision: '2'
+              ready: 2/2
+            pods:
+            - name: web-6d4cf56db6-abcde
+              ready: 1/1
+              status: Running
+              restarts: 1
+        "}
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    fn cronjob() -> CronJob {
+        serde_yaml::from_str(indoc! {"
+            metadata:
+              name: web
+              namespace: default
+              uid: cronjob-uid
+            spec:
+              schedule: '*/5 * * * *'
+              jobTemplate:
+                spec:
+                  template:
+                    spec:
+                      containers:
+                      - name: batch
+                        image: busybox
+        "})
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn 実行中のjobがないcronjobはjobとpodを含めない() {
+        let (tx, _rx): (Sender<Message>, Receiver<Message>) = bounded(1);
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [
+                (
+                    CronJob,
+                    eq("/apis/batch/v1/namespaces/default/cronjobs/web"),
+                    Ok(cronjob())
+                ),
+                (
+                    List<Job>,
+                    eq("/apis/batch/v1/namespaces/default/jobs"),
+                    Ok(List::<Job>::default())
+                )
+            ]
+        );
+
+        let worker = WorkloadDescriptionWorker::new(tx, client, WorkloadRequest::CronJob(params()));
+
+        let actual = worker.fetch().await.unwrap();
+
+        let expected: Vec<String> = indoc! {"
+            workload:
+              kind: CronJob
+              name: web
+              namespace: default
+              ready: '-'
+              upToDate: '-'
+              available: '-'
+              images: busybox
+        "}
+        .lines()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn 取得に失敗したときerrを返す() {
+        let (tx, _rx): (Sender<Message>, Receiver<Message>) = bounded(1);
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            [(
+                StatefulSet,
+                eq("/apis/apps/v1/namespaces/default/statefulsets/web"),
+                bail!("not found")
+            )]
+        );
+
+        let worker =
+            WorkloadDescriptionWorker::new(tx, client, WorkloadRequest::StatefulSet(params()));
+
+        assert!(worker.fetch().await.is_err());
+    }
+}
@@ -0,0 +1,283 @@
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
+    },
+    Resource as _,
+};
+use serde_json::Value;
+
+/// 値を持たない列の表示
+const NONE: &str = "-";
+
+/// テーブルに表示するワークロードの状態
+///
+/// Table APIの列はリソースごとに異なるため、`includeObject=Object` で
+/// 取得したオブジェクトから算出する
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WorkloadStatus {
+    /// ready/desired
+    pub ready: String,
+    pub up_to_date: String,
+    pub available: String,
+    pub images: String,
+}
+
+impl WorkloadStatus {
+    pub fn new(kind: &str, object: &Value) -> Self {
+        let images = images(kind, object);
+
+        match kind {
+            Deployment::KIND | StatefulSet::KIND => {
+                let desired = int(object, "/spec/replicas").unwrap_or(1);
+
+                Self {
+                    ready: ratio(int(object, "/status/readyReplicas"), desired),
+                    up_to_date: count(int(object, "/status/updatedReplicas")),
+                    available: count(int(object, "/status/availableReplicas")),
+                    images,
+                }
+            }
+            DaemonSet::KIND => {
+                let desired = int(object, "/status/desiredNumberScheduled").unwrap_or(0);
+
+                Self {
+                    ready: ratio(int(object, "/status/numberReady"), desired),
+                    up_to_date: count(int(object, "/status/updatedNumberScheduled")),
+                    available: count(int(object, "/status/numberAvailable")),
+                    images,
+                }
+            }
+            Job::KIND => {
+                let completions = int(object, "/spec/completions").unwrap_or(1);
+
+                Self {
+                    ready: ratio(int(object, "/status/succeeded"), completions),
+                    up_to_date: NONE.to_string(),
+                    available: NONE.to_string(),
+                    images,
+                }
+            }
+            _ => {
+                Self {
+                    ready: NONE.to_string(),
+                    up_to_date: NONE.to_string(),
+                    available: NONE.to_string(),
+                    images,
+                }
+            }
+        }
+    }
+}
+
+/// ログクエリの対象
+///
+/// CronJobは実行中のJobのうち最後に作成されたものを対象にする。実行中のJobがなければ `None`
+pub fn log_query(kind: &str, name: &str, object: &Value) -> Option<String> {
+    match kind {
+        Deployment::KIND => Some(format!("deploy/{}", name)),
+        StatefulSet::KIND => Some(format!("sts/{}", name)),
+        DaemonSet::KIND => Some(format!("ds/{}", name)),
+        Job::KIND => Some(format!("job/{}", name)),
+        CronJob::KIND => {
+            object
+                .pointer("/status/active")
+                .and_then(Value::as_array)
+                .and_then(|active| active.last())
+                .and_then(|job| job.get("name"))
+                .and_then(Value::as_str)
+                .map(|job| format!("job/{}", job))
+        }
+        _ => None,
+    }
+}
+
+fn int(object: &Value, pointer: &str) -> Option<i64> {
+    object.pointer(pointer).and_then(Value::as_i64)
+}
+
+fn ratio(current: Option<i64>, desired: i64) -> String {
+    format!("{}/{}", current.unwrap_or(0), desired)
+}
+
+fn count(value: Option<i64>) -> String {
+    value.unwrap_or(0).to_string()
+}
+
+fn images(kind: &str, object: &Value) -> String {
+    let pointer = if kind == CronJob::KIND {
+        "/spec/jobTemplate/spec/template/spec/containers"
+    } else {
+        "/spec/template/spec/containers"
+    };
+
+    object
+        .pointer(pointer)
+        .and_then(Value::as_array)
+        .map(|containers| {
+            containers
+                .iter()
+                .filter_map(|c| c.get("image").and_then(Value::as_str))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn deploymentはレプリカ数から状態を算出する() {
+        let object = json!({
+            "spec": {
+                "replicas": 3,
+                "template": {
+                    "spec": {
+                        "containers": [
+                            { "name": "app", "image": "nginx:1.27" },
+                            { "name": "sidecar", "image": "envoy:1.30" }
+                        ]
+                    }
+                }
+            },
+            "status": {
+                "readyReplicas": 2,
+                "updatedReplicas": 3,
+                "availableReplicas": 2
+            }
+        });
+
+        let actual = WorkloadStatus::new("Deployment", &object);
+
+        assert_eq!(
+            actual,
+            WorkloadStatus {
+                ready: "2/3".into(),
+                up_to_date: "3".into(),
+                available: "2".into(),
+                images: "nginx:1.27,envoy:1.30".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn statusがないときは0として扱う() {
+        let object = json!({ "spec": { "replicas": 1 } });
+
+        let actual = WorkloadStatus::new("StatefulSet", &object);
+
+        assert_eq!(
+            actual,
+            WorkloadStatus {
+                ready: "0/1".into(),
+                up_to_date: "0".into(),
+                available: "0".into(),
+                images: "".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn daemonsetはスケジュール数から状態を算出する() {
+        let object = json!({
+            "status": {
+                "desiredNumberScheduled": 4,
+                "numberReady": 4,
+                "updatedNumberScheduled": 3,
+                "numberAvailable": 4
+            }
+        });
+
+        let actual = WorkloadStatus::new("DaemonSet", &object);
+
+        assert_eq!(actual.ready, "4/4");
+        assert_eq!(actual.up_to_date, "3");
+        assert_eq!(actual.available, "4");
+    }
+
+    #[test]
+    fn jobは完了数を表示する() {
+        let object = json!({
+            "spec": { "completions": 5 },
+            "status": { "succeeded": 2 }
+        });
+
+        let actual = WorkloadStatus::new("Job", &object);
+
+        assert_eq!(actual.ready, "2/5");
+        assert_eq!(actual.up_to_date, "-");
+        assert_eq!(actual.available, "-");
+    }
+
+    #[test]
+    fn cronjobはjob_templateのイメージを表示する() {
+        let object = json!({
+            "spec": {
+                "jobTemplate": {
+                    "spec": {
+                        "template": {
+                            "spec": { "containers": [{ "name": "batch", "image": "busybox" }] }
+                        }
+                    }
+                }
+            }
+        });
+
+        let actual = WorkloadStatus::new("CronJob", &object);
+
+        assert_eq!(
+            actual,
+            WorkloadStatus {
+                ready: "-".into(),
+                up_to_date: "-".into(),
+                available: "-".into(),
+                images: "busybox".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn ログクエリはkindごとのリソース指定になる() {
+        let object = json!({});
+
+        assert_eq!(
+            log_query("Deployment", "web", &object).as_deref(),
+            Some("deploy/web")
+        );
+        assert_eq!(
+            log_query("StatefulSet", "db", &object).as_deref(),
+            Some("sts/db")
+        );
+        assert_eq!(
+            log_query("DaemonSet", "agent", &object).as_deref(),
+            Some("ds/agent")
+        );
+        assert_eq!(
+            log_query("Job", "migrate", &object).as_deref(),
+            Some("job/migrate")
+        );
+    }
+
+    #[test]
+    fn cronjobのログクエリは最後に作成された実行中のjobになる() {
+        let object = json!({
+            "status": {
+                "active": [
+                    { "kind": "Job", "name": "backup-28000000" },
+                    { "kind": "Job", "name": "backup-28000060" }
+                ]
+            }
+        });
+
+        assert_eq!(
+            log_query("CronJob", "backup", &object).as_deref(),
+            Some("job/backup-28000060")
+        );
+        assert_eq!(log_query("CronJob", "backup", &json!({})), None);
+    }
+}
@@ -0,0 +1,450 @@
+use std::{collections::BTreeMap, time};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use crossbeam::channel::Sender;
+use futures::future::{join_all, try_join_all};
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
+    },
+    Resource,
+};
+use kube::Resource as _;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde_json::Value as JsonValue;
+
+use crate::{
+    features::workload::{
+        message::WorkloadResponse,
+        WorkloadColumn,
+        WorkloadColumnSpec,
+        WorkloadColumns,
+    },
+    kube::{
+        apis::v1_table::TableRow,
+        table::{insert_ns, KubeTable, KubeTableRow},
+        KubeClient,
+        KubeClientRequest as _,
+    },
+    logger,
+    message::Message,
+    workers::kube::{
+        InfiniteWorker,
+        SharedTargetNamespaces,
+        SharedWorkloadColumns,
+        SharedWorkloadFilter,
+    },
+};
+
+use super::status::{log_query, WorkloadStatus};
+
+#[derive(Debug, Default, Clone)]
+struct WorkloadTableRow {
+    namespace: String,
+    kind: String,
+    name: String,
+    log_query: Option<String>,
+    cells: Vec<String>,
+}
+
+impl WorkloadTableRow {
+    fn to_kube_table_row(&self, is_insert_ns: bool) -> KubeTableRow {
+        let mut row = self.cells.clone();
+        if is_insert_ns {
+            row.insert(0, self.namespace.clone());
+        }
+
+        let mut metadata = BTreeMap::from([("kind".to_string(), self.kind.clone())]);
+
+        if let Some(query) = &self.log_query {
+            metadata.insert("log_query".to_string(), query.clone());
+        }
+
+        KubeTableRow {
+            namespace: self.namespace.clone(),
+            name: self.name.clone(),
+            metadata: Some(metadata),
+            row,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum TargetResource {
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    Job,
+    CronJob,
+}
+
+const TARGET_RESOURCES: [TargetResource; 5] = [
+    TargetResource::Deployment,
+    TargetResource::StatefulSet,
+    TargetResource::DaemonSet,
+    TargetResource::Job,
+    TargetResource::CronJob,
+];
+
+impl TargetResource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Deployment => Deployment::KIND,
+            Self::StatefulSet => StatefulSet::KIND,
+            Self::DaemonSet => DaemonSet::KIND,
+            Self::Job => Job::KIND,
+            Self::CronJob => CronJob::KIND,
+        }
+    }
+
+    fn url_path(&self, ns: &str) -> String {
+        match self {
+            Self::Deployment => Deployment::url_path(&Default::default(), Some(ns)),
+            Self::StatefulSet => StatefulSet::url_path(&Default::default(), Some(ns)),
+            Self::DaemonSet => DaemonSet::url_path(&Default::default(), Some(ns)),
+            Self::Job => Job::url_path(&Default::default(), Some(ns)),
+            Self::CronJob => CronJob::url_path(&Default::default(), Some(ns)),
+        }
+    }
+
+    async fn fetch_table(
+        &self,
+        client: &KubeClient,
+        ns: &str,
+        label_selector: Option<&str>,
+    ) -> Result<crate::kube::apis::v1_table::Table> {
+        let path = workload_table_path(&self.url_path(ns), label_selector);
+
+        client.request_watched_table(&path).await.with_context(|| {
+            format!(
+                "Failed to fetch table: kind={} namespace={}",
+                self.as_str(),
+                ns
+            )
+        })
+    }
+}
+
+/// READY などの列はオブジェクトから算出するため、常に `includeObject=Object` を付ける
+fn workload_table_path(base: &str, label_selector: Option<&str>) -> String {
+    let mut queries = Vec::new();
+
+    if let Some(sel) = label_selector.filter(|s| !s.is_empty()) {
+        queries.push(format!(
+            "labelSelector={}",
+            utf8_percent_encode(sel, NON_ALPHANUMERIC)
+        ));
+    }
+
+    queries.push("includeObject=Object".to_string());
+
+    format!("{}?{}", base, queries.join("&"))
+}
+
+#[derive(Clone)]
+pub struct WorkloadPoller {
+    tx: Sender<Message>,
+    shared_target_namespaces: SharedTargetNamespaces,
+    shared_workload_columns: SharedWorkloadColumns,
+    shared_workload_filter: SharedWorkloadFilter,
+    kube_client: KubeClient,
+}
+
+impl WorkloadPoller {
+    pub fn new(
+        tx: Sender<Message>,
+        shared_target_namespaces: SharedTargetNamespaces,
+        shared_workload_columns: SharedWorkloadColumns,
+        shared_workload_filter: SharedWorkloadFilter,
+        kube_client: KubeClient,
+    ) -> Self {
+        Self {
+            tx,
+            shared_target_namespaces,
+            shared_workload_columns,
+            shared_workload_filter,
+            kube_client,
+        }
+    }
+}
+
+#[async_trait()]
+impl InfiniteWorker for WorkloadPoller {
+    async fn run(&self) {
+        let mut interval = tokio::time::interval(time::Duration::from_secs(1));
+
+        let tx = &self.tx;
+
+        loop {
+            interval.tick().await;
+
+            let columns = self.shared_workload_columns.read().await.clone();
+            let label_selector = self.shared_workload_filter.read().await.clone();
+
+            let table = self.polling(&columns, label_selector.as_deref()).await;
+
+            if let Err(e) = tx.send(WorkloadResponse::List(table).into()) {
+                logger!(error, "Failed to send WorkloadResponse::List: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+impl WorkloadPoller {
+    async fn polling(
+        &self,
+        columns: &WorkloadColumns,
+        label_selector: Option<&str>,
+    ) -> Result<KubeTable> {
+        let target_namespaces = self.shared_target_namespaces.read().await;
+        let specs = columns.specs();
+
+        let target_columns: Vec<&str> = specs
+            .iter()
+            .filter_map(|s| {
+                match s {
+                    WorkloadColumnSpec::Builtin(c) if c.is_table_cell() => Some(c.as_str()),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        let rows: Vec<_> = join_all(TARGET_RESOURCES.iter().map(|kind| {
+            self.fetch_resource(
+                kind,
+                &target_namespaces,
+                specs,
+                &target_columns,
+                label_selector,
+            )
+        }))
+        .await
+        .into_iter()
+        .inspect(|res| {
+            if let Err(e) = res {
+                logger!(error, "Failed to fetch resource: {:?}", e);
+            }
+        })
+        .filter_map(|res| res.ok())
+        .collect();
+
+        let is_insert_ns = insert_ns(&target_namespaces);
+
+        let mut header: Vec<String> = specs.iter().map(|s| s.header()).collect();
+        if is_insert_ns {
+            header.insert(0, "NAMESPACE".to_string());
+        }
+
+        let kube_rows: Vec<KubeTableRow> = rows
+            .into_iter()
+            .flatten()
+            .map(|r| r.to_kube_table_row(is_insert_ns))
+            .collect();
+
+        Ok(KubeTable {
+            header,
+            rows: kube_rows,
+        })
+    }
+
+    async fn fetch_resource(
+        &self,
+        kind: &TargetResource,
+        namespaces: &[String],
+        specs: &[WorkloadColumnSpec],
+        target_columns: &[&str],
+        label_selector: Option<&str>,
+    ) -> Result<Vec<WorkloadTableRow>> {
+        let client = &self.kube_client;
+
+        let jobs = try_join_all(namespaces.iter().map(|ns| {
+            fetch_resource_per_namespace(client, kind, ns, specs, target_columns, label_selector)
+        }))
+        .await?;
+
+        Ok(jobs.into_iter().flatten().collect())
+    }
+}
+
+/// Build the per-row cell vector from a spec list, the resource's kind name,
+/// and a k8s API `TableRow`.
+///
+/// `builtin_indexes` are the positional indexes into `row.cells` for the
+/// builtin columns taken from the Table API (NAME / AGE, in spec order).
+/// READY / UP-TO-DATE / AVAILABLE / IMAGES are computed from `row.object`.
+fn build_workload_row_cells(
+    specs: &[WorkloadColumnSpec],
+    kind: &str,
+    row: &TableRow,
+    builtin_indexes: &[usize],
+) -> Vec<String> {
+    let object = row
+        .object
+        .as_ref()
+        .map(|o| &o.0)
+        .unwrap_or(&JsonValue::Null);
+
+    let status = WorkloadStatus::new(kind, object);
+
+    let mut builtin_iter = builtin_indexes.iter();
+
+    specs
+        .iter()
+        .map(|s| {
+            match s {
+                WorkloadColumnSpec::Builtin(WorkloadColumn::Kind) => kind.to_string(),
+                WorkloadColumnSpec::Builtin(WorkloadColumn::Ready) => status.ready.clone(),
+                WorkloadColumnSpec::Builtin(WorkloadColumn::UpToDate) => status.up_to_date.clone(),
+                WorkloadColumnSpec::Builtin(WorkloadColumn::Available) => status.available.clone(),
+                WorkloadColumnSpec::Builtin(WorkloadColumn::Images) => status.images.clone(),
+                WorkloadColumnSpec::Builtin(_) => {
+                    let i = builtin_iter.next().expect("builtin index available");
+                    row.cells[*i].to_string()
+                }
+                WorkloadColumnSpec::Label { key, .. } => {
+                    object
+                        .get("metadata")
+                        .and_then(|m| m.get("labels"))
+                        .and_then(|l| l.get(key))
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string()
+                }
+            }
+        })
+        .collect()
+}
+
+async fn fetch_resource_per_namespace(
+    client: &KubeClient,
+    kind: &TargetResource,
+    ns: &str,
+    specs: &[WorkloadColumnSpec],
+    target_columns: &[&str],
+    label_selector: Option<&str>,
+) -> Result<Vec<WorkloadTableRow>> {
+    let table = kind.fetch_table(client, ns, label_selector).await?;
+
+    let indexes = table.find_indexes(target_columns)?;
+    let name_pos_in_specs = specs
+        .iter()
+        .position(|s| matches!(s, WorkloadColumnSpec::Builtin(WorkloadColumn::Name)))
+        .expect("Name column must be present in workload columns");
+
+    let rows = table
+        .rows
+        .iter()
+        .map(|row| {
+            let cells = build_workload_row_cells(specs, kind.as_str(), row, &indexes);
+            let name = cells[name_pos_in_specs].clone();
+            let log_query = row
+                .object
+                .as_ref()
+                .and_then(|o| log_query(kind.as_str(), &name, &o.0));
+
+            WorkloadTableRow {
+                namespace: ns.to_string(),
+                kind: kind.as_str().to_string(),
+                name,
+                log_query,
+                cells,
+            }
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kube::apis::v1_table::Value;
+    use k8s_openapi::apimachinery::pkg::runtime::RawExtension;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn make_row(cells: &[&str], object: JsonValue) -> TableRow {
+        TableRow {
+            cells: cells
+                .iter()
+                .map(|c| Value(JsonValue::String(c.to_string())))
+                .collect(),
+            object: Some(RawExtension(object)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn table_pathにlabel_selectorとinclude_objectを付ける() {
+        assert_eq!(
+            workload_table_path("/apis/apps/v1/namespaces/default/deployments", None),
+            "/apis/apps/v1/namespaces/default/deployments?includeObject=Object"
+        );
+        assert_eq!(
+            workload_table_path(
+                "/apis/apps/v1/namespaces/default/deployments",
+                Some("app=web")
+            ),
+            "/apis/apps/v1/namespaces/default/deployments?labelSelector=app%3Dweb&includeObject=Object"
+        );
+    }
+
+    #[test]
+    fn セルは列順でオブジェクトから算出した値を含む() {
+        let specs = vec![
+            WorkloadColumnSpec::Builtin(WorkloadColumn::Kind),
+            WorkloadColumnSpec::Builtin(WorkloadColumn::Name),
+            WorkloadColumnSpec::Builtin(WorkloadColumn::Ready),
+            WorkloadColumnSpec::Label {
+                key: "app".to_string(),
+                header: "APP".to_string(),
+            },
+            WorkloadColumnSpec::Builtin(WorkloadColumn::Images),
+            WorkloadColumnSpec::Builtin(WorkloadColumn::Age),
+        ];
+
+        let row = make_row(
+            &["web", "3d"],
+            json!({
+                "metadata": { "labels": { "app": "nginx" } },
+                "spec": {
+                    "replicas": 2,
+                    "template": { "spec": { "containers": [{ "image": "nginx" }] } }
+                },
+                "status": { "readyReplicas": 1 }
+            }),
+        );
+
+        let cells = build_workload_row_cells(&specs, "Deployment", &row, &[0, 1]);
+
+        assert_eq!(
+            cells,
+            vec!["Deployment", "web", "1/2", "nginx", "nginx", "3d"]
+        );
+    }
+
+    #[test]
+    fn 行のメタデータにkindとログクエリを含める() {
+        let row = WorkloadTableRow {
+            namespace: "default".into(),
+            kind: "Deployment".into(),
+            name: "web".into(),
+            log_query: Some("deploy/web".into()),
+            cells: vec!["Deployment".into(), "web".into()],
+        };
+
+        let actual = row.to_kube_table_row(true);
+
+        assert_eq!(actual.row, vec!["default", "Deployment", "web"]);
+        assert_eq!(
+            actual.metadata,
+            Some(BTreeMap::from([
+                ("kind".to_string(), "Deployment".to_string()),
+                ("log_query".to_string(), "deploy/web".to_string()),
+            ]))
+        );
+    }
+}
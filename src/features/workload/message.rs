@@ -0,0 +1,77 @@
+use anyhow::Result;
+
+use crate::{
+    features::workload::WorkloadColumns,
+    kube::table::KubeTable,
+    message::Message,
+    workers::kube::message::Kube,
+};
+
+#[derive(Debug, Clone)]
+pub struct WorkloadRequestTargetParams {
+    pub name: String,
+    pub namespace: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum WorkloadRequest {
+    Deployment(WorkloadRequestTargetParams),
+    StatefulSet(WorkloadRequestTargetParams),
+    DaemonSet(WorkloadRequestTargetParams),
+    Job(WorkloadRequestTargetParams),
+    CronJob(WorkloadRequestTargetParams),
+}
+
+#[derive(Debug)]
+pub enum WorkloadResponse {
+    List(Result<KubeTable>),
+    Yaml(Result<Vec<String>>),
+}
+
+#[derive(Debug)]
+pub enum WorkloadMessage {
+    Request(WorkloadRequest),
+    Response(WorkloadResponse),
+    /// Replace the active labelSelector value. `None` clears it (the poller
+    /// stops sending `labelSelector=` in its sub-fetch URLs).
+    Filter(Option<String>),
+    /// Replace the active column composition (sent from the column dialog).
+    /// The poller will use the new columns on the next poll.
+    ColumnsRequest(WorkloadColumns),
+}
+
+impl WorkloadRequest {
+    pub fn data(&self) -> &WorkloadRequestTargetParams {
+        match self {
+            Self::Deployment(data) => data,
+            Self::StatefulSet(data) => data,
+            Self::DaemonSet(data) => data,
+            Self::Job(data) => data,
+            Self::CronJob(data) => data,
+        }
+    }
+}
+
+impl From<WorkloadMessage> for Kube {
+    fn from(m: WorkloadMessage) -> Self {
+        Self::Workload(m)
+    }
+}
+
+impl From<WorkloadMessage> for Message {
+    fn from(m: WorkloadMessage) -> Self {
+        Self::Kube(m.into())
+    }
+}
+
+impl From<WorkloadRequest> for Message {
+    fn from(req: WorkloadRequest) -> Self {
+        WorkloadMessage::Request(req).into()
+    }
+}
+
+impl From<WorkloadResponse> for Message {
+    fn from(res: WorkloadResponse) -> Self {
+        WorkloadMessage::Response(res).into()
+    }
+}
@@ -0,0 +1,80 @@
+use ratatui::layout::{Constraint, Direction};
+
+use crate::{
+    features::{
+        component_id::WORKLOAD_TAB_ID,
+        tab::{TabColumns, TabParams},
+        workload::{
+            view::widgets::{
+                description_widget,
+                workload_columns_dialog,
+                workload_filter_help_widget,
+                workload_widget,
+            },
+            WorkloadColumns,
+            WorkloadLabelColumn,
+        },
+    },
+    ui::{
+        tab::{LayoutElement, NestedLayoutElement, NestedWidgetLayout, TabLayout},
+        widget::Widget,
+        Tab,
+    },
+};
+
+pub struct WorkloadTab {
+    pub tab: Tab<'static>,
+    pub workload_columns_dialog: Widget<'static>,
+    pub workload_filter_help_dialog: Widget<'static>,
+}
+
+impl WorkloadTab {
+    pub fn new(
+        title: &'static str,
+        params: TabParams<'_, TabColumns<WorkloadColumns, WorkloadLabelColumn>>,
+    ) -> Self {
+        let TabParams {
+            tx,
+            clipboard,
+            split_direction,
+            columns:
+                TabColumns {
+                    default: default_columns,
+                    labels: label_registry,
+                },
+            theme,
+            keymap,
+        } = params;
+
+        let error_theme = theme.error.clone().into();
+
+        let workload_widget = workload_widget(tx, label_registry.clone(), theme.clone(), keymap);
+        let description_widget = description_widget(clipboard, theme.clone());
+        let workload_columns_dialog =
+            workload_columns_dialog(tx, default_columns, label_registry, theme.clone());
+        let workload_filter_help_dialog = workload_filter_help_widget(theme);
+
+        let layout = TabLayout::new(layout, split_direction);
+
+        WorkloadTab {
+            tab: Tab::new(
+                WORKLOAD_TAB_ID,
+                title,
+                [workload_widget, description_widget],
+                layout,
+            )
+            .error_theme(error_theme),
+            workload_columns_dialog,
+            workload_filter_help_dialog,
+        }
+    }
+}
+
+fn layout(split_direction: Direction) -> NestedWidgetLayout {
+    NestedWidgetLayout::default()
+        .direction(split_direction)
+        .nested_widget_layout([
+            NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(0)),
+            NestedLayoutElement(Constraint::Percentage(50), LayoutElement::WidgetIndex(1)),
+        ])
+}
@@ -0,0 +1,9 @@
+mod description;
+mod workload;
+mod workload_columns_dialog;
+mod workload_filter_help;
+
+pub(super) use description::*;
+pub(super) use workload::*;
+pub(super) use workload_columns_dialog::*;
+pub(super) use workload_filter_help::*;
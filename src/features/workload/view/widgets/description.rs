@@ -5,7 +5,7 @@ use ratatui::widgets::Block;
 use crate::{
     clipboard::Clipboard,
     config::theme::WidgetThemeConfig,
-    features::component_id::EVENT_WIDGET_ID,
+    features::component_id::WORKLOAD_DESCRIPTION_WIDGET_ID,
     ui::widget::{
         SearchForm,
         SearchFormTheme,
@@ -18,7 +18,7 @@ use crate::{
     },
 };
 
-pub fn event_widget(
+pub fn description_widget(
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
     theme: WidgetThemeConfig,
 ) -> Widget<'static> {
@@ -27,19 +27,17 @@ pub fn event_widget(
     let text_theme = TextTheme::from(theme);
 
     let widget_base = WidgetBase::builder()
-        .title("Event")
+        .title("Description")
         .theme(widget_theme)
         .build();
 
     let search_form = SearchForm::builder().theme(search_theme).build();
 
     let builder = Text::builder()
-        .id(EVENT_WIDGET_ID)
+        .id(WORKLOAD_DESCRIPTION_WIDGET_ID)
         .widget_base(widget_base)
         .search_form(search_form)
         .theme(text_theme)
-        .wrap()
-        .follow()
         .block_injection(block_injection());
 
     if let Some(cb) = clipboard {
@@ -57,7 +55,7 @@ fn block_injection() -> impl Fn(&Text, bool, bool) -> Block<'static> {
 
         let mut base = text.widget_base().clone();
 
-        *base.append_title_mut() = Some(format!(" [{}/{}]", index, size).into());
+        *base.title_mut() = format!("Description [{}/{}]", index, size).into();
 
         base.render_block(text.can_activate() && is_active, is_mouse_over)
     }
@@ -0,0 +1,200 @@
+use crossbeam::channel::Sender;
+use k8s_openapi::{
+    api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
+    },
+    Resource,
+};
+
+use crate::{
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
+    features::{
+        component_id::{
+            POD_LOG_QUERY_WIDGET_ID,
+            POD_LOG_WIDGET_ID,
+            POD_TAB_ID,
+            WORKLOAD_DESCRIPTION_WIDGET_ID,
+            WORKLOAD_WIDGET_ID,
+        },
+        pod::{
+            kube::{LogConfig, LogPrefixType},
+            message::LogMessage,
+        },
+        workload::{
+            message::{WorkloadRequest, WorkloadRequestTargetParams},
+            workload_filter_applicator,
+            WorkloadLabelColumn,
+        },
+    },
+    kube::context::Namespace,
+    message::Message,
+    ui::{
+        event::EventResult,
+        widget::{
+            FilterForm,
+            FilterFormTheme,
+            Item,
+            SelectedItem,
+            Table,
+            TableItem,
+            TableTheme,
+            Widget,
+            WidgetBase,
+            WidgetTheme,
+            WidgetTrait as _,
+        },
+        Window,
+        WindowAction,
+    },
+};
+
+pub fn workload_widget(
+    tx: &Sender<Message>,
+    label_registry: Vec<WorkloadLabelColumn>,
+    theme: WidgetThemeConfig,
+    keymap: &Keymap,
+) -> Widget<'static> {
+    let tx = tx.clone();
+
+    let widget_theme = WidgetTheme::from(theme.clone());
+    let filter_theme = FilterFormTheme::from(theme.clone());
+    let table_theme = TableTheme::from(theme.clone());
+
+    let widget_base = WidgetBase::builder()
+        .title("Workload")
+        .theme(widget_theme)
+        .build();
+
+    let filter_form = FilterForm::builder().theme(filter_theme).build();
+
+    Table::builder()
+        .id(WORKLOAD_WIDGET_ID)
+        .widget_base(widget_base)
+        .filter_form(filter_form)
+        .theme(table_theme)
+        .filter_applicator(workload_filter_applicator(label_registry, tx.clone()))
+        .sort_actions(
+            keymap.events(Action::SortNext),
+            keymap.events(Action::SortPrev),
+        )
+        .mark_actions(
+            keymap.events(Action::ToggleMark),
+            keymap.events(Action::MarkRange),
+            keymap.events(Action::MarkAll),
+        )
+        .actions(
+            keymap.events(Action::OpenColumns),
+            open_workload_columns_dialog(),
+        )
+        .actions(keymap.events(Action::ShowLogs), show_logs(tx.clone()))
+        .block_injection(block_injection())
+        .on_select(on_select(tx))
+        .build()
+        .into()
+}
+
+fn open_workload_columns_dialog() -> impl Fn(&mut Window) -> EventResult {
+    use crate::features::component_id::WORKLOAD_COLUMNS_DIALOG_ID;
+    |w: &mut Window| {
+        w.open_dialog(WORKLOAD_COLUMNS_DIALOG_ID);
+        EventResult::Nop
+    }
+}
+
+fn block_injection() -> impl Fn(&Table) -> WidgetBase {
+    |table: &Table| {
+        let mut base = table.widget_base().clone();
+
+        *base.append_title_mut() = Some(table.count_indicator().into());
+
+        base
+    }
+}
+
+fn on_select(tx: Sender<Message>) -> impl Fn(&mut Window, &TableItem) -> EventResult {
+    move |w: &mut Window, v: &TableItem| {
+        w.widget_clear(WORKLOAD_DESCRIPTION_WIDGET_ID);
+
+        let Some(metadata) = v.metadata.as_ref() else {
+            return EventResult::Ignore;
+        };
+
+        let Some(namespace) = metadata.get("namespace") else {
+            return EventResult::Ignore;
+        };
+
+        let Some(name) = metadata.get("name") else {
+            return EventResult::Ignore;
+        };
+
+        let Some(kind) = metadata.get("kind") else {
+            return EventResult::Ignore;
+        };
+
+        *(w.find_widget_mut(WORKLOAD_DESCRIPTION_WIDGET_ID)
+            .widget_base_mut()
+            .append_title_mut()) = Some((format!(" : {}", name)).into());
+
+        let request_data = WorkloadRequestTargetParams {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+        };
+
+        let req = match kind.as_str() {
+            Deployment::KIND => WorkloadRequest::Deployment(request_data),
+            StatefulSet::KIND => WorkloadRequest::StatefulSet(request_data),
+            DaemonSet::KIND => WorkloadRequest::DaemonSet(request_data),
+            Job::KIND => WorkloadRequest::Job(request_data),
+            CronJob::KIND => WorkloadRequest::CronJob(request_data),
+            _ => return EventResult::Ignore,
+        };
+
+        tx.send(req.into()).expect("Failed to send WorkloadRequest");
+
+        EventResult::WindowAction(WindowAction::Continue)
+    }
+}
+
+/// 選択中のワークロードのログをPodタブで表示する
+///
+/// 実行中のJobがないCronJobなど、ログの対象がない行では何もしない
+fn show_logs(tx: Sender<Message>) -> impl Fn(&mut Window) -> EventResult {
+    move |w: &mut Window| {
+        let Some(SelectedItem::TableRow {
+            metadata: Some(metadata),
+            ..
+        }) = w.find_widget(WORKLOAD_WIDGET_ID).widget_item()
+        else {
+            return EventResult::Ignore;
+        };
+
+        let (Some(namespace), Some(query)) = (metadata.get("namespace"), metadata.get("log_query"))
+        else {
+            return EventResult::Ignore;
+        };
+
+        w.widget_clear(POD_LOG_WIDGET_ID);
+
+        w.find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+            .update_widget_item(Item::Single(query.to_string().into()));
+
+        let config = LogConfig::new(
+            query.to_string(),
+            Namespace(vec![namespace.to_string()]),
+            LogPrefixType::PodAndContainer,
+            false,
+        );
+
+        tx.send(LogMessage::Request(config).into())
+            .expect("Failed to send LogMessage::Request");
+
+        w.activate_tab_by_id(POD_TAB_ID);
+        w.activate_widget_by_id(POD_LOG_WIDGET_ID);
+
+        EventResult::Nop
+    }
+}
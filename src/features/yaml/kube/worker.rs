@@ -96,16 +96,8 @@ async fn fetch_resource_yaml<C: KubeClientRequest>(
         name
     );
 
-    let api = api_resources
-        .iter()
-        .find(|api| *api == kind)
-        .ok_or_else(|| anyhow!("Can't get {} from API resource", kind))?;
     // json string data
-    let path = if api.is_namespaced() {
-        format!("{}/{}", api.api_url_with_namespace(&ns), name)
-    } else {
-        format!("{}/{}", api.api_url(), name)
-    };
+    let path = resource_url(api_resources, kind, &name, &ns)?;
 
     logger!(info, "Fetching resource [{}]", path);
 
@@ -127,3 +119,24 @@ async fn fetch_resource_yaml<C: KubeClientRequest>(
 
     Ok(yaml_string)
 }
+
+/// 選択されているリソースのURLパスを返す
+pub fn resource_url(
+    api_resources: &ApiResources,
+    kind: &ApiResource,
+    name: &str,
+    ns: &str,
+) -> Result<String> {
+    let api = api_resources
+        .iter()
+        .find(|api| *api == kind)
+        .ok_or_else(|| anyhow!("Can't get {} from API resource", kind))?;
+
+    let path = if api.is_namespaced() {
+        format!("{}/{}", api.api_url_with_namespace(ns), name)
+    } else {
+        format!("{}/{}", api.api_url(), name)
+    };
+
+    Ok(path)
+}
@@ -5,7 +5,7 @@ use ratatui::prelude::Constraint;
 
 use crate::{
     clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
+    config::{keybindings::Keymap, theme::WidgetThemeConfig},
     features::component_id::YAML_TAB_ID,
     message::Message,
     ui::{
@@ -33,10 +33,11 @@ impl YamlTab {
         tx: &Sender<Message>,
         clipboard: &Option<Rc<RefCell<Clipboard>>>,
         theme: WidgetThemeConfig,
+        keymap: &Keymap,
     ) -> Self {
         let error_theme = theme.error.clone().into();
 
-        let yaml_widget = yaml_widget(tx, clipboard, theme.clone());
+        let yaml_widget = yaml_widget(tx, clipboard, theme.clone(), keymap);
 
         let layout = TabLayout::new(
             |_| {
@@ -5,7 +5,10 @@ use ratatui::widgets::Block;
 
 use crate::{
     clipboard::Clipboard,
-    config::theme::WidgetThemeConfig,
+    config::{
+        keybindings::{Action, Keymap},
+        theme::WidgetThemeConfig,
+    },
     features::{
         component_id::{YAML_KIND_DIALOG_ID, YAML_WIDGET_ID},
         yaml::message::YamlRequest,
@@ -31,6 +34,7 @@ pub fn yaml_widget(
     tx: &Sender<Message>,
     clipboard: &Option<Rc<RefCell<Clipboard>>>,
     theme: WidgetThemeConfig,
+    keymap: &Keymap,
 ) -> Widget<'static> {
     let tx = tx.clone();
 
@@ -51,7 +55,7 @@ pub fn yaml_widget(
         .search_form(search_form)
         .theme(text_theme)
         .block_injection(block_injection())
-        .action('f', open_kind_dialog(tx))
+        .actions(keymap.events(Action::OpenKindSelect), open_kind_dialog(tx))
         .wrap();
 
     if let Some(cb) = clipboard {
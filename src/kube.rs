@@ -2,6 +2,7 @@ pub mod apis;
 pub mod auth;
 mod client;
 pub mod context;
+pub mod metrics;
 pub mod proxy;
 pub mod table;
 
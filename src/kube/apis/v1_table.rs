@@ -157,6 +157,85 @@ impl PartialOrd for Time {
     }
 }
 
+/// kubectlと同じ形式で経過時間を表示する
+///
+/// `ToTime` とは逆に、経過秒数からAge列の文字列を作る
+pub fn human_duration(seconds: i64) -> String {
+    if seconds < -1 {
+        return "<invalid>".into();
+    }
+
+    if seconds < 0 {
+        return "0s".into();
+    }
+
+    if seconds < 60 * 2 {
+        return format!("{}s", seconds);
+    }
+
+    let minutes = seconds / 60;
+
+    if minutes < 10 {
+        let s = seconds % 60;
+
+        if s == 0 {
+            return format!("{}m", minutes);
+        }
+
+        return format!("{}m{}s", minutes, s);
+    }
+
+    if minutes < 60 * 3 {
+        return format!("{}m", minutes);
+    }
+
+    let hours = minutes / 60;
+
+    if hours < 8 {
+        let m = minutes % 60;
+
+        if m == 0 {
+            return format!("{}h", hours);
+        }
+
+        return format!("{}h{}m", hours, m);
+    }
+
+    if hours < 48 {
+        return format!("{}h", hours);
+    }
+
+    let days = hours / 24;
+
+    if hours < 24 * 8 {
+        let h = hours % 24;
+
+        if h == 0 {
+            return format!("{}d", days);
+        }
+
+        return format!("{}d{}h", days, h);
+    }
+
+    if hours < 24 * 365 * 2 {
+        return format!("{}d", days);
+    }
+
+    let years = days / 365;
+
+    if hours < 24 * 365 * 8 {
+        let d = days % 365;
+
+        if d == 0 {
+            return format!("{}y", years);
+        }
+
+        return format!("{}y{}d", years, d);
+    }
+
+    format!("{}y", years)
+}
+
 impl Table {
     pub fn find_index(&self, target: &str) -> Option<usize> {
         self.column_definitions
@@ -411,4 +490,30 @@ mod tests {
             assert_eq!("3h10m".to_time().to_second(), 3 * 3600 + 10 * 60)
         }
     }
+
+    mod duration {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(-5, "<invalid>")]
+        #[case(-1, "0s")]
+        #[case(59, "59s")]
+        #[case(119, "119s")]
+        #[case(120, "2m")]
+        #[case(150, "2m30s")]
+        #[case(60 * 30, "30m")]
+        #[case(60 * 60 * 3, "3h")]
+        #[case(60 * 60 * 3 + 60 * 5, "3h5m")]
+        #[case(60 * 60 * 10, "10h")]
+        #[case(60 * 60 * 24 * 3, "3d")]
+        #[case(60 * 60 * (24 * 3 + 4), "3d4h")]
+        #[case(60 * 60 * 24 * 30, "30d")]
+        #[case(60 * 60 * 24 * (365 * 3 + 10), "3y10d")]
+        #[case(60 * 60 * 24 * 365 * 10, "10y")]
+        fn human_duration_cases(#[case] seconds: i64, #[case] expected: &str) {
+            assert_eq!(human_duration(seconds), expected);
+        }
+    }
 }
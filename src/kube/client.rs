@@ -4,18 +4,25 @@ use http::header::ACCEPT;
 use kube::{core::request::Error as KubeRequestError, Client};
 use serde::de::DeserializeOwned;
 
-use crate::logger;
+use crate::{
+    kube::{apis::v1_table::Table, table::TableInformers},
+    logger,
+};
 
 const TABLE_REQUEST_HEADER: &str = "application/json;as=Table;v=v1;g=meta.k8s.io,application/json;as=Table;v=v1beta1;g=meta.k8s.io,application/json";
 
 #[derive(Clone)]
 pub struct KubeClient {
     client: Client,
+    informers: TableInformers,
 }
 
 impl KubeClient {
     pub fn new(client: Client) -> Self {
-        Self { client }
+        Self {
+            client,
+            informers: TableInformers::default(),
+        }
     }
 
     #[allow(dead_code)]
@@ -36,6 +43,13 @@ pub trait KubeClientRequest: Send + Sync {
 
     async fn request_text(&self, url_path: &str) -> Result<String>;
 
+    /// watchしている一覧をTable形式で返す
+    ///
+    /// キャッシュを持たない場合は毎回APIサーバーへ問い合わせる
+    async fn request_watched_table(&self, url_path: &str) -> Result<Table> {
+        self.request_table(url_path).await
+    }
+
     fn client(&self) -> &Client;
 }
 
@@ -94,6 +108,11 @@ impl KubeClientRequest for KubeClient {
             .inspect_err(|e| logger!(error, "Request error ({}): {}", url_path, e))
     }
 
+    /// 毎回APIサーバーへ問い合わせる代わりに、watchで更新しているキャッシュから返す
+    async fn request_watched_table(&self, url_path: &str) -> Result<Table> {
+        self.informers.table(self, url_path).await
+    }
+
     fn client(&self) -> &Client {
         &self.client
     }
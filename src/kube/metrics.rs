@@ -0,0 +1,735 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use ratatui::symbols;
+use serde_json::Value as JsonValue;
+
+use crate::logger;
+
+use super::{
+    apis::metrics::{NodeMetrics, NodeMetricsList, PodMetrics, PodMetricsList},
+    KubeClientRequest,
+};
+
+/// metrics-serverを使えない場合に表示する文字列
+pub const NOT_AVAILABLE: &str = "n/a";
+
+/// metrics.k8s.ioへの問い合わせ結果を使い回す時間
+///
+/// metrics-serverは既定で15秒ごとにしか値を更新しないため、毎秒問い合わせる必要はない
+const METRICS_TTL: Duration = Duration::from_secs(10);
+
+/// 推移として保持するサンプル数
+///
+/// metrics-serverの既定の解像度 (15秒) で約10分
+const HISTORY_LEN: usize = 40;
+
+/// リソース名ごとの使用量
+pub type UsageMap = HashMap<String, ResourceUsage>;
+
+/// CPU (コア) とメモリ (バイト) の使用量
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ResourceUsage {
+    pub cpu: f64,
+    pub memory: f64,
+}
+
+/// 使用量と比べる値 (requests、limits、allocatable)
+///
+/// 設定されていない場合は`None`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ResourceAmount {
+    pub cpu: Option<f64>,
+    pub memory: Option<f64>,
+}
+
+/// メトリクスを表示する列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricsColumn {
+    Cpu,
+    Memory,
+    /// CPU使用量の割合
+    CpuRatio,
+    /// メモリ使用量の割合
+    MemoryRatio,
+}
+
+impl MetricsColumn {
+    /// 割合の列は比べる値を取り出すためにオブジェクト全体が必要になる
+    pub fn needs_object(self) -> bool {
+        matches!(self, Self::CpuRatio | Self::MemoryRatio)
+    }
+
+    pub fn format(self, usage: Option<ResourceUsage>, base: ResourceAmount) -> String {
+        let Some(usage) = usage else {
+            return NOT_AVAILABLE.to_string();
+        };
+
+        match self {
+            Self::Cpu => format_cpu(usage.cpu),
+            Self::Memory => format_memory(usage.memory),
+            Self::CpuRatio => format_percent(usage.cpu, base.cpu),
+            Self::MemoryRatio => format_percent(usage.memory, base.memory),
+        }
+    }
+}
+
+/// `kubectl top`と同じくミリコアで表示する
+fn format_cpu(cores: f64) -> String {
+    format!("{}m", (cores * 1000.0).round() as u64)
+}
+
+/// `kubectl top`と同じくMiで表示する
+fn format_memory(bytes: f64) -> String {
+    format!("{}Mi", (bytes / (1024.0 * 1024.0)).round() as u64)
+}
+
+fn format_percent(usage: f64, base: Option<f64>) -> String {
+    match base {
+        Some(base) if 0.0 < base => format!("{}%", (usage / base * 100.0).round() as u64),
+        _ => NOT_AVAILABLE.to_string(),
+    }
+}
+
+/// Kubernetesのquantity (`250m`、`128Mi`、`1e3`など) を基本単位の値に変換する
+pub fn parse_quantity(quantity: &str) -> Option<f64> {
+    const SUFFIXES: &[(&str, f64)] = &[
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Pi", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Ei", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("n", 1e-9),
+        ("u", 1e-6),
+        ("m", 1e-3),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+        ("E", 1e18),
+    ];
+
+    let quantity = quantity.trim();
+
+    let (number, multiplier) = SUFFIXES
+        .iter()
+        .find_map(|(suffix, multiplier)| {
+            quantity
+                .strip_suffix(suffix)
+                .map(|number| (number, *multiplier))
+        })
+        .unwrap_or((quantity, 1.0));
+
+    number.parse::<f64>().ok().map(|n| n * multiplier)
+}
+
+fn usage_from_resource_list(usage: &HashMap<String, String>) -> ResourceUsage {
+    let get = |key: &str| {
+        usage
+            .get(key)
+            .and_then(|q| parse_quantity(q))
+            .unwrap_or_default()
+    };
+
+    ResourceUsage {
+        cpu: get("cpu"),
+        memory: get("memory"),
+    }
+}
+
+/// Podごとのコンテナの使用量の合計
+fn pod_usage(list: PodMetricsList) -> UsageMap {
+    list.items
+        .into_iter()
+        .filter_map(|metrics| {
+            let usage = pod_metrics_usage(&metrics);
+            let name = metrics.metadata?.name?;
+
+            Some((name, usage))
+        })
+        .collect()
+}
+
+fn pod_metrics_usage(metrics: &PodMetrics) -> ResourceUsage {
+    metrics
+        .containers
+        .iter()
+        .map(|c| usage_from_resource_list(&c.usage))
+        .fold(ResourceUsage::default(), |acc, u| {
+            ResourceUsage {
+                cpu: acc.cpu + u.cpu,
+                memory: acc.memory + u.memory,
+            }
+        })
+}
+
+fn node_usage(list: NodeMetricsList) -> UsageMap {
+    list.items
+        .into_iter()
+        .filter_map(|metrics| {
+            let name = metrics.metadata?.name?;
+
+            Some((name, usage_from_resource_list(&metrics.usage)))
+        })
+        .collect()
+}
+
+/// Podのオブジェクトからコンテナのrequestsまたはlimitsの合計を求める
+///
+/// 設定されていないコンテナが1つでもある場合は`None`とする
+pub fn pod_resource_amount(object: &JsonValue, field: &str) -> ResourceAmount {
+    let containers = object
+        .pointer("/spec/containers")
+        .and_then(JsonValue::as_array)
+        .filter(|containers| !containers.is_empty());
+
+    let sum = |resource: &str| {
+        containers?
+            .iter()
+            .map(|c| {
+                c.get("resources")?
+                    .get(field)?
+                    .get(resource)?
+                    .as_str()
+                    .and_then(parse_quantity)
+            })
+            .sum::<Option<f64>>()
+    };
+
+    ResourceAmount {
+        cpu: sum("cpu"),
+        memory: sum("memory"),
+    }
+}
+
+/// `%CPU/R`と`%MEM/L`で使用量と比べる値
+///
+/// CPUはrequests、メモリはlimitsの合計
+pub fn pod_ratio_base(object: &JsonValue) -> ResourceAmount {
+    ResourceAmount {
+        cpu: pod_resource_amount(object, "requests").cpu,
+        memory: pod_resource_amount(object, "limits").memory,
+    }
+}
+
+/// Nodeのオブジェクトからallocatableを取り出す
+pub fn node_allocatable(object: &JsonValue) -> ResourceAmount {
+    let get = |resource: &str| {
+        object
+            .pointer("/status/allocatable")?
+            .get(resource)?
+            .as_str()
+            .and_then(parse_quantity)
+    };
+
+    ResourceAmount {
+        cpu: get("cpu"),
+        memory: get("memory"),
+    }
+}
+
+/// metrics.k8s.ioへの問い合わせ結果のキャッシュ
+///
+/// 取得に失敗した (metrics-serverがない) 場合も結果として保持し、
+/// 失敗した問い合わせを毎秒繰り返さないようにする
+#[derive(Debug, Default, Clone)]
+pub struct MetricsCache {
+    inner: Arc<Mutex<HashMap<String, CachedUsage>>>,
+}
+
+#[derive(Debug)]
+struct CachedUsage {
+    fetched_at: Instant,
+    usage: Option<Arc<UsageMap>>,
+}
+
+impl MetricsCache {
+    /// namespace内のPodの使用量
+    pub async fn pod_usage<C: KubeClientRequest>(
+        &self,
+        client: &C,
+        namespace: &str,
+    ) -> Option<Arc<UsageMap>> {
+        let path = format!("/apis/metrics.k8s.io/v1beta1/namespaces/{}/pods", namespace);
+
+        self.get_or_fetch(path.clone(), async {
+            client.request::<PodMetricsList>(&path).await.map(pod_usage)
+        })
+        .await
+    }
+
+    /// Nodeの使用量
+    pub async fn node_usage<C: KubeClientRequest>(&self, client: &C) -> Option<Arc<UsageMap>> {
+        let path = "/apis/metrics.k8s.io/v1beta1/nodes".to_string();
+
+        self.get_or_fetch(path.clone(), async {
+            client
+                .request::<NodeMetricsList>(&path)
+                .await
+                .map(node_usage)
+        })
+        .await
+    }
+
+    async fn get_or_fetch(
+        &self,
+        key: String,
+        fetch: impl Future<Output = Result<UsageMap>>,
+    ) -> Option<Arc<UsageMap>> {
+        if let Some(cached) = self
+            .inner
+            .lock()
+            .expect("metrics cache poisoned")
+            .get(&key)
+            .filter(|cached| cached.fetched_at.elapsed() < METRICS_TTL)
+        {
+            return cached.usage.clone();
+        }
+
+        let usage = match fetch.await {
+            Ok(usage) => Some(Arc::new(usage)),
+            Err(err) => {
+                logger!(warn, "Metrics are not available ({}): {}", key, err);
+                None
+            }
+        };
+
+        self.inner.lock().expect("metrics cache poisoned").insert(
+            key,
+            CachedUsage {
+                fetched_at: Instant::now(),
+                usage: usage.clone(),
+            },
+        );
+
+        usage
+    }
+}
+
+/// 1回分のmetrics-serverのサンプル
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsSample {
+    pub timestamp: Time,
+    pub usage: ResourceUsage,
+}
+
+/// 1つのPodの使用量を取得する
+pub async fn fetch_pod_sample<C: KubeClientRequest>(
+    client: &C,
+    namespace: &str,
+    name: &str,
+) -> Result<MetricsSample> {
+    let path = format!(
+        "/apis/metrics.k8s.io/v1beta1/namespaces/{}/pods/{}",
+        namespace, name
+    );
+
+    let metrics: PodMetrics = client.request(&path).await?;
+
+    Ok(MetricsSample {
+        usage: pod_metrics_usage(&metrics),
+        timestamp: metrics.timestamp,
+    })
+}
+
+/// 1つのNodeの使用量を取得する
+pub async fn fetch_node_sample<C: KubeClientRequest>(
+    client: &C,
+    name: &str,
+) -> Result<MetricsSample> {
+    let path = format!("/apis/metrics.k8s.io/v1beta1/nodes/{}", name);
+
+    let metrics: NodeMetrics = client.request(&path).await?;
+
+    Ok(MetricsSample {
+        usage: usage_from_resource_list(&metrics.usage),
+        timestamp: metrics.timestamp,
+    })
+}
+
+/// 選択中のPodやNodeの使用量の推移
+///
+/// metrics-serverの値は一定間隔でしか更新されないため、
+/// 問い合わせは`METRICS_TTL`ごとに行い、前回と同じ時刻のサンプルは追加しない
+#[derive(Debug, Default, Clone)]
+pub struct MetricsHistory {
+    samples: VecDeque<MetricsSample>,
+    /// 前回metrics-serverに問い合わせた時刻
+    sampled_at: Option<Instant>,
+}
+
+impl MetricsHistory {
+    /// metrics-serverに問い合わせる時期になっていれば`true`を返し、問い合わせた時刻を記録する
+    ///
+    /// 取得に失敗した場合も次の時期まで問い合わせない
+    pub fn sample_due(&mut self) -> bool {
+        if self
+            .sampled_at
+            .is_some_and(|sampled_at| sampled_at.elapsed() < METRICS_TTL)
+        {
+            return false;
+        }
+
+        self.sampled_at = Some(Instant::now());
+
+        true
+    }
+
+    pub fn push(&mut self, sample: MetricsSample) {
+        if self
+            .samples
+            .back()
+            .is_some_and(|last| last.timestamp == sample.timestamp)
+        {
+            return;
+        }
+
+        if self.samples.len() == HISTORY_LEN {
+            self.samples.pop_front();
+        }
+
+        self.samples.push_back(sample);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// CPUとメモリのスパークラインを`# `始まりの行で返す
+    ///
+    /// `base`が分かる場合はそれを上限として描き、最新の値と並べて表示する
+    pub fn lines(&self, base: ResourceAmount) -> Vec<String> {
+        let Some(latest) = self.samples.back() else {
+            return Vec::new();
+        };
+
+        let cpu: Vec<f64> = self.samples.iter().map(|s| s.usage.cpu).collect();
+        let memory: Vec<f64> = self.samples.iter().map(|s| s.usage.memory).collect();
+
+        let line = |label: &str,
+                    values: &[f64],
+                    base: Option<f64>,
+                    current: String,
+                    format: fn(f64) -> String| {
+            let value = match base {
+                Some(base) => format!("{} / {}", current, format(base)),
+                None => current,
+            };
+
+            format!("# {:<3}  {}  {}", label, sparkline(values, base), value)
+        };
+
+        vec![
+            format!("# Metrics (last {} samples)", self.samples.len()),
+            line(
+                "CPU",
+                &cpu,
+                base.cpu,
+                format_cpu(latest.usage.cpu),
+                format_cpu,
+            ),
+            line(
+                "MEM",
+                &memory,
+                base.memory,
+                format_memory(latest.usage.memory),
+                format_memory,
+            ),
+        ]
+    }
+}
+
+/// ratatuiの`Sparkline`と同じ記号で値の推移を1行に描く
+///
+/// 上限は`base`と最大値の大きい方とする
+fn sparkline(values: &[f64], base: Option<f64>) -> String {
+    let bar = symbols::bar::NINE_LEVELS;
+    let levels = [
+        bar.empty,
+        bar.one_eighth,
+        bar.one_quarter,
+        bar.three_eighths,
+        bar.half,
+        bar.five_eighths,
+        bar.three_quarters,
+        bar.seven_eighths,
+        bar.full,
+    ];
+
+    let max = values
+        .iter()
+        .copied()
+        .fold(base.unwrap_or_default(), f64::max);
+
+    values
+        .iter()
+        .map(|v| {
+            if max <= 0.0 {
+                return levels[0];
+            }
+
+            let level = (v / max * 8.0).round().clamp(0.0, 8.0) as usize;
+            levels[level]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::anyhow;
+    use mockall::predicate::eq;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use crate::{kube::mock::MockTestKubeClient, mock_expect};
+
+    #[rstest]
+    #[case::cores("2", 2.0)]
+    #[case::millicores("250m", 0.25)]
+    #[case::nanocores("1500000n", 0.0015)]
+    #[case::microcores("500u", 0.0005)]
+    #[case::decimal("0.5", 0.5)]
+    #[case::kibibytes("1024Ki", 1024.0 * 1024.0)]
+    #[case::mebibytes("128Mi", 128.0 * 1024.0 * 1024.0)]
+    #[case::gibibytes("1Gi", 1024.0 * 1024.0 * 1024.0)]
+    #[case::megabytes("500M", 5e8)]
+    #[case::exponent("1e3", 1000.0)]
+    fn parse_quantity_cases(#[case] quantity: &str, #[case] expected: f64) {
+        let actual = parse_quantity(quantity).unwrap();
+
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unknown_suffix("1Xi")]
+    #[case::not_number("abc")]
+    fn parse_invalid_quantity(#[case] quantity: &str) {
+        assert_eq!(parse_quantity(quantity), None);
+    }
+
+    #[rstest]
+    #[case::cpu(MetricsColumn::Cpu, "250m")]
+    #[case::memory(MetricsColumn::Memory, "64Mi")]
+    #[case::cpu_ratio(MetricsColumn::CpuRatio, "50%")]
+    #[case::memory_ratio(MetricsColumn::MemoryRatio, "n/a")]
+    fn format_metrics_column(#[case] column: MetricsColumn, #[case] expected: &str) {
+        let usage = ResourceUsage {
+            cpu: 0.25,
+            memory: 64.0 * 1024.0 * 1024.0,
+        };
+
+        let base = ResourceAmount {
+            cpu: Some(0.5),
+            memory: None,
+        };
+
+        assert_eq!(column.format(Some(usage), base), expected);
+    }
+
+    #[test]
+    fn format_without_usage_is_not_available() {
+        assert_eq!(
+            MetricsColumn::Cpu.format(None, ResourceAmount::default()),
+            "n/a"
+        );
+    }
+
+    #[test]
+    fn pod_resource_amount_sums_containers() {
+        let object = json!({
+            "spec": {
+                "containers": [
+                    { "resources": { "requests": { "cpu": "100m", "memory": "64Mi" } } },
+                    { "resources": { "requests": { "cpu": "200m" } } }
+                ]
+            }
+        });
+
+        let actual = pod_resource_amount(&object, "requests");
+
+        assert!((actual.cpu.unwrap() - 0.3).abs() < 1e-9);
+        assert_eq!(actual.memory, None);
+    }
+
+    #[test]
+    fn pod_resource_amount_without_spec() {
+        let actual = pod_resource_amount(&json!({ "metadata": {} }), "limits");
+
+        assert_eq!(actual, ResourceAmount::default());
+    }
+
+    #[test]
+    fn node_allocatable_from_status() {
+        let object = json!({
+            "status": { "allocatable": { "cpu": "4", "memory": "8Gi" } }
+        });
+
+        assert_eq!(
+            node_allocatable(&object),
+            ResourceAmount {
+                cpu: Some(4.0),
+                memory: Some(8.0 * 1024.0 * 1024.0 * 1024.0),
+            }
+        );
+    }
+
+    fn pod_metrics_list() -> PodMetricsList {
+        serde_json::from_value(json!({
+            "items": [{
+                "metadata": { "name": "web" },
+                "timestamp": "2025-01-01T00:00:00Z",
+                "window": "15s",
+                "containers": [
+                    { "name": "app", "usage": { "cpu": "100m", "memory": "32Mi" } },
+                    { "name": "sidecar", "usage": { "cpu": "50m", "memory": "16Mi" } }
+                ]
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn pod_usage_sums_containers() {
+        let mut client = MockTestKubeClient::new();
+
+        mock_expect!(
+            client,
+            request,
+            PodMetricsList,
+            eq("/apis/metrics.k8s.io/v1beta1/namespaces/default/pods"),
+            Ok(pod_metrics_list())
+        );
+
+        let usage = MetricsCache::default()
+            .pod_usage(&client, "default")
+            .await
+            .unwrap();
+
+        let web = usage["web"];
+
+        assert!((web.cpu - 0.15).abs() < 1e-9);
+        assert_eq!(web.memory, 48.0 * 1024.0 * 1024.0);
+    }
+
+    #[tokio::test]
+    async fn unavailable_metrics_are_cached() {
+        let mut client = MockTestKubeClient::new();
+
+        client
+            .expect_request::<NodeMetricsList>()
+            .with(eq("/apis/metrics.k8s.io/v1beta1/nodes"))
+            .times(1)
+            .returning(|_| Err(anyhow!("the server could not find the requested resource")));
+
+        let cache = MetricsCache::default();
+
+        assert!(cache.node_usage(&client).await.is_none());
+        assert!(cache.node_usage(&client).await.is_none());
+    }
+
+    fn sample(second: u32, cpu: f64, memory: f64) -> MetricsSample {
+        MetricsSample {
+            timestamp: serde_json::from_value(json!(format!("2025-01-01T00:00:{:02}Z", second)))
+                .unwrap(),
+            usage: ResourceUsage { cpu, memory },
+        }
+    }
+
+    #[test]
+    fn history_ignores_same_timestamp() {
+        let mut history = MetricsHistory::default();
+
+        history.push(sample(0, 0.1, 0.0));
+        history.push(sample(0, 0.2, 0.0));
+        history.push(sample(15, 0.3, 0.0));
+
+        let cpu: Vec<f64> = history.samples.iter().map(|s| s.usage.cpu).collect();
+
+        assert_eq!(cpu, vec![0.1, 0.3]);
+    }
+
+    #[test]
+    fn history_drops_oldest_sample() {
+        let mut history = MetricsHistory::default();
+
+        for i in 0..=HISTORY_LEN {
+            history.push(MetricsSample {
+                timestamp: serde_json::from_value(json!(format!("2025-01-01T00:{:02}:00Z", i)))
+                    .unwrap(),
+                usage: ResourceUsage {
+                    cpu: i as f64,
+                    memory: 0.0,
+                },
+            });
+        }
+
+        assert_eq!(history.samples.len(), HISTORY_LEN);
+        assert_eq!(history.samples.front().unwrap().usage.cpu, 1.0);
+    }
+
+    #[test]
+    fn sparkline_scales_to_base() {
+        assert_eq!(sparkline(&[0.0, 0.5, 1.0], Some(1.0)), " ▄█");
+        assert_eq!(sparkline(&[0.25, 0.5], Some(1.0)), "▂▄");
+        assert_eq!(sparkline(&[1.0, 2.0], None), "▄█");
+        assert_eq!(sparkline(&[0.0, 0.0], None), "  ");
+    }
+
+    #[test]
+    fn history_lines_show_latest_value_against_base() {
+        let mut history = MetricsHistory::default();
+
+        history.push(sample(0, 0.25, 64.0 * 1024.0 * 1024.0));
+        history.push(sample(15, 0.5, 128.0 * 1024.0 * 1024.0));
+
+        let base = ResourceAmount {
+            cpu: Some(1.0),
+            memory: None,
+        };
+
+        assert_eq!(
+            history.lines(base),
+            vec![
+                "# Metrics (last 2 samples)",
+                "# CPU  ▂▄  500m / 1000m",
+                "# MEM  ▄█  128Mi",
+            ]
+        );
+    }
+
+    #[test]
+    fn history_samples_at_metrics_ttl() {
+        let mut history = MetricsHistory::default();
+
+        assert!(history.sample_due());
+        assert!(!history.sample_due());
+
+        history.sampled_at = Instant::now().checked_sub(METRICS_TTL);
+
+        assert!(history.sample_due());
+    }
+
+    #[test]
+    fn empty_history_has_no_lines() {
+        assert!(MetricsHistory::default()
+            .lines(ResourceAmount::default())
+            .is_empty());
+    }
+}
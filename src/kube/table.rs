@@ -1,3 +1,5 @@
+mod informer;
+
 use std::collections::BTreeMap;
 
 use anyhow::Result;
@@ -8,6 +10,8 @@ use crate::kube::{
     KubeClientRequest as _,
 };
 
+pub use informer::TableInformers;
+
 #[derive(Debug, Default)]
 pub struct KubeTableRow {
     pub namespace: String,
@@ -83,7 +87,7 @@ pub async fn get_resource_per_namespace<F>(
 where
     F: Fn(&TableRow, &[usize]) -> KubeTableRow,
 {
-    let table: Table = client.request_table(&path).await?;
+    let table: Table = client.request_watched_table(&path).await?;
 
     let indexes = table.find_indexes(target_values)?;
 
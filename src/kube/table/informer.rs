@@ -0,0 +1,689 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    pin::pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use futures::{stream, StreamExt as _};
+use jiff::Timestamp;
+use kube::{
+    api::{Api, ApiResource, DynamicObject, PartialObjectMeta, ResourceExt as _},
+    runtime::{
+        watcher::{self, metadata_watcher, Event},
+        WatchStreamExt as _,
+    },
+    Client,
+};
+use percent_encoding::percent_decode_str;
+use tokio::task::AbortHandle;
+
+use crate::{
+    kube::{
+        apis::v1_table::{human_duration, Table, TableRow, Value},
+        KubeClient,
+        KubeClientRequest as _,
+    },
+    logger,
+};
+
+/// 参照されなくなったinformerを停止するまでの時間
+const IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 一覧を取得し直す間隔
+///
+/// "Last Seen"のようにサーバー側で計算される相対時刻の列を更新するため
+const RESYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 停止・再取得が必要かを確認する間隔
+///
+/// 変更されたリソースの行もこの間隔でまとめて取得し直す
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 1回の確認で変更されたリソースがこの件数を超えた場合は、1件ずつ取得せず一覧を取得し直す
+const RELIST_THRESHOLD: usize = 20;
+
+/// 変更されたリソースの行を並行して取得する数
+const FETCH_CONCURRENCY: usize = 8;
+
+/// 作成日時から計算し直す列
+const AGE_COLUMN: &str = "Age";
+
+/// リソース一覧をwatchしてTable形式でローカルに保持する
+///
+/// 一覧のパスごとにinformerを作成し、メタデータのwatchで変更を検知したリソースの行だけを取得し直す。
+/// 変更が多い場合は一覧を1回取得し直す。
+/// RBACでwatchが許可されていない場合や、watchできないパスの場合は毎回一覧を取得する。
+#[derive(Clone, Default)]
+pub struct TableInformers {
+    inner: Arc<Mutex<HashMap<String, Informer>>>,
+}
+
+enum Informer {
+    Watching {
+        store: SharedTableStore,
+        handle: AbortHandle,
+    },
+    /// watchが許可されていない、またはwatchできないパス
+    Polling,
+}
+
+enum Lookup {
+    Cached(SharedTableStore),
+    Polling,
+    Missing,
+}
+
+impl TableInformers {
+    /// キャッシュしている一覧を返す
+    ///
+    /// 初回はサーバーから一覧を取得し、watchを開始する
+    pub async fn table(&self, client: &KubeClient, path: &str) -> Result<Table> {
+        match self.lookup(path) {
+            Lookup::Cached(store) => {
+                let mut store = lock(&store);
+
+                store.touch();
+
+                Ok(store.to_table(Timestamp::now()))
+            }
+            Lookup::Polling => client.request_table(path).await,
+            Lookup::Missing => {
+                let Some(target) = WatchTarget::parse(path) else {
+                    logger!(
+                        info,
+                        "Path is not watchable, fall back to polling [{}]",
+                        path
+                    );
+
+                    lock(&self.inner).insert(path.to_string(), Informer::Polling);
+
+                    return client.request_table(path).await;
+                };
+
+                let table: Table = client.request_table(path).await?;
+
+                let store = Arc::new(Mutex::new(TableStore::new(table.clone())));
+
+                let handle = tokio::spawn(watch(
+                    self.clone(),
+                    client.clone(),
+                    path.to_string(),
+                    target,
+                    store.clone(),
+                ))
+                .abort_handle();
+
+                logger!(info, "Start table informer [{}]", path);
+
+                let replaced = lock(&self.inner)
+                    .insert(path.to_string(), Informer::Watching { store, handle });
+
+                if let Some(Informer::Watching { handle, .. }) = replaced {
+                    handle.abort();
+                }
+
+                Ok(table)
+            }
+        }
+    }
+
+    fn lookup(&self, path: &str) -> Lookup {
+        match lock(&self.inner).get(path) {
+            Some(Informer::Watching { store, handle }) if !handle.is_finished() => {
+                Lookup::Cached(store.clone())
+            }
+            Some(Informer::Polling) => Lookup::Polling,
+            _ => Lookup::Missing,
+        }
+    }
+
+    /// 指定したstoreを持つinformerを置き換える
+    ///
+    /// 同じパスで新しいinformerが作成されている場合は何もしない
+    fn replace(&self, path: &str, store: &SharedTableStore, informer: Option<Informer>) {
+        let mut inner = lock(&self.inner);
+
+        let is_own = matches!(
+            inner.get(path),
+            Some(Informer::Watching { store: s, .. }) if Arc::ptr_eq(s, store)
+        );
+
+        if !is_own {
+            return;
+        }
+
+        match informer {
+            Some(informer) => inner.insert(path.to_string(), informer),
+            None => inner.remove(path),
+        };
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// 一覧のパスから組み立てたwatchの対象
+#[derive(Debug, PartialEq)]
+struct WatchTarget {
+    resource: ApiResource,
+    namespace: Option<String>,
+    config: watcher::Config,
+    /// 1件ずつ取得するときに引き継ぐクエリ (`includeObject` など)
+    query: Vec<String>,
+}
+
+impl WatchTarget {
+    /// `/api/{version}[/namespaces/{namespace}]/{plural}` と
+    /// `/apis/{group}/{version}[/namespaces/{namespace}]/{plural}` の形式に対応する
+    fn parse(path: &str) -> Option<Self> {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+
+        let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+
+        let (group, version, rest) = match segments.as_slice() {
+            ["api", version, rest @ ..] => ("", *version, rest),
+            ["apis", group, version, rest @ ..] => (*group, *version, rest),
+            _ => return None,
+        };
+
+        let (namespace, plural) = match rest {
+            [plural] => (None, *plural),
+            ["namespaces", namespace, plural] => (Some(namespace.to_string()), *plural),
+            _ => return None,
+        };
+
+        let api_version = if group.is_empty() {
+            version.to_string()
+        } else {
+            format!("{}/{}", group, version)
+        };
+
+        // kindはリクエストのURLに使われないため空のままにする
+        let resource = ApiResource {
+            group: group.to_string(),
+            version: version.to_string(),
+            api_version,
+            kind: String::new(),
+            plural: plural.to_string(),
+        };
+
+        let mut config = watcher::Config::default();
+        let mut kept_query = Vec::new();
+
+        for param in query.split('&').filter(|p| !p.is_empty()) {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+
+            let decoded = || percent_decode_str(value).decode_utf8().ok();
+
+            match key {
+                "labelSelector" => config = config.labels(&decoded()?),
+                "fieldSelector" => config = config.fields(&decoded()?),
+                _ => kept_query.push(param.to_string()),
+            }
+        }
+
+        Some(Self {
+            resource,
+            namespace,
+            config,
+            query: kept_query,
+        })
+    }
+
+    fn api(&self, client: Client) -> Api<DynamicObject> {
+        match &self.namespace {
+            Some(namespace) => Api::namespaced_with(client, namespace, &self.resource),
+            None => Api::all_with(client, &self.resource),
+        }
+    }
+
+    /// 1件分のTableを取得するパス
+    fn object_path(&self, namespace: Option<&str>, name: &str) -> String {
+        let prefix = if self.resource.group.is_empty() {
+            format!("/api/{}", self.resource.version)
+        } else {
+            format!("/apis/{}/{}", self.resource.group, self.resource.version)
+        };
+
+        let mut path = match namespace {
+            Some(namespace) => {
+                format!(
+                    "{}/namespaces/{}/{}/{}",
+                    prefix, namespace, self.resource.plural, name
+                )
+            }
+            None => format!("{}/{}/{}", prefix, self.resource.plural, name),
+        };
+
+        if !self.query.is_empty() {
+            path += &format!("?{}", self.query.join("&"));
+        }
+
+        path
+    }
+}
+
+type ObjectMeta = PartialObjectMeta<DynamicObject>;
+
+/// メタデータをwatchし、変更されたリソースの行をstoreに反映する
+async fn watch(
+    informers: TableInformers,
+    client: KubeClient,
+    path: String,
+    target: WatchTarget,
+    store: SharedTableStore,
+) {
+    let stream =
+        metadata_watcher(target.api(client.to_client()), target.config.clone()).default_backoff();
+
+    let mut stream = pin!(stream);
+
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+
+    let mut last_sync = Instant::now();
+
+    // 行を取得し直すリソース
+    let mut pending = BTreeSet::new();
+
+    loop {
+        tokio::select! {
+            Some(event) = stream.next() => {
+                match event {
+                    Ok(Event::Apply(object)) => {
+                        pending.insert(object_key(&object));
+                    }
+                    Ok(Event::Delete(object)) => {
+                        let key = object_key(&object);
+
+                        pending.remove(&key);
+                        lock(&store).remove(&key);
+                    }
+                    // watchを開始し直したときは、取りこぼした変更を反映するため一覧を取得し直す
+                    Ok(Event::InitDone) => {
+                        pending.clear();
+                        relist(&client, &path, &store).await;
+                        last_sync = Instant::now();
+                    }
+                    Ok(Event::Init | Event::InitApply(_)) => {}
+                    Err(err) if is_unsupported(&err) => {
+                        logger!(
+                            info,
+                            "Watch is not allowed, fall back to polling [{}]: {}",
+                            path,
+                            err
+                        );
+                        informers.replace(&path, &store, Some(Informer::Polling));
+                        return;
+                    }
+                    Err(err) => {
+                        logger!(error, "Failed to watch table [{}]: {}", path, err);
+                    }
+                }
+            }
+            _ = interval.tick() => {
+                if IDLE_TIMEOUT < lock(&store).last_access.elapsed() {
+                    logger!(info, "Stop idle table informer [{}]", path);
+                    informers.replace(&path, &store, None);
+                    return;
+                }
+
+                if RESYNC_INTERVAL < last_sync.elapsed() || RELIST_THRESHOLD < pending.len() {
+                    pending.clear();
+                    relist(&client, &path, &store).await;
+                    last_sync = Instant::now();
+                    continue;
+                }
+
+                let keys = std::mem::take(&mut pending);
+
+                stream::iter(keys)
+                    .map(|key| fetch_row(&client, &target, key, &store))
+                    .buffer_unordered(FETCH_CONCURRENCY)
+                    .for_each(|_| async {})
+                    .await;
+            }
+        }
+    }
+}
+
+async fn relist(client: &KubeClient, path: &str, store: &SharedTableStore) {
+    match client.request_table::<Table>(path).await {
+        Ok(table) => lock(store).replace(table),
+        Err(err) => logger!(error, "Failed to relist table [{}]: {}", path, err),
+    }
+}
+
+async fn fetch_row(
+    client: &KubeClient,
+    target: &WatchTarget,
+    key: RowKey,
+    store: &SharedTableStore,
+) {
+    let (namespace, name) = &key;
+
+    let namespace = Some(namespace.as_str()).filter(|ns| !ns.is_empty());
+
+    let path = target.object_path(namespace, name);
+
+    match client.request_table::<Table>(&path).await {
+        Ok(table) => {
+            if let Some(row) = table.rows.into_iter().next() {
+                lock(store).insert(key, row);
+            }
+        }
+        Err(err) => logger!(error, "Failed to fetch table row [{}]: {}", path, err),
+    }
+}
+
+fn is_unsupported(err: &watcher::Error) -> bool {
+    let status = match err {
+        watcher::Error::InitialListFailed(kube::Error::Api(status))
+        | watcher::Error::WatchStartFailed(kube::Error::Api(status))
+        | watcher::Error::WatchFailed(kube::Error::Api(status))
+        | watcher::Error::WatchError(status) => status,
+        _ => return false,
+    };
+
+    status.is_forbidden() || status.code == 405
+}
+
+fn object_key(object: &ObjectMeta) -> RowKey {
+    (object.namespace().unwrap_or_default(), object.name_any())
+}
+
+type SharedTableStore = Arc<Mutex<TableStore>>;
+
+/// (namespace, name)
+type RowKey = (String, String);
+
+/// 一覧の行を保持する
+#[derive(Debug)]
+struct TableStore {
+    /// 行を除いた一覧 (列の定義やメタデータ)
+    header: Table,
+    rows: BTreeMap<RowKey, TableRow>,
+    last_access: Instant,
+}
+
+impl TableStore {
+    fn new(table: Table) -> Self {
+        let mut store = Self {
+            header: Table::default(),
+            rows: BTreeMap::new(),
+            last_access: Instant::now(),
+        };
+
+        store.replace(table);
+
+        store
+    }
+
+    fn touch(&mut self) {
+        self.last_access = Instant::now();
+    }
+
+    /// 一覧で置き換える
+    fn replace(&mut self, mut table: Table) {
+        self.rows = std::mem::take(&mut table.rows)
+            .into_iter()
+            .filter_map(|row| Some((row_key(&row)?, row)))
+            .collect();
+        self.header = table;
+    }
+
+    fn insert(&mut self, key: RowKey, row: TableRow) {
+        self.rows.insert(key, row);
+    }
+
+    fn remove(&mut self, key: &RowKey) {
+        self.rows.remove(key);
+    }
+
+    /// 保持している行をTableに変換する
+    ///
+    /// Age列はキャッシュした時点の値のため、作成日時から計算し直す
+    fn to_table(&self, now: Timestamp) -> Table {
+        let age_index = self.header.find_index(AGE_COLUMN);
+
+        let rows = self
+            .rows
+            .values()
+            .map(|row| {
+                let mut row = row.clone();
+
+                if let Some(age) = age_index.and_then(|i| row.cells.get_mut(i)) {
+                    if let Some(created) = creation_timestamp(&row.object) {
+                        *age = Value::from(human_duration(now.as_second() - created.as_second()));
+                    }
+                }
+
+                row
+            })
+            .collect();
+
+        Table {
+            rows,
+            ..self.header.clone()
+        }
+    }
+}
+
+fn metadata(row: &TableRow) -> Option<&serde_json::Value> {
+    row.object.as_ref()?.0.get("metadata")
+}
+
+fn row_key(row: &TableRow) -> Option<RowKey> {
+    let metadata = metadata(row)?;
+
+    let name = metadata.get("name")?.as_str()?;
+
+    let namespace = metadata
+        .get("namespace")
+        .and_then(|ns| ns.as_str())
+        .unwrap_or_default();
+
+    Some((namespace.to_string(), name.to_string()))
+}
+
+fn creation_timestamp(
+    object: &Option<k8s_openapi::apimachinery::pkg::runtime::RawExtension>,
+) -> Option<Timestamp> {
+    object
+        .as_ref()?
+        .0
+        .get("metadata")?
+        .get("creationTimestamp")?
+        .as_str()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    fn table(rows: &[(&str, &str)], resource_version: &str) -> Table {
+        serde_json::from_value(serde_json::json!({
+            "kind": "Table",
+            "apiVersion": "meta.k8s.io/v1",
+            "metadata": {"resourceVersion": resource_version},
+            "columnDefinitions": [
+                {"name": "Name", "type": "string", "format": "name", "description": "", "priority": 0},
+                {"name": "Age", "type": "string", "format": "", "description": "", "priority": 0},
+            ],
+            "rows": rows.iter().map(|(name, created)| {
+                serde_json::json!({
+                    "cells": [name, "1m"],
+                    "object": {
+                        "kind": "PartialObjectMetadata",
+                        "metadata": {
+                            "name": name,
+                            "namespace": "default",
+                            "creationTimestamp": created,
+                        },
+                    },
+                })
+            }).collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    fn names(store: &TableStore) -> Vec<String> {
+        store
+            .to_table(Timestamp::UNIX_EPOCH)
+            .rows
+            .iter()
+            .map(|row| row.cells[0].to_string())
+            .collect()
+    }
+
+    fn key(name: &str) -> RowKey {
+        ("default".to_string(), name.to_string())
+    }
+
+    const CREATED: &str = "2025-01-01T00:00:00Z";
+
+    #[test]
+    fn changed_rows_are_applied_to_store() {
+        let mut store = TableStore::new(table(&[("b", CREATED), ("a", CREATED)], "1"));
+
+        assert_eq!(names(&store), vec!["a", "b"]);
+
+        let added = table(&[("c", CREATED)], "2").rows.remove(0);
+
+        store.insert(key("c"), added);
+        store.remove(&key("a"));
+
+        assert_eq!(names(&store), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn modified_row_is_replaced() {
+        let mut store = TableStore::new(table(&[("a", CREATED)], "1"));
+
+        let mut modified = table(&[("a", CREATED)], "2").rows.remove(0);
+        modified.cells[1] = Value::from("changed");
+
+        store.insert(key("a"), modified);
+
+        assert_eq!(store.rows.len(), 1);
+        assert_eq!(store.rows[&key("a")].cells[1], Value::from("changed"));
+    }
+
+    #[test]
+    fn list_metadata_is_kept() {
+        let store = TableStore::new(table(&[("a", CREATED)], "1"));
+
+        let table = store.to_table(Timestamp::UNIX_EPOCH);
+
+        assert_eq!(table.type_meta.map(|tm| tm.kind), Some("Table".to_string()));
+        assert_eq!(
+            table.metadata.and_then(|md| md.resource_version),
+            Some("1".to_string())
+        );
+        assert_eq!(table.column_definitions.len(), 2);
+    }
+
+    #[test]
+    fn age_is_calculated_from_creation_timestamp() {
+        let store = TableStore::new(table(&[("a", CREATED)], "1"));
+
+        let now: Timestamp = "2025-01-01T00:05:30Z".parse().unwrap();
+
+        assert_eq!(store.to_table(now).rows[0].cells[1], Value::from("5m30s"));
+    }
+
+    #[rstest]
+    #[case::core_all_namespaces("/api/v1/pods", "", "v1", "pods", None)]
+    #[case::core_namespaced("/api/v1/namespaces/app/pods", "", "v1", "pods", Some("app"))]
+    #[case::group_namespaced(
+        "/apis/apps/v1/namespaces/app/deployments",
+        "apps",
+        "v1",
+        "deployments",
+        Some("app")
+    )]
+    #[case::cluster_scoped("/api/v1/namespaces", "", "v1", "namespaces", None)]
+    fn watch_target_is_parsed_from_list_path(
+        #[case] path: &str,
+        #[case] group: &str,
+        #[case] version: &str,
+        #[case] plural: &str,
+        #[case] namespace: Option<&str>,
+    ) {
+        let target = WatchTarget::parse(path).unwrap();
+
+        assert_eq!(target.resource.group, group);
+        assert_eq!(target.resource.version, version);
+        assert_eq!(target.resource.plural, plural);
+        assert_eq!(target.namespace.as_deref(), namespace);
+    }
+
+    #[rstest]
+    #[case::object("/api/v1/namespaces/app/pods/web")]
+    #[case::subresource("/apis/apps/v1/namespaces/app/deployments/web/scale")]
+    #[case::not_api("/metrics")]
+    fn unwatchable_path_is_not_parsed(#[case] path: &str) {
+        assert_eq!(WatchTarget::parse(path), None);
+    }
+
+    #[test]
+    fn selectors_are_passed_to_watcher() {
+        let target = WatchTarget::parse(
+            "/api/v1/namespaces/app/pods?labelSelector=app%3Dweb&fieldSelector=status.phase%3DRunning&includeObject=Object",
+        )
+        .unwrap();
+
+        assert_eq!(target.config.label_selector.as_deref(), Some("app=web"));
+        assert_eq!(
+            target.config.field_selector.as_deref(),
+            Some("status.phase=Running")
+        );
+        assert_eq!(target.query, vec!["includeObject=Object"]);
+    }
+
+    #[rstest]
+    #[case::namespaced(
+        "/api/v1/pods?includeObject=Object",
+        Some("app"),
+        "/api/v1/namespaces/app/pods/web?includeObject=Object"
+    )]
+    #[case::cluster_scoped(
+        "/apis/storage.k8s.io/v1/storageclasses",
+        None,
+        "/apis/storage.k8s.io/v1/storageclasses/web"
+    )]
+    fn object_path_cases(
+        #[case] path: &str,
+        #[case] namespace: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        let target = WatchTarget::parse(path).unwrap();
+
+        assert_eq!(target.object_path(namespace, "web"), expected);
+    }
+
+    #[test]
+    fn forbidden_watch_falls_back_to_polling() {
+        let status: kube::core::Status = serde_json::from_value(serde_json::json!({
+            "status": "Failure",
+            "code": 403,
+            "reason": "Forbidden",
+            "message": "pods is forbidden",
+        }))
+        .unwrap();
+
+        assert!(is_unsupported(&watcher::Error::InitialListFailed(
+            kube::Error::Api(Box::new(status))
+        )));
+        assert!(!is_unsupported(&watcher::Error::NoResourceVersion));
+    }
+}
@@ -8,6 +8,7 @@ mod features;
 mod kube;
 mod logging;
 mod message;
+mod state;
 mod ui;
 mod workers;
 
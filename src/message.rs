@@ -9,6 +9,7 @@ pub enum UserEvent {
     Mouse(MouseEvent),
     FocusGained,
     FocusLost,
+    Resize(u16, u16),
 }
 
 impl From<char> for UserEvent {
@@ -0,0 +1,202 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::logger;
+
+/// 1つのカラム選択ダイアログで選択されている列
+///
+/// 各列はダイアログの項目が持つメタデータ（`kind` と `id` もしくは `key` / `header`）で表す
+pub type ColumnChoices = Vec<BTreeMap<String, String>>;
+
+/// 終了時の画面の状態
+///
+/// 次回起動時に復元する。CLI引数で指定された値は復元した値より優先する。
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct State {
+    /// 最後に表示していたコンテキスト
+    #[serde(default)]
+    pub context: Option<String>,
+
+    /// コンテキストごとの表示中のネームスペース
+    #[serde(default)]
+    pub namespaces: BTreeMap<String, Vec<String>>,
+
+    /// 最後に表示していたタブのID
+    #[serde(default)]
+    pub tab: Option<String>,
+
+    /// 実行したログクエリ（古い順）
+    #[serde(default)]
+    pub log_query_history: Vec<String>,
+
+    /// テーブルのウィジェットIDごとのフィルター
+    #[serde(default)]
+    pub filters: BTreeMap<String, String>,
+
+    /// カラム選択ダイアログのIDごとの選択列
+    #[serde(default)]
+    pub columns: BTreeMap<String, ColumnChoices>,
+}
+
+impl State {
+    /// 状態ファイルを読み込む
+    ///
+    /// ファイルがない場合や読み込めない場合は空の状態を返す
+    pub fn load(path: &Path) -> Self {
+        match Self::read(path) {
+            Ok(Some(state)) => state,
+            Ok(None) => Self::default(),
+            Err(err) => {
+                logger!(warn, "Failed to load state file: {:?}", err);
+
+                Self::default()
+            }
+        }
+    }
+
+    fn read(path: &Path) -> Result<Option<Self>> {
+        if !path.try_exists()? {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path)?;
+
+        Ok(Some(serde_yaml::from_str(&content)?))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create directory {:?}", dir))?;
+        }
+
+        let content = serde_yaml::to_string(self)?;
+
+        fs::write(path, content).with_context(|| format!("Failed to write state file {:?}", path))
+    }
+}
+
+/// 状態ファイルのパスと、起動時に復元するかどうか
+#[derive(Debug, Clone)]
+pub struct StateOption {
+    pub path: PathBuf,
+    pub restore: bool,
+}
+
+impl StateOption {
+    /// 起動時に復元する状態
+    ///
+    /// 復元しない場合は空の状態を返す
+    pub fn load(&self) -> State {
+        if self.restore {
+            State::load(&self.path)
+        } else {
+            State::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("kubetui-state-test-{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn 保存した状態を読み込める() {
+        let path = temp_path("roundtrip/state.yaml");
+
+        let state = State {
+            context: Some("kind-kind".into()),
+            namespaces: BTreeMap::from([("kind-kind".into(), vec!["app".into(), "db".into()])]),
+            tab: Some("event_tab".into()),
+            log_query_history: vec!["pod:web".into(), "pod:api".into()],
+            filters: BTreeMap::from([("pod_widget".into(), "status:Running".into())]),
+            columns: BTreeMap::from([(
+                "pod_columns_dialog".into(),
+                vec![BTreeMap::from([
+                    ("kind".into(), "builtin".into()),
+                    ("id".into(), "name".into()),
+                ])],
+            )]),
+        };
+
+        state.save(&path).unwrap();
+
+        assert_eq!(State::load(&path), state);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn ファイルがない場合は空の状態を返す() {
+        assert_eq!(
+            State::load(&temp_path("not-found/state.yaml")),
+            State::default()
+        );
+    }
+
+    #[test]
+    fn 壊れたファイルは空の状態として扱う() {
+        let path = temp_path("broken/state.yaml");
+
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "context: [").unwrap();
+
+        assert_eq!(State::load(&path), State::default());
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn 一部の項目だけでも読み込める() {
+        let state: State = serde_yaml::from_str(indoc! {"
+            context: prod
+            tab: workload_tab
+        "})
+        .unwrap();
+
+        assert_eq!(
+            state,
+            State {
+                context: Some("prod".into()),
+                tab: Some("workload_tab".into()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn 復元しない場合は読み込まない() {
+        let path = temp_path("no-restore/state.yaml");
+
+        State {
+            context: Some("prod".into()),
+            ..Default::default()
+        }
+        .save(&path)
+        .unwrap();
+
+        let option = StateOption {
+            path: path.clone(),
+            restore: false,
+        };
+
+        assert_eq!(option.load(), State::default());
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -304,6 +304,14 @@ impl<'a> Widget<'a> {
         }
     }
 
+    pub fn as_input(&self) -> &InputForm {
+        if let Self::Input(w) = self {
+            w
+        } else {
+            panic!("called as_input() on {:?}", self)
+        }
+    }
+
     // as_mut_*
     pub fn as_mut_list(&mut self) -> &mut List<'a> {
         if let Self::List(w) = self {
@@ -352,4 +360,12 @@ impl<'a> Widget<'a> {
             panic!("called as_mut_check_list() on {:?}", self)
         }
     }
+
+    pub fn as_mut_input(&mut self) -> &mut InputForm {
+        if let Self::Input(w) = self {
+            w
+        } else {
+            panic!("called as_mut_input() on {:?}", self)
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{collections::BTreeMap, rc::Rc};
 
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind},
@@ -154,6 +154,50 @@ impl CheckList {
         &self.items
     }
 
+    /// チェックされている項目のメタデータ（表示順）
+    pub fn checked_metadata(&self) -> Vec<BTreeMap<String, String>> {
+        self.items
+            .iter()
+            .filter(|item| item.required || item.checked)
+            .filter_map(|item| item.metadata.clone())
+            .collect()
+    }
+
+    /// メタデータが一致する項目を指定の順にチェックした状態で先頭に並べ、
+    /// 残りの項目は必須のものだけをチェックする
+    ///
+    /// 一致する項目がない場合は何もしない。変更した場合は `on_change` を呼ぶコールバックを返す
+    pub fn restore_checked(&mut self, metadata: &[BTreeMap<String, String>]) -> Option<Callback> {
+        let mut rest = std::mem::take(&mut self.items);
+
+        let mut checked: Vec<CheckListItem> = metadata
+            .iter()
+            .filter_map(|md| {
+                let index = rest
+                    .iter()
+                    .position(|item| item.metadata.as_ref() == Some(md))?;
+
+                Some(rest.remove(index))
+            })
+            .collect();
+
+        if checked.is_empty() {
+            self.items = rest;
+            return None;
+        }
+
+        checked.iter_mut().for_each(|item| item.checked = true);
+        rest.iter_mut()
+            .for_each(|item| item.checked = item.required);
+
+        self.items = checked.into_iter().chain(rest).collect();
+
+        let cb = self.on_change.clone()?;
+        let item = self.items.first().cloned()?;
+
+        Some(Callback::new(move |w| cb(w, &item)))
+    }
+
     fn layout() -> Layout {
         Layout::vertical([
             Constraint::Fill(1),   // Main content
@@ -482,3 +526,73 @@ fn create_list_items<'a, 'b>(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn item(id: &str, checked: bool, required: bool) -> CheckListItem {
+        CheckListItem {
+            label: id.to_uppercase(),
+            checked,
+            required,
+            metadata: Some(metadata(id)),
+        }
+    }
+
+    fn metadata(id: &str) -> BTreeMap<String, String> {
+        BTreeMap::from([("id".to_string(), id.to_string())])
+    }
+
+    fn labels(check_list: &CheckList) -> Vec<(String, bool)> {
+        check_list
+            .items()
+            .iter()
+            .map(|item| (item.label.clone(), item.checked))
+            .collect()
+    }
+
+    #[test]
+    fn 指定の順にチェックして先頭に並べる() {
+        let mut check_list = CheckList::builder()
+            .items([
+                item("name", true, true),
+                item("status", true, false),
+                item("age", false, false),
+                item("ip", false, false),
+            ])
+            .build();
+
+        check_list.restore_checked(&[metadata("ip"), metadata("name"), metadata("gone")]);
+
+        assert_eq!(
+            labels(&check_list),
+            vec![
+                ("IP".to_string(), true),
+                ("NAME".to_string(), true),
+                ("STATUS".to_string(), false),
+                ("AGE".to_string(), false),
+            ]
+        );
+        assert_eq!(
+            check_list.checked_metadata(),
+            vec![metadata("ip"), metadata("name")]
+        );
+    }
+
+    #[test]
+    fn 一致する項目がない場合は変更しない() {
+        let mut check_list = CheckList::builder()
+            .items([item("name", true, true), item("status", true, false)])
+            .build();
+
+        check_list.restore_checked(&[metadata("gone")]);
+
+        assert_eq!(
+            labels(&check_list),
+            vec![("NAME".to_string(), true), ("STATUS".to_string(), true)]
+        );
+    }
+}
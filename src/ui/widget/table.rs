@@ -3,8 +3,9 @@ mod filter;
 mod filter_applicator;
 mod filter_parser;
 mod item;
+mod sort;
 
-use std::rc::Rc;
+use std::{collections::HashSet, rc::Rc};
 
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind},
@@ -58,8 +59,9 @@ pub use filter_applicator::{
     TableFilterPredicate,
 };
 pub use filter_parser::parse_table_filter;
+pub use sort::TableSort;
 
-use item::InnerItem;
+use item::{mark_key, InnerItem};
 
 const COLUMN_SPACING: u16 = 3;
 const HIGHLIGHT_SYMBOL: &str = " ";
@@ -101,10 +103,21 @@ pub struct TableBuilder {
     state: TableState,
     on_select: Option<OnSelectCallback>,
     actions: Vec<(UserEvent, Callback)>,
+    sort_next_events: Vec<UserEvent>,
+    sort_prev_events: Vec<UserEvent>,
+    mark_events: MarkEvents,
     block_injection: Option<RenderBlockInjection>,
     highlight_injection: Option<RenderHighlightInjection>,
 }
 
+/// 行のマークを切り替えるキー
+#[derive(Debug, Default)]
+struct MarkEvents {
+    toggle: Vec<UserEvent>,
+    range: Vec<UserEvent>,
+    all: Vec<UserEvent>,
+}
+
 #[allow(dead_code)]
 impl TableBuilder {
     pub fn id(mut self, id: impl Into<String>) -> Self {
@@ -167,6 +180,50 @@ impl TableBuilder {
         self
     }
 
+    /// 複数のイベントに同じコールバックを登録する
+    pub fn actions<F, I>(self, evs: I, cb: F) -> Self
+    where
+        I: IntoIterator<Item = UserEvent>,
+        F: Into<Callback>,
+    {
+        let cb = cb.into();
+        evs.into_iter()
+            .fold(self, |builder, ev| builder.action(ev, cb.clone()))
+    }
+
+    /// 列での並び替えを有効にする
+    ///
+    /// `next` で「並び替えなし → 1列目昇順 → 1列目降順 → 2列目昇順 → …」の順に切り替え、
+    /// `prev` で逆順に切り替える
+    pub fn sort_actions<N, P>(mut self, next: N, prev: P) -> Self
+    where
+        N: IntoIterator<Item = UserEvent>,
+        P: IntoIterator<Item = UserEvent>,
+    {
+        self.sort_next_events = next.into_iter().collect();
+        self.sort_prev_events = prev.into_iter().collect();
+        self
+    }
+
+    /// 複数行のマークを有効にする
+    ///
+    /// * `toggle` - 選択中の行のマークを切り替えて次の行に移る
+    /// * `range` - 最後にマークを切り替えた行から選択中の行までをマークする
+    /// * `all` - 表示中（フィルター後）の行をすべてマークする。すべてマーク済みならマークを外す
+    pub fn mark_actions<T, R, A>(mut self, toggle: T, range: R, all: A) -> Self
+    where
+        T: IntoIterator<Item = UserEvent>,
+        R: IntoIterator<Item = UserEvent>,
+        A: IntoIterator<Item = UserEvent>,
+    {
+        self.mark_events = MarkEvents {
+            toggle: toggle.into_iter().collect(),
+            range: range.into_iter().collect(),
+            all: all.into_iter().collect(),
+        };
+        self
+    }
+
     pub fn block_injection<F>(mut self, block_injection: F) -> Self
     where
         F: Into<RenderBlockInjection>,
@@ -190,6 +247,9 @@ impl TableBuilder {
             theme: self.theme,
             on_select: self.on_select,
             actions: self.actions,
+            sort_next_events: self.sort_next_events,
+            sort_prev_events: self.sort_prev_events,
+            mark_events: self.mark_events,
             state: self.state,
             block_injection: self.block_injection,
             highlight_injection: self.highlight_injection,
@@ -263,6 +323,14 @@ pub struct Table<'a> {
     filter_state: Option<TableFilterPredicate>,
     filter_error: Option<String>,
     mode: Mode,
+    sort: Option<TableSort>,
+    sort_next_events: Vec<UserEvent>,
+    sort_prev_events: Vec<UserEvent>,
+    /// マークした行のキー
+    marked: HashSet<TableItem>,
+    /// 範囲でマークするときの起点
+    mark_anchor: Option<TableItem>,
+    mark_events: MarkEvents,
     on_select: Option<OnSelectCallback>,
     actions: Vec<(UserEvent, Callback)>,
     block_injection: Option<RenderBlockInjection>,
@@ -282,6 +350,22 @@ impl Table<'_> {
         &self.state
     }
 
+    /// 条件に一致する最初の行を選択する
+    ///
+    /// 一致する行がなければ選択は変えずに `false` を返す
+    pub fn select_item<F>(&mut self, predicate: F) -> bool
+    where
+        F: Fn(&TableItem) -> bool,
+    {
+        let Some(index) = self.items().iter().position(predicate) else {
+            return false;
+        };
+
+        self.state.select(Some(index));
+
+        true
+    }
+
     /// Title suffix showing the cursor position over the visible row count,
     /// e.g. ` [3/12]`. While a filter is active the pre-filter total is
     /// appended in parens: ` [3/12 (50)]`. The parens are driven by filter
@@ -309,6 +393,10 @@ impl Table<'_> {
             indicator.push_str(&format!(" (inactive: {})", inactive.join(", ")));
         }
 
+        if !self.marked.is_empty() {
+            indicator.push_str(&format!(" (marked: {})", self.marked.len()));
+        }
+
         indicator
     }
 
@@ -341,6 +429,182 @@ impl Table<'_> {
             .collect()
     }
 
+    /// 並び替えの列と順序を変更する
+    ///
+    /// 選択中の行は並び替え後も同じ行を選択し続ける
+    pub fn update_sort(&mut self, sort: Option<TableSort>) {
+        let selected = self.selected_item();
+
+        self.sort = sort.clone();
+        self.items.update_sort(sort);
+        self.filter_items();
+
+        if let Some(selected) = selected {
+            self.select_item(|item| *item == *selected);
+        }
+    }
+
+    /// 確定済みのフィルターの入力内容
+    pub fn filter_text(&self) -> Option<String> {
+        if !self.mode.is_filter_confirm() || self.filter_state.is_none() {
+            return None;
+        }
+
+        self.filter_form
+            .as_ref()
+            .map(|f| f.content())
+            .filter(|content| !content.is_empty())
+    }
+
+    /// 入力内容からフィルターを確定した状態にする
+    ///
+    /// 解釈できない入力は無視する。成功時は applicator の on_apply を呼ぶコールバックを返す
+    pub fn restore_filter(&mut self, input: &str) -> Option<Callback> {
+        self.filter_form.as_mut()?.update_content(input.to_string());
+
+        let Some(predicate) = self.run_parser_and_update_state() else {
+            if let Some(filter_form) = self.filter_form.as_mut() {
+                filter_form.clear();
+            }
+
+            self.filter_error = None;
+
+            return None;
+        };
+
+        self.mode.filter_confirm();
+
+        self.filter_items();
+
+        self.on_filter_apply_callback(predicate)
+    }
+
+    /// マークした行
+    ///
+    /// 表示中の行を表示順に並べ、その後ろにフィルターで隠れている行を続ける
+    pub fn marked_items(&self) -> Vec<TableItem> {
+        let is_marked = |item: &&TableItem| self.marked.contains(&mark_key(item));
+
+        let visible: Vec<TableItem> = self.items().iter().filter(is_marked).cloned().collect();
+
+        let hidden: Vec<TableItem> = self
+            .items
+            .original_items()
+            .iter()
+            .filter(is_marked)
+            .filter(|item| !visible.contains(item))
+            .cloned()
+            .collect();
+
+        visible.into_iter().chain(hidden).collect()
+    }
+
+    /// マークした行。マークがなければ選択中の行
+    pub fn marked_or_selected_items(&self) -> Vec<TableItem> {
+        if self.marked.is_empty() {
+            self.selected_item()
+                .map(|item| vec![(*item).clone()])
+                .unwrap_or_default()
+        } else {
+            self.marked_items()
+        }
+    }
+
+    fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.mark_anchor = None;
+        self.items.update_marked(HashSet::new());
+    }
+
+    fn toggle_mark(&mut self) {
+        let Some(item) = self.selected_item() else {
+            return;
+        };
+
+        let key = mark_key(&item);
+
+        if !self.marked.remove(&key) {
+            self.marked.insert(key.clone());
+        }
+
+        self.mark_anchor = Some(key);
+        self.items.update_marked(self.marked.clone());
+        self.update_row_bounds();
+
+        self.select_next(1);
+    }
+
+    fn mark_range(&mut self) {
+        let Some(selected) = self.state.selected() else {
+            return;
+        };
+
+        let anchor = self.mark_anchor.as_ref().and_then(|anchor| {
+            self.items()
+                .iter()
+                .position(|item| mark_key(item) == *anchor)
+        });
+
+        let Some(anchor) = anchor else {
+            self.toggle_mark();
+            return;
+        };
+
+        let (start, end) = (anchor.min(selected), anchor.max(selected));
+
+        let keys: Vec<TableItem> = self.items()[start..=end].iter().map(mark_key).collect();
+
+        self.marked.extend(keys);
+        self.items.update_marked(self.marked.clone());
+        self.update_row_bounds();
+    }
+
+    fn mark_all(&mut self) {
+        let keys: Vec<TableItem> = self.items().iter().map(mark_key).collect();
+
+        if keys.iter().all(|key| self.marked.contains(key)) {
+            for key in &keys {
+                self.marked.remove(key);
+            }
+        } else {
+            self.marked.extend(keys);
+        }
+
+        self.items.update_marked(self.marked.clone());
+        self.update_row_bounds();
+    }
+
+    /// 一覧から消えた行のマークを外す
+    fn retain_marks(&mut self) {
+        if self.marked.is_empty() {
+            return;
+        }
+
+        let keys: HashSet<TableItem> = self.items.original_items().iter().map(mark_key).collect();
+
+        self.marked.retain(|key| keys.contains(key));
+
+        if self
+            .mark_anchor
+            .as_ref()
+            .is_some_and(|anchor| !keys.contains(anchor))
+        {
+            self.mark_anchor = None;
+        }
+
+        self.items.update_marked(self.marked.clone());
+    }
+
+    fn sort_next(&mut self) {
+        let sort = TableSort::next(self.sort.as_ref(), self.items.header().original());
+        self.update_sort(sort);
+    }
+
+    fn sort_prev(&mut self) {
+        let sort = TableSort::prev(self.sort.as_ref(), self.items.header().original());
+        self.update_sort(sort);
+    }
+
     pub fn equal_header(&self, header: &[String]) -> bool {
         self.items.header().original() == header
     }
@@ -356,8 +620,12 @@ impl Table<'_> {
             .header(header)
             .items(rows)
             .max_width(self.max_width())
+            .sort(self.sort.clone())
+            .marked(self.marked.clone())
             .build();
 
+        self.retain_marks();
+
         let header = self.items.header().original().to_vec();
         let state = self.filter_state.clone();
         self.items.apply_filter(|item| {
@@ -577,6 +845,7 @@ impl WidgetTrait for Table<'_> {
         let old_len = self.items.len();
 
         self.items.update_items(items.table());
+        self.retain_marks();
         self.filter_items();
 
         self.adjust_selected(old_len, self.items.len());
@@ -679,6 +948,10 @@ impl WidgetTrait for Table<'_> {
                         }
                     }
 
+                    KeyCode::Esc if !self.marked.is_empty() => {
+                        self.clear_marks();
+                    }
+
                     KeyCode::Enter => {
                         if let Some(cb) = self.on_select_callback() {
                             return EventResult::Callback(cb);
@@ -687,6 +960,26 @@ impl WidgetTrait for Table<'_> {
                         return EventResult::Ignore;
                     }
 
+                    _ if self.sort_next_events.contains(&UserEvent::Key(ev)) => {
+                        self.sort_next();
+                    }
+
+                    _ if self.sort_prev_events.contains(&UserEvent::Key(ev)) => {
+                        self.sort_prev();
+                    }
+
+                    _ if self.mark_events.toggle.contains(&UserEvent::Key(ev)) => {
+                        self.toggle_mark();
+                    }
+
+                    _ if self.mark_events.range.contains(&UserEvent::Key(ev)) => {
+                        self.mark_range();
+                    }
+
+                    _ if self.mark_events.all.contains(&UserEvent::Key(ev)) => {
+                        self.mark_all();
+                    }
+
                     _ => {
                         if let Some(cb) = self.match_action(UserEvent::Key(ev)) {
                             return EventResult::Callback(cb.clone());
@@ -784,7 +1077,13 @@ impl WidgetTrait for Table<'_> {
     fn clear(&mut self) {
         self.state = TableState::default();
 
-        self.items = InnerItem::builder().max_width(self.max_width()).build();
+        self.marked.clear();
+        self.mark_anchor = None;
+
+        self.items = InnerItem::builder()
+            .max_width(self.max_width())
+            .sort(self.sort.clone())
+            .build();
 
         self.row_bounds = Vec::default();
 
@@ -801,7 +1100,8 @@ impl WidgetTrait for Table<'_> {
 }
 
 impl Table<'_> {
-    fn on_select_callback(&self) -> Option<Callback> {
+    /// 選択中の行を引数にした `on_select` のコールバック
+    pub fn on_select_callback(&self) -> Option<Callback> {
         self.on_select.clone().and_then(|cb| {
             self.selected_item()
                 .map(|v| Callback::new(move |w| cb(w, &v)))
@@ -1194,6 +1494,73 @@ mod tests {
         }
     }
 
+    mod フィルターの復元 {
+        use pretty_assertions::assert_eq;
+
+        use crate::ui::widget::{
+            parse_table_filter,
+            ApplyStrategy,
+            OnFilterApply,
+            TableFilterApplicator,
+            TableFilterParser,
+        };
+
+        use super::*;
+
+        fn table() -> Table<'static> {
+            let applicator = TableFilterApplicator::new(
+                TableFilterParser::from(|input: &str| parse_table_filter(input, |_| Ok(()))),
+                ApplyStrategy::EnterToConfirm,
+            )
+            .with_on_apply(OnFilterApply::from(
+                |_: &TableFilterPredicate, _: &mut Window| {},
+            ));
+
+            Table::builder()
+                .header(["NAME".to_string()])
+                .items([
+                    TableItem::new(vec!["web".to_string()], None),
+                    TableItem::new(vec!["db".to_string()], None),
+                ])
+                .filter_form(FilterForm::default())
+                .filter_applicator(applicator)
+                .build()
+        }
+
+        #[test]
+        fn 入力内容でフィルターを確定する() {
+            let mut table = table();
+
+            let cb = table.restore_filter("web");
+
+            assert!(cb.is_some());
+            assert_eq!(table.items().len(), 1);
+            assert_eq!(table.filter_text(), Some("web".to_string()));
+        }
+
+        #[test]
+        fn 解釈できない入力は無視する() {
+            let mut table = table();
+
+            let cb = table.restore_filter("[");
+
+            assert!(cb.is_none());
+            assert!(table.filter_error.is_none());
+            assert_eq!(table.items().len(), 2);
+            assert_eq!(table.filter_text(), None);
+        }
+
+        #[test]
+        fn 入力中のフィルターは確定済みとして扱わない() {
+            let mut table = table();
+
+            table.on_key_event(KeyEvent::from(KeyCode::Char('/')));
+            table.on_key_event(KeyEvent::from(KeyCode::Char('w')));
+
+            assert_eq!(table.filter_text(), None);
+        }
+    }
+
     mod count_indicator {
         use std::collections::HashMap;
 
@@ -1406,6 +1773,43 @@ mod tests {
             }
         }
 
+        mod select_item {
+            use super::*;
+            use pretty_assertions::assert_eq;
+
+            fn table() -> Table<'static> {
+                Table::builder()
+                    .items([
+                        TableItem::new(vec!["Item-0".to_string()], None),
+                        TableItem::new(vec!["Item-1".to_string()], None),
+                        TableItem::new(vec!["Item-2".to_string()], None),
+                    ])
+                    .build()
+            }
+
+            #[test]
+            fn 条件に一致する行を選択() {
+                let mut table = table();
+
+                let actual = table.select_item(|item| item.item[0] == "Item-2");
+
+                assert!(actual);
+                assert_eq!(table.state.selected(), Some(2));
+            }
+
+            #[test]
+            fn 一致する行がなければ選択を変えない() {
+                let mut table = table();
+
+                table.select_next(1);
+
+                let actual = table.select_item(|item| item.item[0] == "Item-9");
+
+                assert!(!actual);
+                assert_eq!(table.state.selected(), Some(1));
+            }
+        }
+
         mod select_prev {
             use super::*;
             use pretty_assertions::assert_eq;
@@ -1762,4 +2166,283 @@ mod tests {
             assert!(!matches!(result, EventResult::Callback(_)));
         }
     }
+
+    mod 並び替え {
+        use super::*;
+        use crate::ui::widget::table::sort::SortOrder;
+        use pretty_assertions::assert_eq;
+
+        fn item(name: &str, age: &str) -> TableItem {
+            TableItem::new(vec![name.to_string(), age.to_string()], None)
+        }
+
+        fn names(table: &Table) -> Vec<String> {
+            table.items().iter().map(|i| i.item[0].clone()).collect()
+        }
+
+        fn sortable_table() -> Table<'static> {
+            Table::builder()
+                .header(["NAME".to_string(), "AGE".to_string()])
+                .items([item("b", "2d"), item("c", "5m"), item("a", "3h")])
+                .sort_actions(['>'.into()], ['<'.into()])
+                .build()
+        }
+
+        #[test]
+        fn キーで列と順序を切り替える() {
+            let mut table = sortable_table();
+
+            table.on_key_event(KeyEvent::from(KeyCode::Char('>')));
+            assert_eq!(names(&table), vec!["a", "b", "c"]);
+
+            table.on_key_event(KeyEvent::from(KeyCode::Char('>')));
+            assert_eq!(names(&table), vec!["c", "b", "a"]);
+
+            table.on_key_event(KeyEvent::from(KeyCode::Char('>')));
+            assert_eq!(
+                table.sort.as_ref(),
+                Some(&TableSort::new("AGE", SortOrder::Ascending))
+            );
+            assert_eq!(names(&table), vec!["c", "a", "b"]);
+
+            table.on_key_event(KeyEvent::from(KeyCode::Char('<')));
+            table.on_key_event(KeyEvent::from(KeyCode::Char('<')));
+            table.on_key_event(KeyEvent::from(KeyCode::Char('<')));
+            assert_eq!(table.sort.as_ref(), None);
+            assert_eq!(names(&table), vec!["b", "c", "a"]);
+        }
+
+        #[test]
+        fn sort_actions未設定ならキーを無視する() {
+            let mut table = Table::builder()
+                .header(["NAME".to_string(), "AGE".to_string()])
+                .items([item("b", "1m"), item("a", "2m")])
+                .build();
+
+            let result = table.on_key_event(KeyEvent::from(KeyCode::Char('>')));
+
+            assert!(matches!(result, EventResult::Ignore));
+            assert_eq!(table.sort.as_ref(), None);
+        }
+
+        #[test]
+        fn 並び替え中の列のヘッダーに矢印を表示する() {
+            let backend = TestBackend::new(30, 7);
+            let mut terminal = Terminal::new(backend).unwrap();
+
+            let mut table = sortable_table();
+            table.update_chunk(Rect::new(0, 0, 30, 7));
+            table.update_sort(Some(TableSort::new("AGE", SortOrder::Descending)));
+
+            terminal
+                .draw(|f| {
+                    table.render(f, true, false);
+                })
+                .unwrap();
+
+            let header: String = (0..30)
+                .map(|x| terminal.backend().buffer()[(x, 1)].symbol().to_string())
+                .collect();
+
+            assert!(header.contains("NAME"), "{}", header);
+            assert!(header.contains("AGE ▼"), "{}", header);
+        }
+
+        #[test]
+        fn アイテム更新後も並び替えを維持する() {
+            let mut table = sortable_table();
+            table.update_sort(Some(TableSort::new("AGE", SortOrder::Ascending)));
+
+            table.update_widget_item(Item::Table(vec![
+                item("d", "1y"),
+                item("e", "10s"),
+                item("b", "2d"),
+            ]));
+
+            assert_eq!(names(&table), vec!["e", "b", "d"]);
+
+            table.update_header_and_rows(
+                &[
+                    "NAMESPACE".to_string(),
+                    "NAME".to_string(),
+                    "AGE".to_string(),
+                ],
+                &[
+                    TableItem::new(
+                        vec!["ns".to_string(), "x".to_string(), "3d".to_string()],
+                        None,
+                    ),
+                    TableItem::new(
+                        vec!["ns".to_string(), "y".to_string(), "1m".to_string()],
+                        None,
+                    ),
+                ],
+            );
+
+            let names: Vec<&str> = table.items().iter().map(|i| i.item[1].as_str()).collect();
+            assert_eq!(names, vec!["y", "x"]);
+        }
+
+        #[test]
+        fn 並び替え後も同じ行を選択し続ける() {
+            let mut table = sortable_table();
+            table.select_item(|i| i.item[0] == "a");
+
+            table.update_sort(Some(TableSort::new("NAME", SortOrder::Descending)));
+
+            assert_eq!(table.state().selected(), Some(2));
+        }
+    }
+
+    mod マーク {
+        use std::collections::BTreeMap;
+
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        fn pod(name: &str, age: &str) -> TableItem {
+            TableItem::new(
+                vec![name.to_string(), age.to_string()],
+                Some(BTreeMap::from([("name".to_string(), name.to_string())])),
+            )
+        }
+
+        fn names(items: &[TableItem]) -> Vec<String> {
+            items.iter().map(|i| i.item[0].clone()).collect()
+        }
+
+        fn key(c: char) -> KeyEvent {
+            KeyEvent::from(KeyCode::Char(c))
+        }
+
+        fn markable_table() -> Table<'static> {
+            Table::builder()
+                .header(["NAME".to_string(), "AGE".to_string()])
+                .items([
+                    pod("a", "1m"),
+                    pod("b", "2m"),
+                    pod("c", "3m"),
+                    pod("d", "4m"),
+                ])
+                .mark_actions([' '.into()], ['V'.into()], ['A'.into()])
+                .build()
+        }
+
+        #[test]
+        fn スペースでマークを切り替えて次の行に移る() {
+            let mut table = markable_table();
+
+            table.on_key_event(key(' '));
+            table.on_key_event(key('j'));
+            table.on_key_event(key(' '));
+
+            assert_eq!(names(&table.marked_items()), vec!["a", "c"]);
+            assert_eq!(table.state().selected(), Some(3));
+
+            table.select_first();
+            table.on_key_event(key(' '));
+
+            assert_eq!(names(&table.marked_items()), vec!["c"]);
+        }
+
+        #[test]
+        fn 最後にマークした行から選択中の行までをマークする() {
+            let mut table = markable_table();
+
+            table.select_last();
+            table.on_key_event(key(' '));
+            table.select_item(|i| i.item[0] == "b");
+            table.on_key_event(key('V'));
+
+            assert_eq!(names(&table.marked_items()), vec!["b", "c", "d"]);
+        }
+
+        #[test]
+        fn フィルター後の行をすべてマークし_もう一度でマークを外す() {
+            let mut table = markable_table();
+            table.filter_state = Some(parse_table_filter("!name:b", |_| Ok(())).unwrap());
+            table.filter_items();
+
+            table.on_key_event(key('A'));
+            assert_eq!(names(&table.marked_items()), vec!["a", "c", "d"]);
+
+            table.on_key_event(key('A'));
+            assert!(table.marked_items().is_empty());
+        }
+
+        #[test]
+        fn アイテム更新後もマークを維持し_消えた行のマークは外す() {
+            let mut table = markable_table();
+            table.on_key_event(key('A'));
+
+            table.update_widget_item(Item::Table(vec![
+                pod("a", "10m"),
+                pod("c", "30m"),
+                pod("e", "1s"),
+            ]));
+
+            let marked: Vec<String> = table
+                .marked_items()
+                .iter()
+                .map(|i| format!("{} {}", i.item[0], i.item[1]))
+                .collect();
+
+            assert_eq!(marked, vec!["a 10m", "c 30m"]);
+            assert_eq!(table.count_indicator(), " [1/3] (marked: 2)");
+        }
+
+        #[test]
+        fn マークがなければ選択中の行を返す() {
+            let mut table = markable_table();
+            table.select_item(|i| i.item[0] == "c");
+
+            assert_eq!(names(&table.marked_or_selected_items()), vec!["c"]);
+
+            table.on_key_event(key(' '));
+            table.on_key_event(key(' '));
+
+            assert_eq!(names(&table.marked_or_selected_items()), vec!["c", "d"]);
+        }
+
+        #[test]
+        fn escでマークをすべて外す() {
+            let mut table = markable_table();
+            table.on_key_event(key(' '));
+
+            let result = table.on_key_event(KeyEvent::from(KeyCode::Esc));
+
+            assert!(matches!(result, EventResult::Nop));
+            assert!(table.marked_items().is_empty());
+
+            // マークがなければEscはウィンドウに任せる
+            let result = table.on_key_event(KeyEvent::from(KeyCode::Esc));
+            assert!(matches!(result, EventResult::Ignore));
+        }
+
+        #[test]
+        fn マークがあるときは行頭にマークの記号を表示する() {
+            let backend = TestBackend::new(30, 8);
+            let mut terminal = Terminal::new(backend).unwrap();
+
+            let mut table = markable_table();
+            table.update_chunk(Rect::new(0, 0, 30, 8));
+            table.on_key_event(key(' '));
+
+            terminal
+                .draw(|f| {
+                    table.render(f, true, false);
+                })
+                .unwrap();
+
+            let line = |y: u16| -> String {
+                (0..30)
+                    .map(|x| terminal.backend().buffer()[(x, y)].symbol().to_string())
+                    .collect()
+            };
+
+            assert!(line(1).contains("    NAME"), "{}", line(1));
+            assert!(line(3).contains("[x] a"), "{}", line(3));
+            assert!(line(4).contains("[ ] b"), "{}", line(4));
+        }
+    }
 }
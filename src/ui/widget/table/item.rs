@@ -1,5 +1,5 @@
 use ratatui::widgets::{Cell, Row};
-use std::ops::Deref;
+use std::{collections::HashSet, ops::Deref};
 
 use crate::ui::widget::{
     line::convert_lines_to_styled_lines,
@@ -8,16 +8,22 @@ use crate::ui::widget::{
     TableItem,
 };
 
-use super::COLUMN_SPACING;
+use super::{sort::TableSort, COLUMN_SPACING};
 
 const HEADER_BOTTOM_MARGIN: u16 = 1;
 const ITEM_BOTTOM_MARGIN: u16 = 1;
 
+/// マークした行の先頭に付ける記号
+const MARKED_SYMBOL: &str = "[x] ";
+const UNMARKED_SYMBOL: &str = "[ ] ";
+
 #[derive(Debug, Default)]
 pub struct InnerItemBuilder {
     header: Vec<String>,
     items: Vec<TableItem>,
     max_width: usize,
+    sort: Option<TableSort>,
+    marked: HashSet<TableItem>,
 }
 
 impl InnerItemBuilder {
@@ -36,14 +42,28 @@ impl InnerItemBuilder {
         self
     }
 
+    pub fn sort(mut self, sort: Option<TableSort>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// マークした行のキー（[`mark_key`]）
+    pub fn marked(mut self, marked: HashSet<TableItem>) -> Self {
+        self.marked = marked;
+        self
+    }
+
     pub fn build(self) -> InnerItem<'static> {
         let mut inner_item = InnerItem {
-            header: Header::new(self.header),
+            header: Header::new(self.header, self.sort.as_ref()),
             original_items: self.items.clone(),
             filtered_items: self.items,
+            sort: self.sort,
+            marked: self.marked,
             ..Default::default()
         };
 
+        inner_item.sort_filtered_items();
         inner_item.update_max_width(self.max_width);
 
         inner_item
@@ -65,6 +85,19 @@ pub struct InnerItem<'a> {
     item_margin: u16,
     digits: Digits,
     max_width: usize,
+    sort: Option<TableSort>,
+    marked: HashSet<TableItem>,
+}
+
+/// 行をマークするときのキー
+///
+/// 一覧は定期的に取り直されてセルの値（AGEなど）が変わるため、
+/// メタデータを持つ行はメタデータだけで同じ行かどうかを判定する
+pub fn mark_key(item: &TableItem) -> TableItem {
+    match &item.metadata {
+        Some(metadata) => TableItem::new(Vec::new(), Some(metadata.clone())),
+        None => item.clone(),
+    }
 }
 
 impl InnerItem<'_> {
@@ -94,6 +127,11 @@ impl InnerItem<'_> {
         &self.filtered_items
     }
 
+    /// フィルター前のすべての行
+    pub fn original_items(&self) -> &[TableItem] {
+        &self.original_items
+    }
+
     pub fn rendered_items(&self) -> &[InnerRow<'_>] {
         &self.rendered_items
     }
@@ -113,14 +151,30 @@ impl InnerItem<'_> {
     pub fn update_items(&mut self, item: Vec<TableItem>) {
         self.original_items = item;
         self.filtered_items = self.original_items.clone();
+        self.sort_filtered_items();
         self.inner_update_rendered_items();
     }
 
+    /// 並び替えの列と順序を変更する
+    ///
+    /// 行の並びは次の `update_items` / `apply_filter` から反映され、以降の更新でも維持される。
+    /// 並び替えを解除したときに元の順序へ戻せるよう、ここでは `filtered_items` を並べ替えない。
+    pub fn update_sort(&mut self, sort: Option<TableSort>) {
+        self.header = Header::new(self.header.original.clone(), sort.as_ref());
+        self.sort = sort;
+    }
+
     pub fn update_max_width(&mut self, max_width: usize) {
         self.max_width = max_width;
         self.inner_update_rendered_items();
     }
 
+    /// マークした行を変更する
+    pub fn update_marked(&mut self, marked: HashSet<TableItem>) {
+        self.marked = marked;
+        self.inner_update_rendered_items();
+    }
+
     /// 外部から渡された predicate で original_items を filtered_items に
     /// 絞り込む。filter_state ベースの新パスで使う。
     pub fn apply_filter<F>(&mut self, mut predicate: F)
@@ -133,13 +187,53 @@ impl InnerItem<'_> {
             .filter(|i| predicate(i))
             .cloned()
             .collect();
+        self.sort_filtered_items();
         self.inner_update_rendered_items();
     }
 }
 
 impl InnerItem<'_> {
+    /// 並び替えの列が表示されていないときは元の順序のままにする
+    fn sort_filtered_items(&mut self) {
+        let Some(sort) = self.sort.as_ref() else {
+            return;
+        };
+
+        if let Some(index) = sort.column_index(&self.header.original) {
+            sort.sort_items(index, &mut self.filtered_items);
+        }
+    }
+
     fn inner_update_rendered_items(&mut self) {
-        self.digits = Digits::new(&self.filtered_items, &self.header.original, self.max_width);
+        let show_marks = !self.marked.is_empty();
+
+        self.header.update_rendered(show_marks);
+
+        // マークがあるときは1列目の先頭にマークの記号を付けて表示する
+        let items: Vec<TableItem> = if show_marks {
+            self.filtered_items
+                .iter()
+                .map(|item| {
+                    let symbol = if self.marked.contains(&mark_key(item)) {
+                        MARKED_SYMBOL
+                    } else {
+                        UNMARKED_SYMBOL
+                    };
+
+                    let mut item = item.clone();
+
+                    if let Some(first) = item.item.first_mut() {
+                        *first = format!("{}{}", symbol, first);
+                    }
+
+                    item
+                })
+                .collect()
+        } else {
+            self.filtered_items.clone()
+        };
+
+        self.digits = Digits::new(&items, &self.header.rendered_labels, self.max_width);
 
         if self.digits.is_empty() {
             return;
@@ -147,8 +241,7 @@ impl InnerItem<'_> {
 
         let mut need_margin = false;
 
-        self.rendered_items = self
-            .filtered_items
+        self.rendered_items = items
             .iter()
             .map(|row| {
                 let mut row_height = 1;
@@ -202,23 +295,51 @@ impl InnerItem<'_> {
 #[derive(Debug, Default)]
 pub struct Header<'a> {
     original: Vec<String>,
+    /// 表示する列名。並び替え中の列には矢印を付ける
+    labels: Vec<String>,
+    /// マークの記号の幅を含めた表示する列名
+    rendered_labels: Vec<String>,
     rendered: Row<'a>,
 }
 
 impl Header<'_> {
-    fn new(header: Vec<String>) -> Self {
-        let rendered = Row::new(
-            header
-                .iter()
-                .cloned()
-                .map(|h| Cell::from(h.styled_graphemes_symbols().concat())),
-        )
-        .bottom_margin(HEADER_BOTTOM_MARGIN);
+    fn new(header: Vec<String>, sort: Option<&TableSort>) -> Self {
+        let labels: Vec<String> = header
+            .iter()
+            .map(|h| {
+                let label = h.styled_graphemes_symbols().concat();
 
-        Self {
+                match sort {
+                    Some(sort) if sort.column == *h => format!("{} {}", label, sort.order.arrow()),
+                    _ => label,
+                }
+            })
+            .collect();
+
+        let mut header = Self {
             original: header,
-            rendered,
+            labels,
+            ..Default::default()
+        };
+
+        header.update_rendered(false);
+
+        header
+    }
+
+    /// `show_marks` のときは1列目をマークの記号の幅だけずらす
+    fn update_rendered(&mut self, show_marks: bool) {
+        let mut labels = self.labels.clone();
+
+        if show_marks {
+            if let Some(first) = labels.first_mut() {
+                *first = format!("{}{}", " ".repeat(UNMARKED_SYMBOL.len()), first);
+            }
         }
+
+        self.rendered =
+            Row::new(labels.iter().cloned().map(Cell::from)).bottom_margin(HEADER_BOTTOM_MARGIN);
+        self.rendered_labels = labels;
     }
 
     pub fn original(&self) -> &[String] {
@@ -0,0 +1,418 @@
+use std::cmp::Ordering;
+
+use crate::{
+    kube::metrics::parse_quantity,
+    ui::widget::{styled_graphemes::StyledGraphemes, TableItem},
+};
+
+/// Direction of a column sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    /// Arrow appended to the header of the sorted column.
+    pub fn arrow(self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "▲",
+            SortOrder::Descending => "▼",
+        }
+    }
+}
+
+/// Column the table is sorted by.
+///
+/// The column is kept by header name rather than by index, so the sort
+/// survives header changes such as column customization or the NAMESPACE
+/// column being inserted when multiple namespaces are selected. While the
+/// column is not displayed, the rows keep the order they were given in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableSort {
+    pub column: String,
+    pub order: SortOrder,
+}
+
+impl TableSort {
+    pub fn new(column: impl Into<String>, order: SortOrder) -> Self {
+        Self {
+            column: column.into(),
+            order,
+        }
+    }
+
+    /// Next state of the sort cycle:
+    /// unsorted → 1st column ▲ → 1st column ▼ → 2nd column ▲ → … → last column ▼ → unsorted.
+    pub fn next(current: Option<&TableSort>, header: &[String]) -> Option<TableSort> {
+        let cycle = cycle(header);
+        let position = position(&cycle, current);
+
+        cycle[(position + 1) % cycle.len()].clone()
+    }
+
+    /// Previous state of the sort cycle. See [`TableSort::next`].
+    pub fn prev(current: Option<&TableSort>, header: &[String]) -> Option<TableSort> {
+        let cycle = cycle(header);
+        let position = position(&cycle, current);
+
+        cycle[(position + cycle.len() - 1) % cycle.len()].clone()
+    }
+
+    /// Index of the sorted column, or `None` if it is not displayed.
+    pub fn column_index(&self, header: &[String]) -> Option<usize> {
+        header.iter().position(|h| *h == self.column)
+    }
+
+    /// Stable-sorts `items` by the cell at `index`.
+    ///
+    /// Cells that cannot be read as the column's type (e.g. `<none>` in AGE)
+    /// are placed last regardless of the order.
+    pub fn sort_items(&self, index: usize, items: &mut [TableItem]) {
+        let kind = SortKind::from_column(&self.column);
+
+        let mut keyed: Vec<(SortKey, TableItem)> = items
+            .iter()
+            .map(|item| (kind.key(&cell_text(item, index)), item.clone()))
+            .collect();
+
+        keyed.sort_by(|(a, _), (b, _)| a.compare(b, self.order));
+
+        for (item, (_, sorted)) in items.iter_mut().zip(keyed) {
+            *item = sorted;
+        }
+    }
+}
+
+fn cycle(header: &[String]) -> Vec<Option<TableSort>> {
+    std::iter::once(None)
+        .chain(header.iter().flat_map(|column| {
+            [
+                Some(TableSort::new(column, SortOrder::Ascending)),
+                Some(TableSort::new(column, SortOrder::Descending)),
+            ]
+        }))
+        .collect()
+}
+
+/// Position of `current` in the cycle. A column that is no longer displayed
+/// is treated as unsorted.
+fn position(cycle: &[Option<TableSort>], current: Option<&TableSort>) -> usize {
+    cycle
+        .iter()
+        .position(|sort| sort.as_ref() == current)
+        .unwrap_or(0)
+}
+
+fn cell_text(item: &TableItem, index: usize) -> String {
+    item.item
+        .get(index)
+        .map(|c| c.styled_graphemes_symbols().concat())
+        .unwrap_or_default()
+}
+
+/// How the cells of a column are compared, chosen from the header name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortKind {
+    /// `3d4h`, `5m30s`, … as printed by kubectl
+    Duration,
+    /// Leading integer, e.g. `3` of `3 (5m ago)`
+    Count,
+    /// `ready/total`
+    Ratio,
+    /// Kubernetes quantity such as `250m` or `128Mi`
+    Quantity,
+    /// `42%`
+    Percent,
+    /// Numeric if both cells are numbers, otherwise lexicographic
+    Text,
+}
+
+impl SortKind {
+    fn from_column(column: &str) -> Self {
+        match column {
+            "AGE" | "LAST SEEN" | "LAST SCHEDULE" | "DURATION" => Self::Duration,
+            "RESTARTS" | "COUNT" => Self::Count,
+            "READY" => Self::Ratio,
+            "CPU" | "MEM" => Self::Quantity,
+            // %CPU/R, %MEM/L, %CPU/A, …
+            c if c.starts_with('%') => Self::Percent,
+            _ => Self::Text,
+        }
+    }
+
+    fn key(self, cell: &str) -> SortKey {
+        let cell = cell.trim();
+
+        match self {
+            Self::Duration => SortKey::Number(parse_duration(cell).map(|secs| secs as f64)),
+            Self::Count => SortKey::Number(parse_leading_number(cell).map(|n| n as f64)),
+            Self::Ratio => SortKey::Number(parse_ratio(cell)),
+            Self::Quantity => SortKey::Number(parse_quantity(cell)),
+            Self::Percent => {
+                SortKey::Number(cell.strip_suffix('%').and_then(|n| n.trim().parse().ok()))
+            }
+            Self::Text => {
+                match cell.parse::<f64>() {
+                    Ok(n) if n.is_finite() => SortKey::Number(Some(n)),
+                    _ => SortKey::Text(cell.to_string()),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SortKey {
+    /// `None` when the cell could not be parsed
+    Number(Option<f64>),
+    Text(String),
+}
+
+impl SortKey {
+    fn compare(&self, other: &Self, order: SortOrder) -> Ordering {
+        let directed = |ordering: Ordering| {
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        };
+
+        match (self, other) {
+            (Self::Number(Some(a)), Self::Number(Some(b))) => directed(a.total_cmp(b)),
+            (Self::Number(Some(_)), Self::Number(None)) => Ordering::Less,
+            (Self::Number(None), Self::Number(Some(_))) => Ordering::Greater,
+            (Self::Number(None), Self::Number(None)) => Ordering::Equal,
+            // Numbers come before text in a column of mixed values
+            (Self::Number(_), Self::Text(_)) => Ordering::Less,
+            (Self::Text(_), Self::Number(_)) => Ordering::Greater,
+            (Self::Text(a), Self::Text(b)) => directed(a.cmp(b)),
+        }
+    }
+}
+
+/// Seconds of a kubectl-style duration such as `2y10d`, `3d4h` or `5m30s`.
+fn parse_duration(s: &str) -> Option<u64> {
+    if s.is_empty() {
+        return None;
+    }
+
+    let mut total = 0u64;
+    let mut digits = String::new();
+
+    for c in s.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+
+        let unit = match c {
+            'y' => 365 * 24 * 60 * 60,
+            'd' => 24 * 60 * 60,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+
+        let value: u64 = digits.parse().ok()?;
+        total = total.checked_add(value.checked_mul(unit)?)?;
+        digits.clear();
+    }
+
+    digits.is_empty().then_some(total)
+}
+
+fn parse_leading_number(s: &str) -> Option<u64> {
+    let digits: String = s.chars().take_while(char::is_ascii_digit).collect();
+
+    digits.parse().ok()
+}
+
+/// `ready/total` as a ratio. `0/0` is treated as 0.
+fn parse_ratio(s: &str) -> Option<f64> {
+    let (ready, total) = s.split_once('/')?;
+
+    let ready: u64 = ready.trim().parse().ok()?;
+    let total: u64 = total.trim().parse().ok()?;
+
+    if total == 0 {
+        return Some(0.0);
+    }
+
+    Some(ready as f64 / total as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn header(cols: &[&str]) -> Vec<String> {
+        cols.iter().map(ToString::to_string).collect()
+    }
+
+    fn sorted(column: &str, order: SortOrder, cells: &[&str]) -> Vec<String> {
+        let mut items: Vec<TableItem> = cells
+            .iter()
+            .map(|c| TableItem::new(vec![c.to_string()], None))
+            .collect();
+
+        TableSort::new(column, order).sort_items(0, &mut items);
+
+        items.into_iter().map(|i| i.item[0].clone()).collect()
+    }
+
+    #[test]
+    fn cycle_goes_through_every_column_in_both_orders() {
+        let header = header(&["NAME", "AGE"]);
+
+        let mut sort = None;
+        let mut states = Vec::new();
+
+        for _ in 0..5 {
+            sort = TableSort::next(sort.as_ref(), &header);
+            states.push(sort.clone());
+        }
+
+        assert_eq!(
+            states,
+            vec![
+                Some(TableSort::new("NAME", SortOrder::Ascending)),
+                Some(TableSort::new("NAME", SortOrder::Descending)),
+                Some(TableSort::new("AGE", SortOrder::Ascending)),
+                Some(TableSort::new("AGE", SortOrder::Descending)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn prev_walks_the_cycle_backwards() {
+        let header = header(&["NAME", "AGE"]);
+
+        assert_eq!(
+            TableSort::prev(None, &header),
+            Some(TableSort::new("AGE", SortOrder::Descending))
+        );
+        assert_eq!(
+            TableSort::prev(Some(&TableSort::new("NAME", SortOrder::Ascending)), &header),
+            None
+        );
+    }
+
+    #[test]
+    fn column_not_displayed_restarts_the_cycle() {
+        let header = header(&["NAME", "AGE"]);
+        let hidden = TableSort::new("STATUS", SortOrder::Descending);
+
+        assert_eq!(
+            TableSort::next(Some(&hidden), &header),
+            Some(TableSort::new("NAME", SortOrder::Ascending))
+        );
+    }
+
+    #[test]
+    fn age_is_sorted_by_duration() {
+        assert_eq!(
+            sorted(
+                "AGE",
+                SortOrder::Ascending,
+                &["2d", "5m30s", "n/a", "1y2d", "3h", "45s"]
+            ),
+            vec!["45s", "5m30s", "3h", "2d", "1y2d", "n/a"]
+        );
+    }
+
+    #[test]
+    fn unparsable_cells_stay_last_in_descending_order() {
+        assert_eq!(
+            sorted("AGE", SortOrder::Descending, &["n/a", "3h", "2d"]),
+            vec!["2d", "3h", "n/a"]
+        );
+    }
+
+    #[test]
+    fn restarts_are_sorted_numerically() {
+        assert_eq!(
+            sorted(
+                "RESTARTS",
+                SortOrder::Ascending,
+                &["10", "2 (5m ago)", "0", "9"]
+            ),
+            vec!["0", "2 (5m ago)", "9", "10"]
+        );
+    }
+
+    #[test]
+    fn ready_is_sorted_by_ratio() {
+        assert_eq!(
+            sorted("READY", SortOrder::Ascending, &["2/2", "1/3", "1/2", "0/0"]),
+            vec!["0/0", "1/3", "1/2", "2/2"]
+        );
+    }
+
+    #[test]
+    fn metrics_usage_is_sorted_by_quantity() {
+        assert_eq!(
+            sorted(
+                "CPU",
+                SortOrder::Ascending,
+                &["1", "250m", "n/a", "1500m", "50m"]
+            ),
+            vec!["50m", "250m", "1", "1500m", "n/a"]
+        );
+        assert_eq!(
+            sorted(
+                "MEM",
+                SortOrder::Descending,
+                &["512Mi", "2Gi", "64Mi", "1000Mi"]
+            ),
+            vec!["2Gi", "1000Mi", "512Mi", "64Mi"]
+        );
+    }
+
+    #[test]
+    fn metrics_ratio_is_sorted_by_percent() {
+        assert_eq!(
+            sorted(
+                "%CPU/R",
+                SortOrder::Ascending,
+                &["100%", "9%", "n/a", "25%"]
+            ),
+            vec!["9%", "25%", "100%", "n/a"]
+        );
+        assert_eq!(
+            sorted("%MEM/L", SortOrder::Descending, &["9%", "n/a", "120%"]),
+            vec!["120%", "9%", "n/a"]
+        );
+    }
+
+    #[test]
+    fn other_columns_are_sorted_as_text_with_stable_ties() {
+        let mut items = vec![
+            TableItem::new(vec!["b".to_string(), "1".to_string()], None),
+            TableItem::new(vec!["a".to_string(), "2".to_string()], None),
+            TableItem::new(vec!["b".to_string(), "3".to_string()], None),
+        ];
+
+        TableSort::new("NAME", SortOrder::Descending).sort_items(0, &mut items);
+
+        let second: Vec<&str> = items.iter().map(|i| i.item[1].as_str()).collect();
+
+        assert_eq!(second, vec!["1", "3", "2"]);
+    }
+
+    #[test]
+    fn styled_cells_are_compared_by_their_text() {
+        assert_eq!(
+            sorted(
+                "AGE",
+                SortOrder::Ascending,
+                &["\x1b[31m2d\x1b[0m", "\x1b[31m3h\x1b[0m"]
+            ),
+            vec!["\x1b[31m3h\x1b[0m", "\x1b[31m2d\x1b[0m"]
+        );
+    }
+}
@@ -3,10 +3,15 @@ mod render;
 mod search;
 mod wrap;
 
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use ratatui::{
-    crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind},
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     layout::Rect,
     widgets::{Block, Scrollbar, ScrollbarOrientation, ScrollbarState},
     Frame,
@@ -36,7 +41,7 @@ use super::{
 
 use self::{
     highlight_content::{HighlightArea, Point},
-    item::TextItem,
+    item::{LineFilter, SearchPattern, TextItem},
     render::{Render, Scroll},
 };
 
@@ -44,8 +49,26 @@ pub use item::{SearchHighlightFocusStyle, SearchHighlightMatchesStyle, SearchHig
 pub use render::SelectionStyle;
 pub use search::{SearchForm, SearchFormTheme};
 
+/// 絞り込み時に表示する前後の行数の候補
+const SEARCH_FILTER_CONTEXTS: [usize; 5] = [0, 1, 3, 5, 10];
+
 define_callback!(pub RenderBlockInjection, Fn(&Text, bool, bool) -> Block<'static> );
 
+define_callback!(pub RenderLinePrefix, Fn(&LiteralItem) -> Option<LinePrefix>);
+
+/// 行の先頭に付けて表示する文字列
+///
+/// 受け取った行とは別に扱い、[`Text::refresh_line_prefix`] で付け直せる
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinePrefix {
+    pub text: String,
+    /// 時間の経過で変わるため定期的に付け直す
+    pub volatile: bool,
+}
+
+/// 付けた接頭辞の長さを保持するメタデータのキー
+const LINE_PREFIX_LEN_KEY: &str = "text/line-prefix-len";
+
 mod highlight_content {
 
     #[derive(Default, Debug, Copy, Clone)]
@@ -193,6 +216,7 @@ pub struct TextBuilder {
     follow: bool,
     theme: TextTheme,
     block_injection: Option<RenderBlockInjection>,
+    line_prefix: Option<RenderLinePrefix>,
     actions: Vec<(UserEvent, Callback)>,
     clipboard: Option<Rc<RefCell<Clipboard>>>,
     max_lines: Option<usize>,
@@ -247,6 +271,17 @@ impl TextBuilder {
         self
     }
 
+    /// 複数のイベントに同じコールバックを登録する
+    pub fn actions<F, I>(self, evs: I, cb: F) -> Self
+    where
+        I: IntoIterator<Item = UserEvent>,
+        F: Into<Callback>,
+    {
+        let cb = cb.into();
+        evs.into_iter()
+            .fold(self, |builder, ev| builder.action(ev, cb.clone()))
+    }
+
     pub fn block_injection<F>(mut self, block_injection: F) -> Self
     where
         F: Into<RenderBlockInjection>,
@@ -255,6 +290,15 @@ impl TextBuilder {
         self
     }
 
+    /// 各行を表示するときに先頭に付ける文字列を指定する
+    pub fn line_prefix<F>(mut self, line_prefix: F) -> Self
+    where
+        F: Into<RenderLinePrefix>,
+    {
+        self.line_prefix = Some(line_prefix.into());
+        self
+    }
+
     pub fn clipboard(mut self, clipboard: Rc<RefCell<Clipboard>>) -> Self {
         self.clipboard = Some(clipboard);
         self
@@ -278,6 +322,7 @@ impl TextBuilder {
             theme: self.theme,
             actions: self.actions,
             block_injection: self.block_injection,
+            line_prefix: self.line_prefix,
             clipboard: self.clipboard,
             default_max_lines: self.max_lines,
             ..Default::default()
@@ -297,9 +342,20 @@ pub struct Text {
     search_form: SearchForm,
     /// 検索中、検索ワード入力中、オフの3つのモード
     mode: Mode,
+    /// 単語全体にマッチする箇所のみ検索する
+    search_whole_word: bool,
+    /// 検索ワードが正規表現として不正かどうか
+    search_invalid: bool,
+    /// 検索ワードにマッチしない行を隠す
+    search_filter: bool,
+    /// 絞り込み時に表示する前後の行数
+    search_filter_context: usize,
     interaction_state: InteractionState,
     theme: TextTheme,
     block_injection: Option<RenderBlockInjection>,
+    line_prefix: Option<RenderLinePrefix>,
+    /// 時間の経過で変わる接頭辞を最後に付けた時刻（そのような接頭辞がない場合は `None`）
+    line_prefix_refreshed_at: Option<Instant>,
     actions: Vec<(UserEvent, Callback)>,
     clipboard: Option<Rc<RefCell<Clipboard>>>,
     /// マウスドラッグ中に受け取ったアイテムを一時的に保持するバッファ
@@ -322,6 +378,96 @@ impl Text {
         self.item.set_max_lines(max_lines);
     }
 
+    /// すべての行の接頭辞を付け直す
+    pub fn refresh_line_prefix(&mut self) {
+        if self.line_prefix.is_none() {
+            return;
+        }
+
+        let items = self.item.literal_items().cloned().collect();
+
+        self.line_prefix_refreshed_at = None;
+
+        self.update_widget_item(Item::Array(items));
+    }
+
+    /// 時間の経過で変わる接頭辞を `interval` ごとに付け直す
+    ///
+    /// 表示している行だけを付け直し、ほかの行はスクロールして表示したときに付け直す。
+    /// マウスドラッグ中は選択範囲がずれないように付け直さない
+    pub fn refresh_volatile_line_prefix(&mut self, interval: Duration) {
+        if matches!(self.interaction_state, InteractionState::Selecting { .. }) {
+            return;
+        }
+
+        if self
+            .line_prefix_refreshed_at
+            .is_none_or(|at| at.elapsed() < interval)
+        {
+            return;
+        }
+
+        let is_bottom = self.is_bottom();
+
+        let top = self.scroll.y;
+        let range = self
+            .item
+            .line_range(top..top + self.inner_chunk().height as usize);
+
+        let items = self
+            .item
+            .literal_items()
+            .skip(range.start)
+            .take(range.len())
+            .cloned()
+            .collect();
+
+        let items = self.apply_line_prefix(items);
+        self.item.replace_lines(range.start, items);
+
+        self.line_prefix_refreshed_at = Some(Instant::now());
+
+        if self.should_follow() && is_bottom || self.scroll_y_last_index() < self.scroll.y {
+            self.select_last()
+        }
+    }
+
+    /// 各行に接頭辞を付ける
+    ///
+    /// すでに付いている接頭辞は付け直す
+    fn apply_line_prefix(&mut self, items: Vec<LiteralItem>) -> Vec<LiteralItem> {
+        let Some(line_prefix) = &self.line_prefix else {
+            return items;
+        };
+
+        let mut volatile = false;
+
+        let items = items
+            .into_iter()
+            .map(|mut item| {
+                strip_line_prefix(&mut item);
+
+                if let Some(prefix) = (line_prefix)(&item) {
+                    volatile |= prefix.volatile;
+
+                    item.item.insert_str(0, &prefix.text);
+
+                    item.metadata
+                        .get_or_insert_with(BTreeMap::new)
+                        .insert(LINE_PREFIX_LEN_KEY.into(), prefix.text.len().to_string());
+                }
+
+                item
+            })
+            .collect();
+
+        if volatile && self.line_prefix_refreshed_at.is_none() {
+            self.line_prefix_refreshed_at = Some(Instant::now());
+        }
+
+        items
+    }
+
     /// ドラッグ中にバッファリングされたアイテムを反映する
     fn flush_pending_items(&mut self) {
         let items = std::mem::take(&mut self.pending_items);
@@ -338,6 +484,9 @@ impl Text {
 /// - マッチした文字列をハイライト
 /// - マッチした文字列に移動
 /// - 検索モード終了時にハイライトを削除
+/// - `/regex/` で正規表現、大文字を含まない場合は大文字小文字を区別しない
+/// - 単語全体にマッチする箇所のみの検索を切り替え
+/// - マッチしない行を隠す絞り込みを切り替え（前後の行数を指定可能）
 impl Text {
     pub fn search(&mut self) {
         let is_bottom = self.is_bottom();
@@ -350,13 +499,32 @@ impl Text {
 
         let word = self.search_form.content();
 
+        self.search_invalid = false;
+
         if word.is_empty() {
             // 入力文字が空の時に1文字だけハイライトが残るのを防ぐため
             self.item.clear_highlight();
+            self.set_line_filter(None);
             return;
         }
 
-        self.item.highlight(&word);
+        let Ok(pattern) = SearchPattern::new(&word, self.search_whole_word) else {
+            self.search_invalid = true;
+            self.item.clear_highlight();
+            self.set_line_filter(None);
+            return;
+        };
+
+        // 絞り込み後の行で検索し直さないよう、先にハイライトを消す
+        self.item.clear_highlight();
+
+        let filter = self
+            .search_filter
+            .then(|| LineFilter::new(pattern.clone(), self.search_filter_context));
+
+        self.set_line_filter(filter);
+
+        self.item.highlight(&pattern);
 
         if let Some(index) = self
             .item
@@ -402,8 +570,68 @@ impl Text {
         }
     }
 
+    /// 単語全体にマッチする箇所のみ検索するかを切り替え、検索し直す
+    pub fn toggle_search_whole_word(&mut self) {
+        self.search_whole_word = !self.search_whole_word;
+
+        self.search_again();
+    }
+
+    /// マッチしない行を隠すかを切り替え、検索し直す
+    pub fn toggle_search_filter(&mut self) {
+        self.search_filter = !self.search_filter;
+
+        self.search_again();
+    }
+
+    /// 絞り込み時に表示する前後の行数を次の候補に切り替える
+    pub fn cycle_search_filter_context(&mut self) {
+        let next = SEARCH_FILTER_CONTEXTS
+            .iter()
+            .position(|c| *c == self.search_filter_context)
+            .map_or(0, |i| (i + 1) % SEARCH_FILTER_CONTEXTS.len());
+
+        self.search_filter_context = SEARCH_FILTER_CONTEXTS[next];
+
+        if self.search_filter {
+            self.search_again();
+        }
+    }
+
+    /// 入力中か確定済みかのモードを保ったまま検索し直す
+    fn search_again(&mut self) {
+        let is_search_confirm = self.mode.is_search_confirm();
+
+        self.search();
+
+        if is_search_confirm {
+            self.mode.search_confirm();
+        }
+    }
+
+    /// 行の絞り込み条件を変更する
+    ///
+    /// フォーカスしている行が画面内に残るようスクロール位置を調整する
+    fn set_line_filter(&mut self, filter: Option<LineFilter>) {
+        if !self.item.is_filtered() && filter.is_none() {
+            return;
+        }
+
+        let focused_line_index = self.focused_line_index();
+
+        self.item.set_filter(filter);
+
+        if let Some(line_number) = focused_line_index.and_then(|i| self.item.line_number(i)) {
+            self.scroll.y = line_number.min(self.scroll_y_last_index());
+        } else if self.scroll_y_last_index() < self.scroll.y {
+            self.select_last()
+        }
+    }
+
     pub fn search_cancel(&mut self) {
         self.mode.normal();
+        self.search_invalid = false;
+        self.set_line_filter(None);
         self.item.clear_highlight();
 
         if self.scroll_y_last_index() < self.scroll.y {
@@ -437,7 +665,33 @@ impl Text {
     fn highlight_status_str(&self) -> String {
         let (selected, total) = self.item.highlight_status();
 
-        format!(" [{}/{}]", selected, total)
+        let mut status = format!(" [{}/{}]", selected, total);
+
+        if self.search_whole_word {
+            status.push_str(" [word]");
+        }
+
+        if self.search_filter {
+            if self.item.is_filtered() {
+                let (matched, total) = self.item.filter_status();
+
+                status.push_str(&format!(" [filter {}/{} lines", matched, total));
+            } else {
+                status.push_str(" [filter");
+            }
+
+            if 0 < self.search_filter_context {
+                status.push_str(&format!(", context {}", self.search_filter_context));
+            }
+
+            status.push(']');
+        }
+
+        if self.search_invalid {
+            status.push_str(" [invalid regex]");
+        }
+
+        status
     }
 }
 
@@ -559,6 +813,26 @@ impl Text {
         (self.scroll.y, self.scroll_y_last_index())
     }
 
+    /// 保持している全行を元の文字列のまま返す
+    pub fn raw_lines(&self) -> impl Iterator<Item = &str> {
+        self.item.raw_lines()
+    }
+
+    /// フォーカスしている行のインデックスを返す
+    ///
+    /// 検索中は選択しているマッチ箇所の行、それ以外は表示範囲の先頭行
+    pub fn focused_line_index(&self) -> Option<usize> {
+        let line_number = self
+            .item
+            .highlight_selected_line_number()
+            .unwrap_or(self.scroll.y);
+
+        self.item
+            .wrapped_lines()
+            .get(line_number)
+            .map(|line| line.index())
+    }
+
     fn match_action(&self, ev: UserEvent) -> Option<&Callback> {
         self.actions
             .iter()
@@ -635,8 +909,15 @@ impl WidgetTrait for Text {
         let is_bottom = self.is_bottom();
 
         match item {
-            Item::Single(i) => self.item.push(i),
-            Item::Array(i) => self.item.extend(i),
+            Item::Single(i) => {
+                for i in self.apply_line_prefix(vec![i]) {
+                    self.item.push(i)
+                }
+            }
+            Item::Array(i) => {
+                let i = self.apply_line_prefix(i);
+                self.item.extend(i)
+            }
             _ => {
                 unreachable!()
             }
@@ -656,7 +937,7 @@ impl WidgetTrait for Text {
     fn update_widget_item(&mut self, item: Item) {
         let is_bottom = self.is_bottom();
 
-        let item = item.array();
+        let item = self.apply_line_prefix(item.array());
         self.item.update(item);
 
         if self.should_follow() && is_bottom {
@@ -871,6 +1152,18 @@ impl WidgetTrait for Text {
                         self.search_prev();
                     }
 
+                    Char('w') if ev.modifiers == KeyModifiers::ALT && !self.mode.is_normal() => {
+                        self.toggle_search_whole_word();
+                    }
+
+                    Char('f') if ev.modifiers == KeyModifiers::ALT && !self.mode.is_normal() => {
+                        self.toggle_search_filter();
+                    }
+
+                    Char('c') if ev.modifiers == KeyModifiers::ALT && !self.mode.is_normal() => {
+                        self.cycle_search_filter_context();
+                    }
+
                     _ => {
                         if let Some(cb) = self.match_action(UserEvent::Key(ev)) {
                             return EventResult::Callback(cb.clone());
@@ -890,6 +1183,18 @@ impl WidgetTrait for Text {
                         self.search_cancel();
                     }
 
+                    Char('w') if ev.modifiers == KeyModifiers::ALT => {
+                        self.toggle_search_whole_word();
+                    }
+
+                    Char('f') if ev.modifiers == KeyModifiers::ALT => {
+                        self.toggle_search_filter();
+                    }
+
+                    Char('c') if ev.modifiers == KeyModifiers::ALT => {
+                        self.cycle_search_filter_context();
+                    }
+
                     _ => {
                         let ev = self.search_form.on_key_event(ev);
 
@@ -945,11 +1250,25 @@ impl WidgetTrait for Text {
         self.search_cancel();
 
         self.interaction_state = InteractionState::Idle;
+        self.line_prefix_refreshed_at = None;
 
         *(self.widget_base.append_title_mut()) = None;
     }
 }
 
+/// 付けた接頭辞を取り除く
+fn strip_line_prefix(item: &mut LiteralItem) {
+    let len = item
+        .metadata
+        .as_mut()
+        .and_then(|metadata| metadata.remove(LINE_PREFIX_LEN_KEY))
+        .and_then(|len| len.parse::<usize>().ok());
+
+    if let Some(len) = len {
+        item.item.drain(..len);
+    }
+}
+
 impl RenderTrait for Text {
     fn render(&mut self, f: &mut Frame<'_>, is_active: bool, is_mouse_over: bool) {
         let block = if let Some(block_injection) = &self.block_injection {
@@ -1108,4 +1427,286 @@ mod tests {
             assert_eq!(text.scroll.x, 7);
         }
     }
+
+    mod フォーカス行 {
+        use super::*;
+
+        fn text() -> Text {
+            let mut text = Text::builder()
+                .items([
+                    "0".to_string(),
+                    "0123456789".to_string(),
+                    "2".to_string(),
+                    "3".to_string(),
+                    "4".to_string(),
+                ])
+                .wrap()
+                .build();
+
+            text.update_chunk(Rect::new(0, 0, 5, 4));
+
+            text
+        }
+
+        #[test]
+        fn 表示範囲の先頭行() {
+            let mut text = text();
+
+            assert_eq!(text.focused_line_index(), Some(0));
+
+            // 折り返した行は元の行のインデックスを返す
+            text.select_next(2);
+
+            assert_eq!(text.focused_line_index(), Some(1));
+
+            text.select_next(3);
+
+            assert_eq!(text.focused_line_index(), Some(2));
+        }
+
+        #[test]
+        fn 検索中は選択しているマッチ箇所の行() {
+            let mut text = text();
+
+            text.item
+                .highlight(&SearchPattern::new("3", false).unwrap());
+            text.item.select_nearest_highlight(0);
+
+            assert_eq!(text.focused_line_index(), Some(1));
+
+            text.item.select_next_highlight();
+
+            assert_eq!(text.focused_line_index(), Some(3));
+        }
+    }
+    mod 検索 {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn text(items: &[&str]) -> Text {
+            let mut text = Text::builder()
+                .items(items.iter().map(|i| i.to_string()).collect::<Vec<_>>())
+                .wrap()
+                .build();
+
+            text.update_chunk(Rect::new(0, 0, 5, 4));
+
+            text
+        }
+
+        /// 折り返さない幅のText
+        fn wide_text(items: &[&str]) -> Text {
+            let mut text = text(items);
+
+            text.update_chunk(Rect::new(0, 0, 20, 10));
+
+            text
+        }
+
+        fn search(text: &mut Text, word: &str) {
+            text.search_form.update_content(word.to_string());
+            text.search();
+        }
+
+        #[test]
+        fn 正規表現にマッチした箇所を数える() {
+            let mut text = text(&["error: a", "timeout", "ok"]);
+
+            search(&mut text, "/error|timeout/");
+
+            assert_eq!(text.highlight_status_str(), " [1/2]");
+        }
+
+        #[test]
+        fn 不正な正規表現はハイライトせずステータスに表示する() {
+            let mut text = text(&["error: a"]);
+
+            search(&mut text, "/(/");
+
+            assert_eq!(text.highlight_status_str(), " [0/0] [invalid regex]");
+        }
+
+        #[test]
+        fn 単語全体へのマッチを切り替えると検索し直す() {
+            let mut text = text(&["log logs catalog"]);
+
+            search(&mut text, "log");
+            text.mode.search_confirm();
+
+            assert_eq!(text.highlight_status_str(), " [1/3]");
+
+            text.toggle_search_whole_word();
+
+            assert_eq!(text.highlight_status_str(), " [1/1] [word]");
+            assert!(text.mode.is_search_confirm());
+        }
+
+        #[test]
+        fn 折り返した行の先頭から始まるマッチは折り返し後の行番号() {
+            let mut text = text(&["0", "0123456789"]);
+
+            search(&mut text, "56");
+
+            assert_eq!(text.item.highlight_selected_line_number(), Some(2));
+        }
+
+        #[test]
+        fn 絞り込みを切り替えるとマッチしない行を隠す() {
+            let mut text = wide_text(&["ok", "err1", "ok", "err2"]);
+
+            search(&mut text, "err");
+            text.toggle_search_filter();
+
+            assert_eq!(text.item.wrapped_lines().len(), 2);
+            assert!(text
+                .highlight_status_str()
+                .ends_with("/2] [filter 2/4 lines]"));
+
+            text.cycle_search_filter_context();
+
+            assert_eq!(text.item.wrapped_lines().len(), 4);
+            assert!(text
+                .highlight_status_str()
+                .ends_with("/2] [filter 2/4 lines, context 1]"));
+        }
+
+        #[test]
+        fn 検索を終了すると全ての行を表示する() {
+            let mut text = wide_text(&["ok", "err1", "ok", "err2"]);
+
+            text.search_filter = true;
+            search(&mut text, "err");
+            text.search_cancel();
+
+            assert_eq!(text.item.wrapped_lines().len(), 4);
+        }
+
+        #[test]
+        fn 絞り込み中に追加した行も保持する() {
+            let mut text = wide_text(&["err1"]);
+
+            text.search_filter = true;
+            search(&mut text, "err");
+
+            text.append_widget_item(Item::Array(vec![
+                LiteralItem::new("ok", None),
+                LiteralItem::new("err2", None),
+            ]));
+
+            assert_eq!(text.item.wrapped_lines().len(), 2);
+
+            text.toggle_search_filter();
+
+            assert_eq!(text.raw_lines().collect::<Vec<_>>(), ["err1", "ok", "err2"]);
+            assert_eq!(text.item.wrapped_lines().len(), 3);
+        }
+    }
+
+    mod 接頭辞 {
+        use super::*;
+
+        fn text(prefix: Rc<RefCell<String>>) -> Text {
+            Text::builder()
+                .line_prefix(move |item: &LiteralItem| {
+                    item.metadata.as_ref()?;
+
+                    Some(LinePrefix {
+                        text: prefix.borrow().clone(),
+                        volatile: false,
+                    })
+                })
+                .build()
+        }
+
+        fn item(s: &str, metadata: bool) -> LiteralItem {
+            LiteralItem::new(s, metadata.then(BTreeMap::new))
+        }
+
+        #[test]
+        fn 追加した行に接頭辞を付ける() {
+            let mut text = text(Rc::new(RefCell::new("a ".into())));
+
+            text.append_widget_item(Item::Array(vec![item("1", true), item("2", false)]));
+
+            assert_eq!(text.raw_lines().collect::<Vec<_>>(), ["a 1", "2"]);
+        }
+
+        #[test]
+        fn 付け直すと前の接頭辞を置き換える() {
+            let prefix = Rc::new(RefCell::new("a ".to_string()));
+
+            let mut text = text(prefix.clone());
+
+            text.append_widget_item(Item::Array(vec![item("1", true), item("2", false)]));
+
+            *prefix.borrow_mut() = "bb ".into();
+
+            text.refresh_line_prefix();
+
+            assert_eq!(text.raw_lines().collect::<Vec<_>>(), ["bb 1", "2"]);
+
+            *prefix.borrow_mut() = String::new();
+
+            text.refresh_line_prefix();
+
+            assert_eq!(text.raw_lines().collect::<Vec<_>>(), ["1", "2"]);
+        }
+
+        #[test]
+        fn 時間で変わる接頭辞は表示している行だけ付け直す() {
+            let prefix = Rc::new(RefCell::new("a ".to_string()));
+
+            let mut text = Text::builder()
+                .line_prefix({
+                    let prefix = prefix.clone();
+
+                    move |_: &LiteralItem| {
+                        Some(LinePrefix {
+                            text: prefix.borrow().clone(),
+                            volatile: true,
+                        })
+                    }
+                })
+                .build();
+
+            // 枠線を除いて2行を表示する
+            text.update_chunk(Rect::new(0, 0, 10, 4));
+
+            text.append_widget_item(Item::Array(vec![
+                item("1", true),
+                item("2", true),
+                item("3", true),
+            ]));
+
+            text.select_first();
+
+            *prefix.borrow_mut() = "bb ".into();
+
+            text.refresh_volatile_line_prefix(Duration::ZERO);
+
+            assert_eq!(
+                text.raw_lines().collect::<Vec<_>>(),
+                ["bb 1", "bb 2", "a 3"]
+            );
+
+            text.select_last();
+
+            text.refresh_volatile_line_prefix(Duration::ZERO);
+
+            assert_eq!(
+                text.raw_lines().collect::<Vec<_>>(),
+                ["bb 1", "bb 2", "bb 3"]
+            );
+        }
+
+        #[test]
+        fn 時間で変わる接頭辞がないときは定期的に付け直さない() {
+            let mut text = text(Rc::new(RefCell::new("a ".into())));
+
+            text.append_widget_item(Item::Array(vec![item("1", true)]));
+
+            assert!(text.line_prefix_refreshed_at.is_none());
+        }
+    }
 }
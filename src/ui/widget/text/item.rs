@@ -9,6 +9,8 @@ use std::ops::{Deref, Range};
 
 use search::Search;
 
+pub use search::SearchPattern;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Highlight {
     /// Graphemesのインデックス
@@ -21,18 +23,33 @@ pub struct Highlight {
     line_number: usize,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 struct Highlights {
-    /// 検索ワード
-    word: String,
+    /// 検索条件
+    pattern: SearchPattern,
 
-    /// wordにマッチする場所に関するデータ
+    /// patternにマッチする場所に関するデータ
     item: Vec<Highlight>,
 
     /// 選択しているインデックス
     selected_index: usize,
 }
 
+/// 表示する行の絞り込み条件
+///
+/// patternにマッチする行と、その前後context行のみを表示する
+#[derive(Debug, Clone)]
+pub struct LineFilter {
+    pattern: SearchPattern,
+    context: usize,
+}
+
+impl LineFilter {
+    pub fn new(pattern: SearchPattern, context: usize) -> Self {
+        Self { pattern, context }
+    }
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct SearchHighlightStyle {
     pub matches: SearchHighlightMatchesStyle,
@@ -117,6 +134,11 @@ pub struct TextItem {
 
     /// 直前のtrim_to_limitで削除されたwrapped_linesの数
     last_trimmed_wrapped_count: usize,
+
+    /// 行の絞り込み条件
+    ///
+    /// 絞り込まれた行はlinesに残したままwrapped_linesから除く
+    filter: Option<LineFilter>,
 }
 
 impl TextItem {
@@ -142,6 +164,7 @@ impl TextItem {
             highlight_style,
             max_lines: None,
             last_trimmed_wrapped_count: 0,
+            filter: None,
         }
     }
 
@@ -150,14 +173,16 @@ impl TextItem {
         let highlights = self.highlights.clone();
         let highlight_style = self.highlight_style.clone();
         let max_lines = self.max_lines;
+        let filter = self.filter.take();
 
         let mut new = Self::new(item, wrap_width, highlight_style);
         new.max_lines = max_lines;
+        new.set_filter(filter);
 
         if let Some(highlights) = highlights {
             let prev_line_number = highlights.item[highlights.selected_index].line_number;
 
-            new.highlight(&highlights.word);
+            new.highlight(&highlights.pattern);
 
             new.select_nearest_highlight(prev_line_number);
         }
@@ -189,29 +214,118 @@ impl TextItem {
         self.lines.extend(lines);
         self.wrapped_lines.extend(wrapped_lines);
 
-        if let Some(highlights) = &mut self.highlights {
-            let lines_len = self.lines.len();
-            let start = lines_len - extend_len;
-            let lines = self.lines.make_contiguous();
+        let start = self.lines.len() - extend_len;
 
-            let hls: Vec<Highlight> = lines[start..]
-                .iter_mut()
-                .filter_map(|line| {
-                    line.highlight_word(
-                        &highlights.word,
-                        &self.wrapped_lines[line.wrapped_lines.clone()],
-                        *self.highlight_style.matches,
-                    )
-                })
-                .flatten()
-                .collect();
+        // 前の行が前後の行として表示される場合があるため、追加した行より前からやり直す
+        let begin = start.saturating_sub(self.filter.as_ref().map_or(0, |f| f.context));
 
-            highlights.item.extend(hls);
-        }
+        self.apply_filter_from(start);
+        self.highlight_lines(begin..self.lines.len());
 
         self.trim_to_limit();
     }
 
+    /// start行目からの行をitemsで置き換える
+    ///
+    /// 折り返した行数や絞り込みの結果が変わらない限り、ほかの行は作り直さない
+    pub fn replace_lines(&mut self, start: usize, items: Vec<LiteralItem>) {
+        let end = (start + items.len()).min(self.lines.len());
+
+        for (line, literal_item) in self.lines.range_mut(start..end).zip(items) {
+            line.graphemes = literal_item.item.styled_graphemes();
+            line.literal_item = literal_item;
+        }
+
+        // 表示・非表示が変わった行の範囲
+        let mut changed = start..start;
+
+        if let Some(filter) = &self.filter {
+            let context = filter.context;
+            let last = self.lines.len().saturating_sub(1);
+
+            for line in self.lines.range_mut(start..end) {
+                line.matched = line.graphemes.search(&filter.pattern).is_some();
+            }
+
+            for i in start.saturating_sub(context)..(end + context).min(self.lines.len()) {
+                let hidden = !(i.saturating_sub(context)..=(i + context).min(last))
+                    .any(|j| self.lines[j].matched);
+
+                if self.lines[i].hidden != hidden {
+                    self.lines[i].hidden = hidden;
+
+                    if changed.is_empty() {
+                        changed = i..i + 1;
+                    } else {
+                        changed.end = i + 1;
+                    }
+                }
+            }
+        }
+
+        let mut rewrap_start = (!changed.is_empty()).then_some(changed.start);
+
+        for i in start..end {
+            if rewrap_start.is_some_and(|s| s <= i) {
+                break;
+            }
+
+            let line = &self.lines[i];
+
+            let new_wrapped = if line.hidden {
+                Vec::new()
+            } else {
+                line.create_wrapped_lines(self.wrap_width)
+            };
+
+            if new_wrapped.len() != line.wrapped_lines.len() {
+                rewrap_start = Some(i);
+                break;
+            }
+
+            self.max_chars = new_wrapped
+                .iter()
+                .map(|l| l.line().len())
+                .max()
+                .unwrap_or_default()
+                .max(self.max_chars);
+
+            let range = line.wrapped_lines.clone();
+
+            self.wrapped_lines.splice(range, new_wrapped);
+        }
+
+        if let Some(rewrap_start) = rewrap_start {
+            self.rewrap_from(rewrap_start);
+
+            self.max_chars = self.wrapped_lines[self.lines[rewrap_start].line_number..]
+                .iter()
+                .map(|l| l.line().len())
+                .max()
+                .unwrap_or_default()
+                .max(self.max_chars);
+        }
+
+        let range = if changed.is_empty() {
+            start..end
+        } else {
+            start.min(changed.start)..end.max(changed.end)
+        };
+
+        self.highlight_lines(range);
+    }
+
+    /// 折り返しを考慮した行番号の範囲に含まれる行の範囲
+    pub fn line_range(&self, line_numbers: Range<usize>) -> Range<usize> {
+        let wrapped_lines = &self.wrapped_lines[line_numbers.start.min(self.wrapped_lines.len())
+            ..line_numbers.end.min(self.wrapped_lines.len())];
+
+        match (wrapped_lines.first(), wrapped_lines.last()) {
+            (Some(first), Some(last)) => first.line_index..last.line_index + 1,
+            _ => 0..0,
+        }
+    }
+
     /// Vec<LiteralItem>からLine, WrappedLineを生成する
     /// extendにも対応できるようインデックスに関する引数を追加している
     ///
@@ -241,6 +355,8 @@ impl TextItem {
                 literal_item,
                 graphemes,
                 wrapped_lines: 0..0, // 仮の値
+                hidden: false,
+                matched: false,
             };
 
             // graphemesが確定した位置にあるのでポインタを安全に取得できる
@@ -266,6 +382,18 @@ impl TextItem {
         self.max_chars
     }
 
+    /// 各行の元の文字列（ANSIエスケープシーケンスを含む）を返す
+    pub fn raw_lines(&self) -> impl Iterator<Item = &str> {
+        self.lines
+            .iter()
+            .map(|line| line.literal_item.item.as_str())
+    }
+
+    /// 各行の元のアイテムを返す
+    pub fn literal_items(&self) -> impl Iterator<Item = &LiteralItem> {
+        self.lines.iter().map(|line| &line.literal_item)
+    }
+
     pub fn set_max_lines(&mut self, max_lines: Option<usize>) {
         self.max_lines = max_lines;
     }
@@ -392,15 +520,16 @@ impl TextItem {
 }
 
 impl TextItem {
-    pub fn highlight(&mut self, word: &str) {
+    pub fn highlight(&mut self, pattern: &SearchPattern) {
         self.clear_highlight();
 
         let highlight_words: Vec<_> = self
             .lines
             .iter_mut()
+            .filter(|line| !line.hidden)
             .filter_map(|line| {
                 line.highlight_word(
-                    word,
+                    pattern,
                     &self.wrapped_lines[line.wrapped_lines.clone()],
                     *self.highlight_style.matches,
                 )
@@ -410,7 +539,7 @@ impl TextItem {
 
         if !highlight_words.is_empty() {
             let highlights = Highlights {
-                word: word.to_string(),
+                pattern: pattern.clone(),
                 item: highlight_words,
                 selected_index: 0,
             };
@@ -515,6 +644,157 @@ impl TextItem {
     }
 }
 
+/// 行の絞り込み機能
+///
+/// 絞り込み中もlinesは保持したままなので、解除すると元の表示に戻る
+impl TextItem {
+    pub fn set_filter(&mut self, filter: Option<LineFilter>) {
+        if self.filter.is_none() && filter.is_none() {
+            return;
+        }
+
+        self.filter = filter;
+
+        for line in self.lines.iter_mut() {
+            line.matched = false;
+            line.hidden = false;
+        }
+
+        if self.filter.is_some() {
+            self.apply_filter_from(0);
+        } else {
+            self.rewrap_from(0);
+        }
+
+        self.rehighlight();
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    /// line_index行目の折り返しを考慮した行番号
+    pub fn line_number(&self, line_index: usize) -> Option<usize> {
+        self.lines.get(line_index).map(|line| line.line_number)
+    }
+
+    /// 絞り込み条件にマッチした行数と全体の行数
+    pub fn filter_status(&self) -> (usize, usize) {
+        let matched = self.lines.iter().filter(|line| line.matched).count();
+
+        (matched, self.lines.len())
+    }
+
+    /// start行目以降の行のマッチを判定し、表示する行を決め直す
+    ///
+    /// start行目より前の行も、後ろの行の前後の行として表示される可能性があるためやり直す
+    fn apply_filter_from(&mut self, start: usize) {
+        let Some(filter) = &self.filter else {
+            return;
+        };
+
+        let context = filter.context;
+
+        for line in self.lines.range_mut(start..) {
+            line.matched = line.graphemes.search(&filter.pattern).is_some();
+        }
+
+        let begin = start.saturating_sub(context);
+        let last = self.lines.len().saturating_sub(1);
+
+        for i in begin..self.lines.len() {
+            let hidden = !(i.saturating_sub(context)..=(i + context).min(last))
+                .any(|j| self.lines[j].matched);
+
+            self.lines[i].hidden = hidden;
+        }
+
+        self.rewrap_from(begin);
+    }
+
+    /// ハイライト中の場合、range行目の表示している行で検索し直す
+    ///
+    /// ほかの行のハイライトはそのまま残し、選択中のハイライトが範囲内にあった場合は選択し直す
+    fn highlight_lines(&mut self, range: Range<usize>) {
+        let Some(highlights) = &mut self.highlights else {
+            return;
+        };
+
+        let begin = highlights
+            .item
+            .partition_point(|hl| hl.line_index < range.start);
+        let end = highlights
+            .item
+            .partition_point(|hl| hl.line_index < range.end);
+
+        let selected = highlights.selected_index;
+
+        let focused = (begin..end).contains(&selected).then(|| {
+            let hl = &highlights.item[selected];
+            (hl.line_index, hl.range.start, hl.line_number)
+        });
+
+        let lines = self.lines.make_contiguous();
+
+        let hls: Vec<Highlight> = lines[range]
+            .iter_mut()
+            .filter(|line| !line.hidden)
+            .filter_map(|line| {
+                line.highlight_word(
+                    &highlights.pattern,
+                    &self.wrapped_lines[line.wrapped_lines.clone()],
+                    *self.highlight_style.matches,
+                )
+            })
+            .flatten()
+            .collect();
+
+        let new_end = begin + hls.len();
+
+        highlights.item.splice(begin..end, hls);
+
+        if highlights.item.is_empty() {
+            self.highlights = None;
+            return;
+        }
+
+        if end <= selected {
+            highlights.selected_index = selected - end + new_end;
+            return;
+        }
+
+        let Some((line_index, start, line_number)) = focused else {
+            return;
+        };
+
+        let index = highlights.item[begin..new_end]
+            .iter()
+            .position(|hl| hl.line_index == line_index && hl.range.start == start);
+
+        if let Some(index) = index {
+            self.highlight_focus(begin + index);
+        } else {
+            highlights.selected_index = begin.min(highlights.item.len() - 1);
+            self.select_nearest_highlight(line_number);
+        }
+    }
+
+    /// ハイライト中の場合、表示している行で検索し直す
+    ///
+    /// 選択中のハイライトに近い箇所を選択する
+    fn rehighlight(&mut self) {
+        let Some(highlights) = &self.highlights else {
+            return;
+        };
+
+        let pattern = highlights.pattern.clone();
+        let prev_line_number = highlights.item[highlights.selected_index].line_number;
+
+        self.highlight(&pattern);
+        self.select_nearest_highlight(prev_line_number);
+    }
+}
+
 impl TextItem {
     pub fn wrapped_lines(&self) -> &[WrappedLine] {
         &self.wrapped_lines
@@ -523,22 +803,36 @@ impl TextItem {
     pub fn rewrap(&mut self, wrap_width: usize) {
         self.wrap_width = Some(wrap_width);
 
-        let mut all_wrapped_lines = Vec::new();
-        let mut line_number = 0;
+        self.rewrap_from(0);
+    }
 
-        for line in self.lines.iter_mut() {
-            let new_wrapped = line.create_wrapped_lines(self.wrap_width);
+    /// start行目以降のwrapped_linesを作り直す
+    ///
+    /// 絞り込まれた行はwrapped_linesを持たない
+    fn rewrap_from(&mut self, start: usize) {
+        let mut line_number = self
+            .lines
+            .get(start)
+            .map(|line| line.line_number)
+            .unwrap_or(self.wrapped_lines.len());
+
+        self.wrapped_lines.truncate(line_number);
+
+        for line in self.lines.range_mut(start..) {
+            let new_wrapped = if line.hidden {
+                Vec::new()
+            } else {
+                line.create_wrapped_lines(self.wrap_width)
+            };
             let wrapped_len = new_wrapped.len();
 
             line.line_number = line_number;
             line.wrapped_lines = line_number..(line_number + wrapped_len);
 
-            all_wrapped_lines.extend(new_wrapped);
+            self.wrapped_lines.extend(new_wrapped);
             line_number += wrapped_len;
         }
 
-        self.wrapped_lines = all_wrapped_lines;
-
         if let Some(highlights) = &mut self.highlights {
             highlights.item.iter_mut().for_each(|hl| {
                 let line = &self.lines[hl.line_index];
@@ -572,18 +866,22 @@ struct Line {
     /// ワード検索でスクロール位置を割り出すのに使う
     /// TextItem.wrappedのポインターをもつ
     wrapped_lines: Range<usize>,
+
+    /// 絞り込みで非表示になっているかどうか
+    hidden: bool,
+
+    /// 絞り込み条件にマッチしたかどうか
+    matched: bool,
 }
 
 impl Line {
     pub fn highlight_word(
         &mut self,
-        word: &str,
+        pattern: &SearchPattern,
         wrapped_lines: &[WrappedLine],
         highlight_style: Style,
     ) -> Option<Vec<Highlight>> {
-        let word = word.styled_graphemes_symbols();
-
-        if let Some(ranges) = self.graphemes.search(&word) {
+        if let Some(ranges) = self.graphemes.search(pattern) {
             let ret: Vec<Highlight> = ranges
                 .iter()
                 .cloned()
@@ -641,7 +939,7 @@ fn highlight_line_number(
 
     for w in wrapped_lines {
         grapheme_len += w.line().len();
-        if grapheme_len < start_index {
+        if grapheme_len <= start_index {
             line_number += 1;
         } else {
             break;
@@ -787,6 +1085,22 @@ mod tests {
             assert_eq!(wrapped_lines[5].slice_ptr, &lines[2].graphemes[2..]);
         }
 
+        #[test]
+        fn raw_lines() {
+            let item = TextItem::new(
+                vec![
+                    LiteralItem::new("\x1b[31m0123456789\x1b[39m", None),
+                    LiteralItem::new("あいうえ", None),
+                ],
+                Some(5),
+                SearchHighlightStyle::default(),
+            );
+
+            let actual: Vec<&str> = item.raw_lines().collect();
+
+            assert_eq!(actual, vec!["\x1b[31m0123456789\x1b[39m", "あいうえ"]);
+        }
+
         #[test]
         fn highlight() {
             let mut item = TextItem::new(
@@ -798,7 +1112,7 @@ mod tests {
                 SearchHighlightStyle::default(),
             );
 
-            item.highlight("world");
+            item.highlight(&SearchPattern::new("world", false).unwrap());
 
             let actual: Vec<(usize, Vec<Style>)> = item
                 .lines
@@ -859,7 +1173,7 @@ mod tests {
                 SearchHighlightStyle::default(),
             );
 
-            item.highlight("world");
+            item.highlight(&SearchPattern::new("world", false).unwrap());
             item.clear_highlight();
 
             let actual: Vec<(usize, Vec<Style>)> = item
@@ -1007,11 +1321,12 @@ mod tests {
                 literal_item: item,
                 graphemes,
                 wrapped_lines: 0..1,
+                ..Default::default()
             };
 
             let highlight = line
                 .highlight_word(
-                    "hello",
+                    &SearchPattern::new("hello", false).unwrap(),
                     &wrapped_lines,
                     *SearchHighlightMatchesStyle::default(),
                 )
@@ -1070,10 +1385,11 @@ mod tests {
                 literal_item: item,
                 graphemes,
                 wrapped_lines: 0..1,
+                ..Default::default()
             };
 
             let highlight = line.highlight_word(
-                "hoge",
+                &SearchPattern::new("hoge", false).unwrap(),
                 &wrapped_lines,
                 *SearchHighlightMatchesStyle::default(),
             );
@@ -1125,11 +1441,12 @@ mod tests {
                 literal_item: item,
                 graphemes,
                 wrapped_lines: 0..1,
+                ..Default::default()
             };
 
             let _highlight = line
                 .highlight_word(
-                    "hello",
+                    &SearchPattern::new("hello", false).unwrap(),
                     &wrapped_lines,
                     *SearchHighlightMatchesStyle::default(),
                 )
@@ -1266,56 +1583,297 @@ mod tests {
             assert_eq!(item.lines[2].literal_item.item, "line5".to_string());
         }
     }
+    mod line_filter {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn item(lines: &[&str]) -> TextItem {
+            TextItem::new(
+                lines.iter().map(|l| LiteralItem::new(*l, None)).collect(),
+                None,
+                SearchHighlightStyle::default(),
+            )
+        }
+
+        fn filter(word: &str, context: usize) -> Option<LineFilter> {
+            Some(LineFilter::new(
+                SearchPattern::new(word, false).unwrap(),
+                context,
+            ))
+        }
+
+        fn visible_lines(item: &TextItem) -> Vec<String> {
+            item.wrapped_lines()
+                .iter()
+                .map(|l| l.line().iter().map(|g| g.symbol()).collect())
+                .collect()
+        }
+
+        #[test]
+        fn filter_hides_unmatched_lines() {
+            let mut item = item(&["info a", "error b", "info c", "error d"]);
+
+            item.set_filter(filter("error", 0));
+
+            assert_eq!(visible_lines(&item), vec!["error b", "error d"]);
+            assert_eq!(item.filter_status(), (2, 4));
+        }
+
+        #[test]
+        fn filter_shows_context_lines() {
+            let mut item = item(&["0", "1", "error", "3", "4", "5"]);
+
+            item.set_filter(filter("error", 1));
+
+            assert_eq!(visible_lines(&item), vec!["1", "error", "3"]);
+        }
+
+        #[test]
+        fn unset_filter_restores_all_lines() {
+            let mut item = item(&["info a", "error b", "info c"]);
+
+            item.set_filter(filter("error", 0));
+            item.set_filter(None);
+
+            assert_eq!(visible_lines(&item), vec!["info a", "error b", "info c"]);
+        }
+
+        #[test]
+        fn extend_applies_filter_to_new_and_previous_lines() {
+            let mut item = item(&["0", "error", "2", "3"]);
+
+            item.set_filter(filter("error", 1));
+
+            item.extend(vec![
+                LiteralItem::new("4", None),
+                LiteralItem::new("error", None),
+            ]);
+
+            assert_eq!(visible_lines(&item), vec!["0", "error", "2", "4", "error"]);
+            assert_eq!(item.raw_lines().count(), 6);
+        }
+
+        #[test]
+        fn extend_highlights_new_and_revealed_lines() {
+            let mut item = item(&["pod-a", "error pod-b", "pod-c", "pod-d", "pod-e"]);
+
+            item.set_filter(filter("error", 2));
+            item.highlight(&SearchPattern::new("pod", false).unwrap());
+            item.select_next_highlight();
+            item.select_next_highlight();
+            item.select_next_highlight();
+
+            assert_eq!(item.highlight_status(), (4, 4));
+
+            item.extend(vec![LiteralItem::new("error pod-f", None)]);
+
+            assert_eq!(
+                visible_lines(&item),
+                vec![
+                    "pod-a",
+                    "error pod-b",
+                    "pod-c",
+                    "pod-d",
+                    "pod-e",
+                    "error pod-f"
+                ]
+            );
+            assert_eq!(item.highlight_status(), (4, 6));
+            assert_eq!(item.highlight_selected_line_number(), Some(3));
+
+            item.select_next_highlight();
+
+            assert_eq!(item.highlight_selected_line_number(), Some(4));
+        }
+
+        #[test]
+        fn replace_lines_rewraps_and_highlights_replaced_lines() {
+            let mut item = TextItem::new(
+                vec![
+                    LiteralItem::new("a pod-a", None),
+                    LiteralItem::new("a pod-b", None),
+                    LiteralItem::new("a pod-c", None),
+                ],
+                Some(8),
+                SearchHighlightStyle::default(),
+            );
+
+            item.highlight(&SearchPattern::new("pod", false).unwrap());
+            item.select_next_highlight();
+            item.select_next_highlight();
+
+            item.replace_lines(1, vec![LiteralItem::new("bbbb pod-b", None)]);
+
+            assert_eq!(
+                visible_lines(&item),
+                vec!["a pod-a", "bbbb pod", "-b", "a pod-c"]
+            );
+            assert_eq!(item.highlight_status(), (3, 3));
+            assert_eq!(item.highlight_selected_line_number(), Some(3));
+
+            item.select_prev_highlight();
+
+            assert_eq!(item.highlight_selected_line_number(), Some(1));
+        }
+
+        #[test]
+        fn replace_lines_applies_filter() {
+            let mut item = item(&["error a", "info b", "info c"]);
+
+            item.set_filter(filter("error", 0));
+
+            item.replace_lines(1, vec![LiteralItem::new("error b", None)]);
+
+            assert_eq!(visible_lines(&item), vec!["error a", "error b"]);
+        }
+
+        #[test]
+        fn update_keeps_filter() {
+            let mut item = item(&["error a"]);
+
+            item.set_filter(filter("error", 0));
+
+            item.update(vec![
+                LiteralItem::new("info b", None),
+                LiteralItem::new("error c", None),
+            ]);
+
+            assert_eq!(visible_lines(&item), vec!["error c"]);
+        }
+
+        #[test]
+        fn highlight_skips_hidden_lines() {
+            let mut item = item(&["error pod-a", "info pod-b", "error pod-c"]);
+
+            item.set_filter(filter("error", 0));
+            item.highlight(&SearchPattern::new("pod", false).unwrap());
+
+            assert_eq!(item.highlight_status(), (1, 2));
+
+            item.select_next_highlight();
+
+            assert_eq!(item.highlight_selected_line_number(), Some(1));
+        }
+
+        #[test]
+        fn filter_with_wrapped_lines() {
+            let mut item = TextItem::new(
+                vec![
+                    LiteralItem::new("0123456789", None),
+                    LiteralItem::new("error 6789", None),
+                ],
+                Some(5),
+                SearchHighlightStyle::default(),
+            );
+
+            item.set_filter(filter("error", 0));
+
+            assert_eq!(visible_lines(&item), vec!["error", " 6789"]);
+            assert_eq!(item.line_number(1), Some(0));
+        }
+    }
 }
 
 mod search {
     use std::ops::Range;
 
+    use regex::{Regex, RegexBuilder};
+
     use crate::ui::widget::styled_graphemes::StyledGrapheme;
 
-    pub trait Search {
-        fn search(&self, word: &[&str]) -> Option<Vec<Range<usize>>>;
+    /// 検索フォームの入力から作るマッチ条件
+    ///
+    /// - `/regex/` のようにスラッシュで囲むと正規表現として扱う
+    /// - 大文字を含まない場合は大文字小文字を区別しない（smart-case）
+    /// - `whole_word` が有効な場合は単語全体にマッチする箇所のみ
+    #[derive(Debug, Clone)]
+    pub struct SearchPattern {
+        regex: Regex,
     }
 
-    impl Search for Vec<&str> {
-        fn search(&self, word: &[&str]) -> Option<Vec<Range<usize>>> {
-            let mut match_list = Vec::new();
+    impl SearchPattern {
+        pub fn new(word: &str, whole_word: bool) -> Result<Self, regex::Error> {
+            let (pattern, is_regex) = match word.strip_prefix('/').and_then(|w| w.strip_suffix('/'))
+            {
+                Some(pattern) => (pattern.to_string(), true),
+                None => (regex::escape(word), false),
+            };
 
-            let word_len = word.len();
-            let line_len = self.len();
-            for i in 0..line_len {
-                let range = i..(i + word_len);
+            let case_insensitive = if is_regex {
+                !has_uppercase_in_regex(&pattern)
+            } else {
+                !word.chars().any(char::is_uppercase)
+            };
 
-                if let Some(target) = self.get(range.clone()) {
-                    if target == word {
-                        match_list.push(range);
-                    }
-                }
+            let pattern = if whole_word {
+                format!(r"\b(?:{})\b", pattern)
+            } else {
+                pattern
+            };
+
+            let regex = RegexBuilder::new(&pattern)
+                .case_insensitive(case_insensitive)
+                .build()?;
+
+            Ok(Self { regex })
+        }
+
+        /// 1行分の文字列のうちマッチする範囲をgraphemeのインデックスで返す
+        ///
+        /// 空文字へのマッチは含めない
+        fn find_ranges<'a>(&self, symbols: impl Iterator<Item = &'a str>) -> Vec<Range<usize>> {
+            let mut line = String::new();
+            let mut starts = Vec::new();
+
+            for symbol in symbols {
+                starts.push(line.len());
+                line.push_str(symbol);
             }
 
+            self.regex
+                .find_iter(&line)
+                .filter(|m| !m.is_empty())
+                .map(|m| {
+                    let start = starts.partition_point(|&s| s <= m.start()) - 1;
+                    let end = starts.partition_point(|&s| s < m.end());
+
+                    start..end
+                })
+                .collect()
+        }
+    }
+
+    /// エスケープシーケンス（`\S` や `\P{..}` など）を除いて大文字を含むかどうか
+    fn has_uppercase_in_regex(pattern: &str) -> bool {
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                chars.next();
+            } else if c.is_uppercase() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub trait Search {
+        fn search(&self, pattern: &SearchPattern) -> Option<Vec<Range<usize>>>;
+    }
+
+    impl Search for Vec<&str> {
+        fn search(&self, pattern: &SearchPattern) -> Option<Vec<Range<usize>>> {
+            let match_list = pattern.find_ranges(self.iter().copied());
+
             (!match_list.is_empty()).then_some(match_list)
         }
     }
 
     impl Search for Vec<StyledGrapheme> {
-        fn search(&self, word: &[&str]) -> Option<Vec<Range<usize>>> {
-            let mut match_list = Vec::new();
-
-            let word_len = word.len();
-            let line_len = self.len();
-            for i in 0..line_len {
-                let range = i..(i + word_len);
-
-                if let Some(target) = self.get(range.clone()) {
-                    if target
-                        .iter()
-                        .zip(word.iter())
-                        .all(|(t, w)| &t.symbol() == w)
-                    {
-                        match_list.push(range);
-                    }
-                }
-            }
+        fn search(&self, pattern: &SearchPattern) -> Option<Vec<Range<usize>>> {
+            let match_list = pattern.find_ranges(self.iter().map(|g| g.symbol()));
 
             (!match_list.is_empty()).then_some(match_list)
         }
@@ -1336,9 +1894,9 @@ mod search {
             fn 指定ワードにマッチしたとき範囲のリストを返す() {
                 let line = "hello world. hello world.".styled_graphemes();
 
-                let word = "hello".styled_graphemes_symbols();
+                let pattern = SearchPattern::new("hello", false).unwrap();
 
-                let actual = line.search(&word);
+                let actual = line.search(&pattern);
 
                 let expected = Some(vec![0..5, 13..18]);
 
@@ -1349,9 +1907,9 @@ mod search {
             fn 指定ワードにマッチしないときnoneを返す() {
                 let line = "hello world. hello world.".styled_graphemes();
 
-                let word = "hogehoge".styled_graphemes_symbols();
+                let pattern = SearchPattern::new("hogehoge", false).unwrap();
 
-                let actual = line.search(&word);
+                let actual = line.search(&pattern);
 
                 let expected = None;
 
@@ -1367,9 +1925,9 @@ mod search {
             fn 指定ワードにマッチしたとき範囲のリストを返す() {
                 let line = "hello world. hello world.".styled_graphemes_symbols();
 
-                let word = "hello".styled_graphemes_symbols();
+                let pattern = SearchPattern::new("hello", false).unwrap();
 
-                let actual = line.search(&word);
+                let actual = line.search(&pattern);
 
                 let expected = Some(vec![0..5, 13..18]);
 
@@ -1380,14 +1938,84 @@ mod search {
             fn 指定ワードにマッチしないときnoneを返す() {
                 let line = "hello world. hello world.".styled_graphemes_symbols();
 
-                let word = "hogehoge".styled_graphemes_symbols();
+                let pattern = SearchPattern::new("hogehoge", false).unwrap();
 
-                let actual = line.search(&word);
+                let actual = line.search(&pattern);
 
                 let expected = None;
 
                 assert_eq!(actual, expected);
             }
         }
+        mod search_pattern {
+            use super::*;
+            use pretty_assertions::assert_eq;
+
+            /// マッチ範囲を `(start, end)` のリストで返す
+            fn ranges(word: &str, whole_word: bool, line: &str) -> Vec<(usize, usize)> {
+                let pattern = SearchPattern::new(word, whole_word).unwrap();
+
+                line.styled_graphemes()
+                    .search(&pattern)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|r| (r.start, r.end))
+                    .collect()
+            }
+
+            #[test]
+            fn スラッシュで囲むと正規表現として扱う() {
+                assert_eq!(
+                    ranges("/error|timeout/", false, "error: request timeout"),
+                    vec![(0, 5), (15, 22)]
+                );
+            }
+
+            #[test]
+            fn スラッシュで囲まない場合は記号もそのまま検索する() {
+                assert_eq!(ranges("a.c", false, "abc a.c"), vec![(4, 7)]);
+            }
+
+            #[test]
+            fn 小文字のみの場合は大文字小文字を区別しない() {
+                assert_eq!(ranges("error", false, "Error ERROR"), vec![(0, 5), (6, 11)]);
+            }
+
+            #[test]
+            fn 大文字を含む場合は大文字小文字を区別する() {
+                assert_eq!(ranges("Error", false, "Error ERROR"), vec![(0, 5)]);
+            }
+
+            #[test]
+            fn 正規表現のエスケープシーケンスは大文字として扱わない() {
+                assert_eq!(
+                    ranges(r"/id=\S+/", false, "ID=abc id=def"),
+                    vec![(0, 6), (7, 13)]
+                );
+            }
+
+            #[test]
+            fn 単語全体にマッチする箇所のみ() {
+                assert_eq!(
+                    ranges("log", true, "log logs catalog log"),
+                    vec![(0, 3), (17, 20)]
+                );
+            }
+
+            #[test]
+            fn マルチバイト文字の範囲をgraphemeのインデックスで返す() {
+                assert_eq!(ranges("/う.+/", false, "あいうえお"), vec![(2, 5)]);
+            }
+
+            #[test]
+            fn 空文字へのマッチは含めない() {
+                assert_eq!(ranges("/x*/", false, "abc"), vec![]);
+            }
+
+            #[test]
+            fn 不正な正規表現はエラーを返す() {
+                assert!(SearchPattern::new("/(/", false).is_err());
+            }
+        }
     }
 }
@@ -188,6 +188,17 @@ impl<'a> WindowBuilder<'a> {
         self
     }
 
+    /// 複数のイベントに同じコールバックを登録する
+    pub fn actions<F, I>(self, evs: I, cb: F) -> Self
+    where
+        I: IntoIterator<Item = UserEvent>,
+        F: Into<Callback>,
+    {
+        let cb = cb.into();
+        evs.into_iter()
+            .fold(self, |builder, ev| builder.action(ev, cb.clone()))
+    }
+
     pub fn dialogs(mut self, dialogs: impl Into<Vec<Dialog<'a>>>) -> Self {
         self.dialogs = dialogs.into();
         self
@@ -355,6 +366,10 @@ impl Window<'_> {
     pub fn opening_dialog(&self) -> bool {
         self.opening_dialog_id.is_some()
     }
+
+    pub fn opening_dialog_id(&self) -> Option<&str> {
+        self.opening_dialog_id.as_deref()
+    }
 }
 
 // Tab
@@ -586,6 +601,7 @@ impl Window<'_> {
 
                 EventResult::Nop
             }
+            UserEvent::Resize(..) => EventResult::Nop,
         }
     }
 
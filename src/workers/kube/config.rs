@@ -1,4 +1,4 @@
-use std::{ops::Deref, path::PathBuf};
+use std::{collections::BTreeMap, ops::Deref, path::PathBuf};
 
 use anyhow::{anyhow, Result};
 use kube::config::{Kubeconfig, KubeconfigError};
@@ -10,6 +10,7 @@ use crate::features::{
     network::NetworkColumns,
     node::kube::NodeConfig,
     pod::kube::PodConfig,
+    workload::WorkloadColumns,
 };
 
 use super::{ApisConfig, TargetNamespaces, YamlConfig};
@@ -27,8 +28,17 @@ pub struct KubeWorkerConfig {
     pub api_config: ApiConfig,
     pub apis_config: ApisConfig,
     pub yaml_config: YamlConfig,
+    /// Secretの値を伏せ字で表示する
+    pub mask_secret_values: bool,
+    /// リソースを変更する操作を拒否する
+    pub read_only: bool,
     pub default_config_columns: ConfigColumns,
     pub default_network_columns: NetworkColumns,
+    pub default_workload_columns: WorkloadColumns,
+    /// 前回終了時のコンテキスト。`context` が指定されていない場合に使う
+    pub restored_context: Option<String>,
+    /// 前回終了時のコンテキストごとのネームスペース
+    pub restored_namespaces: BTreeMap<String, TargetNamespaces>,
 }
 
 pub struct Context(String);
@@ -1,6 +1,6 @@
-use std::{sync::Arc, time::Duration};
+use std::{collections::BTreeMap, ops::ControlFlow, sync::Arc, time::Duration};
 
-use anyhow::{Context as _, Result};
+use anyhow::{anyhow, Context as _, Result};
 use async_trait::async_trait;
 use crossbeam::channel::{Receiver, Sender};
 use k8s_openapi::api::core::v1::Namespace;
@@ -8,7 +8,7 @@ use kube::{api::ListParams, config::Kubeconfig, Api, ResourceExt as _};
 use ratatui::style::{Color, Style};
 use tokio::{
     sync::RwLock,
-    task::{self, AbortHandle},
+    task::{self, AbortHandle, JoinSet},
 };
 
 use crate::{
@@ -19,13 +19,23 @@ use crate::{
             message::{ApiMessage, ApiRequest, ApiResponse},
         },
         config::{
-            kube::{ConfigPoller, ConfigsDataWorker},
-            message::ConfigMessage,
+            kube::{ConfigPoller, ConfigsDataWorker, SecretReveal},
+            message::{ConfigMessage, ConfigRequest, RevealRequest},
             ConfigColumns,
         },
         context::message::{ContextMessage, ContextRequest, ContextResponse},
-        event::kube::{EventConfig, EventPoller},
-        get::{kube::yaml::GetYamlWorker, message::GetMessage},
+        edit::{
+            kube::{fetch_document, replace_resource, EditTarget},
+            message::{EditMessage, EditRequest, EditResponse, EditSource},
+        },
+        event::{
+            kube::{EventConfig, EventPoller, SharedEventGroup},
+            message::EventMessage,
+        },
+        get::{
+            kube::yaml::GetYamlWorker,
+            message::{GetMessage, GetRequest},
+        },
         namespace::message::{NamespaceMessage, NamespaceRequest, NamespaceResponse},
         network::{
             kube::{NetworkDescriptionWorker, NetworkPoller},
@@ -36,14 +46,43 @@ use crate::{
             kube::{NodeConfig, NodeDetailWorker, NodePoller, SharedNodeColumns, SharedNodeFilter},
             message::{NodeDetailMessage, NodeMessage},
         },
+        operation::{
+            kube::{execute, fetch_replicas},
+            message::{OperationMessage, OperationRequest, OperationResponse},
+        },
         pod::{
-            kube::{LogConfig, LogWorker, PodConfig, PodPoller},
-            message::{LogMessage, PodMessage},
+            kube::{
+                fetch_containers,
+                ExecHandle,
+                ExecWorker,
+                LogConfig,
+                LogWorker,
+                PodConfig,
+                PodDescribeWorker,
+                PodPoller,
+            },
+            message::{
+                DescribeMessage,
+                ExecMessage,
+                ExecRequest,
+                ExecResponse,
+                LogMessage,
+                PodMessage,
+            },
             PodColumns,
         },
+        port_forward::{
+            kube::{fetch_ports, PendingPortForward, PortForwards, SharedPortForwards},
+            message::{PortForwardMessage, PortForwardRequest, PortForwardResponse},
+        },
+        workload::{
+            kube::{WorkloadDescriptionWorker, WorkloadPoller},
+            message::WorkloadMessage,
+            WorkloadColumns,
+        },
         yaml::{
             kube::{FetchResourceList, YamlWorker},
-            message::{YamlMessage, YamlRequest, YamlResponse},
+            message::{YamlMessage, YamlRequest, YamlResponse, YamlTarget},
         },
         StyledApiResource,
     },
@@ -74,6 +113,8 @@ pub type SharedConfigFilter = Arc<RwLock<Option<String>>>;
 pub type SharedConfigColumns = Arc<RwLock<ConfigColumns>>;
 pub type SharedNetworkFilter = Arc<RwLock<Option<String>>>;
 pub type SharedNetworkColumns = Arc<RwLock<NetworkColumns>>;
+pub type SharedWorkloadFilter = Arc<RwLock<Option<String>>>;
+pub type SharedWorkloadColumns = Arc<RwLock<WorkloadColumns>>;
 
 /// APIタブのダイアログで表示されるAPIリソースのスタイル設定
 #[derive(Debug, Clone)]
@@ -157,8 +198,12 @@ pub struct KubeController {
     api_config: ApiConfig,
     apis_config: ApisConfig,
     yaml_config: YamlConfig,
+    mask_secret_values: bool,
+    read_only: bool,
     default_config_columns: ConfigColumns,
     default_network_columns: NetworkColumns,
+    default_workload_columns: WorkloadColumns,
+    restored_namespaces: BTreeMap<String, TargetNamespaces>,
 }
 
 impl KubeController {
@@ -179,12 +224,23 @@ impl KubeController {
             api_config,
             apis_config,
             yaml_config,
+            mask_secret_values,
+            read_only,
             default_config_columns,
             default_network_columns,
+            default_workload_columns,
+            restored_context,
+            mut restored_namespaces,
         } = config;
 
         let kubeconfig = read_kubeconfig(kubeconfig)?;
 
+        // kubeconfigから削除されたコンテキストは復元しない
+        let context = context.or_else(|| {
+            restored_context
+                .filter(|restored| kubeconfig.contexts.iter().any(|ctx| ctx.name == *restored))
+        });
+
         let context = Context::try_from(&kubeconfig, context)?;
 
         let mut store =
@@ -197,6 +253,10 @@ impl KubeController {
             ..
         } = store.get_mut(&context)?;
 
+        if let Some(namespaces) = restored_namespaces.remove(context.as_str()) {
+            *state_of_target_namespaces = namespaces;
+        }
+
         if let Some(namespaces) = target_namespaces {
             *state_of_target_namespaces = namespaces;
         }
@@ -220,8 +280,12 @@ impl KubeController {
             api_config,
             apis_config,
             yaml_config,
+            mask_secret_values,
+            read_only,
             default_config_columns,
             default_network_columns,
+            default_workload_columns,
+            restored_namespaces,
         })
     }
 
@@ -239,18 +303,30 @@ impl KubeController {
             api_config,
             apis_config,
             yaml_config,
+            mask_secret_values,
+            read_only,
             default_config_columns,
             default_network_columns,
+            default_workload_columns,
+            mut restored_namespaces,
         } = self;
 
         let mut override_namespaces: Option<Vec<String>> = None;
 
+        // ポートフォワードはコンテキストを切り替えるまで維持する
+        let port_forwards = PortForwards::shared();
+
         loop {
             store
                 .ensure_context(&kubeconfig, &context)
                 .await
                 .context("Failed to initialize context")?;
 
+            // 前回終了時のネームスペースは初めて切り替えたときだけ使う
+            if let Some(namespaces) = restored_namespaces.remove(&context) {
+                store.get_mut(&context)?.target_namespaces = namespaces;
+            }
+
             let KubeState {
                 client,
                 target_namespaces: mut stored_target_namespaces,
@@ -329,6 +405,10 @@ impl KubeController {
             let shared_network_filter: SharedNetworkFilter = Arc::new(RwLock::new(None));
             let shared_network_columns: SharedNetworkColumns =
                 Arc::new(RwLock::new(default_network_columns.clone()));
+            let shared_workload_filter: SharedWorkloadFilter = Arc::new(RwLock::new(None));
+            let shared_workload_columns: SharedWorkloadColumns =
+                Arc::new(RwLock::new(default_workload_columns.clone()));
+            let shared_event_group: SharedEventGroup = Arc::new(RwLock::new(false));
 
             let contexts = kubeconfig
                 .contexts
@@ -352,9 +432,15 @@ impl KubeController {
                 shared_config_columns: shared_config_columns.clone(),
                 shared_network_filter: shared_network_filter.clone(),
                 shared_network_columns: shared_network_columns.clone(),
+                shared_workload_filter: shared_workload_filter.clone(),
+                shared_workload_columns: shared_workload_columns.clone(),
+                shared_event_group: shared_event_group.clone(),
                 apis_config: apis_config.clone(),
                 yaml_config: yaml_config.clone(),
+                mask_secret_values,
+                read_only,
                 fallback_namespaces: fallback_namespaces.clone(),
+                port_forwards: port_forwards.clone(),
             };
 
             let event_controller_handle = EventController::new(event_controller_args).spawn();
@@ -396,9 +482,19 @@ impl KubeController {
             )
             .spawn();
 
+            let workload_handle = WorkloadPoller::new(
+                tx.clone(),
+                shared_target_namespaces.clone(),
+                shared_workload_columns.clone(),
+                shared_workload_filter.clone(),
+                client.clone(),
+            )
+            .spawn();
+
             let event_handle = EventPoller::new(
                 tx.clone(),
                 shared_target_namespaces.clone(),
+                shared_event_group.clone(),
                 client.clone(),
                 event_config.clone(),
             )
@@ -419,6 +515,7 @@ impl KubeController {
                 node_handle,
                 config_handle,
                 network_handle,
+                workload_handle,
                 event_handle,
                 api_handle,
             ];
@@ -446,6 +543,10 @@ impl KubeController {
                         ),
                     );
 
+                    port_forwards.write().await.stop_all();
+
+                    tx.send(PortForwardResponse::List(Vec::new()).into())?;
+
                     context = target_context;
 
                     if let Some(ns) = target_namespaces {
@@ -479,9 +580,15 @@ struct EventControllerArgs {
     shared_config_columns: SharedConfigColumns,
     shared_network_filter: SharedNetworkFilter,
     shared_network_columns: SharedNetworkColumns,
+    shared_workload_filter: SharedWorkloadFilter,
+    shared_workload_columns: SharedWorkloadColumns,
+    shared_event_group: SharedEventGroup,
     apis_config: ApisConfig,
     yaml_config: YamlConfig,
+    mask_secret_values: bool,
+    read_only: bool,
     fallback_namespaces: Option<Vec<String>>,
+    port_forwards: SharedPortForwards,
 }
 
 #[derive(Clone)]
@@ -501,9 +608,15 @@ struct EventController {
     shared_config_columns: SharedConfigColumns,
     shared_network_filter: SharedNetworkFilter,
     shared_network_columns: SharedNetworkColumns,
+    shared_workload_filter: SharedWorkloadFilter,
+    shared_workload_columns: SharedWorkloadColumns,
+    shared_event_group: SharedEventGroup,
     apis_config: ApisConfig,
     yaml_config: YamlConfig,
+    mask_secret_values: bool,
+    read_only: bool,
     fallback_namespaces: Option<Vec<String>>,
+    port_forwards: SharedPortForwards,
 }
 
 impl EventController {
@@ -524,10 +637,58 @@ impl EventController {
             shared_config_columns: args.shared_config_columns,
             shared_network_filter: args.shared_network_filter,
             shared_network_columns: args.shared_network_columns,
+            shared_workload_filter: args.shared_workload_filter,
+            shared_workload_columns: args.shared_workload_columns,
+            shared_event_group: args.shared_event_group,
             apis_config: args.apis_config,
             yaml_config: args.yaml_config,
+            mask_secret_values: args.mask_secret_values,
+            read_only: args.read_only,
             fallback_namespaces: args.fallback_namespaces,
+            port_forwards: args.port_forwards,
+        }
+    }
+}
+
+/// 読み取り専用モードでリソースを変更するリクエストを拒否する
+///
+/// 描画側でキーを登録しないだけでなく、ここでもリクエストを受け付けないようにする。
+/// 拒否した場合はエラーの応答（応答がないリクエストは `None`）を返す。
+fn reject_in_read_only(ev: Kube) -> ControlFlow<Option<Message>, Kube> {
+    let error = || anyhow!("kubetui is running in read-only mode");
+
+    match ev {
+        Kube::Operation(OperationMessage::Request(OperationRequest::Execute(operation))) => {
+            ControlFlow::Break(Some(
+                OperationResponse::Execute {
+                    operation,
+                    result: Err(error()),
+                }
+                .into(),
+            ))
+        }
+
+        Kube::Exec(ExecMessage::Request(ExecRequest::Start(_))) => {
+            ControlFlow::Break(Some(ExecResponse::Finished(Err(error())).into()))
+        }
+
+        Kube::Exec(ExecMessage::Request(ExecRequest::Input(_))) => ControlFlow::Break(None),
+
+        Kube::Edit(EditMessage::Request(EditRequest::Start(_))) => {
+            ControlFlow::Break(Some(EditResponse::Fetched(Err(error())).into()))
+        }
+
+        Kube::Edit(EditMessage::Request(EditRequest::Apply(document))) => {
+            ControlFlow::Break(Some(
+                EditResponse::Applied {
+                    document,
+                    result: Err(error()),
+                }
+                .into(),
+            ))
         }
+
+        ev => ControlFlow::Continue(ev),
     }
 }
 
@@ -548,6 +709,50 @@ impl LogHandle {
 
         self.handler = LogWorker::new(tx, client, self.config.clone()).spawn();
     }
+
+    fn toggle_previous(&mut self, tx: Sender<Message>, client: KubeClient) {
+        self.abort();
+
+        self.config.previous = !self.config.previous;
+
+        self.handler = LogWorker::new(tx, client, self.config.clone()).spawn();
+    }
+}
+
+struct ConfigHandle {
+    handler: AbortHandle,
+    req: ConfigRequest,
+    reveal: SecretReveal,
+}
+
+impl ConfigHandle {
+    fn spawn(
+        tx: Sender<Message>,
+        client: KubeClient,
+        req: ConfigRequest,
+        reveal: SecretReveal,
+    ) -> Self {
+        let handler = ConfigsDataWorker::new(tx, client, req.clone(), reveal.clone()).spawn();
+
+        Self {
+            handler,
+            req,
+            reveal,
+        }
+    }
+
+    fn abort(&self) {
+        self.handler.abort();
+    }
+
+    fn toggle_reveal(&mut self, tx: Sender<Message>, client: KubeClient, req: RevealRequest) {
+        self.abort();
+
+        self.reveal.toggle(req);
+
+        self.handler =
+            ConfigsDataWorker::new(tx, client, self.req.clone(), self.reveal.clone()).spawn();
+    }
 }
 
 #[async_trait]
@@ -556,11 +761,19 @@ impl Worker for EventController {
 
     async fn run(&self) -> Self::Output {
         let mut log_handler: Option<LogHandle> = None;
-        let mut config_handler: Option<AbortHandle> = None;
+        let mut exec_handler: Option<ExecHandle> = None;
+        let mut config_handler: Option<ConfigHandle> = None;
         let mut network_handler: Option<AbortHandle> = None;
+        let mut workload_handler: Option<AbortHandle> = None;
+        let mut describe_handler: Option<AbortHandle> = None;
         let mut node_detail_handler: Option<AbortHandle> = None;
         let mut yaml_handler: Option<AbortHandle> = None;
         let mut get_handler: Option<AbortHandle> = None;
+        let mut edit_targets: (Option<YamlTarget>, Option<GetRequest>) = (None, None);
+
+        // APIサーバーへの問い合わせは他のメッセージの処理を止めないようにタスクで実行し、
+        // 結果はtxで返す。コンテキストを切り替えると実行中のタスクは中断される。
+        let mut request_tasks: JoinSet<()> = JoinSet::new();
 
         let EventController {
             shared_target_namespaces,
@@ -578,12 +791,20 @@ impl Worker for EventController {
             shared_config_columns,
             shared_network_filter,
             shared_network_columns,
+            shared_workload_filter,
+            shared_workload_columns,
+            shared_event_group,
             apis_config,
             yaml_config,
+            mask_secret_values,
+            read_only,
             fallback_namespaces,
+            port_forwards,
         } = self;
 
         loop {
+            while request_tasks.try_join_next().is_some() {}
+
             let rx = rx.clone();
             let tx = tx.clone();
 
@@ -593,6 +814,21 @@ impl Worker for EventController {
 
             match recv {
                 Ok(Message::Kube(ev)) => {
+                    let ev = if *read_only {
+                        match reject_in_read_only(ev) {
+                            ControlFlow::Continue(ev) => ev,
+                            ControlFlow::Break(response) => {
+                                if let Some(response) = response {
+                                    tx.send(response)
+                                        .expect("Failed to send read-only rejection");
+                                }
+                                continue;
+                            }
+                        }
+                    } else {
+                        ev
+                    };
+
                     match ev {
                         Kube::Namespace(NamespaceMessage::Request(req)) => {
                             match req {
@@ -640,6 +876,16 @@ impl Worker for EventController {
                                         network_handler = None;
                                     }
 
+                                    if let Some(handler) = workload_handler {
+                                        handler.abort();
+                                        workload_handler = None;
+                                    }
+
+                                    if let Some(handler) = describe_handler {
+                                        handler.abort();
+                                        describe_handler = None;
+                                    }
+
                                     if let Some(handler) = node_detail_handler {
                                         handler.abort();
                                         node_detail_handler = None;
@@ -706,17 +952,207 @@ impl Worker for EventController {
                             }
                         }
 
-                        Kube::Config(ConfigMessage::Request(req)) => {
-                            if let Some(handler) = config_handler {
+                        Kube::Log(LogMessage::TogglePrevious) => {
+                            if let Some(ref mut handler) = log_handler {
+                                handler.toggle_previous(tx.clone(), kube_client.clone());
+                                task::yield_now().await;
+                            }
+                        }
+
+                        Kube::Exec(ExecMessage::Request(req)) => {
+                            match req {
+                                ExecRequest::Containers { namespace, pod } => {
+                                    let client = kube_client.clone();
+
+                                    request_tasks.spawn(async move {
+                                        let containers =
+                                            fetch_containers(&client, &namespace, &pod).await;
+
+                                        tx.send(
+                                            ExecResponse::Containers {
+                                                namespace,
+                                                pod,
+                                                containers,
+                                            }
+                                            .into(),
+                                        )
+                                        .expect("Failed to send ExecResponse::Containers");
+                                    });
+                                }
+                                ExecRequest::Start(target) => {
+                                    if let Some(handler) = exec_handler {
+                                        handler.abort();
+                                    }
+
+                                    exec_handler = Some(
+                                        ExecWorker::new(tx, kube_client.clone(), target).spawn(),
+                                    );
+
+                                    task::yield_now().await;
+                                }
+                                ExecRequest::Input(input) => {
+                                    if let Some(ref handler) = exec_handler {
+                                        handler.send(input);
+                                    }
+                                }
+                            }
+                        }
+
+                        Kube::Describe(DescribeMessage::Request(req)) => {
+                            if let Some(handler) = describe_handler {
                                 handler.abort();
                             }
 
+                            describe_handler =
+                                Some(PodDescribeWorker::new(tx, kube_client.clone(), req).spawn());
+
+                            task::yield_now().await;
+                        }
+
+                        Kube::Describe(DescribeMessage::Close) => {
+                            if let Some(handler) = describe_handler.take() {
+                                handler.abort();
+                            }
+                        }
+
+                        Kube::PortForward(PortForwardMessage::Request(req)) => {
+                            match req {
+                                PortForwardRequest::Ports(target) => {
+                                    let client = kube_client.clone();
+
+                                    request_tasks.spawn(async move {
+                                        let ports = fetch_ports(&client, &target).await;
+
+                                        tx.send(
+                                            PortForwardResponse::Ports { target, ports }.into(),
+                                        )
+                                        .expect("Failed to send PortForwardResponse::Ports");
+                                    });
+                                }
+                                PortForwardRequest::Start { target, mappings } => {
+                                    let client = kube_client.clone();
+                                    let port_forwards = port_forwards.clone();
+
+                                    request_tasks.spawn(async move {
+                                        // 転送先の解決中は他のポートフォワードの操作を妨げないようにロックを取らない
+                                        let pending =
+                                            PendingPortForward::prepare(&client, target, &mappings)
+                                                .await;
+
+                                        let mut port_forwards = port_forwards.write().await;
+
+                                        let result = pending
+                                            .and_then(|pending| port_forwards.start(pending));
+
+                                        tx.send(PortForwardResponse::Start(result).into())
+                                            .expect("Failed to send PortForwardResponse::Start");
+
+                                        tx.send(
+                                            PortForwardResponse::List(port_forwards.entries())
+                                                .into(),
+                                        )
+                                        .expect("Failed to send PortForwardResponse::List");
+                                    });
+                                }
+                                PortForwardRequest::Stop(id) => {
+                                    let mut port_forwards = port_forwards.write().await;
+
+                                    port_forwards.stop(id);
+
+                                    tx.send(
+                                        PortForwardResponse::List(port_forwards.entries()).into(),
+                                    )
+                                    .expect("Failed to send PortForwardResponse::List");
+                                }
+                            }
+                        }
+
+                        Kube::Operation(OperationMessage::Request(req)) => {
+                            match req {
+                                OperationRequest::Replicas(target) => {
+                                    let client = kube_client.clone();
+
+                                    request_tasks.spawn(async move {
+                                        let replicas = fetch_replicas(&client, &target).await;
+
+                                        tx.send(
+                                            OperationResponse::Replicas { target, replicas }.into(),
+                                        )
+                                        .expect("Failed to send OperationResponse::Replicas");
+                                    });
+                                }
+                                OperationRequest::Execute(operation) => {
+                                    let client = kube_client.clone();
+
+                                    request_tasks.spawn(async move {
+                                        let result = execute(&client, &operation).await;
+
+                                        tx.send(
+                                            OperationResponse::Execute { operation, result }.into(),
+                                        )
+                                        .expect("Failed to send OperationResponse::Execute");
+                                    });
+                                }
+                            }
+                        }
+
+                        Kube::Edit(EditMessage::Request(req)) => {
+                            match req {
+                                EditRequest::Start(source) => {
+                                    let target = match source {
+                                        EditSource::Yaml => {
+                                            edit_targets.0.clone().map(EditTarget::Yaml)
+                                        }
+                                        EditSource::Get => {
+                                            edit_targets.1.clone().map(EditTarget::Get)
+                                        }
+                                    };
+
+                                    let api_resources = shared_api_resources.read().await;
+
+                                    let document =
+                                        fetch_document(kube_client, &api_resources, target).await;
+
+                                    tx.send(EditResponse::Fetched(document).into())
+                                        .expect("Failed to send EditResponse::Fetched");
+                                }
+                                EditRequest::Apply(document) => {
+                                    let result = replace_resource(kube_client, &document).await;
+
+                                    tx.send(EditResponse::Applied { document, result }.into())
+                                        .expect("Failed to send EditResponse::Applied");
+                                }
+                            }
+                        }
+
+                        Kube::Config(ConfigMessage::Request(req)) => {
+                            // 同じリソースを選択し直した場合は表示状態を引き継ぐ
+                            let reveal = match config_handler {
+                                Some(handler) if handler.req == req => {
+                                    handler.abort();
+                                    handler.reveal
+                                }
+                                Some(handler) => {
+                                    handler.abort();
+                                    SecretReveal::new(!mask_secret_values)
+                                }
+                                None => SecretReveal::new(!mask_secret_values),
+                            };
+
                             config_handler =
-                                Some(ConfigsDataWorker::new(tx, kube_client.clone(), req).spawn());
+                                Some(ConfigHandle::spawn(tx, kube_client.clone(), req, reveal));
 
                             task::yield_now().await;
                         }
 
+                        Kube::Config(ConfigMessage::Reveal(req)) => {
+                            if let Some(handler) = &mut config_handler {
+                                if matches!(handler.req, ConfigRequest::Secret(_)) {
+                                    handler.toggle_reveal(tx, kube_client.clone(), req);
+                                }
+                            }
+                        }
+
                         Kube::Config(ConfigMessage::Filter(sel)) => {
                             *shared_config_filter.write().await = sel;
                         }
@@ -768,6 +1204,14 @@ impl Worker for EventController {
                                         h.abort();
                                     }
 
+                                    if let Some(h) = workload_handler {
+                                        h.abort();
+                                    }
+
+                                    if let Some(h) = describe_handler {
+                                        h.abort();
+                                    }
+
                                     if let Some(h) = node_detail_handler {
                                         h.abort();
                                     }
@@ -832,6 +1276,8 @@ impl Worker for EventController {
                                         handler.abort();
                                     }
 
+                                    edit_targets.0 = Some(req.clone());
+
                                     yaml_handler = Some(
                                         YamlWorker::new(
                                             tx,
@@ -852,6 +1298,12 @@ impl Worker for EventController {
                                 handler.abort();
                             }
 
+                            // 複数のリソースをまとめたyamlは編集できない
+                            edit_targets.1 = match req.as_slice() {
+                                [req] => Some(req.clone()),
+                                _ => None,
+                            };
+
                             get_handler =
                                 Some(GetYamlWorker::new(tx, kube_client.clone(), req).spawn());
 
@@ -884,6 +1336,32 @@ impl Worker for EventController {
                             *shared_network_columns.write().await = columns;
                         }
 
+                        Kube::Workload(WorkloadMessage::Request(req)) => {
+                            if let Some(handler) = workload_handler {
+                                handler.abort();
+                            }
+
+                            workload_handler = Some(
+                                WorkloadDescriptionWorker::new(tx, kube_client.clone(), req)
+                                    .spawn(),
+                            );
+
+                            task::yield_now().await;
+                        }
+
+                        Kube::Workload(WorkloadMessage::Filter(sel)) => {
+                            *shared_workload_filter.write().await = sel;
+                        }
+
+                        Kube::Workload(WorkloadMessage::ColumnsRequest(columns)) => {
+                            *shared_workload_columns.write().await = columns;
+                        }
+
+                        Kube::Event(EventMessage::ToggleGroup) => {
+                            let mut grouped = shared_event_group.write().await;
+                            *grouped = !*grouped;
+                        }
+
                         Kube::NodeDetail(NodeDetailMessage::Request { name }) => {
                             if let Some(handler) = node_detail_handler {
                                 handler.abort();
@@ -912,6 +1390,105 @@ mod tests {
 
     use super::*;
 
+    mod read_only {
+        use crate::features::{
+            edit::message::EditDocument,
+            operation::message::Operation,
+            pod::message::ExecTarget,
+        };
+
+        use super::*;
+
+        fn kube(message: impl Into<Message>) -> Kube {
+            let Message::Kube(ev) = message.into() else {
+                panic!("message must be Message::Kube");
+            };
+
+            ev
+        }
+
+        #[test]
+        fn 削除などの操作を拒否してエラーを返す() {
+            let operation = Operation::DeletePod {
+                namespace: "default".into(),
+                name: "web-0".into(),
+            };
+
+            let actual = reject_in_read_only(kube(OperationRequest::Execute(operation.clone())));
+
+            let ControlFlow::Break(Some(Message::Kube(Kube::Operation(
+                OperationMessage::Response(OperationResponse::Execute {
+                    operation: rejected,
+                    result: Err(_),
+                }),
+            )))) = actual
+            else {
+                panic!("operation must be rejected");
+            };
+
+            assert_eq!(rejected, operation);
+        }
+
+        #[test]
+        fn execを拒否してエラーを返す() {
+            let target = ExecTarget {
+                namespace: "default".into(),
+                pod: "web-0".into(),
+                container: "app".into(),
+            };
+
+            let actual = reject_in_read_only(kube(ExecRequest::Start(target)));
+
+            assert!(matches!(
+                actual,
+                ControlFlow::Break(Some(Message::Kube(Kube::Exec(ExecMessage::Response(
+                    ExecResponse::Finished(Err(_))
+                )))))
+            ));
+        }
+
+        #[test]
+        fn 編集の開始と適用を拒否してエラーを返す() {
+            let actual = reject_in_read_only(kube(EditRequest::Start(EditSource::Yaml)));
+
+            assert!(matches!(
+                actual,
+                ControlFlow::Break(Some(Message::Kube(Kube::Edit(EditMessage::Response(
+                    EditResponse::Fetched(Err(_))
+                )))))
+            ));
+
+            let document = EditDocument {
+                title: "default/deployments/app".into(),
+                path: "/apis/apps/v1/namespaces/default/deployments/app".into(),
+                yaml: "a: 1\n".into(),
+            };
+
+            let actual = reject_in_read_only(kube(EditRequest::Apply(document)));
+
+            assert!(matches!(
+                actual,
+                ControlFlow::Break(Some(Message::Kube(Kube::Edit(EditMessage::Response(
+                    EditResponse::Applied { result: Err(_), .. }
+                )))))
+            ));
+        }
+
+        #[test]
+        fn 参照するだけのリクエストは拒否しない() {
+            let actual = reject_in_read_only(kube(ExecRequest::Containers {
+                namespace: "default".into(),
+                pod: "web-0".into(),
+            }));
+
+            assert!(matches!(actual, ControlFlow::Continue(Kube::Exec(_))));
+
+            let actual = reject_in_read_only(kube(LogMessage::TogglePrevious));
+
+            assert!(matches!(actual, ControlFlow::Continue(Kube::Log(_))));
+        }
+    }
+
     const CONFIG: &str = indoc! {
         r#"
             apiVersion: v1
@@ -1,15 +1,18 @@
-use anyhow::Result;
-
 use crate::{
     features::{
         api_resources::message::ApiMessage,
         config::message::ConfigMessage,
         context::message::ContextMessage,
+        edit::message::EditMessage,
+        event::message::EventMessage,
         get::message::GetMessage,
         namespace::message::NamespaceMessage,
         network::message::NetworkMessage,
         node::message::{NodeDetailMessage, NodeMessage},
-        pod::message::{LogMessage, PodMessage},
+        operation::message::OperationMessage,
+        pod::message::{DescribeMessage, ExecMessage, LogMessage, PodMessage},
+        port_forward::message::PortForwardMessage,
+        workload::message::WorkloadMessage,
         yaml::message::YamlMessage,
     },
     message::Message,
@@ -26,14 +29,20 @@ pub enum Kube {
         context: String,
         namespaces: TargetNamespaces,
     },
-    Event(Result<Vec<String>>),
+    Event(EventMessage),
     Namespace(NamespaceMessage),
     Pod(PodMessage),
     Node(NodeMessage),
     NodeDetail(NodeDetailMessage),
     Log(LogMessage),
+    Exec(ExecMessage),
+    Describe(DescribeMessage),
+    PortForward(PortForwardMessage),
+    Operation(OperationMessage),
+    Edit(EditMessage),
     Config(ConfigMessage),
     Network(NetworkMessage),
+    Workload(WorkloadMessage),
     Yaml(YamlMessage),
     Get(GetMessage),
 }
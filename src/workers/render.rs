@@ -1,30 +1,42 @@
 mod action;
+mod config;
+mod editor;
+mod exec;
+mod state;
 mod window;
 
-use std::{cell::RefCell, io, rc::Rc};
+use std::{cell::RefCell, io, path::PathBuf, rc::Rc};
 
 use anyhow::Result;
 use crossbeam::channel::{Receiver, Sender};
-use ratatui::{backend::CrosstermBackend, layout::Direction, Terminal, TerminalOptions, Viewport};
+use ratatui::{backend::CrosstermBackend, Terminal, TerminalOptions, Viewport};
 
 use crate::{
-    cmd::ClipboardMode,
-    config::theme::ThemeConfig,
     features::{
-        config::{ConfigColumns, ConfigLabelColumn},
-        network::{NetworkColumns, NetworkLabelColumn},
-        node::{NodeColumns, NodeLabelColumn},
-        pod::{PodColumns, PodLabelColumn},
+        component_id::POD_DESCRIBE_DIALOG_ID,
+        edit::message::{EditMessage, EditRequest, EditResponse},
+        namespace::message::{NamespaceMessage, NamespaceResponse},
+        pod::{
+            message::{DescribeMessage, ExecMessage, ExecResponse},
+            view::{request_logs, LogQueryHistory},
+        },
     },
     kube::context::{Context, Namespace},
     logger,
     message::Message,
     panic_set_hook,
+    state::State,
     ui::WindowAction,
+    workers::{kube::message::Kube, InputSuspender},
 };
 
+pub use self::config::RenderConfig;
+
 use self::{
     action::{update_contents, window_action},
+    editor::{run_editor_session, with_error_header},
+    exec::run_exec_session,
+    state::{capture_state, column_choices, record_namespaces, restore_state},
     window::WindowInit,
 };
 
@@ -32,18 +44,10 @@ pub struct Render {
     tx: Sender<Message>,
     rx: Receiver<Message>,
     tx_shutdown: Sender<Result<()>>,
-    direction: Direction,
-    default_pod_columns: Option<PodColumns>,
-    default_node_columns: Option<NodeColumns>,
-    default_config_columns: ConfigColumns,
-    default_network_columns: NetworkColumns,
-    pod_label_columns: Vec<PodLabelColumn>,
-    node_label_columns: Vec<NodeLabelColumn>,
-    config_label_columns: Vec<ConfigLabelColumn>,
-    network_label_columns: Vec<NetworkLabelColumn>,
-    theme: ThemeConfig,
-    clipboard_mode: ClipboardMode,
-    log_max_lines: Option<usize>,
+    config: RenderConfig,
+    input_suspender: InputSuspender,
+    state: State,
+    state_path: PathBuf,
 }
 
 impl Render {
@@ -51,35 +55,19 @@ impl Render {
         tx: Sender<Message>,
         rx: Receiver<Message>,
         tx_shutdown: Sender<Result<()>>,
-        direction: Direction,
-        default_pod_columns: Option<PodColumns>,
-        default_node_columns: Option<NodeColumns>,
-        default_config_columns: ConfigColumns,
-        default_network_columns: NetworkColumns,
-        pod_label_columns: Vec<PodLabelColumn>,
-        node_label_columns: Vec<NodeLabelColumn>,
-        config_label_columns: Vec<ConfigLabelColumn>,
-        network_label_columns: Vec<NetworkLabelColumn>,
-        theme: ThemeConfig,
-        clipboard_mode: ClipboardMode,
-        log_max_lines: Option<usize>,
+        config: RenderConfig,
+        input_suspender: InputSuspender,
+        state: State,
+        state_path: PathBuf,
     ) -> Self {
         Self {
-            direction,
             tx,
             rx,
             tx_shutdown,
-            default_pod_columns,
-            default_node_columns,
-            default_config_columns,
-            default_network_columns,
-            pod_label_columns,
-            node_label_columns,
-            config_label_columns,
-            network_label_columns,
-            theme,
-            clipboard_mode,
-            log_max_lines,
+            config,
+            input_suspender,
+            state,
+            state_path,
         }
     }
 
@@ -112,26 +100,28 @@ impl Render {
     fn render(&self) -> Result<()> {
         let namespace = Rc::new(RefCell::new(Namespace::new()));
         let context = Rc::new(RefCell::new(Context::new()));
+        let log_query_history = Rc::new(RefCell::new(LogQueryHistory::new(
+            self.state.log_query_history.clone(),
+        )));
+
+        let mut state = self.state.clone();
+
+        // 前回終了時のログクエリはコンテキストとネームスペースが決まってから実行する
+        let mut pending_log_query = state.log_query_history.last().cloned();
 
         let mut window = WindowInit::new(
-            self.direction,
             self.tx.clone(),
             context.clone(),
             namespace.clone(),
-            self.default_pod_columns.clone(),
-            self.default_node_columns.clone(),
-            self.default_config_columns.clone(),
-            self.default_network_columns.clone(),
-            self.pod_label_columns.clone(),
-            self.node_label_columns.clone(),
-            self.config_label_columns.clone(),
-            self.network_label_columns.clone(),
-            self.theme.clone(),
-            self.clipboard_mode,
-            self.log_max_lines,
+            log_query_history.clone(),
+            self.config.clone(),
         )
         .build();
 
+        let default_columns = column_choices(&window);
+
+        restore_state(&mut window, &state);
+
         let mut terminal = Terminal::with_options(
             CrosstermBackend::new(io::stdout()),
             TerminalOptions {
@@ -151,17 +141,102 @@ impl Render {
                 WindowAction::CloseWindow => {
                     break;
                 }
+                WindowAction::UpdateContents(Kube::Exec(ExecMessage::Response(
+                    ExecResponse::Attached(session),
+                ))) => {
+                    run_exec_session(
+                        &mut terminal,
+                        &mut window,
+                        &self.rx,
+                        &self.tx,
+                        session,
+                        &context,
+                        &namespace,
+                    )?;
+                }
+                WindowAction::UpdateContents(Kube::Edit(EditMessage::Response(
+                    EditResponse::Fetched(Ok(document)),
+                ))) => {
+                    if let Some(document) = run_editor_session(
+                        &mut terminal,
+                        &mut window,
+                        &self.rx,
+                        &self.input_suspender,
+                        document,
+                        &context,
+                        &namespace,
+                    )? {
+                        self.tx.send(EditRequest::Apply(document).into())?;
+                    }
+                }
+                WindowAction::UpdateContents(Kube::Edit(EditMessage::Response(
+                    EditResponse::Applied {
+                        document,
+                        result: Err(err),
+                    },
+                ))) => {
+                    logger!(error, "Failed to apply {}: {:?}", document, err);
+
+                    // kubectl editと同様に、エラーをコメントにして編集したyamlを開き直す
+                    if let Some(document) = run_editor_session(
+                        &mut terminal,
+                        &mut window,
+                        &self.rx,
+                        &self.input_suspender,
+                        with_error_header(document, &err),
+                        &context,
+                        &namespace,
+                    )? {
+                        self.tx.send(EditRequest::Apply(document).into())?;
+                    }
+                }
+                WindowAction::UpdateContents(Kube::Describe(DescribeMessage::Response(_)))
+                    if window.opening_dialog_id() != Some(POD_DESCRIBE_DIALOG_ID) =>
+                {
+                    // マウス操作などで閉じられたダイアログの詳細は取得を止める
+                    self.tx.send(DescribeMessage::Close.into())?;
+                }
                 WindowAction::UpdateContents(ev) => {
+                    let restored_context = matches!(ev, Kube::RestoreContext { .. });
+
+                    let namespaces_changed = restored_context
+                        || matches!(
+                            ev,
+                            Kube::Namespace(NamespaceMessage::Response(NamespaceResponse::Set(_)))
+                        );
+
                     update_contents(
                         &mut window,
                         ev,
                         &mut context.borrow_mut(),
                         &mut namespace.borrow_mut(),
                     );
+
+                    if namespaces_changed {
+                        record_namespaces(&mut state, &context.borrow(), &namespace.borrow());
+                    }
+
+                    if restored_context {
+                        if let Some(query) = pending_log_query.take() {
+                            request_logs(&self.tx, query, &namespace.borrow());
+                        }
+                    }
                 }
             }
         }
 
+        capture_state(
+            &mut state,
+            &window,
+            &context.borrow(),
+            &log_query_history.borrow(),
+            &default_columns,
+        );
+
+        if let Err(e) = state.save(&self.state_path) {
+            logger!(error, "Failed to save state: {:?}", e);
+        }
+
         Ok(())
     }
 }
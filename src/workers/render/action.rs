@@ -18,9 +18,17 @@ use crate::{
             NETWORK_WIDGET_ID,
             NODE_DETAIL_WIDGET_ID,
             NODE_WIDGET_ID,
+            OPERATION_CONFIRM_DIALOG_ID,
+            OPERATION_SCALE_DIALOG_ID,
+            POD_DESCRIBE_DIALOG_ID,
+            POD_EXEC_DIALOG_ID,
             POD_LOG_WIDGET_ID,
             POD_WIDGET_ID,
+            PORT_FORWARD_DIALOG_ID,
+            PORT_FORWARD_LIST_DIALOG_ID,
             SINGLE_NAMESPACE_DIALOG_ID,
+            WORKLOAD_DESCRIPTION_WIDGET_ID,
+            WORKLOAD_WIDGET_ID,
             YAML_DIALOG_ID,
             YAML_KIND_DIALOG_ID,
             YAML_NAME_DIALOG_ID,
@@ -29,11 +37,31 @@ use crate::{
         },
         config::message::ConfigMessage,
         context::message::{ContextMessage, ContextResponse},
+        edit::{
+            message::{EditMessage, EditResponse},
+            view::open_edit_error,
+        },
+        event::message::EventMessage,
         get::message::{GetMessage, GetResponse},
         namespace::message::{NamespaceMessage, NamespaceResponse},
-        network::message::{NetworkMessage, NetworkResponse},
+        network::{
+            message::{NetworkDescription, NetworkMessage, NetworkResponse},
+            view::update_related_resources,
+        },
         node::message::{NodeDetailMessage, NodeMessage},
-        pod::message::{LogMessage, PodMessage},
+        operation::{
+            message::{OperationMessage, OperationResponse},
+            view::update_scale_replicas,
+        },
+        pod::{
+            message::{DescribeMessage, ExecMessage, ExecResponse, LogMessage, PodMessage},
+            view::{log_item, refresh_log_timestamps},
+        },
+        port_forward::{
+            message::{PortForwardMessage, PortForwardResponse},
+            view::{port_forward_list_items, update_port_forward_ports},
+        },
+        workload::message::{WorkloadMessage, WorkloadResponse},
         yaml::message::{YamlMessage, YamlResourceListItem, YamlResponse},
     },
     kube::{
@@ -88,6 +116,8 @@ pub fn window_action(window: &mut Window, rx: &Receiver<Message>) -> WindowActio
 
         Message::Tick => {
             window.on_tick();
+
+            refresh_log_timestamps(window);
         }
         Message::Kube(k) => return WindowAction::UpdateContents(k),
         Message::Error(err) => {
@@ -209,12 +239,7 @@ pub fn update_contents(
                     let widget = window.find_widget_mut(POD_LOG_WIDGET_ID);
                     let array = i
                         .into_iter()
-                        .map(|i| {
-                            LiteralItem {
-                                metadata: None,
-                                item: convert_tabs_to_spaces(i),
-                            }
-                        })
+                        .map(|line| log_item(convert_tabs_to_spaces(line.content), line.timestamp))
                         .collect();
 
                     widget.append_widget_item(Item::Array(array));
@@ -250,6 +275,155 @@ pub fn update_contents(
             widget.as_mut_text().set_max_lines(max_lines);
         }
 
+        Kube::Describe(DescribeMessage::Response(res)) => {
+            update_widget_item_for_vec(window, POD_DESCRIBE_DIALOG_ID, res);
+        }
+
+        Kube::Exec(ExecMessage::Response(res)) => {
+            match res {
+                ExecResponse::Containers {
+                    namespace,
+                    pod,
+                    containers,
+                } => {
+                    match containers {
+                        Ok(containers) => {
+                            window.clear_widget_error(POD_EXEC_DIALOG_ID);
+
+                            let items = containers
+                                .into_iter()
+                                .map(|container| {
+                                    let metadata = BTreeMap::from([
+                                        ("namespace".to_string(), namespace.to_string()),
+                                        ("pod".to_string(), pod.to_string()),
+                                        ("container".to_string(), container.to_string()),
+                                    ]);
+
+                                    LiteralItem::new(container, Some(metadata))
+                                })
+                                .collect();
+
+                            window
+                                .find_widget_mut(POD_EXEC_DIALOG_ID)
+                                .update_widget_item(Item::Array(items));
+                        }
+                        Err(e) => {
+                            window.set_widget_error(POD_EXEC_DIALOG_ID, &e);
+                        }
+                    }
+                }
+                ExecResponse::Finished(Ok(())) => {}
+                ExecResponse::Finished(Err(e)) => {
+                    // シェルの起動に失敗した場合はダイアログにエラーを表示する
+                    window.open_dialog(POD_EXEC_DIALOG_ID);
+                    window.set_widget_error(POD_EXEC_DIALOG_ID, &e);
+                }
+                ExecResponse::Attached(session) => {
+                    // 描画ループ以外（エディタの起動中など）で受け取った場合は端末を明け渡せないため、
+                    // セッションを破棄する。ExecWorkerは端末を受け取れずに終了し、エラーを通知する。
+                    logger!(
+                        error,
+                        "Exec session was attached while the terminal is in use"
+                    );
+
+                    drop(session);
+                }
+            }
+        }
+
+        Kube::Operation(OperationMessage::Response(res)) => {
+            match res {
+                OperationResponse::Replicas { target, replicas } => {
+                    match replicas {
+                        Ok(replicas) => {
+                            update_scale_replicas(window, replicas);
+                        }
+                        Err(e) => {
+                            logger!(error, "Failed to fetch replicas of {}: {:?}", target, e);
+
+                            window.set_widget_error(OPERATION_SCALE_DIALOG_ID, &e);
+                        }
+                    }
+                }
+                OperationResponse::Execute {
+                    operation,
+                    result: Ok(()),
+                } => {
+                    logger!(info, "Succeeded to {}", operation);
+                }
+                OperationResponse::Execute {
+                    operation,
+                    result: Err(e),
+                } => {
+                    logger!(error, "Failed to {}: {:?}", operation, e);
+
+                    // 実行に失敗した場合は確認ダイアログにエラーを表示する
+                    window.open_dialog(OPERATION_CONFIRM_DIALOG_ID);
+                    window.set_widget_error(OPERATION_CONFIRM_DIALOG_ID, &e);
+                }
+            }
+        }
+
+        Kube::Edit(EditMessage::Response(res)) => {
+            match res {
+                EditResponse::Fetched(Ok(document)) => {
+                    // エディタの起動中に届いた場合は無視する
+                    logger!(info, "Ignore edit request for {}", document);
+                }
+                EditResponse::Fetched(Err(e)) => {
+                    logger!(error, "Failed to fetch resource for edit: {:?}", e);
+
+                    open_edit_error(window, None, &e);
+                }
+                EditResponse::Applied {
+                    document,
+                    result: Ok(()),
+                } => {
+                    logger!(info, "Succeeded to apply {}", document);
+                }
+                EditResponse::Applied {
+                    document,
+                    result: Err(e),
+                } => {
+                    // エディタの起動中に届いた場合は開き直さずにエラーを表示する
+                    logger!(error, "Failed to apply {}: {:?}", document, e);
+
+                    open_edit_error(window, Some(&document.title), &e);
+                }
+            }
+        }
+
+        Kube::PortForward(PortForwardMessage::Response(res)) => {
+            match res {
+                PortForwardResponse::Ports { target, ports } => {
+                    match ports {
+                        Ok(ports) => {
+                            update_port_forward_ports(window, &ports);
+                        }
+                        Err(e) => {
+                            logger!(error, "Failed to fetch ports of {}: {:?}", target, e);
+
+                            window.set_widget_error(PORT_FORWARD_DIALOG_ID, &e);
+                        }
+                    }
+                }
+                PortForwardResponse::Start(Ok(())) => {
+                    // 割り当てられたローカルポートを確認できるよう一覧を表示する
+                    if window.opening_dialog_id() == Some(PORT_FORWARD_DIALOG_ID) {
+                        window.open_dialog(PORT_FORWARD_LIST_DIALOG_ID);
+                    }
+                }
+                PortForwardResponse::Start(Err(e)) => {
+                    window.set_widget_error(PORT_FORWARD_DIALOG_ID, &e);
+                }
+                PortForwardResponse::List(entries) => {
+                    window
+                        .find_widget_mut(PORT_FORWARD_LIST_DIALOG_ID)
+                        .update_widget_item(Item::Array(port_forward_list_items(entries)));
+                }
+            }
+        }
+
         Kube::Config(ConfigMessage::Response(res)) => {
             use crate::features::config::message::ConfigResponse::*;
 
@@ -263,8 +437,17 @@ pub fn update_contents(
             }
         }
 
-        Kube::Event(ev) => {
-            update_widget_item_for_vec(window, EVENT_WIDGET_ID, ev);
+        Kube::Event(EventMessage::Poll { table, grouped }) => {
+            *window
+                .find_widget_mut(EVENT_WIDGET_ID)
+                .widget_base_mut()
+                .title_mut() = if grouped {
+                "Event (grouped by object)".into()
+            } else {
+                "Event".into()
+            };
+
+            update_widget_item_for_table(window, EVENT_WIDGET_ID, table);
         }
 
         Kube::Namespace(NamespaceMessage::Response(res)) => {
@@ -496,8 +679,25 @@ pub fn update_contents(
 
             match ev {
                 List(res) => update_widget_item_for_table(window, NETWORK_WIDGET_ID, res),
+                Description(res) => {
+                    let (lines, targets) = match res {
+                        Ok(NetworkDescription { lines, targets }) => (Ok(lines), targets),
+                        Err(e) => (Err(e), Vec::new()),
+                    };
+
+                    update_related_resources(window, targets);
+                    update_widget_item_for_vec(window, NETWORK_DESCRIPTION_WIDGET_ID, lines);
+                }
+            }
+        }
+
+        Kube::Workload(WorkloadMessage::Response(ev)) => {
+            use WorkloadResponse::*;
+
+            match ev {
+                List(res) => update_widget_item_for_table(window, WORKLOAD_WIDGET_ID, res),
                 Yaml(res) => {
-                    update_widget_item_for_vec(window, NETWORK_DESCRIPTION_WIDGET_ID, res);
+                    update_widget_item_for_vec(window, WORKLOAD_DESCRIPTION_WIDGET_ID, res);
                 }
             }
         }
@@ -0,0 +1,31 @@
+use ratatui::layout::Direction;
+
+use crate::{
+    cmd::ClipboardMode,
+    config::{keybindings::Keymap, theme::ThemeConfig, LoggingConfig},
+    features::{
+        config::{ConfigColumns, ConfigLabelColumn},
+        network::{NetworkColumns, NetworkLabelColumn},
+        node::{NodeColumns, NodeLabelColumn},
+        pod::{PodColumns, PodLabelColumn},
+        tab::TabColumns,
+        workload::{WorkloadColumns, WorkloadLabelColumn},
+    },
+};
+
+/// 画面の組み立てに使う設定
+#[derive(Debug, Clone)]
+pub struct RenderConfig {
+    pub split_direction: Direction,
+    pub pod_columns: TabColumns<Option<PodColumns>, PodLabelColumn>,
+    pub node_columns: TabColumns<Option<NodeColumns>, NodeLabelColumn>,
+    pub config_columns: TabColumns<ConfigColumns, ConfigLabelColumn>,
+    pub network_columns: TabColumns<NetworkColumns, NetworkLabelColumn>,
+    pub workload_columns: TabColumns<WorkloadColumns, WorkloadLabelColumn>,
+    pub theme: ThemeConfig,
+    pub clipboard_mode: ClipboardMode,
+    pub logging: LoggingConfig,
+    /// リソースを変更する操作を無効にする
+    pub read_only: bool,
+    pub keymap: Keymap,
+}
@@ -0,0 +1,373 @@
+use std::{
+    cell::RefCell,
+    fs,
+    io::{self, Stdout, Write as _},
+    path::Path,
+    process::{Command, ExitStatus},
+    time::Duration,
+};
+
+use anyhow::{bail, Context as _, Result};
+use crossbeam::channel::{Receiver, RecvTimeoutError};
+use ratatui::{
+    backend::CrosstermBackend,
+    crossterm::{
+        cursor::Show,
+        event::{DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture},
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    },
+    Terminal,
+};
+use tempfile::NamedTempFile;
+
+use crate::{
+    features::edit::{message::EditDocument, view::open_edit_error},
+    kube::context::{Context, Namespace},
+    logger,
+    message::Message,
+    ui::Window,
+    workers::InputSuspender,
+};
+
+use super::action::update_contents;
+
+/// エディタが終了したかを確認する間隔
+const WAIT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// エディタが指定されていない場合に起動するコマンド
+const DEFAULT_EDITOR: &str = "vi";
+
+/// エディタに端末を明け渡し、編集したyamlを返す
+///
+/// `KUBE_EDITOR`、`EDITOR`の順にエディタを決める。
+/// エディタの起動中もKubeワーカーからのメッセージは受け取り続け、描画せずにウィンドウへ反映する。
+/// 編集を取り消した場合や失敗した場合は`None`を返す。
+pub fn run_editor_session(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    window: &mut Window,
+    rx: &Receiver<Message>,
+    suspender: &InputSuspender,
+    document: EditDocument,
+    context: &RefCell<Context>,
+    namespace: &RefCell<Namespace>,
+) -> Result<Option<EditDocument>> {
+    window.close_dialog();
+
+    // Secretの値を含むため、所有者のみ読み書きできるファイルを新規に作成する。
+    // ファイルはドロップ時に削除される。
+    let file = match create_temp_file(&document) {
+        Ok(file) => file,
+        Err(err) => {
+            open_edit_error(window, Some(&document.title), &err);
+            return Ok(None);
+        }
+    };
+
+    let path = file.path();
+
+    let status = {
+        let _suspended = suspender.suspend();
+
+        execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            DisableFocusChange,
+            Show
+        )?;
+        disable_raw_mode()?;
+
+        let status = wait_editor(path, window, rx, context, namespace);
+
+        enable_raw_mode()?;
+        execute!(
+            io::stdout(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableFocusChange
+        )?;
+
+        terminal.clear()?;
+
+        status
+    };
+
+    let edited = status.and_then(|status| {
+        if !status.success() {
+            bail!("Editor exited with {}", status);
+        }
+
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+    });
+
+    if let Err(err) = file.close() {
+        logger!(error, "Failed to remove temporary file: {}", err);
+    }
+
+    match edited {
+        Ok(edited) => {
+            let Some(yaml) = edited_yaml(&document.yaml, edited) else {
+                logger!(info, "Edit cancelled, no changes made [{}]", document);
+                return Ok(None);
+            };
+
+            Ok(Some(EditDocument { yaml, ..document }))
+        }
+        Err(err) => {
+            open_edit_error(window, Some(&document.title), &err);
+            Ok(None)
+        }
+    }
+}
+
+/// エディタを起動し、終了するまで待つ
+fn wait_editor(
+    path: &Path,
+    window: &mut Window,
+    rx: &Receiver<Message>,
+    context: &RefCell<Context>,
+    namespace: &RefCell<Namespace>,
+) -> Result<ExitStatus> {
+    let command = editor_command(
+        std::env::var("KUBE_EDITOR").ok(),
+        std::env::var("EDITOR").ok(),
+    );
+
+    logger!(info, "Launch editor {:?} [{}]", command, path.display());
+
+    let Some((program, args)) = command.split_first() else {
+        unreachable!()
+    };
+
+    let mut child = Command::new(program)
+        .args(args)
+        .arg(path)
+        .spawn()
+        .with_context(|| format!("Failed to launch editor {:?}", program))?;
+
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+
+        match rx.recv_timeout(WAIT_INTERVAL) {
+            Ok(Message::Kube(ev)) => {
+                update_contents(
+                    window,
+                    ev,
+                    &mut context.borrow_mut(),
+                    &mut namespace.borrow_mut(),
+                );
+            }
+
+            Ok(Message::Error(err)) => {
+                logger!(error, "Error: {:?}", err);
+            }
+
+            Ok(Message::User(_) | Message::Tick) | Err(RecvTimeoutError::Timeout) => {}
+
+            Err(RecvTimeoutError::Disconnected) => {
+                child.kill()?;
+                bail!("Channel disconnected while waiting for editor");
+            }
+        }
+    }
+}
+
+/// エディタを起動するコマンド
+///
+/// 環境変数には引数を含めることができる (例: `code --wait`)
+fn editor_command(kube_editor: Option<String>, editor: Option<String>) -> Vec<String> {
+    let command: Vec<String> = [kube_editor, editor]
+        .into_iter()
+        .flatten()
+        .map(|cmd| cmd.split_whitespace().map(String::from).collect::<Vec<_>>())
+        .find(|cmd| !cmd.is_empty())
+        .unwrap_or_default();
+
+    if command.is_empty() {
+        vec![DEFAULT_EDITOR.to_string()]
+    } else {
+        command
+    }
+}
+
+/// 編集するyamlを書き込んだ一時ファイルを作成する
+///
+/// ファイル名は推測できないランダムな名前で、既存のファイルやシンボリックリンクは上書きしない。
+/// パーミッションは所有者のみ読み書きできる `0600` になる。
+fn create_temp_file(document: &EditDocument) -> Result<NamedTempFile> {
+    let mut file = tempfile::Builder::new()
+        .prefix(&temp_file_prefix(&document.title))
+        .suffix(".yaml")
+        .tempfile()
+        .context("Failed to create temporary file")?;
+
+    file.write_all(document.yaml.as_bytes())
+        .and_then(|_| file.flush())
+        .with_context(|| format!("Failed to write {}", file.path().display()))?;
+
+    Ok(file)
+}
+
+/// 一時ファイルの名前の先頭部分
+fn temp_file_prefix(title: &str) -> String {
+    let name: String = title
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    format!("kubetui-edit-{}-", name)
+}
+
+/// 適用に失敗したyamlの先頭にエラーをコメントとして付ける
+///
+/// 前回の失敗で付けたコメントは付け直す
+pub fn with_error_header(document: EditDocument, err: &anyhow::Error) -> EditDocument {
+    let mut yaml = format!(
+        "# Please edit the object below. Lines beginning with a '#' will be ignored,\n\
+         # and an empty or unchanged file will abort the edit.\n\
+         #\n\
+         # {} could not be applied:\n",
+        document.title
+    );
+
+    for line in format!("{:#}", err).lines() {
+        yaml += "# ";
+        yaml += line;
+        yaml += "\n";
+    }
+
+    yaml += "#\n";
+    yaml += strip_comment_header(&document.yaml);
+
+    EditDocument { yaml, ..document }
+}
+
+/// 先頭のコメント行を取り除く
+fn strip_comment_header(yaml: &str) -> &str {
+    let mut rest = yaml;
+
+    while rest.starts_with('#') {
+        rest = rest.split_once('\n').map_or("", |(_, rest)| rest);
+    }
+
+    rest
+}
+
+/// 編集後のyamlを返す
+///
+/// 変更がない場合や空の場合は編集を取り消したものとみなす
+fn edited_yaml(original: &str, edited: String) -> Option<String> {
+    if edited.trim().is_empty() || edited == original {
+        None
+    } else {
+        Some(edited)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::kube_editor(Some("nvim"), Some("vim"), vec!["nvim"])]
+    #[case::editor(None, Some("vim"), vec!["vim"])]
+    #[case::with_args(None, Some("code --wait"), vec!["code", "--wait"])]
+    #[case::empty_kube_editor(Some(" "), Some("nano"), vec!["nano"])]
+    #[case::default(None, None, vec!["vi"])]
+    fn editor_command_cases(
+        #[case] kube_editor: Option<&str>,
+        #[case] editor: Option<&str>,
+        #[case] expected: Vec<&str>,
+    ) {
+        let actual = editor_command(kube_editor.map(String::from), editor.map(String::from));
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn temp_file_prefix_replaces_separators() {
+        let actual = temp_file_prefix("default/deployments/app");
+
+        assert_eq!(actual, "kubetui-edit-default_deployments_app-");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn temp_file_is_private_and_removed_on_drop() {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let document = EditDocument {
+            title: "default/secrets/app".to_string(),
+            path: "/api/v1/namespaces/default/secrets/app".to_string(),
+            yaml: "a: 1\n".to_string(),
+        };
+
+        let file = create_temp_file(&document).unwrap();
+        let path = file.path().to_path_buf();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a: 1\n");
+        assert_eq!(
+            fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+            0o600
+        );
+
+        drop(file);
+
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn with_error_header_replaces_previous_header() {
+        let document = EditDocument {
+            title: "default/configmaps/app".to_string(),
+            path: "/api/v1/namespaces/default/configmaps/app".to_string(),
+            yaml: "a: 1\n".to_string(),
+        };
+
+        let document = with_error_header(document, &anyhow::anyhow!("first"));
+        let document = with_error_header(
+            document,
+            &anyhow::anyhow!("Conflict\nobject has been modified").context("Failed to apply"),
+        );
+
+        let expected = indoc::indoc! {"
+            # Please edit the object below. Lines beginning with a '#' will be ignored,
+            # and an empty or unchanged file will abort the edit.
+            #
+            # default/configmaps/app could not be applied:
+            # Failed to apply: Conflict
+            # object has been modified
+            #
+            a: 1
+        "};
+
+        assert_eq!(document.yaml, expected);
+    }
+
+    #[rstest]
+    #[case::changed("a: 1\n", "a: 2\n", Some("a: 2\n"))]
+    #[case::unchanged("a: 1\n", "a: 1\n", None)]
+    #[case::empty("a: 1\n", " \n", None)]
+    fn edited_yaml_cases(
+        #[case] original: &str,
+        #[case] edited: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            edited_yaml(original, edited.to_string()),
+            expected.map(String::from)
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    cell::RefCell,
+    io::{self, Stdout},
+};
+
+use anyhow::Result;
+use crossbeam::channel::{Receiver, Sender};
+use ratatui::{
+    backend::CrosstermBackend,
+    crossterm::{
+        cursor::Show,
+        event::{
+            DisableFocusChange,
+            DisableMouseCapture,
+            EnableFocusChange,
+            EnableMouseCapture,
+            KeyCode,
+            KeyEvent,
+            KeyModifiers,
+        },
+        execute,
+        terminal::{EnterAlternateScreen, LeaveAlternateScreen},
+    },
+    Terminal,
+};
+
+use crate::{
+    features::pod::{
+        kube::ExecSession,
+        message::{ExecInput, ExecMessage, ExecRequest, ExecResponse},
+    },
+    kube::context::{Context, Namespace},
+    logger,
+    message::{Message, UserEvent},
+    ui::Window,
+    workers::kube::message::Kube,
+};
+
+use super::action::update_contents;
+
+/// コンテナのシェルに端末を明け渡し、シェルが終了するまで待つ
+///
+/// セッション中もKubeワーカーからのメッセージは受け取り続け、描画せずにウィンドウへ反映する。
+/// キー入力はバイト列に変換してシェルに転送する。
+pub fn run_exec_session(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    window: &mut Window,
+    rx: &Receiver<Message>,
+    tx: &Sender<Message>,
+    session: ExecSession,
+    context: &RefCell<Context>,
+    namespace: &RefCell<Namespace>,
+) -> Result<()> {
+    window.close_dialog();
+
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableFocusChange,
+        Show
+    )?;
+
+    session.ready();
+
+    loop {
+        match rx.recv()? {
+            Message::User(UserEvent::Key(ev)) => {
+                let bytes = key_event_to_bytes(ev);
+
+                if !bytes.is_empty() {
+                    tx.send(ExecRequest::Input(ExecInput::Bytes(bytes)).into())?;
+                }
+            }
+
+            Message::User(UserEvent::Resize(width, height)) => {
+                tx.send(ExecRequest::Input(ExecInput::Resize { width, height }).into())?;
+            }
+
+            Message::User(_) | Message::Tick => {}
+
+            Message::Kube(Kube::Exec(ExecMessage::Response(ExecResponse::Finished(res)))) => {
+                execute!(
+                    io::stdout(),
+                    EnterAlternateScreen,
+                    EnableMouseCapture,
+                    EnableFocusChange
+                )?;
+
+                terminal.clear()?;
+
+                update_contents(
+                    window,
+                    Kube::Exec(ExecMessage::Response(ExecResponse::Finished(res))),
+                    &mut context.borrow_mut(),
+                    &mut namespace.borrow_mut(),
+                );
+
+                return Ok(());
+            }
+
+            Message::Kube(ev) => {
+                update_contents(
+                    window,
+                    ev,
+                    &mut context.borrow_mut(),
+                    &mut namespace.borrow_mut(),
+                );
+            }
+
+            Message::Error(err) => {
+                logger!(error, "Error: {:?}", err);
+            }
+        }
+    }
+}
+
+/// キーイベントを端末に入力されるバイト列に変換する
+fn key_event_to_bytes(ev: KeyEvent) -> Vec<u8> {
+    let bytes: Vec<u8> = match ev.code {
+        KeyCode::Char(c) if ev.modifiers.contains(KeyModifiers::CONTROL) => {
+            match c {
+                'a'..='z' | 'A'..='Z' => vec![c.to_ascii_lowercase() as u8 & 0x1f],
+                '@' | ' ' | '2' => vec![0x00],
+                '[' | '3' => vec![0x1b],
+                '\\' | '4' => vec![0x1c],
+                ']' | '5' => vec![0x1d],
+                '^' | '6' => vec![0x1e],
+                '_' | '7' | '/' => vec![0x1f],
+                '?' | '8' => vec![0x7f],
+                _ => c.to_string().into_bytes(),
+            }
+        }
+        KeyCode::Char(c) => c.to_string().into_bytes(),
+        KeyCode::Enter => b"\r".to_vec(),
+        KeyCode::Tab => b"\t".to_vec(),
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => b"\x1b[A".to_vec(),
+        KeyCode::Down => b"\x1b[B".to_vec(),
+        KeyCode::Right => b"\x1b[C".to_vec(),
+        KeyCode::Left => b"\x1b[D".to_vec(),
+        KeyCode::Home => b"\x1b[H".to_vec(),
+        KeyCode::End => b"\x1b[F".to_vec(),
+        KeyCode::Insert => b"\x1b[2~".to_vec(),
+        KeyCode::Delete => b"\x1b[3~".to_vec(),
+        KeyCode::PageUp => b"\x1b[5~".to_vec(),
+        KeyCode::PageDown => b"\x1b[6~".to_vec(),
+        KeyCode::F(n) => {
+            match n {
+                1 => b"\x1bOP".to_vec(),
+                2 => b"\x1bOQ".to_vec(),
+                3 => b"\x1bOR".to_vec(),
+                4 => b"\x1bOS".to_vec(),
+                5 => b"\x1b[15~".to_vec(),
+                6 => b"\x1b[17~".to_vec(),
+                7 => b"\x1b[18~".to_vec(),
+                8 => b"\x1b[19~".to_vec(),
+                9 => b"\x1b[20~".to_vec(),
+                10 => b"\x1b[21~".to_vec(),
+                11 => b"\x1b[23~".to_vec(),
+                12 => b"\x1b[24~".to_vec(),
+                _ => vec![],
+            }
+        }
+        _ => vec![],
+    };
+
+    if ev.modifiers.contains(KeyModifiers::ALT) && !bytes.is_empty() {
+        [vec![0x1b], bytes].concat()
+    } else {
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::char(KeyEvent::from(KeyCode::Char('a')), b"a".to_vec())]
+    #[case::upper_char(KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT), b"A".to_vec())]
+    #[case::multibyte_char(KeyEvent::from(KeyCode::Char('あ')), "あ".as_bytes().to_vec())]
+    #[case::ctrl_c(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL), vec![0x03])]
+    #[case::ctrl_d(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL), vec![0x04])]
+    #[case::ctrl_bracket(KeyEvent::new(KeyCode::Char('['), KeyModifiers::CONTROL), vec![0x1b])]
+    #[case::ctrl_space(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::CONTROL), vec![0x00])]
+    #[case::alt_b(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT), b"\x1bb".to_vec())]
+    #[case::enter(KeyEvent::from(KeyCode::Enter), b"\r".to_vec())]
+    #[case::backspace(KeyEvent::from(KeyCode::Backspace), vec![0x7f])]
+    #[case::up(KeyEvent::from(KeyCode::Up), b"\x1b[A".to_vec())]
+    #[case::delete(KeyEvent::from(KeyCode::Delete), b"\x1b[3~".to_vec())]
+    #[case::f1(KeyEvent::from(KeyCode::F(1)), b"\x1bOP".to_vec())]
+    #[case::f12(KeyEvent::from(KeyCode::F(12)), b"\x1b[24~".to_vec())]
+    #[case::unsupported(KeyEvent::from(KeyCode::CapsLock), vec![])]
+    fn key_event_to_bytes_cases(#[case] ev: KeyEvent, #[case] expected: Vec<u8>) {
+        assert_eq!(key_event_to_bytes(ev), expected);
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    features::{
+        component_id::{
+            CONFIG_COLUMNS_DIALOG_ID,
+            CONFIG_WIDGET_ID,
+            EVENT_WIDGET_ID,
+            NETWORK_COLUMNS_DIALOG_ID,
+            NETWORK_WIDGET_ID,
+            NODE_COLUMNS_DIALOG_ID,
+            NODE_WIDGET_ID,
+            POD_COLUMNS_DIALOG_ID,
+            POD_LOG_QUERY_WIDGET_ID,
+            POD_WIDGET_ID,
+            WORKLOAD_COLUMNS_DIALOG_ID,
+            WORKLOAD_WIDGET_ID,
+        },
+        pod::view::LogQueryHistory,
+    },
+    kube::context::{Context, Namespace},
+    state::{ColumnChoices, State},
+    ui::{
+        widget::{Item, WidgetTrait as _},
+        Window,
+    },
+};
+
+/// フィルターを保存するテーブル
+const FILTER_TABLE_IDS: [&str; 6] = [
+    POD_WIDGET_ID,
+    NODE_WIDGET_ID,
+    CONFIG_WIDGET_ID,
+    NETWORK_WIDGET_ID,
+    WORKLOAD_WIDGET_ID,
+    EVENT_WIDGET_ID,
+];
+
+/// 選択列を保存するカラム選択ダイアログ
+const COLUMNS_DIALOG_IDS: [&str; 5] = [
+    POD_COLUMNS_DIALOG_ID,
+    NODE_COLUMNS_DIALOG_ID,
+    CONFIG_COLUMNS_DIALOG_ID,
+    NETWORK_COLUMNS_DIALOG_ID,
+    WORKLOAD_COLUMNS_DIALOG_ID,
+];
+
+/// 前回終了時のタブ、フィルター、選択列、ログクエリを画面に反映する
+///
+/// フィルターと選択列は各ウィジェットのコールバックを通してKubeWorkerにも反映する
+pub fn restore_state(window: &mut Window, state: &State) {
+    if let Some(tab) = &state.tab {
+        window.activate_tab_by_id(tab);
+    }
+
+    for id in FILTER_TABLE_IDS {
+        let Some(filter) = state.filters.get(id) else {
+            continue;
+        };
+
+        if let Some(cb) = window
+            .find_widget_mut(id)
+            .as_mut_table()
+            .restore_filter(filter)
+        {
+            cb(window);
+        }
+    }
+
+    for id in COLUMNS_DIALOG_IDS {
+        let Some(columns) = state.columns.get(id) else {
+            continue;
+        };
+
+        if let Some(cb) = window
+            .find_widget_mut(id)
+            .as_mut_check_list()
+            .restore_checked(columns)
+        {
+            cb(window);
+        }
+    }
+
+    if let Some(query) = state.log_query_history.last() {
+        window
+            .find_widget_mut(POD_LOG_QUERY_WIDGET_ID)
+            .update_widget_item(Item::Single(query.into()));
+    }
+}
+
+/// 表示中のコンテキストのネームスペースを記録する
+pub fn record_namespaces(state: &mut State, context: &Context, namespaces: &Namespace) {
+    state
+        .namespaces
+        .insert(context.to_string(), namespaces.to_vec());
+}
+
+/// カラム選択ダイアログごとの選択列
+pub fn column_choices(window: &Window) -> BTreeMap<String, ColumnChoices> {
+    COLUMNS_DIALOG_IDS
+        .into_iter()
+        .map(|id| {
+            (
+                id.to_string(),
+                window.find_widget(id).as_check_list().checked_metadata(),
+            )
+        })
+        .collect()
+}
+
+/// 終了時の画面の状態を `state` に書き込む
+///
+/// 選択列は起動時の既定値 `default_columns` から変更したものだけを残す。
+/// コンテキストは一度も接続できていない場合は前回の値を残す。
+pub fn capture_state(
+    state: &mut State,
+    window: &Window,
+    context: &Context,
+    log_query_history: &LogQueryHistory,
+    default_columns: &BTreeMap<String, ColumnChoices>,
+) {
+    let context = context.to_string();
+
+    if state.namespaces.contains_key(&context) {
+        state.context = Some(context);
+    }
+
+    state.tab = Some(window.active_tab_id().to_string());
+
+    state.log_query_history = log_query_history.entries().to_vec();
+
+    state.filters = FILTER_TABLE_IDS
+        .into_iter()
+        .filter_map(|id| {
+            window
+                .find_widget(id)
+                .as_table()
+                .filter_text()
+                .map(|filter| (id.to_string(), filter))
+        })
+        .collect();
+
+    state.columns = column_choices(window)
+        .into_iter()
+        .filter(|(id, columns)| default_columns.get(id) != Some(columns))
+        .collect();
+}
@@ -1,24 +1,20 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use crossbeam::channel::Sender;
 use k8s_openapi::{
     api::{
+        apps::v1::{DaemonSet, Deployment, StatefulSet},
+        batch::v1::{CronJob, Job},
         core::v1::{ConfigMap, Pod, Secret, Service},
         networking::v1::{Ingress, NetworkPolicy},
     },
     Resource as _,
 };
-use ratatui::{
-    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
-    layout::Direction,
-    text::Line,
-    widgets::Paragraph,
-};
+use ratatui::{text::Line, widgets::Paragraph};
 
 use crate::{
     clipboard::Clipboard,
-    cmd::ClipboardMode,
-    config::theme::ThemeConfig,
+    config::keybindings::Action,
     features::{
         api_resources::view::ApiTab,
         component_id::{
@@ -29,10 +25,12 @@ use crate::{
             NETWORK_WIDGET_ID,
             POD_WIDGET_ID,
             SINGLE_NAMESPACE_DIALOG_ID,
+            WORKLOAD_WIDGET_ID,
             YAML_DIALOG_ID,
         },
-        config::{view::ConfigTab, ConfigColumns, ConfigLabelColumn},
+        config::view::ConfigTab,
         context::{message::ContextRequest, view::ContextDialog},
+        edit::view::{edit_yaml, EditErrorDialog},
         event::view::EventTab,
         get::{
             message::{GetRequest, GetYamlKind},
@@ -46,11 +44,27 @@ use crate::{
         network::{
             message::{GatewayVersion, HTTPRouteVersion},
             view::NetworkTab,
-            NetworkColumns,
-            NetworkLabelColumn,
         },
-        node::{view::NodeTab, NodeColumns, NodeLabelColumn},
-        pod::{view::PodTab, PodColumns, PodLabelColumn},
+        node::view::NodeTab,
+        operation::view::{
+            delete_pod,
+            restart_workload,
+            scale_workload,
+            OperationConfirmDialog,
+            OperationScaleDialog,
+            SharedPendingOperation,
+            SharedScaleTarget,
+        },
+        pod::view::{PodTab, SharedLogQueryHistory},
+        port_forward::view::{
+            open_port_forward_dialog,
+            open_port_forward_list_dialog,
+            PortForwardDialog,
+            PortForwardListDialog,
+            SharedPortForwardTarget,
+        },
+        tab::TabParams,
+        workload::view::WorkloadTab,
         yaml::view::YamlTab,
     },
     kube::{
@@ -58,11 +72,11 @@ use crate::{
         context::{Context, Namespace},
     },
     logger,
-    message::{Message, UserEvent},
+    message::Message,
     ui::{
         dialog::{Dialog, DialogTheme},
         event::{CallbackFn, EventResult},
-        widget::{SelectedItem, WidgetTrait},
+        widget::{Widget, WidgetTrait},
         Header,
         HeaderTheme,
         Tab,
@@ -72,70 +86,53 @@ use crate::{
     },
 };
 
+use super::config::RenderConfig;
+
 pub struct WindowInit {
-    split_mode: Direction,
     tx: Sender<Message>,
     context: Rc<RefCell<Context>>,
     namespaces: Rc<RefCell<Namespace>>,
-    default_pod_columns: Option<PodColumns>,
-    default_node_columns: Option<NodeColumns>,
-    default_config_columns: ConfigColumns,
-    default_network_columns: NetworkColumns,
-    pod_label_columns: Vec<PodLabelColumn>,
-    node_label_columns: Vec<NodeLabelColumn>,
-    config_label_columns: Vec<ConfigLabelColumn>,
-    network_label_columns: Vec<NetworkLabelColumn>,
-    theme: ThemeConfig,
-    clipboard_mode: ClipboardMode,
-    log_max_lines: Option<usize>,
+    log_query_history: SharedLogQueryHistory,
+    config: RenderConfig,
+    port_forward_target: SharedPortForwardTarget,
+    pending_operation: SharedPendingOperation,
+    scale_target: SharedScaleTarget,
 }
 
 impl WindowInit {
     pub fn new(
-        split_mode: Direction,
         tx: Sender<Message>,
         context: Rc<RefCell<Context>>,
         namespaces: Rc<RefCell<Namespace>>,
-        default_pod_columns: Option<PodColumns>,
-        default_node_columns: Option<NodeColumns>,
-        default_config_columns: ConfigColumns,
-        default_network_columns: NetworkColumns,
-        pod_label_columns: Vec<PodLabelColumn>,
-        node_label_columns: Vec<NodeLabelColumn>,
-        config_label_columns: Vec<ConfigLabelColumn>,
-        network_label_columns: Vec<NetworkLabelColumn>,
-        theme: ThemeConfig,
-        clipboard_mode: ClipboardMode,
-        log_max_lines: Option<usize>,
+        log_query_history: SharedLogQueryHistory,
+        config: RenderConfig,
     ) -> Self {
         Self {
-            split_mode,
             tx,
             context,
             namespaces,
-            default_pod_columns,
-            default_node_columns,
-            default_config_columns,
-            default_network_columns,
-            pod_label_columns,
-            node_label_columns,
-            config_label_columns,
-            network_label_columns,
-            theme,
-            clipboard_mode,
-            log_max_lines,
+            log_query_history,
+            config,
+            port_forward_target: Default::default(),
+            pending_operation: Default::default(),
+            scale_target: Default::default(),
         }
     }
 
     pub fn build(self) -> Window<'static> {
-        let (tabs, dialogs) = self.tabs_dialogs();
+        let clipboard = Clipboard::new(self.config.clipboard_mode)
+            .map(|clipboard| Rc::new(RefCell::new(clipboard)));
+
+        let (tabs, dialogs) = self.tabs_dialogs(&clipboard);
 
         let builder = Window::builder().tabs(tabs).dialogs(dialogs);
 
+        let keymap = &self.config.keymap;
+
         // Configure Action
         let tx = self.tx.clone();
-        let builder = builder.action(
-            UserEvent::Key(KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT)),
+        let builder = builder.actions(
+            keymap.events(Action::OpenNamespaces),
             move |w: &mut Window| {
                 tx.send(NamespaceRequest::Get.into())
                     .expect("Failed to send NamespaceRequest::Get");
@@ -145,12 +142,15 @@ impl WindowInit {
         );
 
         let tx = self.tx.clone();
-        let builder = builder.action('n', move |w: &mut Window| {
-            tx.send(NamespaceRequest::Get.into())
-                .expect("Failed to send NamespaceRequest::Get");
-            w.open_dialog(SINGLE_NAMESPACE_DIALOG_ID);
-            EventResult::Nop
-        });
+        let builder = builder.actions(
+            keymap.events(Action::OpenNamespace),
+            move |w: &mut Window| {
+                tx.send(NamespaceRequest::Get.into())
+                    .expect("Failed to send NamespaceRequest::Get");
+                w.open_dialog(SINGLE_NAMESPACE_DIALOG_ID);
+                EventResult::Nop
+            },
+        );
 
         let fn_close = |w: &mut Window| {
             if w.opening_dialog() {
@@ -162,7 +162,7 @@ impl WindowInit {
         };
 
         let tx = self.tx.clone();
-        let builder = builder.action('c', move |w: &mut Window| {
+        let builder = builder.actions(keymap.events(Action::OpenContext), move |w: &mut Window| {
             tx.send(ContextRequest::Get.into())
                 .expect("Failed to send ContextRequest::Get");
             w.open_dialog(CONTEXT_DIALOG_ID);
@@ -176,8 +176,43 @@ impl WindowInit {
 
         let open_yaml = open_yaml(self.tx.clone());
 
-        let builder = builder.action('h', open_help).action('?', open_help);
-        let builder = builder.action('y', open_yaml);
+        let builder = builder.actions(keymap.events(Action::OpenHelp), open_help);
+        let builder = builder.actions(keymap.events(Action::OpenYaml), open_yaml);
+        let builder = builder.actions(keymap.events(Action::CopyNames), copy_names(clipboard));
+
+        let builder = builder
+            .actions(
+                keymap.events(Action::PortForward),
+                open_port_forward_dialog(self.tx.clone(), self.port_forward_target.clone()),
+            )
+            .actions(
+                keymap.events(Action::PortForwardList),
+                open_port_forward_list_dialog(),
+            );
+
+        // クラスタに変更を加える操作は読み取り専用モードでは登録しない
+        let builder = if self.config.read_only {
+            builder
+        } else {
+            builder
+                .actions(
+                    keymap.events(Action::Delete),
+                    delete_pod(self.pending_operation.clone()),
+                )
+                .actions(
+                    keymap.events(Action::Restart),
+                    restart_workload(self.pending_operation.clone()),
+                )
+                .actions(
+                    keymap.events(Action::Scale),
+                    scale_workload(
+                        self.tx.clone(),
+                        self.scale_target.clone(),
+                        self.pending_operation.clone(),
+                    ),
+                )
+                .actions(keymap.events(Action::Edit), edit_yaml(self.tx.clone()))
+        };
 
         //　分割方向を変更する
         let toggle_split_direction = move |w: &mut Window| {
@@ -188,22 +223,24 @@ impl WindowInit {
             EventResult::Nop
         };
 
-        let builder = builder.action(
-            KeyEvent::new(KeyCode::Char('S'), KeyModifiers::SHIFT),
-            toggle_split_direction,
-        );
+        let builder = builder.actions(keymap.events(Action::ToggleSplit), toggle_split_direction);
 
-        let builder = builder.action('q', fn_close).action(KeyCode::Esc, fn_close);
+        let builder = builder.actions(keymap.events(Action::Quit), fn_close);
 
         let context = self.context.clone();
         let namespaces = self.namespaces.clone();
+        let read_only = if self.config.read_only {
+            " [read-only]"
+        } else {
+            ""
+        };
 
         let header = Header::new_callback(2, move |theme: &HeaderTheme| {
             let context = context.borrow();
             let namespaces = namespaces.borrow();
 
             Paragraph::new(vec![
-                Line::from(format!(" ctx: {}", context)).style(theme.line_styles[0]),
+                Line::from(format!(" ctx: {}{}", context, read_only)).style(theme.line_styles[0]),
                 Line::from(format!(" ns: {}", namespaces)).style(theme.line_styles[1]),
             ])
             .style(theme.base_style)
@@ -211,38 +248,62 @@ impl WindowInit {
 
         let builder = builder.header(header);
 
-        let tab_theme = TabTheme::from(self.theme.clone());
+        let tab_theme = TabTheme::from(self.config.theme.clone());
 
         let builder = builder.tab_theme(tab_theme);
 
-        let header_theme = HeaderTheme::from(self.theme.clone());
+        let header_theme = HeaderTheme::from(self.config.theme.clone());
 
         let builder = builder.header_theme(header_theme);
 
-        let builder = builder.base_style(self.theme.base);
+        let builder = builder.base_style(self.config.theme.base);
 
         builder.build()
     }
 
-    fn tabs_dialogs(&self) -> (Vec<Tab<'static>>, Vec<Dialog<'static>>) {
-        let clipboard =
-            Clipboard::new(self.clipboard_mode).map(|clipboard| Rc::new(RefCell::new(clipboard)));
+    fn tab_params<'a, C>(
+        &'a self,
+        clipboard: &'a Option<Rc<RefCell<Clipboard>>>,
+        columns: C,
+    ) -> TabParams<'a, C> {
+        TabParams {
+            tx: &self.tx,
+            clipboard,
+            split_direction: self.config.split_direction,
+            columns,
+            theme: self.config.theme.component.clone(),
+            keymap: &self.config.keymap,
+        }
+    }
 
+    fn tabs_dialogs(
+        &self,
+        clipboard: &Option<Rc<RefCell<Clipboard>>>,
+    ) -> (Vec<Tab<'static>>, Vec<Dialog<'static>>) {
         let PodTab {
             tab: pod_tab,
+            exec_dialog: pod_exec_dialog,
+            describe_dialog: pod_describe_dialog,
             log_query_help_dialog,
+            log_save_dialog,
             pod_columns_dialog,
             pod_filter_help_dialog,
         } = PodTab::new(
             "Pod",
-            &self.tx,
-            &clipboard,
-            self.split_mode,
+            self.tab_params(clipboard, self.config.pod_columns.clone()),
+            self.context.clone(),
             self.namespaces.clone(),
-            self.default_pod_columns.clone(),
-            self.pod_label_columns.clone(),
-            self.theme.component.clone(),
-            self.log_max_lines,
+            self.log_query_history.clone(),
+            self.config.logging.clone(),
+        );
+
+        let WorkloadTab {
+            tab: workload_tab,
+            workload_columns_dialog,
+            workload_filter_help_dialog,
+        } = WorkloadTab::new(
+            "Workload",
+            self.tab_params(clipboard, self.config.workload_columns.clone()),
         );
 
         let ConfigTab {
@@ -251,30 +312,28 @@ impl WindowInit {
             config_filter_help_dialog,
         } = ConfigTab::new(
             "Config",
-            &self.tx,
-            &clipboard,
-            self.split_mode,
-            self.default_config_columns.clone(),
-            self.config_label_columns.clone(),
-            self.theme.component.clone(),
+            self.tab_params(clipboard, self.config.config_columns.clone()),
         );
 
         let NetworkTab {
             tab: network_tab,
             network_columns_dialog,
             network_filter_help_dialog,
+            related_resource_dialog,
         } = NetworkTab::new(
             "Network",
-            &self.tx,
-            &clipboard,
-            self.split_mode,
-            self.default_network_columns.clone(),
-            self.network_label_columns.clone(),
-            self.theme.component.clone(),
+            self.tab_params(clipboard, self.config.network_columns.clone()),
         );
 
-        let EventTab { tab: event_tab } =
-            EventTab::new("Event", &clipboard, self.theme.component.clone());
+        let EventTab {
+            tab: event_tab,
+            event_filter_help_dialog,
+        } = EventTab::new(
+            "Event",
+            &self.tx,
+            self.config.theme.component.clone(),
+            &self.config.keymap,
+        );
 
         let NodeTab {
             tab: node_tab,
@@ -282,49 +341,89 @@ impl WindowInit {
             node_filter_help_dialog,
         } = NodeTab::new(
             "Node",
-            &self.tx,
-            &clipboard,
-            self.split_mode,
-            self.default_node_columns.clone(),
-            self.node_label_columns.clone(),
-            self.theme.component.clone(),
+            self.tab_params(clipboard, self.config.node_columns.clone()),
         );
 
         let ApiTab {
             tab: api_tab,
             dialog: api_dialog,
-        } = ApiTab::new("API", &self.tx, &clipboard, self.theme.component.clone());
+        } = ApiTab::new(
+            "API",
+            &self.tx,
+            clipboard,
+            self.config.theme.component.clone(),
+            &self.config.keymap,
+        );
 
         let YamlTab {
             tab: yaml_tab,
             kind_dialog: yaml_kind_dialog,
             name_dialog: yaml_name_dialog,
             not_found_dialog: yaml_not_found_dialog,
-        } = YamlTab::new("Yaml", &self.tx, &clipboard, self.theme.component.clone());
+        } = YamlTab::new(
+            "Yaml",
+            &self.tx,
+            clipboard,
+            self.config.theme.component.clone(),
+            &self.config.keymap,
+        );
 
         let ContextDialog {
             widget: context_dialog,
-        } = ContextDialog::new(&self.tx, self.theme.clone());
+        } = ContextDialog::new(&self.tx, self.config.theme.clone());
 
         let SingleNamespaceDialog {
             widget: single_namespace_dialog,
-        } = SingleNamespaceDialog::new(&self.tx, self.theme.clone());
+        } = SingleNamespaceDialog::new(&self.tx, self.config.theme.clone());
 
         let MultipleNamespacesDialog {
             widget: multiple_namespaces_dialog,
-        } = MultipleNamespacesDialog::new(&self.tx, self.theme.clone());
+        } = MultipleNamespacesDialog::new(&self.tx, self.config.theme.clone());
 
         let HelpDialog {
             widget: help_dialog,
-        } = HelpDialog::new(self.theme.clone());
+        } = HelpDialog::new(self.config.theme.clone(), &self.config.keymap);
 
         let YamlDialog {
             widget: yaml_dialog,
-        } = YamlDialog::new(&clipboard, self.theme.clone());
+        } = YamlDialog::new(clipboard, self.config.theme.clone());
+
+        let PortForwardDialog {
+            widget: port_forward_dialog,
+        } = PortForwardDialog::new(
+            &self.tx,
+            &self.port_forward_target,
+            self.config.theme.clone(),
+        );
+
+        let PortForwardListDialog {
+            widget: port_forward_list_dialog,
+        } = PortForwardListDialog::new(&self.tx, self.config.theme.clone());
+
+        let OperationConfirmDialog {
+            widget: operation_confirm_dialog,
+        } = OperationConfirmDialog::new(
+            &self.tx,
+            &self.pending_operation,
+            self.config.theme.clone(),
+        );
+
+        let OperationScaleDialog {
+            widget: operation_scale_dialog,
+        } = OperationScaleDialog::new(
+            &self.scale_target,
+            &self.pending_operation,
+            self.config.theme.clone(),
+        );
+
+        let EditErrorDialog {
+            widget: edit_error_dialog,
+        } = EditErrorDialog::new(self.config.theme.clone());
 
         // Init Window
         let tabs = vec![
             pod_tab,
+            workload_tab,
             config_tab,
             network_tab,
             event_tab,
@@ -333,7 +432,7 @@ impl WindowInit {
             yaml_tab,
         ];
 
-        let dialog_theme = DialogTheme::from(self.theme.clone());
+        let dialog_theme = DialogTheme::from(self.config.theme.clone());
 
         let dialog_widgets = vec![
             context_dialog,
@@ -345,12 +444,24 @@ impl WindowInit {
             yaml_not_found_dialog,
             help_dialog,
             log_query_help_dialog,
+            log_save_dialog,
             pod_columns_dialog,
             pod_filter_help_dialog,
+            pod_exec_dialog,
+            pod_describe_dialog,
+            workload_columns_dialog,
+            workload_filter_help_dialog,
+            port_forward_dialog,
+            port_forward_list_dialog,
+            operation_confirm_dialog,
+            operation_scale_dialog,
+            edit_error_dialog,
             config_filter_help_dialog,
             config_columns_dialog,
             network_filter_help_dialog,
             network_columns_dialog,
+            related_resource_dialog,
+            event_filter_help_dialog,
             node_columns_dialog,
             node_filter_help_dialog,
             yaml_dialog,
@@ -370,73 +481,110 @@ impl WindowInit {
     }
 }
 
+/// 選択中の行のyamlを表示する
+///
+/// 行をマークしているときはマークしたすべての行のyamlをまとめて表示する
 fn open_yaml(tx: Sender<Message>) -> impl CallbackFn {
     move |w: &mut Window| {
         let widget = w.active_tab().active_widget();
 
         match widget.id() {
-            POD_WIDGET_ID | CONFIG_WIDGET_ID | NETWORK_WIDGET_ID => {}
+            POD_WIDGET_ID | CONFIG_WIDGET_ID | NETWORK_WIDGET_ID | WORKLOAD_WIDGET_ID => {}
             _ => {
                 return EventResult::Ignore;
             }
         }
 
-        let Some(SelectedItem::TableRow { metadata, .. }) = widget.widget_item() else {
-            return EventResult::Ignore;
-        };
+        let req: Vec<GetRequest> = widget
+            .as_table()
+            .marked_or_selected_items()
+            .iter()
+            .filter_map(|item| item.metadata.as_ref().and_then(get_request))
+            .collect();
 
-        let Some(ref metadata) = metadata else {
+        if req.is_empty() {
             return EventResult::Ignore;
-        };
+        }
 
-        let Some(ref namespace) = metadata.get("namespace") else {
-            return EventResult::Ignore;
-        };
+        tx.send(req.into())
+            .expect("Failed to send YamlMessage::Request");
 
-        let Some(ref name) = metadata.get("name") else {
-            return EventResult::Ignore;
-        };
+        w.widget_clear(YAML_DIALOG_ID);
+        w.open_dialog(YAML_DIALOG_ID);
 
-        let version = metadata.get("version");
-
-        let kind = match metadata.get("kind").map(|v| v.as_str()) {
-            Some(Pod::KIND) => GetYamlKind::Pod,
-            Some(ConfigMap::KIND) => GetYamlKind::ConfigMap,
-            Some(Secret::KIND) => GetYamlKind::Secret,
-            Some(Ingress::KIND) => GetYamlKind::Ingress,
-            Some(Service::KIND) => GetYamlKind::Service,
-            Some(NetworkPolicy::KIND) => GetYamlKind::NetworkPolicy,
-            Some(Gateway::KIND) => {
-                match version.as_ref().map(|v| v.as_str()) {
-                    Some("v1") => GetYamlKind::Gateway(GatewayVersion::V1),
-                    Some("v1beta1") => GetYamlKind::Gateway(GatewayVersion::V1Beta1),
-                    _ => unreachable!(),
-                }
-            }
-            Some(HTTPRoute::KIND) => {
-                match version.as_ref().map(|v| v.as_str()) {
-                    Some("v1") => GetYamlKind::HTTPRoute(HTTPRouteVersion::V1),
-                    Some("v1beta1") => GetYamlKind::HTTPRoute(HTTPRouteVersion::V1Beta1),
-                    _ => unreachable!(),
-                }
+        EventResult::Nop
+    }
+}
+
+fn get_request(metadata: &BTreeMap<String, String>) -> Option<GetRequest> {
+    let namespace = metadata.get("namespace")?;
+
+    let name = metadata.get("name")?;
+
+    let version = metadata.get("version");
+
+    let kind = match metadata.get("kind").map(|v| v.as_str()) {
+        Some(Pod::KIND) => GetYamlKind::Pod,
+        Some(ConfigMap::KIND) => GetYamlKind::ConfigMap,
+        Some(Secret::KIND) => GetYamlKind::Secret,
+        Some(Ingress::KIND) => GetYamlKind::Ingress,
+        Some(Service::KIND) => GetYamlKind::Service,
+        Some(NetworkPolicy::KIND) => GetYamlKind::NetworkPolicy,
+        Some(Gateway::KIND) => {
+            match version.as_ref().map(|v| v.as_str()) {
+                Some("v1") => GetYamlKind::Gateway(GatewayVersion::V1),
+                Some("v1beta1") => GetYamlKind::Gateway(GatewayVersion::V1Beta1),
+                _ => unreachable!(),
             }
-            _ => {
-                unreachable!();
+        }
+        Some(HTTPRoute::KIND) => {
+            match version.as_ref().map(|v| v.as_str()) {
+                Some("v1") => GetYamlKind::HTTPRoute(HTTPRouteVersion::V1),
+                Some("v1beta1") => GetYamlKind::HTTPRoute(HTTPRouteVersion::V1Beta1),
+                _ => unreachable!(),
             }
+        }
+        Some(Deployment::KIND) => GetYamlKind::Deployment,
+        Some(StatefulSet::KIND) => GetYamlKind::StatefulSet,
+        Some(DaemonSet::KIND) => GetYamlKind::DaemonSet,
+        Some(Job::KIND) => GetYamlKind::Job,
+        Some(CronJob::KIND) => GetYamlKind::CronJob,
+        _ => return None,
+    };
+
+    Some(GetRequest {
+        name: name.to_string(),
+        namespace: namespace.to_string(),
+        kind,
+    })
+}
+
+/// マークした行（マークがなければ選択中の行）の名前を改行区切りでクリップボードにコピーする
+fn copy_names(clipboard: Option<Rc<RefCell<Clipboard>>>) -> impl CallbackFn {
+    move |w: &mut Window| {
+        let Widget::Table(table) = w.active_tab().active_widget() else {
+            return EventResult::Ignore;
         };
 
-        tx.send(
-            GetRequest {
-                name: name.to_string(),
-                namespace: namespace.to_string(),
-                kind,
-            }
-            .into(),
-        )
-        .expect("Failed to send YamlMessage::Request");
+        let names: Vec<String> = table
+            .marked_or_selected_items()
+            .iter()
+            .filter_map(|item| item.metadata.as_ref()?.get("name").cloned())
+            .collect();
 
-        w.widget_clear(YAML_DIALOG_ID);
-        w.open_dialog(YAML_DIALOG_ID);
+        if names.is_empty() {
+            return EventResult::Ignore;
+        }
+
+        let Some(clipboard) = &clipboard else {
+            logger!(error, "Clipboard is not available");
+            return EventResult::Nop;
+        };
+
+        match clipboard.borrow_mut().set_contents(names.join("\n")) {
+            Ok(()) => logger!(info, "Copied {} names to clipboard", names.len()),
+            Err(e) => logger!(error, "Failed to copy names to clipboard: {}", e),
+        }
 
         EventResult::Nop
     }
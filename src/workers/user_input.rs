@@ -1,4 +1,7 @@
-use std::time::Duration;
+use std::{
+    sync::{Arc, Condvar, Mutex},
+    time::Duration,
+};
 
 use anyhow::Result;
 use crossbeam::channel::Sender;
@@ -10,16 +13,99 @@ use crate::{
     panic_set_hook,
 };
 
+/// イベントを待つ間隔
+///
+/// 一時停止の要求にはこの間隔で応答する
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Default)]
+struct SuspendState {
+    requested: bool,
+    suspended: bool,
+}
+
+/// ユーザー入力の読み取りを一時停止するためのハンドル
+///
+/// 外部のプロセスに端末を明け渡している間、入力を横取りしないようにする
+#[derive(Debug, Default, Clone)]
+pub struct InputSuspender {
+    state: Arc<(Mutex<SuspendState>, Condvar)>,
+}
+
+impl InputSuspender {
+    /// 入力の読み取りが止まるまで待つ
+    ///
+    /// 返り値をドロップすると読み取りを再開する
+    pub fn suspend(&self) -> SuspendGuard<'_> {
+        let (lock, cvar) = &*self.state;
+
+        let mut state = lock.lock().expect("Failed to lock SuspendState");
+
+        state.requested = true;
+
+        let _state = cvar
+            .wait_while(state, |state| !state.suspended)
+            .expect("Failed to wait for SuspendState");
+
+        SuspendGuard { suspender: self }
+    }
+
+    /// 一時停止が要求されていれば、再開されるまでブロックする
+    fn wait_if_suspended(&self) {
+        let (lock, cvar) = &*self.state;
+
+        let mut state = lock.lock().expect("Failed to lock SuspendState");
+
+        if !state.requested {
+            return;
+        }
+
+        state.suspended = true;
+
+        cvar.notify_all();
+
+        let mut state = cvar
+            .wait_while(state, |state| state.requested)
+            .expect("Failed to wait for SuspendState");
+
+        state.suspended = false;
+    }
+}
+
+pub struct SuspendGuard<'a> {
+    suspender: &'a InputSuspender,
+}
+
+impl Drop for SuspendGuard<'_> {
+    fn drop(&mut self) {
+        let (lock, cvar) = &*self.suspender.state;
+
+        if let Ok(mut state) = lock.lock() {
+            state.requested = false;
+            cvar.notify_all();
+        }
+    }
+}
+
 /// ユーザー入力を受け付けるワーカースレッドを生成する構造体
 /// イベントデータはチャネルを介してメインスレッドに送信される
 pub struct UserInput {
     tx: Sender<Message>,
     tx_shutdown: Sender<Result<()>>,
+    suspender: InputSuspender,
 }
 
 impl UserInput {
-    pub fn new(tx: Sender<Message>, tx_shutdown: Sender<Result<()>>) -> Self {
-        Self { tx, tx_shutdown }
+    pub fn new(
+        tx: Sender<Message>,
+        tx_shutdown: Sender<Result<()>>,
+        suspender: InputSuspender,
+    ) -> Self {
+        Self {
+            tx,
+            tx_shutdown,
+            suspender,
+        }
     }
 
     pub fn start(&self) {
@@ -50,7 +136,9 @@ impl UserInput {
 
     fn poll(&self) -> Result<()> {
         loop {
-            if let Ok(true) = poll(Duration::from_secs(1)) {
+            self.suspender.wait_if_suspended();
+
+            if let Ok(true) = poll(POLL_INTERVAL) {
                 let ev = read()?;
 
                 logger!(debug, "{:?}", ev);
@@ -66,7 +154,9 @@ impl UserInput {
                         }
                     }
                     CEvent::Mouse(ev) => self.tx.send(Message::User(UserEvent::Mouse(ev)))?,
-                    CEvent::Resize(..) => {}
+                    CEvent::Resize(width, height) => {
+                        self.tx.send(UserEvent::Resize(width, height).into())?
+                    }
                     CEvent::FocusGained => self.tx.send(UserEvent::FocusGained.into())?,
                     CEvent::FocusLost => self.tx.send(UserEvent::FocusLost.into())?,
                     CEvent::Paste(_) => {}
@@ -75,3 +165,48 @@ impl UserInput {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+    };
+
+    #[test]
+    fn suspend_blocks_until_guard_is_dropped() {
+        let suspender = InputSuspender::default();
+        let resumed = Arc::new(AtomicBool::new(false));
+
+        let handle = thread::spawn({
+            let suspender = suspender.clone();
+            let resumed = resumed.clone();
+
+            move || {
+                loop {
+                    suspender.wait_if_suspended();
+
+                    if resumed.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    thread::sleep(Duration::from_millis(1));
+                }
+            }
+        });
+
+        let guard = suspender.suspend();
+
+        assert!(suspender.state.0.lock().unwrap().suspended);
+
+        resumed.store(true, Ordering::SeqCst);
+
+        drop(guard);
+
+        handle.join().unwrap();
+
+        assert!(!suspender.state.0.lock().unwrap().suspended);
+    }
+}